@@ -3,3 +3,11 @@ sweet-enemies-pack-name = Sweet Enemies
 chocolate-bar-name = Chocolate Bar
 
 gummy-bear-name = Gummy Bear
+
+gingerbread-golem-name = Gingerbread Golem
+
+sugar-rush-name = Sugar Rush
+sugar-rush-description = Kill 100 enemies.
+
+sweet-tooth-name = Sweet Tooth
+sweet-tooth-description = Kill 1000 enemies.
@@ -1,3 +1,8 @@
 bow-of-artemis-name = Bow of Artemis
 
 bident-of-hades-name = Bident of Hades
+
+coin-of-tyche-name = Coin of Tyche
+
+favoured-by-tyche-name = Favoured by Tyche
+favoured-by-tyche-description = Have 500 $ in a run.
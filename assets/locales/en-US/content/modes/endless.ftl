@@ -0,0 +1,3 @@
+endless-mode-name = Endless Mode
+
+endless-mode-hud-score = Score { $score }
@@ -0,0 +1,5 @@
+mutator-double-enemy-speed-name = Double Enemy Speed
+mutator-no-market-name = No Market
+mutator-glass-cannon-name = Glass Cannon
+mutator-no-dashing-name = No Dashing
+mutator-experience-decay-name = Experience Decay
@@ -9,3 +9,9 @@ dodgy-description = +{ $bonus }% Dodge Chance
 
 regenerative-name = Regenerative { $rarity }
 regenerative-description = +{ $bonus } HP Regeneration / Seconds
+
+lucky-name = Lucky { $rarity }
+lucky-description = +{ $bonus } Luck
+
+seasoned-name = Seasoned
+seasoned-description = Reach level 10 in a run.
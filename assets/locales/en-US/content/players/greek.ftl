@@ -3,3 +3,9 @@ greek-mythology-name = Greek Mythology
 artemis-name = Artemis
 
 hades-name = Hades
+
+mistress-of-the-hunt-name = Mistress of the Hunt
+mistress-of-the-hunt-description = Win a run with Artemis.
+
+lord-of-the-underworld-name = Lord of the Underworld
+lord-of-the-underworld-description = Win a run with Hades.
@@ -0,0 +1 @@
+achievement-toast-title = Achievement Unlocked
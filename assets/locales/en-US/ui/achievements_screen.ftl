@@ -0,0 +1,8 @@
+achievements-screen-title = Achievements
+achievements-screen-summary = Unlocked: { $unlocked } / { $total }
+
+achievements-screen-unlocked-status = Unlocked
+achievements-screen-locked-status = Locked
+achievements-screen-progress-status = { $current } / { $target }
+
+achievements-screen-back-button = Back
@@ -0,0 +1,8 @@
+game-mode-selection-screen-back-button = Back
+
+game-mode-selection-screen-game-mode-button = { $name }
+game-mode-selection-screen-game-mode-difficulty-button = { $name } - Difficulty { $difficulty }
+game-mode-selection-screen-locked-game-mode-difficulty-button = { $name } - Difficulty { $difficulty } (Locked)
+
+game-mode-selection-screen-enabled-mutator-button = { $name } (On)
+game-mode-selection-screen-disabled-mutator-button = { $name } (Off)
@@ -1,3 +1,10 @@
 level-up-screen-select-button = Select
 
-level-up-screen-reroll-button = Reroll - { $cost }
+level-up-screen-lock-button = Lock
+level-up-screen-unlock-button = Unlock
+
+level-up-screen-banish-button = Banish ({ $remaining })
+
+level-up-screen-skip-button = Skip + { format-currency }
+
+level-up-screen-reroll-button = Reroll - { format-currency }
@@ -1,3 +1,7 @@
 main-menu-play-button = Play
+main-menu-daily-challenge-button = Daily Challenge
+main-menu-attempted-daily-challenge-button = Daily Challenge (Practice)
+main-menu-unlocks-button = Unlocks
+main-menu-achievements-button = Achievements
 main-menu-settings-button = Settings
 main-menu-quit-button = Quit
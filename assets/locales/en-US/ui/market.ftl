@@ -1,4 +1,4 @@
-market-refresh-button = Refresh - { $cost }
+market-refresh-button = Refresh - { format-currency }
 
 market-lock-button = Lock
 market-unlock-button = Unlock
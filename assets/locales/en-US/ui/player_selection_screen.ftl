@@ -1 +1,3 @@
 player-selection-screen-back-button = Back
+
+player-selection-screen-locked-player-button = { $name } (Locked)
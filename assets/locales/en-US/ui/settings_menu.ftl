@@ -1,4 +1,66 @@
+settings-menu-general-section = General
+settings-menu-video-section = Video
+settings-menu-audio-section = Audio
+settings-menu-accessibility-section = Accessibility
+settings-menu-controls-section = Controls
+
 settings-menu-language-setting-name = Language:
 settings-menu-language-setting-value = English
 
+settings-menu-aim-with-mouse-setting-name = Aim with Mouse:
+settings-menu-fire-manually-setting-name = Fire Manually:
+settings-menu-damage-numbers-setting-name = Damage Numbers:
+settings-menu-hit-flashes-setting-name = Hit Flashes:
+settings-menu-death-effects-setting-name = Death Effects:
+settings-menu-enemy-health-bars-setting-name = Enemy Health Bars:
+settings-menu-window-mode-setting-name = Window Mode:
+settings-menu-resolution-setting-name = Resolution:
+settings-menu-vsync-setting-name = VSync:
+settings-menu-frame-rate-limit-setting-name = Frame Rate Limit:
+settings-menu-master-volume-setting-name = Master Volume:
+settings-menu-music-volume-setting-name = Music Volume:
+settings-menu-sound-effects-volume-setting-name = Sound Effects Volume:
+settings-menu-ui-scale-setting-name = UI Scale:
+settings-menu-color-palette-setting-name = Color Palette:
+settings-menu-reduce-screen-effects-setting-name = Reduce Screen Effects:
+settings-menu-high-contrast-setting-name = High Contrast:
+
+settings-menu-toggle-on = On
+settings-menu-toggle-off = Off
+
+settings-menu-window-mode-windowed = Windowed
+settings-menu-window-mode-borderless-fullscreen = Borderless Fullscreen
+settings-menu-window-mode-fullscreen = Fullscreen
+
+settings-menu-frame-rate-limit-unlimited = Unlimited
+
+settings-menu-enemy-health-bars-always = Always
+settings-menu-enemy-health-bars-damaged-only = Damaged Only
+settings-menu-enemy-health-bars-never = Never
+
+settings-menu-color-palette-default = Default
+settings-menu-color-palette-deuteranopia = Deuteranopia
+settings-menu-color-palette-protanopia = Protanopia
+settings-menu-color-palette-tritanopia = Tritanopia
+
+settings-menu-key-binding-up = Move Up
+settings-menu-key-binding-left = Move Left
+settings-menu-key-binding-down = Move Down
+settings-menu-key-binding-right = Move Right
+settings-menu-key-binding-dash = Dash
+settings-menu-key-binding-pause = Pause
+settings-menu-key-binding-market = Open Market
+settings-menu-key-binding-fire = Fire
+settings-menu-key-binding-select = Select
+settings-menu-key-binding-back = Back
+
+settings-menu-key-binding-capturing-key = Press a key...
+settings-menu-key-binding-capturing-gamepad-button = Press a button...
+
+settings-menu-key-binding-status = Escape to cancel, Backspace to unbind.
+settings-menu-key-binding-conflict = { $input } is already bound to { $binding }.
+settings-menu-key-binding-required = { $binding } is needed in menus, so it can't be unbound.
+
+settings-menu-reset-key-bindings-button = Reset to Defaults
+
 settings-menu-back-button = Back
@@ -0,0 +1,7 @@
+unlock-screen-title = Unlocks
+unlock-screen-ambrosia-text = Ambrosia: { $ambrosia }
+
+unlock-screen-unlock-button = { $name } - { $cost } Ambrosia
+unlock-screen-unlocked-button = { $name } (Unlocked)
+
+unlock-screen-back-button = Back
@@ -3,3 +3,11 @@ sweet-enemies-pack-name = Tatlı Düşmanlar
 chocolate-bar-name = Tablet Çikolata
 
 gummy-bear-name = Jelibon Ayıcık
+
+gingerbread-golem-name = Zencefilli Kurabiye Golemi
+
+sugar-rush-name = Şeker Koması
+sugar-rush-description = 100 düşman öldür.
+
+sweet-tooth-name = Tatlı Düşkünü
+sweet-tooth-description = 1000 düşman öldür.
@@ -1,3 +1,8 @@
 bow-of-artemis-name = Artemis'in Yayı
 
 bident-of-hades-name = Hades'in İki Uçlu Çatalı
+
+coin-of-tyche-name = Tyche'nin Sikkesi
+
+favoured-by-tyche-name = Tyche'nin Gözdesi
+favoured-by-tyche-description = Bir oyunda 500 $ biriktir.
@@ -0,0 +1,3 @@
+endless-mode-name = Sonsuz Mod
+
+endless-mode-hud-score = Skor { $score }
@@ -0,0 +1,5 @@
+mutator-double-enemy-speed-name = İki Kat Düşman Hızı
+mutator-no-market-name = Marketsiz
+mutator-glass-cannon-name = Cam Top
+mutator-no-dashing-name = Atılmasız
+mutator-experience-decay-name = Deneyim Kaybı
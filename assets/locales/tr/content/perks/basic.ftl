@@ -9,3 +9,9 @@ dodgy-description = +{ $bonus }% Zarardan Kaçınma Şansı
 
 regenerative-name = Yenilenici { $rarity }
 regenerative-description = +{ $bonus } Can Yenileme / Saniye
+
+lucky-name = Şanslı { $rarity }
+lucky-description = +{ $bonus } Şans
+
+seasoned-name = Tecrübeli
+seasoned-description = Bir oyunda 10. seviyeye ulaş.
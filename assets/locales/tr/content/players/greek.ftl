@@ -3,3 +3,9 @@ greek-mythology-name = Yünan Mitolojisi
 artemis-name = Artemis
 
 hades-name = Hades
+
+mistress-of-the-hunt-name = Avın Hanımı
+mistress-of-the-hunt-description = Artemis ile bir oyunu kazan.
+
+lord-of-the-underworld-name = Yeraltının Efendisi
+lord-of-the-underworld-description = Hades ile bir oyunu kazan.
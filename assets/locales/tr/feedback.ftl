@@ -0,0 +1 @@
+feedback-miss = Iska
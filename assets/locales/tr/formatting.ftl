@@ -0,0 +1 @@
+format-currency = { $amount } $
@@ -0,0 +1 @@
+achievement-toast-title = Başarım Açıldı
@@ -0,0 +1,8 @@
+achievements-screen-title = Başarımlar
+achievements-screen-summary = Açılan: { $unlocked } / { $total }
+
+achievements-screen-unlocked-status = Açıldı
+achievements-screen-locked-status = Kilitli
+achievements-screen-progress-status = { $current } / { $target }
+
+achievements-screen-back-button = Geri git
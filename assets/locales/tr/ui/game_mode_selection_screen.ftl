@@ -0,0 +1,8 @@
+game-mode-selection-screen-back-button = Geri git
+
+game-mode-selection-screen-game-mode-button = { $name }
+game-mode-selection-screen-game-mode-difficulty-button = { $name } - Zorluk { $difficulty }
+game-mode-selection-screen-locked-game-mode-difficulty-button = { $name } - Zorluk { $difficulty } (Kilitli)
+
+game-mode-selection-screen-enabled-mutator-button = { $name } (Açık)
+game-mode-selection-screen-disabled-mutator-button = { $name } (Kapalı)
@@ -1,3 +1,10 @@
 level-up-screen-select-button = Seç
 
-level-up-screen-reroll-button = Yenile - { $cost }
+level-up-screen-lock-button = Kilitle
+level-up-screen-unlock-button = Kilidi aç
+
+level-up-screen-banish-button = Yasakla ({ $remaining })
+
+level-up-screen-skip-button = Atla + { format-currency }
+
+level-up-screen-reroll-button = Yenile - { format-currency }
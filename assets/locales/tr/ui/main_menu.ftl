@@ -1,3 +1,7 @@
 main-menu-play-button = Oyna
+main-menu-daily-challenge-button = Günlük Meydan Okuma
+main-menu-attempted-daily-challenge-button = Günlük Meydan Okuma (Antrenman)
+main-menu-unlocks-button = Kilit Açma
+main-menu-achievements-button = Başarımlar
 main-menu-settings-button = Ayarlar
 main-menu-quit-button = Kapat
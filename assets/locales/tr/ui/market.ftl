@@ -1,4 +1,4 @@
-market-refresh-button = Yenile - { $cost }
+market-refresh-button = Yenile - { format-currency }
 
 market-lock-button = Kilitle
 market-unlock-button = Kilidi aç
@@ -1 +1,3 @@
 player-selection-screen-back-button = Geri git
+
+player-selection-screen-locked-player-button = { $name } (Kilitli)
@@ -1,4 +1,66 @@
+settings-menu-general-section = Genel
+settings-menu-video-section = Görüntü
+settings-menu-audio-section = Ses
+settings-menu-accessibility-section = Erişilebilirlik
+settings-menu-controls-section = Kontroller
+
 settings-menu-language-setting-name = Dil:
 settings-menu-language-setting-value = Türkçe
 
+settings-menu-aim-with-mouse-setting-name = Fare ile nişan al:
+settings-menu-fire-manually-setting-name = Elle ateş et:
+settings-menu-damage-numbers-setting-name = Hasar sayıları:
+settings-menu-hit-flashes-setting-name = Vuruş parlaması:
+settings-menu-death-effects-setting-name = Ölüm efektleri:
+settings-menu-enemy-health-bars-setting-name = Düşman can barları:
+settings-menu-window-mode-setting-name = Pencere modu:
+settings-menu-resolution-setting-name = Çözünürlük:
+settings-menu-vsync-setting-name = Dikey senkronizasyon:
+settings-menu-frame-rate-limit-setting-name = Kare hızı sınırı:
+settings-menu-master-volume-setting-name = Ana ses seviyesi:
+settings-menu-music-volume-setting-name = Müzik ses seviyesi:
+settings-menu-sound-effects-volume-setting-name = Efekt ses seviyesi:
+settings-menu-ui-scale-setting-name = Arayüz ölçeği:
+settings-menu-color-palette-setting-name = Renk paleti:
+settings-menu-reduce-screen-effects-setting-name = Ekran efektlerini azalt:
+settings-menu-high-contrast-setting-name = Yüksek kontrast:
+
+settings-menu-toggle-on = Açık
+settings-menu-toggle-off = Kapalı
+
+settings-menu-window-mode-windowed = Pencereli
+settings-menu-window-mode-borderless-fullscreen = Kenarlıksız tam ekran
+settings-menu-window-mode-fullscreen = Tam ekran
+
+settings-menu-frame-rate-limit-unlimited = Sınırsız
+
+settings-menu-enemy-health-bars-always = Her zaman
+settings-menu-enemy-health-bars-damaged-only = Yalnızca hasar alınca
+settings-menu-enemy-health-bars-never = Asla
+
+settings-menu-color-palette-default = Varsayılan
+settings-menu-color-palette-deuteranopia = Döteranopi
+settings-menu-color-palette-protanopia = Protanopi
+settings-menu-color-palette-tritanopia = Tritanopi
+
+settings-menu-key-binding-up = Yukarı git
+settings-menu-key-binding-left = Sola git
+settings-menu-key-binding-down = Aşağı git
+settings-menu-key-binding-right = Sağa git
+settings-menu-key-binding-dash = Atıl
+settings-menu-key-binding-pause = Duraklat
+settings-menu-key-binding-market = Marketi aç
+settings-menu-key-binding-fire = Ateş et
+settings-menu-key-binding-select = Seç
+settings-menu-key-binding-back = Geri git
+
+settings-menu-key-binding-capturing-key = Bir tuşa basın...
+settings-menu-key-binding-capturing-gamepad-button = Bir düğmeye basın...
+
+settings-menu-key-binding-status = İptal etmek için Escape, kaldırmak için Backspace.
+settings-menu-key-binding-conflict = { $input } zaten { $binding } için atanmış.
+settings-menu-key-binding-required = { $binding } menülerde gerekli olduğu için kaldırılamaz.
+
+settings-menu-reset-key-bindings-button = Varsayılanlara dön
+
 settings-menu-back-button = Geri git
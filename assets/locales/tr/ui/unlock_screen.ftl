@@ -0,0 +1,7 @@
+unlock-screen-title = Kilit Açma
+unlock-screen-ambrosia-text = Ambrosia: { $ambrosia }
+
+unlock-screen-unlock-button = { $name } - { $cost } Ambrosia
+unlock-screen-unlocked-button = { $name } (Açıldı)
+
+unlock-screen-back-button = Geri git
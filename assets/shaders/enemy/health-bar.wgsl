@@ -0,0 +1,9 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+#import mythmallow::bar::{BarMaterial, bar_color}
+
+@group(2) @binding(0) var<uniform> health_bar: BarMaterial;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    return bar_color(mesh.uv, health_bar);
+}
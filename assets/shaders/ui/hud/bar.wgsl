@@ -0,0 +1,24 @@
+#define_import_path mythmallow::bar
+
+struct BarMaterial {
+    foreground_color: vec4<f32>,
+    background_color: vec4<f32>,
+    percent: f32,
+    border_x: f32,
+    border_y: f32,
+};
+
+fn bar_color(uv: vec2<f32>, bar: BarMaterial) -> vec4<f32> {
+    if (uv.x <= bar.border_x || uv.x >= (1 - bar.border_x)) {
+        return bar.background_color;
+    }
+    if (uv.y <= bar.border_y || uv.y >= (1 - bar.border_y)) {
+        return bar.background_color;
+    }
+
+    if (uv.x <= bar.percent) {
+        return bar.foreground_color;
+    } else {
+        return bar.background_color;
+    }
+}
@@ -0,0 +1,61 @@
+use crate::prelude::*;
+
+/// Achievement for killing a hundred enemies.
+#[derive(Debug)]
+pub struct SugarRush;
+
+impl IAchievement for SugarRush {
+    fn id(&self) -> SmolStr {
+        "sugar-rush".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "sugar-rush-name",
+            args: smallvec![],
+            fallback: "Sugar Rush".into(),
+        }
+    }
+
+    fn description(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "sugar-rush-description",
+            args: smallvec![],
+            fallback: "Kill 100 enemies.".into(),
+        }
+    }
+
+    fn condition(&self) -> AchievementCondition {
+        AchievementCondition::KillEnemies(100)
+    }
+}
+
+/// Achievement for killing a thousand enemies.
+#[derive(Debug)]
+pub struct SweetTooth;
+
+impl IAchievement for SweetTooth {
+    fn id(&self) -> SmolStr {
+        "sweet-tooth".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "sweet-tooth-name",
+            args: smallvec![],
+            fallback: "Sweet Tooth".into(),
+        }
+    }
+
+    fn description(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "sweet-tooth-description",
+            args: smallvec![],
+            fallback: "Kill 1000 enemies.".into(),
+        }
+    }
+
+    fn condition(&self) -> AchievementCondition {
+        AchievementCondition::KillEnemies(1000)
+    }
+}
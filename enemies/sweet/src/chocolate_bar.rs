@@ -85,7 +85,10 @@ impl Plugin for ChocolateBarPlugin {
         app.register_type::<ChocolateBar>();
 
         // Add systems.
-        app.add_systems(Update, attack.in_set(GameplaySystems::Enemy));
+        app.add_systems(
+            Update,
+            attack.in_set(GameplaySystems::Enemy).run_if(enemy_ai_is_not_frozen),
+        );
     }
 }
 
@@ -93,7 +96,7 @@ impl Plugin for ChocolateBarPlugin {
 pub fn spawn(
     In((enemy, position)): In<(ChocolateBar, Position)>,
     mut commands: Commands,
-    player_query: Query<Entity, With<Player>>,
+    player_query: Query<(Entity, &Position), (With<Player>, Without<Downed>)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut counter: ResMut<EnemyCounter>,
@@ -105,7 +108,10 @@ pub fn spawn(
         ..default()
     };
 
-    let player_entity = player_query.get_single().unwrap();
+    let player_entity = match utils::player::find_closest_player(position.xy(), &player_query) {
+        Some((player_entity, _)) => player_entity,
+        None => return,
+    };
     EnemyBundle::builder()
         .enemy(enemy)
         .position(position)
@@ -120,25 +126,25 @@ pub fn spawn(
         ));
 }
 
-/// Attacks to the player.
+/// Attacks to the closest player.
 pub fn attack(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     enemy_query: Query<(Entity, &Transform), (With<ChocolateBar>, Without<Cooldown<Attack>>)>,
-    player_query: Query<&Transform, (With<Player>, Without<ChocolateBar>)>,
+    player_query: Query<(Entity, &Position), (With<Player>, Without<Downed>)>,
     spatial_query: SpatialQuery,
 ) {
-    let player_transform = match player_query.get_single() {
-        Ok(query_result) => query_result,
-        Err(_) => {
-            return;
-        },
-    };
     for (enemy_entity, enemy_transform) in enemy_query.iter() {
         let enemy_position = Position::new(enemy_transform.translation.xy());
 
-        let to_player = (player_transform.translation - enemy_transform.translation).xy();
+        let player_position =
+            match utils::player::find_closest_player(enemy_position.xy(), &player_query) {
+                Some((_, player_position)) => player_position,
+                None => return,
+            };
+
+        let to_player = player_position.xy() - enemy_position.xy();
         let player_distance = to_player.length();
         let player_direction = to_player.normalize();
 
@@ -0,0 +1,112 @@
+use crate::prelude::*;
+
+/// Size of the enemy.
+pub const SIZE: f32 = 40.00;
+
+/// Color of the enemy.
+pub const COLOR: Color = Color::srgba(0.690, 0.408, 0.180, 1.000);
+
+/// Health of the enemy.
+pub const HEALTH: Health = Health(150.00);
+
+/// Speed of the enemy.
+pub const SPEED: Speed = Speed(60.00);
+
+/// Contact damage of the enemy.
+pub const CONTACT_DAMAGE: f32 = 10.00;
+
+/// Cooldown of contact damage of the enemy.
+pub const CONTACT_DAMAGE_COOLDOWN: Duration = Duration::from_millis(1500);
+
+/// Experience for defeating the enemy.
+pub const EXPERIENCE_REWARD: Experience = Experience(50.00);
+
+/// Component for the boss "Gingerbread Golem".
+#[derive(Clone, Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct GingerbreadGolem;
+
+impl IEnemy for GingerbreadGolem {
+    fn id(&self) -> SmolStr {
+        "gingerbread-golem".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "gingerbread-golem-name",
+            args: smallvec![],
+            fallback: "Gingerbread Golem".into(),
+        }
+    }
+
+    fn is_boss(&self) -> bool {
+        true
+    }
+
+    fn contact_damage(&self) -> Option<(Damage, DamageCooldown)> {
+        Some((Damage(CONTACT_DAMAGE), DamageCooldown::new(CONTACT_DAMAGE_COOLDOWN)))
+    }
+
+    fn health(&self) -> Health {
+        HEALTH
+    }
+
+    fn speed(&self) -> Speed {
+        SPEED
+    }
+
+    fn experience_reward(&self) -> Experience {
+        EXPERIENCE_REWARD
+    }
+
+    fn collider(&self) -> Collider {
+        Collider::circle(SIZE)
+    }
+
+    fn spawn(&self, world: &mut World, position: Position) {
+        world.run_system_once_with((self.clone(), position), spawn);
+    }
+}
+
+/// Plugin for managing the boss "Gingerbread Golem".
+pub struct GingerbreadGolemPlugin;
+
+impl Plugin for GingerbreadGolemPlugin {
+    fn build(&self, app: &mut App) {
+        // Register the enemy.
+        let mut enemy_registry = app.world_mut().resource_mut::<EnemyRegistry>();
+        enemy_registry.register(SweetEnemyPack, GingerbreadGolem);
+
+        // Register components.
+        app.register_type::<GingerbreadGolem>();
+    }
+}
+
+/// Spawns the enemy.
+pub fn spawn(
+    In((enemy, position)): In<(GingerbreadGolem, Position)>,
+    mut commands: Commands,
+    player_query: Query<(Entity, &Position), (With<Player>, Without<Downed>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut counter: ResMut<EnemyCounter>,
+) {
+    let mesh = MaterialMesh2dBundle {
+        mesh: meshes.add(Circle::new(SIZE)).into(),
+        material: materials.add(ColorMaterial::from(COLOR)),
+        transform: Transform::from_translation(position.extend(Depth::Enemy.z())),
+        ..default()
+    };
+
+    let player_entity = match utils::player::find_closest_player(position.xy(), &player_query) {
+        Some((player_entity, _)) => player_entity,
+        None => return,
+    };
+    EnemyBundle::builder()
+        .enemy(enemy)
+        .position(position)
+        .mesh(mesh)
+        .build()
+        .spawn(&mut commands, &mut counter)
+        .insert((AttractedTo(player_entity), IdealAttractionDistance(50.00)));
+}
@@ -82,7 +82,7 @@ impl Plugin for GummyBearPlugin {
 pub fn spawn(
     In((enemy, position)): In<(GummyBear, Position)>,
     mut commands: Commands,
-    player_query: Query<Entity, With<Player>>,
+    player_query: Query<(Entity, &Position), (With<Player>, Without<Downed>)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut counter: ResMut<EnemyCounter>,
@@ -94,7 +94,10 @@ pub fn spawn(
         ..default()
     };
 
-    let player_entity = player_query.get_single().unwrap();
+    let player_entity = match utils::player::find_closest_player(position.xy(), &player_query) {
+        Some((player_entity, _)) => player_entity,
+        None => return,
+    };
     EnemyBundle::builder()
         .enemy(enemy)
         .position(position)
@@ -1,6 +1,8 @@
+pub mod achievements;
 pub mod pack;
 pub mod plugin;
 pub mod prelude;
 
 pub mod chocolate_bar;
+pub mod gingerbread_golem;
 pub mod gummy_bear;
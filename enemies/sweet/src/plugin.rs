@@ -1,5 +1,6 @@
 use crate::{
     chocolate_bar::ChocolateBarPlugin,
+    gingerbread_golem::GingerbreadGolemPlugin,
     gummy_bear::GummyBearPlugin,
     prelude::*,
 };
@@ -15,5 +16,11 @@ impl Plugin for SweetEnemiesPlugin {
         // Add sub-plugins.
         app.add_plugins(ChocolateBarPlugin);
         app.add_plugins(GummyBearPlugin);
+        app.add_plugins(GingerbreadGolemPlugin);
+
+        // Register achievements.
+        let mut achievement_registry = app.world_mut().resource_mut::<AchievementRegistry>();
+        achievement_registry.register(SugarRush);
+        achievement_registry.register(SweetTooth);
     }
 }
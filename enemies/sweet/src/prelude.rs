@@ -1,5 +1,10 @@
 pub use crate::{
+    achievements::{
+        SugarRush,
+        SweetTooth,
+    },
     chocolate_bar::ChocolateBar,
+    gingerbread_golem::GingerbreadGolem,
     gummy_bear::GummyBear,
     pack::SweetEnemyPack,
     plugin::SweetEnemiesPlugin,
@@ -0,0 +1,7 @@
+use crate::prelude::*;
+
+
+/// Component for the original color of entities that are tinted according to the color palette.
+#[derive(Clone, Copy, Component, Debug, Deref, Reflect)]
+#[reflect(Component)]
+pub struct OriginalColor(pub Color);
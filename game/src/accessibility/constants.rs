@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+
+/// Foreground color of health bars in the default color palette.
+pub const DEFAULT_HEALTH_BAR_COLOR: Color = Color::srgb(1.00, 0.00, 0.00);
+
+/// Foreground color of experience bars in the default color palette.
+pub const DEFAULT_EXPERIENCE_BAR_COLOR: Color = Color::srgb(0.00, 1.00, 0.00);
+
+/// Foreground color of health bars in color palettes for red-green color blindness.
+pub const RED_GREEN_SAFE_HEALTH_BAR_COLOR: Color = Color::srgb(0.90, 0.62, 0.00);
+
+/// Foreground color of experience bars in color palettes for red-green color blindness.
+pub const RED_GREEN_SAFE_EXPERIENCE_BAR_COLOR: Color = Color::srgb(0.00, 0.45, 0.70);
+
+/// Foreground color of health bars in color palettes for blue-yellow color blindness.
+pub const BLUE_YELLOW_SAFE_HEALTH_BAR_COLOR: Color = Color::srgb(0.84, 0.00, 0.25);
+
+/// Foreground color of experience bars in color palettes for blue-yellow color blindness.
+pub const BLUE_YELLOW_SAFE_EXPERIENCE_BAR_COLOR: Color = Color::srgb(0.00, 0.62, 0.45);
+
+
+/// Matrix to convert RGB colors to LMS colors, in row-major order.
+pub const RGB_TO_LMS: [[f32; 3]; 3] =
+    [[17.8824, 43.5161, 4.11935], [3.45565, 27.1554, 3.86714], [0.0299566, 0.184309, 1.46709]];
+
+/// Matrix to convert LMS colors to RGB colors, in row-major order.
+pub const LMS_TO_RGB: [[f32; 3]; 3] = [
+    [0.08094445, -0.1305044, 0.1167211],
+    [-0.01024853, 0.05401933, -0.1136147],
+    [-0.0003652969, -0.004121615, 0.6935114],
+];
+
+/// Matrix to simulate deuteranopia in LMS colors, in row-major order.
+pub const DEUTERANOPIA_SIMULATION: [[f32; 3]; 3] =
+    [[1.00, 0.00, 0.00], [0.494207, 0.00, 1.24827], [0.00, 0.00, 1.00]];
+
+/// Matrix to simulate protanopia in LMS colors, in row-major order.
+pub const PROTANOPIA_SIMULATION: [[f32; 3]; 3] =
+    [[0.00, 2.02344, -2.52581], [0.00, 1.00, 0.00], [0.00, 0.00, 1.00]];
+
+/// Matrix to simulate tritanopia in LMS colors, in row-major order.
+pub const TRITANOPIA_SIMULATION: [[f32; 3]; 3] =
+    [[1.00, 0.00, 0.00], [0.00, 1.00, 0.00], [-0.395913, 0.801109, 0.00]];
+
+/// Matrix to shift the lost color information to the perceived channels, in row-major order.
+pub const DALTONIZATION_CORRECTION: [[f32; 3]; 3] =
+    [[0.00, 0.00, 0.00], [0.70, 1.00, 0.00], [0.70, 0.00, 1.00]];
+
+
+/// Minimum scale of the user interface.
+pub const MIN_UI_SCALE: f32 = 0.50;
+
+/// Maximum scale of the user interface.
+pub const MAX_UI_SCALE: f32 = 2.00;
+
+
+/// Text color of the widgets in high contrast mode.
+pub const HIGH_CONTRAST_TEXT_COLOR: Color = Color::srgb(1.00, 1.00, 1.00);
+
+/// Disabled text color of the widgets in high contrast mode.
+pub const HIGH_CONTRAST_DISABLED_TEXT_COLOR: Color = Color::srgb(1.00, 0.45, 0.45);
+
+/// Background color of the widgets in high contrast mode.
+pub const HIGH_CONTRAST_NORMAL_COLOR: Color = Color::srgb(0.00, 0.00, 0.00);
+
+/// Background color of the selected widgets in high contrast mode.
+pub const HIGH_CONTRAST_SELECTED_COLOR: Color = Color::srgb(0.00, 0.32, 0.85);
+
+/// Background color of the pressed widgets in high contrast mode.
+pub const HIGH_CONTRAST_PRESSED_COLOR: Color = Color::srgb(0.00, 0.20, 0.55);
@@ -0,0 +1,27 @@
+use crate::{
+    accessibility::systems::*,
+    prelude::*,
+};
+
+/// Plugin for managing the accessibility of the application.
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<OriginalColor>();
+
+        // Add systems.
+        app.add_systems(
+            PostUpdate,
+            (
+                update_ui_scale,
+                retint_enemies,
+                update_widget_colors_on_high_contrast_change
+                    .before(crate::ui::widget::systems::update_widget_colors_on_state_change),
+            )
+                .run_if(resource_changed::<Persistent<GeneralSettings>>),
+        );
+        app.add_systems(PostUpdate, tint_spawned_enemies);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    accessibility::constants::*,
+    prelude::*,
+};
+
+
+/// Updates the scale of the user interface.
+pub fn update_ui_scale(
+    general_settings: Res<Persistent<GeneralSettings>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let new_ui_scale = general_settings.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+    if ui_scale.0 != new_ui_scale {
+        ui_scale.0 = new_ui_scale;
+    }
+}
+
+
+/// Tints the spawned enemies according to the color palette.
+pub fn tint_spawned_enemies(
+    mut commands: Commands,
+    enemy_query: Query<(Entity, &Handle<ColorMaterial>), Added<Enemy>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    for (entity, material_handle) in enemy_query.iter() {
+        if let Some(material) = materials.get_mut(material_handle) {
+            commands.entity(entity).insert(OriginalColor(material.color));
+            material.color = general_settings.color_palette.tint(material.color);
+        }
+    }
+}
+
+/// Tints the enemies again when the color palette is changed.
+pub fn retint_enemies(
+    enemy_query: Query<(&Handle<ColorMaterial>, &OriginalColor), With<Enemy>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    mut previous_color_palette: Local<Option<ColorPalette>>,
+) {
+    let color_palette = general_settings.color_palette;
+    if previous_color_palette.replace(color_palette) == Some(color_palette) {
+        return;
+    }
+
+    for (material_handle, original_color) in enemy_query.iter() {
+        if let Some(material) = materials.get_mut(material_handle) {
+            material.color = color_palette.tint(original_color.0);
+        }
+    }
+}
+
+
+/// Updates the colors of all widgets when high contrast mode is changed.
+pub fn update_widget_colors_on_high_contrast_change(
+    mut widget_query: Query<(&mut Widget, &Children, Has<WidgetDisabled>, &WidgetColors)>,
+    mut text_query: Query<&mut Text>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    mut previous_high_contrast: Local<Option<bool>>,
+) {
+    let high_contrast = general_settings.high_contrast;
+    if previous_high_contrast.replace(high_contrast).unwrap_or(false) == high_contrast {
+        return;
+    }
+
+    for (mut widget, children, is_disabled, widget_colors) in widget_query.iter_mut() {
+        // Mark the widget as changed to update its background color.
+        widget.set_changed();
+
+        let widget_colors =
+            if high_contrast { widget_colors.high_contrast() } else { *widget_colors };
+        let text_color = if is_disabled { widget_colors.disabled_text } else { widget_colors.text };
+        for &child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                text.sections[0].style.color = text_color;
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+/// Controls the achievements.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "achievement")]
+#[command(disable_help_flag = true)]
+pub struct AchievementCommand {
+    #[clap(subcommand)]
+    pub subcommand: AchievementCommands,
+}
+
+/// Achievement commands.
+#[derive(Debug, Subcommand)]
+pub enum AchievementCommands {
+    /// Lists the achievements.
+    List,
+    /// Unlocks an achievement.
+    #[clap(arg_required_else_help = true)]
+    Unlock { id: SmolStr },
+    /// Locks an achievement.
+    #[clap(arg_required_else_help = true)]
+    Lock { id: SmolStr },
+    /// Resets the achievements.
+    Reset,
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+
+
+/// Storage format for achievement files.
+pub const ACHIEVEMENTS_STORAGE_FORMAT: StorageFormat = {
+    #[cfg(feature = "native")]
+    {
+        StorageFormat::Toml
+    }
+    #[cfg(feature = "wasm")]
+    {
+        StorageFormat::Json
+    }
+};
@@ -0,0 +1,8 @@
+use crate::prelude::*;
+
+
+/// Event for unlocking an achievement.
+#[derive(Debug, Event, Reflect)]
+pub struct AchievementUnlockedEvent {
+    pub id: SmolStr,
+}
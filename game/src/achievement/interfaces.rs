@@ -0,0 +1,15 @@
+use crate::prelude::*;
+
+
+/// Interface for achievements.
+pub trait IAchievement: Debug + Send + Sync + 'static {
+    /// Gets the unique identifier of the achievement.
+    fn id(&self) -> SmolStr;
+    /// Gets the localized name of the achievement.
+    fn name(&self) -> LocalizedText;
+    /// Gets the localized description of the achievement.
+    fn description(&self) -> LocalizedText;
+
+    /// Gets the condition to unlock the achievement.
+    fn condition(&self) -> AchievementCondition;
+}
@@ -0,0 +1,8 @@
+pub mod commands;
+pub mod constants;
+pub mod events;
+pub mod interfaces;
+pub mod plugin;
+pub mod registry;
+pub mod resources;
+pub mod systems;
@@ -0,0 +1,46 @@
+use crate::{
+    achievement::{
+        commands::*,
+        systems::*,
+    },
+    prelude::*,
+};
+
+/// Plugin for managing the achievements.
+pub struct AchievementPlugin;
+
+impl Plugin for AchievementPlugin {
+    fn build(&self, app: &mut App) {
+        // Initialize registry.
+        app.init_resource::<AchievementRegistry>();
+
+        // Register resources.
+        app.register_type::<Achievements>();
+
+        // Insert resources.
+        Achievements::initialize(app);
+
+        // Add events.
+        app.add_event::<AchievementUnlockedEvent>();
+
+        // Add console commands.
+        app.add_console_command::<AchievementCommand, _>(apply_achievement_command);
+
+        // Add systems.
+        app.add_systems(
+            Last,
+            (
+                count_killed_enemies.run_if(on_event::<EnemyDiedEvent>()),
+                check_level_achievements.run_if(on_event::<LeveledUpEvent>()),
+                check_balance_achievements
+                    .run_if(in_state(AppState::Game))
+                    .run_if(resource_changed::<Balance>),
+            ),
+        );
+        app.add_systems(
+            OnEnter(GameState::Over),
+            (check_victory_achievements, persist_achievements).chain(),
+        );
+        app.add_systems(OnExit(AppState::Game), persist_achievements);
+    }
+}
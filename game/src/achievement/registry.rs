@@ -0,0 +1,93 @@
+use crate::prelude::*;
+
+
+/// Container for the achievement registry.
+#[derive(Debug, Default, Deref, Resource)]
+pub struct AchievementRegistry(Vec<AchievementRegistryEntry>);
+
+impl AchievementRegistry {
+    /// Registers an achievement to the achievement registry.
+    pub fn register(&mut self, achievement: impl IAchievement) -> &mut RegisteredAchievement {
+        let achievement_id = achievement.id();
+        let achievement_index =
+            match self.iter().position(|entry| entry.achievement.id() == achievement_id) {
+                Some(index) => {
+                    log::warn!(
+                        "tried to register {:?} to the achievement registry again",
+                        achievement_id,
+                    );
+                    index
+                },
+                None => {
+                    log::info!("registered {:?} to the achievement registry", achievement_id);
+                    let index = self.len();
+                    self.0.push(AchievementRegistryEntry::new(achievement));
+                    index
+                },
+            };
+        &mut self.0[achievement_index].achievement
+    }
+}
+
+impl AchievementRegistry {
+    /// Gets the number of achievements in the achievement registry.
+    pub fn number_of_achievements(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Finds the achievement with the specified id.
+    pub fn find_achievement_by_id(&self, achievement_id: &str) -> Option<&RegisteredAchievement> {
+        for entry in self.iter() {
+            if entry.achievement.id() == achievement_id {
+                return Some(&entry.achievement);
+            }
+        }
+        None
+    }
+}
+
+
+/// Container for the entries of the achievement registry.
+#[derive(Debug)]
+pub struct AchievementRegistryEntry {
+    pub achievement: RegisteredAchievement,
+}
+
+impl AchievementRegistryEntry {
+    /// Creates a new achievement registry entry.
+    pub fn new(achievement: impl IAchievement) -> AchievementRegistryEntry {
+        AchievementRegistryEntry { achievement: RegisteredAchievement::new(achievement) }
+    }
+}
+
+impl Deref for AchievementRegistryEntry {
+    type Target = RegisteredAchievement;
+
+    fn deref(&self) -> &RegisteredAchievement {
+        &self.achievement
+    }
+}
+
+
+/// Container for registered achievements.
+#[derive(Debug)]
+pub struct RegisteredAchievement {
+    pub achievement: Arc<dyn IAchievement>,
+    pub condition: AchievementCondition,
+}
+
+impl RegisteredAchievement {
+    /// Creates a new registered achievement.
+    pub fn new(achievement: impl IAchievement) -> RegisteredAchievement {
+        let condition = achievement.condition();
+        RegisteredAchievement { achievement: Arc::new(achievement), condition }
+    }
+}
+
+impl Deref for RegisteredAchievement {
+    type Target = Arc<dyn IAchievement>;
+
+    fn deref(&self) -> &Arc<dyn IAchievement> {
+        &self.achievement
+    }
+}
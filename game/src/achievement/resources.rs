@@ -0,0 +1,101 @@
+use crate::{
+    achievement::constants::*,
+    prelude::*,
+};
+
+
+/// Condition to unlock an achievement.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AchievementCondition {
+    /// Reaching a level in a run.
+    ReachLevel(u16),
+    /// Killing a number of enemies across all runs.
+    KillEnemies(u64),
+    /// Winning a run with a player.
+    WinWithPlayer(SmolStr),
+    /// Having a balance in a run.
+    ReachBalance(Balance),
+}
+
+impl Display for AchievementCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AchievementCondition::ReachLevel(level) => write!(f, "reach level {}", level),
+            AchievementCondition::KillEnemies(enemies) => write!(f, "kill {} enemies", enemies),
+            AchievementCondition::WinWithPlayer(player_id) => {
+                write!(f, "win with {:?}", player_id)
+            },
+            AchievementCondition::ReachBalance(balance) => write!(f, "have {}", balance),
+        }
+    }
+}
+
+
+/// Resource for the achievements of the game, which persists across runs.
+#[derive(Debug, Default, Deserialize, Reflect, Resource, Serialize)]
+#[serde(default)]
+pub struct Achievements {
+    pub unlocked: Vec<String>,
+    pub enemies_killed: u64,
+}
+
+impl Achievements {
+    /// Initializes the resource in the app.
+    pub fn initialize(app: &mut App) {
+        let args = app.world().resource::<Args>();
+        app.insert_resource(
+            Persistent::<Achievements>::builder()
+                .name("achievements")
+                .format(ACHIEVEMENTS_STORAGE_FORMAT)
+                .path({
+                    #[cfg(feature = "native")]
+                    {
+                        args.data_directory.join("achievements.toml")
+                    }
+                    #[cfg(feature = "wasm")]
+                    {
+                        args.data_directory.join("achievements")
+                    }
+                })
+                .default(Achievements::default())
+                .revertible(true)
+                .build()
+                .unwrap_or_else(|_| panic!("fatal: unable to initialize the achievements")),
+        );
+    }
+}
+
+impl Achievements {
+    /// Gets whether an achievement is unlocked.
+    pub fn is_unlocked(&self, achievement_id: &str) -> bool {
+        self.unlocked.iter().any(|unlocked_id| unlocked_id == achievement_id)
+    }
+
+    /// Gets the progress towards an achievement condition as `(current, target)`, if applicable.
+    pub fn progress(&self, condition: &AchievementCondition) -> Option<(u64, u64)> {
+        match condition {
+            AchievementCondition::KillEnemies(enemies) => {
+                Some((self.enemies_killed.min(*enemies), *enemies))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Achievements {
+    /// Unlocks an achievement, returning whether it was locked before.
+    pub fn unlock(&mut self, achievement_id: &str) -> bool {
+        if self.is_unlocked(achievement_id) {
+            return false;
+        }
+        self.unlocked.push(achievement_id.to_owned());
+        true
+    }
+
+    /// Locks an achievement, returning whether it was unlocked before.
+    pub fn lock(&mut self, achievement_id: &str) -> bool {
+        let length_before = self.unlocked.len();
+        self.unlocked.retain(|unlocked_id| unlocked_id != achievement_id);
+        self.unlocked.len() != length_before
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{
+    achievement::commands::*,
+    prelude::*,
+};
+
+
+/// Applies the achievement console commands.
+pub fn apply_achievement_command(
+    mut achievements: ResMut<Persistent<Achievements>>,
+    achievement_registry: Res<AchievementRegistry>,
+    localization: Res<Localization>,
+    mut achievement_unlocked_event_writer: EventWriter<AchievementUnlockedEvent>,
+    mut command: ConsoleCommand<AchievementCommand>,
+) {
+    if let Some(Ok(AchievementCommand { subcommand })) = command.take() {
+        match subcommand {
+            AchievementCommands::List => {
+                reply!(command, "enemies killed: {}", achievements.enemies_killed);
+                for (i, entry) in achievement_registry.iter().enumerate() {
+                    let achievement = &entry.achievement;
+                    let achievement_id = achievement.id();
+                    reply!(command, "{}) {}", i + 1, achievement_id);
+                    reply!(command, "    - name: {}", achievement.name().get(&localization));
+                    reply!(
+                        command,
+                        "    - description: {}",
+                        achievement.description().get(&localization),
+                    );
+                    reply!(command, "    - condition: {}", achievement.condition);
+                    reply!(
+                        command,
+                        "    - unlocked: {}",
+                        if achievements.is_unlocked(&achievement_id) { "yes" } else { "no" },
+                    );
+                }
+            },
+            AchievementCommands::Unlock { id } => {
+                if achievement_registry.find_achievement_by_id(&id).is_none() {
+                    reply!(command, "Failed to unlock {:?} as it doesn't exist.", id);
+                } else {
+                    let mut unlocked = false;
+                    achievements
+                        .update(|achievements| {
+                            unlocked = achievements.unlock(&id);
+                        })
+                        .ok();
+
+                    if unlocked {
+                        achievement_unlocked_event_writer.send(AchievementUnlockedEvent { id });
+                        reply!(command, "Unlocked.");
+                    } else {
+                        reply!(command, "{:?} is already unlocked.", id);
+                    }
+                }
+            },
+            AchievementCommands::Lock { id } => {
+                let mut locked = false;
+                achievements
+                    .update(|achievements| {
+                        locked = achievements.lock(&id);
+                    })
+                    .ok();
+
+                if locked {
+                    reply!(command, "Locked.");
+                } else {
+                    reply!(command, "{:?} is not unlocked.", id);
+                }
+            },
+            AchievementCommands::Reset => {
+                achievements.update(|achievements| *achievements = Achievements::default()).ok();
+                reply!(command, "Reset.");
+            },
+        }
+        reply!(command, "");
+    }
+}
+
+
+/// Counts the enemies killed for the achievements.
+///
+/// Killed enemies are counted in memory, and they are persisted when an achievement is unlocked
+/// or when the game ends, so the achievements aren't written to the disk on every kill.
+pub fn count_killed_enemies(
+    mut enemy_died_event_reader: EventReader<EnemyDiedEvent>,
+    achievement_registry: Res<AchievementRegistry>,
+    mut achievements: ResMut<Persistent<Achievements>>,
+    mut achievement_unlocked_event_writer: EventWriter<AchievementUnlockedEvent>,
+) {
+    let killed_enemies = enemy_died_event_reader.read().count() as u64;
+    if killed_enemies == 0 {
+        return;
+    }
+
+    achievements.enemies_killed = achievements.enemies_killed.saturating_add(killed_enemies);
+
+    let enemies_killed = achievements.enemies_killed;
+    unlock_achievements(
+        &achievement_registry,
+        &mut achievements,
+        &mut achievement_unlocked_event_writer,
+        |condition| {
+            match condition {
+                AchievementCondition::KillEnemies(enemies) => enemies_killed >= *enemies,
+                _ => false,
+            }
+        },
+    );
+}
+
+/// Checks the level achievements.
+pub fn check_level_achievements(
+    mut leveled_up_event_reader: EventReader<LeveledUpEvent>,
+    player_query: Query<(), With<Player>>,
+    achievement_registry: Res<AchievementRegistry>,
+    mut achievements: ResMut<Persistent<Achievements>>,
+    mut achievement_unlocked_event_writer: EventWriter<AchievementUnlockedEvent>,
+) {
+    let highest_level = leveled_up_event_reader
+        .read()
+        .filter(|event| player_query.contains(event.entity))
+        .map(|event| event.new_level.get())
+        .max();
+
+    if let Some(highest_level) = highest_level {
+        unlock_achievements(
+            &achievement_registry,
+            &mut achievements,
+            &mut achievement_unlocked_event_writer,
+            |condition| {
+                match condition {
+                    AchievementCondition::ReachLevel(level) => highest_level >= *level,
+                    _ => false,
+                }
+            },
+        );
+    }
+}
+
+/// Checks the balance achievements.
+pub fn check_balance_achievements(
+    balance: Res<Balance>,
+    achievement_registry: Res<AchievementRegistry>,
+    mut achievements: ResMut<Persistent<Achievements>>,
+    mut achievement_unlocked_event_writer: EventWriter<AchievementUnlockedEvent>,
+) {
+    let balance = *balance;
+    unlock_achievements(
+        &achievement_registry,
+        &mut achievements,
+        &mut achievement_unlocked_event_writer,
+        |condition| {
+            match condition {
+                AchievementCondition::ReachBalance(amount) => balance >= *amount,
+                _ => false,
+            }
+        },
+    );
+}
+
+/// Checks the victory achievements.
+pub fn check_victory_achievements(
+    game_result: Res<GameResult>,
+    player_registry: Res<PlayerRegistry>,
+    selected_mythology_index: Option<Res<SelectedMythologyIndex>>,
+    selected_player_index: Option<Res<SelectedPlayerIndex>>,
+    achievement_registry: Res<AchievementRegistry>,
+    mut achievements: ResMut<Persistent<Achievements>>,
+    mut achievement_unlocked_event_writer: EventWriter<AchievementUnlockedEvent>,
+) {
+    if *game_result != GameResult::Won {
+        return;
+    }
+
+    let selected_player = match (selected_mythology_index, selected_player_index) {
+        (Some(selected_mythology_index), Some(selected_player_index)) => {
+            player_registry.find_selected_player(*selected_mythology_index, *selected_player_index)
+        },
+        _ => None,
+    };
+    let player_id = match selected_player {
+        Some(selected_player) => selected_player.id(),
+        None => {
+            log::error!("couldn't check the victory achievements as the player isn't selected");
+            return;
+        },
+    };
+    unlock_achievements(
+        &achievement_registry,
+        &mut achievements,
+        &mut achievement_unlocked_event_writer,
+        |condition| {
+            match condition {
+                AchievementCondition::WinWithPlayer(winner) => *winner == player_id,
+                _ => false,
+            }
+        },
+    );
+}
+
+
+/// Persists the achievements, including the enemies killed since they were last persisted.
+pub fn persist_achievements(achievements: Res<Persistent<Achievements>>) {
+    achievements.persist().ok();
+}
+
+
+/// Unlocks the locked achievements with conditions satisfying the predicate.
+fn unlock_achievements(
+    achievement_registry: &AchievementRegistry,
+    achievements: &mut Persistent<Achievements>,
+    achievement_unlocked_event_writer: &mut EventWriter<AchievementUnlockedEvent>,
+    predicate: impl Fn(&AchievementCondition) -> bool,
+) {
+    let mut newly_unlocked = Vec::new();
+    for entry in achievement_registry.iter() {
+        let achievement_id = entry.achievement.id();
+        if !achievements.is_unlocked(&achievement_id) && predicate(&entry.achievement.condition) {
+            newly_unlocked.push(achievement_id);
+        }
+    }
+
+    if newly_unlocked.is_empty() {
+        return;
+    }
+
+    achievements
+        .update(|achievements| {
+            for achievement_id in newly_unlocked.iter() {
+                achievements.unlock(achievement_id);
+            }
+        })
+        .ok();
+
+    for achievement_id in newly_unlocked {
+        log::info!("unlocked {:?} achievement", achievement_id);
+        achievement_unlocked_event_writer.send(AchievementUnlockedEvent { id: achievement_id });
+    }
+}
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+
+/// Component for the music.
+#[derive(Clone, Copy, Component, Debug, Deref, Reflect)]
+pub struct Music(pub MusicTrack);
+
+
+/// Tag component for the sound effects.
+#[derive(Component, Debug, Reflect)]
+pub struct SoundEffectPlayer;
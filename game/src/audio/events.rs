@@ -0,0 +1,8 @@
+use crate::prelude::*;
+
+
+/// Event for playing a sound effect.
+#[derive(Debug, Event, Reflect)]
+pub struct PlaySoundEffectEvent {
+    pub sound_effect: SoundEffect,
+}
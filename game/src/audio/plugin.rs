@@ -0,0 +1,62 @@
+use crate::{
+    audio::systems::*,
+    prelude::*,
+};
+
+/// Plugin for managing the audio.
+pub struct AudioPlugin;
+
+impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<Music>();
+        app.register_type::<SoundEffectPlayer>();
+
+        // Register resources.
+        app.register_type::<MusicTrack>();
+        app.register_type::<SoundEffect>();
+
+        // Add events.
+        app.add_event::<PlaySoundEffectEvent>();
+
+        // Add systems.
+        app.add_systems(Startup, load_audio_assets);
+        app.add_systems(
+            Update,
+            (
+                play_attack_sound_effect,
+                play_hit_sound_effect,
+                play_enemy_death_sound_effect,
+                play_experience_pickup_sound_effect,
+                play_level_up_sound_effect,
+                play_purchase_sound_effect,
+            )
+                .after(GameplaySystems::Combat)
+                .after(GameplaySystems::Item),
+        );
+        app.add_systems(
+            PostUpdate,
+            play_ui_sound_effects
+                .in_set(MenuSystems)
+                .before(crate::ui::widget::systems::update_widget_state_on_user_interactions)
+                .before(MainMenuSystems)
+                .before(SettingsMenuSystems)
+                .before(GameModeSelectionScreenSystems)
+                .before(PlayerSelectionScreenSystems)
+                .before(EnemySelectionScreenSystems)
+                .before(LevelUpScreenSystems)
+                .before(MarketSystems)
+                .before(PauseMenuSystems)
+                .before(GameOverMenuSystems),
+        );
+        app.add_systems(
+            Last,
+            (
+                play_music,
+                update_music_volume.run_if(resource_changed::<Persistent<GeneralSettings>>),
+                play_sound_effects,
+            )
+                .chain(),
+        );
+    }
+}
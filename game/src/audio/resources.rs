@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+
+/// Music tracks of the game.
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, PartialEq, Reflect)]
+pub enum MusicTrack {
+    Menu,
+    Game,
+    Market,
+    GameOver,
+}
+
+impl MusicTrack {
+    /// Gets the path of the music track.
+    pub fn path(&self) -> &'static str {
+        match self {
+            MusicTrack::Menu => "audio/music/menu.ogg",
+            MusicTrack::Game => "audio/music/game.ogg",
+            MusicTrack::Market => "audio/music/market.ogg",
+            MusicTrack::GameOver => "audio/music/game-over.ogg",
+        }
+    }
+
+    /// Gets the music track to play in a state.
+    ///
+    /// Returns `None` if the music track shouldn't change in the state (e.g., the music of the
+    /// game keeps playing when a wave is won, as the game continues afterwards).
+    pub fn of(app_state: AppState, game_state: GameState) -> Option<MusicTrack> {
+        match app_state {
+            AppState::LoadingInitialLocalization => None,
+            AppState::MainMenu
+            | AppState::SettingsMenu
+            | AppState::UnlockScreen
+            | AppState::AchievementsScreen
+            | AppState::GameModeSelectionScreen
+            | AppState::PlayerSelectionScreen
+            | AppState::EnemySelectionScreen => Some(MusicTrack::Menu),
+            AppState::Game => {
+                match game_state {
+                    GameState::Transition | GameState::Restart | GameState::Won => None,
+                    GameState::Initialization
+                    | GameState::Loading
+                    | GameState::Playing
+                    | GameState::LevelUpScreen
+                    | GameState::Paused
+                    | GameState::Settings => Some(MusicTrack::Game),
+                    GameState::Market => Some(MusicTrack::Market),
+                    GameState::Over => Some(MusicTrack::GameOver),
+                }
+            },
+        }
+    }
+}
+
+
+/// Sound effects of the game.
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, PartialEq, Reflect)]
+pub enum SoundEffect {
+    Attack,
+    Hit,
+    EnemyDeath,
+    ExperiencePickup,
+    LevelUp,
+    Purchase,
+    UiNavigation,
+    UiSelect,
+}
+
+impl SoundEffect {
+    /// Gets the path of the sound effect.
+    pub fn path(&self) -> &'static str {
+        match self {
+            SoundEffect::Attack => "audio/sound-effects/attack.ogg",
+            SoundEffect::Hit => "audio/sound-effects/hit.ogg",
+            SoundEffect::EnemyDeath => "audio/sound-effects/enemy-death.ogg",
+            SoundEffect::ExperiencePickup => "audio/sound-effects/experience-pickup.ogg",
+            SoundEffect::LevelUp => "audio/sound-effects/level-up.ogg",
+            SoundEffect::Purchase => "audio/sound-effects/purchase.ogg",
+            SoundEffect::UiNavigation => "audio/sound-effects/ui-navigation.ogg",
+            SoundEffect::UiSelect => "audio/sound-effects/ui-select.ogg",
+        }
+    }
+}
+
+
+/// Resource for the audio assets.
+#[derive(Debug, Default, Resource)]
+pub struct AudioAssets {
+    pub music: HashMap<MusicTrack, Handle<AudioSource>>,
+    pub sound_effects: HashMap<SoundEffect, Handle<AudioSource>>,
+}
@@ -0,0 +1,238 @@
+use crate::prelude::*;
+
+
+/// Loads the audio assets.
+///
+/// Audio files that don't exist are skipped, and they are not played.
+pub fn load_audio_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mut audio_assets = AudioAssets::default();
+    for music_track in MusicTrack::iter() {
+        if audio_file_exists(music_track.path()) {
+            audio_assets.music.insert(music_track, asset_server.load(music_track.path()));
+        }
+    }
+    for sound_effect in SoundEffect::iter() {
+        if audio_file_exists(sound_effect.path()) {
+            audio_assets.sound_effects.insert(sound_effect, asset_server.load(sound_effect.path()));
+        }
+    }
+    commands.insert_resource(audio_assets);
+}
+
+
+/// Plays the music of the current state.
+pub fn play_music(
+    mut commands: Commands,
+    app_state: Res<State<AppState>>,
+    game_state: Res<State<GameState>>,
+    audio_assets: Res<AudioAssets>,
+    audio_sources: Res<Assets<AudioSource>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    music_query: Query<(Entity, &Music)>,
+) {
+    let music_track = match MusicTrack::of(*app_state.get(), *game_state.get()) {
+        Some(music_track) => music_track,
+        None => return,
+    };
+
+    let mut music_track_is_already_playing = false;
+    for (music_entity, music) in music_query.iter() {
+        if music.0 == music_track {
+            music_track_is_already_playing = true;
+        } else {
+            commands.entity(music_entity).despawn_recursive();
+        }
+    }
+    if music_track_is_already_playing {
+        return;
+    }
+
+    let source = match audio_assets.music.get(&music_track) {
+        Some(source) => source,
+        None => return,
+    };
+    if !audio_sources.contains(source) {
+        // Music track is not loaded (yet), so it'll be tried again in the next frame.
+        return;
+    }
+
+    commands.spawn((
+        Name::new(format!("Music ({:?})", music_track)),
+        Music(music_track),
+        AudioBundle {
+            source: source.clone(),
+            settings: PlaybackSettings::LOOP
+                .with_volume(Volume::new(general_settings.effective_music_volume())),
+        },
+    ));
+}
+
+/// Updates the volume of the music.
+pub fn update_music_volume(
+    general_settings: Res<Persistent<GeneralSettings>>,
+    music_query: Query<&AudioSink, With<Music>>,
+) {
+    for audio_sink in music_query.iter() {
+        audio_sink.set_volume(general_settings.effective_music_volume());
+    }
+}
+
+
+/// Plays the requested sound effects.
+///
+/// Each sound effect is played at most once per frame.
+pub fn play_sound_effects(
+    mut commands: Commands,
+    mut play_sound_effect_event_reader: EventReader<PlaySoundEffectEvent>,
+    audio_assets: Res<AudioAssets>,
+    audio_sources: Res<Assets<AudioSource>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    let volume = general_settings.effective_sound_effects_volume();
+
+    let mut played_sound_effects = HashSet::new();
+    for event in play_sound_effect_event_reader.read() {
+        if volume <= 0.00 || !played_sound_effects.insert(event.sound_effect) {
+            continue;
+        }
+
+        let source = match audio_assets.sound_effects.get(&event.sound_effect) {
+            Some(source) => source,
+            None => continue,
+        };
+        if !audio_sources.contains(source) {
+            continue;
+        }
+
+        commands.spawn((
+            Name::new(format!("Sound Effect ({:?})", event.sound_effect)),
+            SoundEffectPlayer,
+            AudioBundle {
+                source: source.clone(),
+                settings: PlaybackSettings::DESPAWN.with_volume(Volume::new(volume)),
+            },
+        ));
+    }
+}
+
+
+/// Plays the attack sound effect when weapons attack.
+pub fn play_attack_sound_effect(
+    weapon_query: Query<(), (With<Weapon>, Added<Cooldown<Attack>>)>,
+    mut play_sound_effect_event_writer: EventWriter<PlaySoundEffectEvent>,
+) {
+    if !weapon_query.is_empty() {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::Attack });
+    }
+}
+
+/// Plays the hit sound effect when damage is received.
+pub fn play_hit_sound_effect(
+    mut damage_received_event_reader: EventReader<DamageReceivedEvent>,
+    mut play_sound_effect_event_writer: EventWriter<PlaySoundEffectEvent>,
+) {
+    if damage_received_event_reader.read().any(|event| !event.dodged) {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::Hit });
+    }
+}
+
+/// Plays the enemy death sound effect when enemies die.
+pub fn play_enemy_death_sound_effect(
+    mut enemy_died_event_reader: EventReader<EnemyDiedEvent>,
+    mut play_sound_effect_event_writer: EventWriter<PlaySoundEffectEvent>,
+) {
+    if enemy_died_event_reader.read().count() > 0 {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::EnemyDeath });
+    }
+}
+
+/// Plays the experience pickup sound effect when experience is gained.
+pub fn play_experience_pickup_sound_effect(
+    mut experience_gained_event_reader: EventReader<ExperienceGainedEvent>,
+    mut play_sound_effect_event_writer: EventWriter<PlaySoundEffectEvent>,
+) {
+    if experience_gained_event_reader.read().count() > 0 {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::ExperiencePickup });
+    }
+}
+
+/// Plays the level up sound effect when players level up.
+pub fn play_level_up_sound_effect(
+    mut leveled_up_event_reader: EventReader<LeveledUpEvent>,
+    mut play_sound_effect_event_writer: EventWriter<PlaySoundEffectEvent>,
+) {
+    if leveled_up_event_reader.read().count() > 0 {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::LevelUp });
+    }
+}
+
+/// Plays the purchase sound effect when items are purchased.
+pub fn play_purchase_sound_effect(
+    mut item_purchased_event_reader: EventReader<ItemPurchasedEvent>,
+    mut play_sound_effect_event_writer: EventWriter<PlaySoundEffectEvent>,
+) {
+    if item_purchased_event_reader.read().count() > 0 {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::Purchase });
+    }
+}
+
+/// Plays the user interface sound effects when widgets are navigated or clicked.
+pub fn play_ui_sound_effects(
+    selected_widget_query: Query<Ref<Widget>, Added<WidgetSelected>>,
+    interacted_widget_query: Query<
+        (Ref<Widget>, Option<Ref<Interaction>>),
+        Or<(Changed<Widget>, Changed<Interaction>)>,
+    >,
+    mut play_sound_effect_event_writer: EventWriter<PlaySoundEffectEvent>,
+) {
+    // Widgets which are selected as soon as they are spawned are not navigated to.
+    if selected_widget_query.iter().any(|widget| !widget.is_added()) {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::UiNavigation });
+    }
+
+    let clicked = interacted_widget_query.iter().any(|(widget, interaction)| {
+        let clicked_with_keyboard = widget.clicked;
+        let clicked_with_mouse = interaction
+            .map(|interaction| {
+                interaction.is_changed()
+                    && *interaction == Interaction::Hovered
+                    && widget.is_pressed
+            })
+            .unwrap_or(false);
+        clicked_with_keyboard || clicked_with_mouse
+    });
+    if clicked {
+        play_sound_effect_event_writer
+            .send(PlaySoundEffectEvent { sound_effect: SoundEffect::UiSelect });
+    }
+}
+
+
+/// Gets whether an audio file exists in the assets.
+fn audio_file_exists(path: &str) -> bool {
+    #[cfg(feature = "native")]
+    {
+        let exists = bevy::asset::io::file::FileAssetReader::get_base_path()
+            .join(AssetPlugin::default().file_path)
+            .join(path)
+            .exists();
+        if !exists {
+            log::info!("skipping {} as it doesn't exist", path);
+        }
+        exists
+    }
+    #[cfg(feature = "wasm")]
+    {
+        // Existence of files can't be checked without requesting them from the server,
+        // so all audio files are loaded and the missing ones are not played.
+        let _ = path;
+        true
+    }
+}
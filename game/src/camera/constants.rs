@@ -3,3 +3,13 @@ use crate::prelude::*;
 
 /// Background color of the main camera.
 pub const BACKGROUND_COLOR: Color = Color::srgb(0.53, 0.53, 0.53);
+
+
+/// Minimum distance between the players and the edges of the screen when framing the players.
+pub const PLAYER_FRAMING_MARGIN: f32 = 150.00;
+
+/// Minimum scale of the main camera.
+pub const MIN_CAMERA_SCALE: f32 = 1.00;
+
+/// Maximum scale of the main camera.
+pub const MAX_CAMERA_SCALE: f32 = 2.50;
@@ -1,4 +1,7 @@
-use crate::prelude::*;
+use crate::{
+    camera::constants::*,
+    prelude::*,
+};
 
 
 /// Spawns the main camera.
@@ -12,14 +15,45 @@ pub fn spawn_main_camera(mut commands: Commands) {
 }
 
 
-/// Makes the main camera locked to the player.
+/// Makes the main camera locked to the players.
+///
+/// Main camera is centered between the players that are not down, and it's zoomed out as much
+/// as necessary to keep all of them on the screen.
 pub fn player_lock(
-    mut camera_query: Query<&mut Transform, With<MainCamera>>,
-    player_query: Query<&Transform, (With<Player>, Changed<Transform>, Without<MainCamera>)>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<MainCamera>>,
+    player_query: Query<&Transform, (With<Player>, Without<Downed>, Without<MainCamera>)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        let mut camera_transform = camera_query.single_mut();
-        camera_transform.translation.x = player_transform.translation.x;
-        camera_transform.translation.y = player_transform.translation.y;
+    let mut player_positions = player_query.iter().map(|transform| transform.translation.xy());
+    let (min, max) = match player_positions.next() {
+        Some(first) => {
+            player_positions
+                .fold((first, first), |(min, max), position| (min.min(position), max.max(position)))
+        },
+        None => return,
+    };
+
+    let (mut camera_transform, mut camera_projection) = match camera_query.get_single_mut() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+
+    let center = (min + max) / 2.00;
+    camera_transform.translation.x = center.x;
+    camera_transform.translation.y = center.y;
+
+    if let Ok(window) = window_query.get_single() {
+        if window.width() <= 0.00 || window.height() <= 0.00 {
+            return;
+        }
+
+        let required_area = (max - min) + Vec2::splat(2.00 * PLAYER_FRAMING_MARGIN);
+        let required_scale =
+            (required_area.x / window.width()).max(required_area.y / window.height());
+
+        let new_scale = required_scale.clamp(MIN_CAMERA_SCALE, MAX_CAMERA_SCALE);
+        if camera_projection.scale != new_scale {
+            camera_projection.scale = new_scale;
+        }
     }
 }
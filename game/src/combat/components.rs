@@ -10,6 +10,19 @@ pub enum Attack {
 }
 
 
+/// Component for the direction of manually aimed weapons.
+#[derive(Clone, Copy, Component, Debug, Deref, Reflect)]
+pub struct ManualAim(pub Dir2);
+
+
+/// Component for manually fired weapons.
+#[derive(Clone, Copy, Component, Debug, Default, Reflect)]
+pub struct ManualFire {
+    /// Whether the weapon is being fired.
+    pub firing: bool,
+}
+
+
 /// Component for cooldown of applying damage.
 #[derive(Component, Debug, Deref, DerefMut, Reflect)]
 pub struct DamageCooldown {
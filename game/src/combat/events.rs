@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+
+/// Event for receiving damage.
+#[derive(Debug, Event, Reflect)]
+pub struct DamageReceivedEvent {
+    pub entity: Entity,
+    /// Position of the entity when it received the damage,
+    /// as the entity might be despawned before the event is handled.
+    pub position: Position,
+    pub damage: Damage,
+    pub dodged: bool,
+}
+
+
+/// Event for regenerating health.
+#[derive(Debug, Event, Reflect)]
+pub struct HealthRegeneratedEvent {
+    pub entity: Entity,
+    pub amount: f32,
+}
+
+
+/// Event for the death of an enemy.
+#[derive(Debug, Event, Reflect)]
+pub struct EnemyDiedEvent {
+    pub entity: Entity,
+    pub position: Position,
+}
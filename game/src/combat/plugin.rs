@@ -10,6 +10,8 @@ impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
         // Register components.
         app.register_type::<Attack>();
+        app.register_type::<ManualAim>();
+        app.register_type::<ManualFire>();
         app.register_type::<DamageCooldown>();
         app.register_type::<Cooldown<Attack>>();
         app.register_type::<Cooldown<Damage>>();
@@ -17,6 +19,11 @@ impl Plugin for CombatPlugin {
         app.register_type::<Projectile>();
         app.register_type::<RemainingHealth>();
 
+        // Add events.
+        app.add_event::<DamageReceivedEvent>();
+        app.add_event::<HealthRegeneratedEvent>();
+        app.add_event::<EnemyDiedEvent>();
+
         // Add systems.
         {
             app.add_systems(
@@ -48,6 +55,12 @@ impl Plugin for CombatPlugin {
                 ),
             );
 
+            app.add_systems(
+                Update,
+                (aim_weapons_with_mouse, fire_weapons_manually)
+                    .in_set(GameplaySystems::Combat)
+                    .before(GameplaySystems::Item),
+            );
             app.add_systems(
                 Update,
                 (
@@ -63,8 +63,11 @@ pub fn apply_damage(
     commands: &mut Commands,
     name_query: &Query<&Name>,
     rng: &mut ResMut<GlobalEntropy<ChaCha8Rng>>,
+    damage_received_event_writer: &mut EventWriter<DamageReceivedEvent>,
 
+    damaged_entity: Entity,
     damaged_entity_name: &Name,
+    damaged_entity_position: &Position,
     damaged_entity_dodge_chance: Option<&DodgeChance>,
     damaged_entity_remaining_health: &mut RemainingHealth,
 
@@ -97,6 +100,12 @@ pub fn apply_damage(
                 damaging_entity_name,
                 originator,
             );
+            damage_received_event_writer.send(DamageReceivedEvent {
+                entity: damaged_entity,
+                position: *damaged_entity_position,
+                damage: *damage,
+                dodged: true,
+            });
             return;
         }
     }
@@ -109,6 +118,12 @@ pub fn apply_damage(
         originator,
     );
     damaged_entity_remaining_health.0 -= damage.0;
+    damage_received_event_writer.send(DamageReceivedEvent {
+        entity: damaged_entity,
+        position: *damaged_entity_position,
+        damage: *damage,
+        dodged: false,
+    });
 
     if damaged_entity_remaining_health.0 > 0.00 {
         log::info!(
@@ -123,25 +138,34 @@ pub fn apply_damage(
 pub fn damage_player_on_contact(
     mut commands: Commands,
     name_query: Query<&Name>,
-    mut player_query: Query<(&Name, &DodgeChance, &mut RemainingHealth), With<Player>>,
+    mut player_query: Query<
+        (Entity, &Name, &Position, &DodgeChance, &mut RemainingHealth),
+        (With<Player>, Without<Downed>),
+    >,
     player_hit_box_query: Query<&Parent, With<PlayerHitBox>>,
     player_damage_query: Query<
         (Entity, &Name, Option<&Originator>, &Damage, Option<&DamageCooldown>),
         (With<Attack>, With<DamagePlayerOnContact>, Without<Cooldown<Damage>>),
     >,
     mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
+    mut damage_received_event_writer: EventWriter<DamageReceivedEvent>,
     mut collision_event_reader: EventReader<Collision>,
 ) {
     for Collision(contacts) in collision_event_reader.read().cloned() {
-        let (player_name, player_dodge_chance, mut player_remaining_health) =
-            match player_hit_box_query
-                .get(contacts.entity1)
-                .or_else(|_| player_hit_box_query.get(contacts.entity2))
-                .and_then(|parent| player_query.get_mut(parent.get()))
-            {
-                Ok(query_result) => query_result,
-                Err(_) => continue,
-            };
+        let (
+            player_entity,
+            player_name,
+            player_position,
+            player_dodge_chance,
+            mut player_remaining_health,
+        ) = match player_hit_box_query
+            .get(contacts.entity1)
+            .or_else(|_| player_hit_box_query.get(contacts.entity2))
+            .and_then(|parent| player_query.get_mut(parent.get()))
+        {
+            Ok(query_result) => query_result,
+            Err(_) => continue,
+        };
 
         let (
             damaging_entity,
@@ -161,7 +185,10 @@ pub fn damage_player_on_contact(
             &mut commands,
             &name_query,
             &mut rng,
+            &mut damage_received_event_writer,
+            player_entity,
             player_name,
+            player_position,
             Some(player_dodge_chance),
             &mut player_remaining_health,
             damaging_entity,
@@ -177,25 +204,34 @@ pub fn damage_player_on_contact(
 pub fn damage_player_on_contact_started(
     mut commands: Commands,
     name_query: Query<&Name>,
-    mut player_query: Query<(&Name, &DodgeChance, &mut RemainingHealth), With<Player>>,
+    mut player_query: Query<
+        (Entity, &Name, &Position, &DodgeChance, &mut RemainingHealth),
+        (With<Player>, Without<Downed>),
+    >,
     player_hit_box_query: Query<&Parent, With<PlayerHitBox>>,
     player_damage_query: Query<
         (Entity, &Name, Option<&Originator>, &Damage, Option<&DamageCooldown>),
         (With<Attack>, With<DamagePlayerOnContactStarted>, Without<Cooldown<Damage>>),
     >,
     mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
+    mut damage_received_event_writer: EventWriter<DamageReceivedEvent>,
     mut collision_started_event_reader: EventReader<CollisionStarted>,
 ) {
     for CollisionStarted(entity1, entity2) in collision_started_event_reader.read().cloned() {
-        let (player_name, player_dodge_chance, mut player_remaining_health) =
-            match player_hit_box_query
-                .get(entity1)
-                .or_else(|_| player_hit_box_query.get(entity2))
-                .and_then(|parent| player_query.get_mut(parent.get()))
-            {
-                Ok(query_result) => query_result,
-                Err(_) => continue,
-            };
+        let (
+            player_entity,
+            player_name,
+            player_position,
+            player_dodge_chance,
+            mut player_remaining_health,
+        ) = match player_hit_box_query
+            .get(entity1)
+            .or_else(|_| player_hit_box_query.get(entity2))
+            .and_then(|parent| player_query.get_mut(parent.get()))
+        {
+            Ok(query_result) => query_result,
+            Err(_) => continue,
+        };
 
         let (
             damaging_entity,
@@ -212,7 +248,10 @@ pub fn damage_player_on_contact_started(
             &mut commands,
             &name_query,
             &mut rng,
+            &mut damage_received_event_writer,
+            player_entity,
             player_name,
+            player_position,
             Some(player_dodge_chance),
             &mut player_remaining_health,
             damaging_entity,
@@ -228,17 +267,27 @@ pub fn damage_player_on_contact_started(
 pub fn damage_enemies_on_contact(
     mut commands: Commands,
     name_query: Query<&Name>,
-    mut enemy_query: Query<(&Name, Option<&DodgeChance>, &mut RemainingHealth), With<Enemy>>,
+    mut enemy_query: Query<
+        (Entity, &Name, &Position, Option<&DodgeChance>, &mut RemainingHealth),
+        With<Enemy>,
+    >,
     enemy_hit_box_query: Query<&Parent, With<EnemyHitBox>>,
     enemy_damage_query: Query<
         (Entity, &Name, Option<&Originator>, &Damage, Option<&DamageCooldown>),
         (With<Attack>, With<DamageEnemiesOnContact>, Without<Cooldown<Damage>>),
     >,
     mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
+    mut damage_received_event_writer: EventWriter<DamageReceivedEvent>,
     mut collision_event_reader: EventReader<Collision>,
 ) {
     for Collision(contacts) in collision_event_reader.read().cloned() {
-        let (enemy_name, enemy_dodge_chance, mut enemy_remaining_health) = match enemy_hit_box_query
+        let (
+            enemy_entity,
+            enemy_name,
+            enemy_position,
+            enemy_dodge_chance,
+            mut enemy_remaining_health,
+        ) = match enemy_hit_box_query
             .get(contacts.entity1)
             .or_else(|_| enemy_hit_box_query.get(contacts.entity2))
             .and_then(|parent| enemy_query.get_mut(parent.get()))
@@ -265,7 +314,10 @@ pub fn damage_enemies_on_contact(
             &mut commands,
             &name_query,
             &mut rng,
+            &mut damage_received_event_writer,
+            enemy_entity,
             enemy_name,
+            enemy_position,
             enemy_dodge_chance,
             &mut enemy_remaining_health,
             damaging_entity,
@@ -281,17 +333,27 @@ pub fn damage_enemies_on_contact(
 pub fn damage_enemies_on_contact_started(
     mut commands: Commands,
     name_query: Query<&Name>,
-    mut enemy_query: Query<(&Name, Option<&DodgeChance>, &mut RemainingHealth), With<Enemy>>,
+    mut enemy_query: Query<
+        (Entity, &Name, &Position, Option<&DodgeChance>, &mut RemainingHealth),
+        With<Enemy>,
+    >,
     enemy_hit_box_query: Query<&Parent, With<EnemyHitBox>>,
     enemy_damage_query: Query<
         (Entity, &Name, Option<&Originator>, &Damage, Option<&DamageCooldown>),
         (With<Attack>, With<DamageEnemiesOnContactStarted>, Without<Cooldown<Damage>>),
     >,
     mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
+    mut damage_received_event_writer: EventWriter<DamageReceivedEvent>,
     mut collision_started_event_reader: EventReader<CollisionStarted>,
 ) {
     for CollisionStarted(entity1, entity2) in collision_started_event_reader.read().cloned() {
-        let (enemy_name, enemy_dodge_chance, mut enemy_remaining_health) = match enemy_hit_box_query
+        let (
+            enemy_entity,
+            enemy_name,
+            enemy_position,
+            enemy_dodge_chance,
+            mut enemy_remaining_health,
+        ) = match enemy_hit_box_query
             .get(entity1)
             .or_else(|_| enemy_hit_box_query.get(entity2))
             .and_then(|parent| enemy_query.get_mut(parent.get()))
@@ -315,7 +377,10 @@ pub fn damage_enemies_on_contact_started(
             &mut commands,
             &name_query,
             &mut rng,
+            &mut damage_received_event_writer,
+            enemy_entity,
             enemy_name,
+            enemy_position,
             enemy_dodge_chance,
             &mut enemy_remaining_health,
             damaging_entity,
@@ -329,18 +394,48 @@ pub fn damage_enemies_on_contact_started(
 
 
 /// Handles player death.
+///
+/// Players without any health left are downed, and the game is lost when all of them are down.
+/// Inventories of downed players are stashed, and they are restored when the players are revived.
 pub fn player_death(
     mut commands: Commands,
-    player_query: Query<(&Name, &RemainingHealth), With<Player>>,
+    mut player_query: Query<
+        (Entity, &Name, &RemainingHealth, &mut Inventory, &mut Visibility, &mut LinearVelocity),
+        (With<Player>, Without<Downed>),
+    >,
     mut game_state_stack: ResMut<GameStateStack>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    let (player_name, player_remaining_health) = match player_query.get_single() {
-        Ok(query_result) => query_result,
-        Err(_) => return,
-    };
-    if player_remaining_health.0 <= 0.00 {
-        log::info!("{:?} has died", player_name);
+    if player_query.is_empty() {
+        return;
+    }
+
+    let mut number_of_players_left = 0;
+    for (
+        player_entity,
+        player_name,
+        player_remaining_health,
+        mut player_inventory,
+        mut player_visibility,
+        mut player_velocity,
+    ) in player_query.iter_mut()
+    {
+        if player_remaining_health.0 <= 0.00 {
+            log::info!("{:?} is down", player_name);
+
+            player_inventory.stash();
+
+            *player_visibility = Visibility::Hidden;
+            player_velocity.0 = Vector::ZERO;
+
+            commands.entity(player_entity).insert((Downed, CollisionLayers::NONE));
+        } else {
+            number_of_players_left += 1;
+        }
+    }
+
+    if number_of_players_left == 0 {
+        log::info!("all players are down");
         commands.insert_resource(GameResult::Lost);
         game_state_stack.transition(GameState::Over);
         next_game_state.set(GameState::Transition);
@@ -366,6 +461,7 @@ pub fn enemy_death(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut experience_point_counter: ResMut<ExperiencePointCounter>,
+    mut enemy_died_event_writer: EventWriter<EnemyDiedEvent>,
 ) {
     for (
         enemy_entity,
@@ -407,6 +503,8 @@ pub fn enemy_death(
             } else {
                 log::info!("{:?} has died", enemy_name);
             }
+            enemy_died_event_writer
+                .send(EnemyDiedEvent { entity: enemy_entity, position: *enemy_position });
             commands.entity(enemy_entity).despawn_recursive();
         }
     }
@@ -437,3 +535,92 @@ pub fn despawn_projectiles(
         commands.entity(projectile_entity).despawn_recursive();
     }
 }
+
+
+/// Aims the weapons of the player using the mouse toward the cursor.
+pub fn aim_weapons_with_mouse(
+    mut commands: Commands,
+    player_query: Query<(&PlayerIndex, &Children), (With<Player>, Without<Downed>)>,
+    mut weapon_query: Query<(Entity, &GlobalTransform, Option<&mut ManualAim>), With<Weapon>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    let cursor_position =
+        window_query.get_single().ok().and_then(|window| window.cursor_position()).and_then(
+            |cursor_position| {
+                let (camera, camera_transform) = camera_query.get_single().ok()?;
+                camera.viewport_to_world_2d(camera_transform, cursor_position)
+            },
+        );
+
+    for (player_index, player_children) in player_query.iter() {
+        let uses_mouse = player_index.0 == 0 && general_settings.aim_with_mouse;
+        for &child in player_children.iter() {
+            let Ok((weapon_entity, weapon_transform, weapon_manual_aim)) =
+                weapon_query.get_mut(child)
+            else {
+                continue;
+            };
+
+            if !uses_mouse {
+                if weapon_manual_aim.is_some() {
+                    commands.entity(weapon_entity).remove::<ManualAim>();
+                }
+                continue;
+            }
+
+            if let Some(cursor_position) = cursor_position {
+                let weapon_position = weapon_transform.translation().xy();
+                if let Ok(direction) = Dir2::new(cursor_position - weapon_position) {
+                    match weapon_manual_aim {
+                        Some(mut weapon_manual_aim) => {
+                            if weapon_manual_aim.0 != direction {
+                                weapon_manual_aim.0 = direction;
+                            }
+                        },
+                        None => {
+                            commands.entity(weapon_entity).insert(ManualAim(direction));
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Fires the weapons of the player using the mouse when the fire action is pressed.
+pub fn fire_weapons_manually(
+    mut commands: Commands,
+    player_query: Query<
+        (&PlayerIndex, &ActionState<GameAction>, &Children),
+        (With<Player>, Without<Downed>),
+    >,
+    mut weapon_query: Query<(Entity, Option<&mut ManualFire>), With<Weapon>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    for (player_index, player_action_state, player_children) in player_query.iter() {
+        let fires_manually = player_index.0 == 0 && general_settings.fire_manually;
+        let firing = player_action_state.pressed(&GameAction::Fire);
+        for &child in player_children.iter() {
+            let Ok((weapon_entity, weapon_manual_fire)) = weapon_query.get_mut(child) else {
+                continue;
+            };
+
+            match weapon_manual_fire {
+                Some(mut weapon_manual_fire) => {
+                    if !fires_manually {
+                        commands.entity(weapon_entity).remove::<ManualFire>();
+                    } else if weapon_manual_fire.firing != firing {
+                        weapon_manual_fire.firing = firing;
+                    }
+                },
+                None => {
+                    if fires_manually {
+                        commands.entity(weapon_entity).insert(ManualFire { firing });
+                    }
+                },
+            }
+        }
+    }
+}
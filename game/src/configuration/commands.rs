@@ -19,4 +19,6 @@ pub enum LocaleCommands {
     Show,
     /// Sets the current locale.
     Set { locale: String },
+    /// Checks locales for missing or extra keys against the reference locale.
+    Check { locale: Option<String> },
 }
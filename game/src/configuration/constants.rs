@@ -12,3 +12,10 @@ pub const CONFIGURATION_STORAGE_FORMAT: StorageFormat = {
         StorageFormat::Json
     }
 };
+
+
+/// Locales to support when the locales can't be discovered from the assets.
+pub const FALLBACK_SUPPORTED_LOCALES: &[&str] = &["en-US", "tr"];
+
+/// Locale the other locales are checked against.
+pub const REFERENCE_LOCALE: &str = "en-US";
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+
+/// Gets the localized text of an amount of currency.
+pub fn currency(amount: f64, number_format: &NumberFormat) -> LocalizedText {
+    let amount = number_format.decimal(amount, 2);
+    LocalizedText::Localized {
+        key: "format-currency",
+        args: smallvec![("amount", amount.to_smolstr())],
+        fallback: format!("{} $", amount).into(),
+    }
+}
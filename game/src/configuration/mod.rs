@@ -1,5 +1,7 @@
 pub mod commands;
 pub mod constants;
+pub mod localization;
 pub mod plugin;
 pub mod resources;
 pub mod systems;
+pub mod utils;
@@ -25,7 +25,8 @@ impl Plugin for ConfigurationPlugin {
             let general_settings = app.world_mut().resource::<Persistent<GeneralSettings>>();
 
             let supported_locales = SupportedLocales::get();
-            let locale_assets = LocaleAssets::default();
+            let mut locale_assets = LocaleAssets::default();
+            locale_assets.push("formatting.ftl");
             let default_locale = DefaultLocale::get(&supported_locales);
             let current_locale = match general_settings.locale() {
                 Some(locale) => {
@@ -53,6 +54,7 @@ impl Plugin for ConfigurationPlugin {
             app.insert_resource(supported_locales);
             app.insert_resource(locale_assets);
             app.insert_resource(default_locale);
+            app.insert_resource(NumberFormat::new(&current_locale.requested));
             app.insert_resource(current_locale);
 
             app.add_systems(OnEnter(LocalizationState::Loading), load_locale_assets);
@@ -80,6 +82,13 @@ impl Plugin for ConfigurationPlugin {
                     .run_if(in_state(LocalizationState::Loading))
                     .run_if(resource_exists::<LocalesFolder>),
             );
+            #[cfg(feature = "development")]
+            app.add_systems(
+                Update,
+                reload_locale_on_change
+                    .run_if(in_state(LocalizationState::Ready))
+                    .run_if(resource_exists::<WatchedLocaleResources>),
+            );
             app.add_systems(
                 OnEnter(LocalizationState::Ready),
                 transition_to_application.run_if(in_state(AppState::LoadingInitialLocalization)),
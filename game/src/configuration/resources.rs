@@ -1,4 +1,5 @@
 use crate::{
+    accessibility::constants::*,
     configuration::constants::*,
     prelude::*,
 };
@@ -32,6 +33,8 @@ pub struct Args {
     pub data_directory: PathBuf,
     /// Seed for random number generation.
     pub seed: Option<u64>,
+    /// Number of local players.
+    pub players: Option<usize>,
     /// Flag for starting the application in game.
     pub start_in_game: bool,
     /// Game mode to pick when starting in game.
@@ -52,6 +55,10 @@ pub struct Args {
     pub start_in_game_free_refreshes: Option<usize>,
     /// Flag to enable god mode.
     pub enable_god_mode: bool,
+    /// Mutators to enable for the runs.
+    pub mutators: Vec<Mutator>,
+    /// Console script to execute at startup.
+    pub startup_script: Option<PathBuf>,
 }
 
 impl Args {
@@ -84,6 +91,8 @@ impl Args {
             #[arg(long)]
             pub seed: Option<u64>,
             #[arg(long)]
+            pub players: Option<usize>,
+            #[arg(long)]
             pub game: bool,
             #[arg(long)]
             pub mode: Option<String>,
@@ -103,6 +112,10 @@ impl Args {
             pub free_refreshes: Option<usize>,
             #[arg(long)]
             pub god_mode: bool,
+            #[arg(long, value_delimiter = ',')]
+            pub mutators: Vec<Mutator>,
+            #[arg(long)]
+            pub exec: Option<PathBuf>,
         }
 
         impl Default for ArgsParser {
@@ -111,6 +124,7 @@ impl Args {
                     configuration: None,
                     data: None,
                     seed: None,
+                    players: None,
                     game: false,
                     mode: None,
                     player: None,
@@ -121,6 +135,8 @@ impl Args {
                     balance: None,
                     free_refreshes: None,
                     god_mode: false,
+                    mutators: vec![],
+                    exec: None,
                 }
             }
         }
@@ -136,6 +152,9 @@ impl Args {
                 if let Some(seed) = &self.seed {
                     write!(f, " --seed {}", seed)?;
                 }
+                if let Some(players) = &self.players {
+                    write!(f, " --players {}", players)?;
+                }
                 if self.game {
                     write!(f, " --game")?;
                 }
@@ -170,6 +189,14 @@ impl Args {
                 if self.god_mode {
                     write!(f, " --god-mode")?;
                 }
+                if !self.mutators.is_empty() {
+                    let mutators =
+                        self.mutators.iter().map(|mutator| mutator.id()).collect::<Vec<_>>();
+                    write!(f, " --mutators \"{}\"", mutators.join(","))?;
+                }
+                if let Some(exec) = &self.exec {
+                    write!(f, " --exec \"{}\"", exec.display())?;
+                }
                 Ok(())
             }
         }
@@ -241,6 +268,7 @@ impl Args {
                 );
 
                 let seed = self.seed;
+                let players = self.players;
                 let start_in_game = self.game;
                 let start_in_game_mode = self.mode;
                 let start_in_game_player = self.player;
@@ -257,11 +285,14 @@ impl Args {
                 let start_in_game_balance = self.balance;
                 let start_in_game_free_refreshes = self.free_refreshes;
                 let enable_god_mode = self.god_mode;
+                let mutators = self.mutators;
+                let startup_script = self.exec;
 
                 Args {
                     data_directory,
                     configuration_directory,
                     seed,
+                    players,
                     start_in_game,
                     start_in_game_mode,
                     start_in_game_player,
@@ -272,6 +303,8 @@ impl Args {
                     start_in_game_balance,
                     start_in_game_free_refreshes,
                     enable_god_mode,
+                    mutators,
+                    startup_script,
                 }
             }
         }
@@ -345,6 +378,26 @@ pub struct GeneralSettings {
     pub pause_on_losing_focus: bool,
     pub show_diagnostics_overlay: bool,
 
+    pub aim_with_mouse: bool,
+    pub fire_manually: bool,
+
+    pub damage_numbers: bool,
+    pub hit_flashes: bool,
+    pub death_effects: bool,
+    pub enemy_health_bars: EnemyHealthBars,
+
+    pub vsync: bool,
+    pub frame_rate_limit: u32,
+
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sound_effects_volume: f32,
+
+    pub ui_scale: f32,
+    pub color_palette: ColorPalette,
+    pub reduce_screen_effects: bool,
+    pub high_contrast: bool,
+
     #[cfg(feature = "development")]
     pub enable_physics_gizmos: bool,
 }
@@ -380,6 +433,16 @@ impl GeneralSettings {
     pub fn locale(&self) -> Option<LanguageIdentifier> {
         self.locale.parse::<LanguageIdentifier>().ok()
     }
+
+    /// Gets the effective volume of the music.
+    pub fn effective_music_volume(&self) -> f32 {
+        (self.master_volume * self.music_volume).clamp(0.00, 1.00)
+    }
+
+    /// Gets the effective volume of the sound effects.
+    pub fn effective_sound_effects_volume(&self) -> f32 {
+        (self.master_volume * self.sound_effects_volume).clamp(0.00, 1.00)
+    }
 }
 
 impl Default for GeneralSettings {
@@ -390,6 +453,26 @@ impl Default for GeneralSettings {
             pause_on_losing_focus: true,
             show_diagnostics_overlay: false,
 
+            aim_with_mouse: false,
+            fire_manually: false,
+
+            damage_numbers: true,
+            hit_flashes: true,
+            death_effects: true,
+            enemy_health_bars: EnemyHealthBars::default(),
+
+            vsync: true,
+            frame_rate_limit: 0,
+
+            master_volume: 1.00,
+            music_volume: 0.50,
+            sound_effects_volume: 0.75,
+
+            ui_scale: 1.00,
+            color_palette: ColorPalette::default(),
+            reduce_screen_effects: false,
+            high_contrast: false,
+
             #[cfg(feature = "development")]
             enable_physics_gizmos: false,
         }
@@ -397,6 +480,114 @@ impl Default for GeneralSettings {
 }
 
 
+/// Color palettes of the game.
+#[derive(Clone, Copy, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Reflect, Serialize)]
+pub enum ColorPalette {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl ColorPalette {
+    /// Gets the foreground color of health bars.
+    pub fn health_bar(&self) -> Color {
+        match self {
+            ColorPalette::Default => DEFAULT_HEALTH_BAR_COLOR,
+            ColorPalette::Deuteranopia | ColorPalette::Protanopia => {
+                RED_GREEN_SAFE_HEALTH_BAR_COLOR
+            },
+            ColorPalette::Tritanopia => BLUE_YELLOW_SAFE_HEALTH_BAR_COLOR,
+        }
+    }
+
+    /// Gets the foreground color of experience bars.
+    pub fn experience_bar(&self) -> Color {
+        match self {
+            ColorPalette::Default => DEFAULT_EXPERIENCE_BAR_COLOR,
+            ColorPalette::Deuteranopia | ColorPalette::Protanopia => {
+                RED_GREEN_SAFE_EXPERIENCE_BAR_COLOR
+            },
+            ColorPalette::Tritanopia => BLUE_YELLOW_SAFE_EXPERIENCE_BAR_COLOR,
+        }
+    }
+
+    /// Tints a color to make it distinguishable with the palette.
+    ///
+    /// Color information lost to the color vision deficiency of the palette is shifted
+    /// towards the channels that are still perceived (i.e., daltonization).
+    pub fn tint(&self, color: Color) -> Color {
+        let simulation = match self {
+            ColorPalette::Default => return color,
+            ColorPalette::Deuteranopia => DEUTERANOPIA_SIMULATION,
+            ColorPalette::Protanopia => PROTANOPIA_SIMULATION,
+            ColorPalette::Tritanopia => TRITANOPIA_SIMULATION,
+        };
+
+        let rgb_to_lms = Mat3::from_cols_array_2d(&RGB_TO_LMS).transpose();
+        let lms_to_rgb = Mat3::from_cols_array_2d(&LMS_TO_RGB).transpose();
+        let simulation = Mat3::from_cols_array_2d(&simulation).transpose();
+        let correction = Mat3::from_cols_array_2d(&DALTONIZATION_CORRECTION).transpose();
+
+        let srgba = color.to_srgba();
+        let original = Vec3::new(srgba.red, srgba.green, srgba.blue);
+        let simulated = lms_to_rgb * (simulation * (rgb_to_lms * original));
+        let corrected = original + correction * (original - simulated);
+
+        Color::srgba(
+            corrected.x.clamp(0.00, 1.00),
+            corrected.y.clamp(0.00, 1.00),
+            corrected.z.clamp(0.00, 1.00),
+            srgba.alpha,
+        )
+    }
+}
+
+impl ColorPalette {
+    /// Gets the previous color palette.
+    pub fn previous(&self) -> ColorPalette {
+        utils::configuration::previous_variant(self)
+    }
+
+    /// Gets the next color palette.
+    pub fn next(&self) -> ColorPalette {
+        utils::configuration::next_variant(self)
+    }
+}
+
+
+/// Visibility of the health bars of enemies.
+#[derive(Clone, Copy, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Reflect, Serialize)]
+pub enum EnemyHealthBars {
+    Always,
+    #[default]
+    DamagedOnly,
+    Never,
+}
+
+impl EnemyHealthBars {
+    /// Gets whether the health bar of an enemy should be visible.
+    pub fn is_visible(&self, damaged: bool) -> bool {
+        match self {
+            EnemyHealthBars::Always => true,
+            EnemyHealthBars::DamagedOnly => damaged,
+            EnemyHealthBars::Never => false,
+        }
+    }
+
+    /// Gets the previous option.
+    pub fn previous(&self) -> EnemyHealthBars {
+        utils::configuration::previous_variant(self)
+    }
+
+    /// Gets the next option.
+    pub fn next(&self) -> EnemyHealthBars {
+        utils::configuration::next_variant(self)
+    }
+}
+
+
 /// Supported locales of the game.
 #[derive(Clone, Default, Deref, Resource)]
 pub struct SupportedLocales(pub Vec<LanguageIdentifier>);
@@ -404,8 +595,18 @@ pub struct SupportedLocales(pub Vec<LanguageIdentifier>);
 impl SupportedLocales {
     /// Gets the supported locales.
     pub fn get() -> SupportedLocales {
+        #[cfg(feature = "native")]
+        {
+            match utils::configuration::discover_locales() {
+                Ok(locales) if !locales.is_empty() => return SupportedLocales(locales),
+                Ok(_) => log::error!("no locales are found in the assets, using fallback locales"),
+                Err(error) => {
+                    log::error!("unable to discover locales ({}), using fallback locales", error);
+                },
+            }
+        }
         SupportedLocales(
-            ["en-US", "tr"]
+            FALLBACK_SUPPORTED_LOCALES
                 .iter()
                 .map(|s| s.parse::<LanguageIdentifier>().expect("invalid locale constant"))
                 .collect(),
@@ -444,6 +645,75 @@ impl DefaultLocale {
 }
 
 
+/// Number format of the current locale.
+#[derive(Clone, Debug, Resource)]
+pub struct NumberFormat {
+    locale: NumLocale,
+}
+
+impl NumberFormat {
+    /// Creates the number format of a locale.
+    pub fn new(locale: &LanguageIdentifier) -> NumberFormat {
+        let locale = NumLocale::from_name(locale.to_string())
+            .or_else(|_| NumLocale::from_name(locale.language.as_str()))
+            .unwrap_or(NumLocale::en);
+        NumberFormat { locale }
+    }
+}
+
+impl NumberFormat {
+    /// Formats an integer.
+    pub fn integer(&self, value: impl ToFormattedString) -> String {
+        value.to_formatted_string(&self.locale)
+    }
+
+    /// Formats a decimal with the specified number of fractional digits.
+    pub fn decimal(&self, value: f64, precision: usize) -> String {
+        if value.is_nan() {
+            return "?".to_owned();
+        }
+
+        let sign = if value.is_sign_negative() { self.locale.minus_sign() } else { "" };
+        if value.is_infinite() {
+            return format!("{}∞", sign);
+        }
+
+        let digits = format!("{:.*}", precision, value.abs());
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+        let mut formatted = String::with_capacity(digits.len() + 8);
+        if digits.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            formatted.push_str(sign);
+        }
+        match integer.parse::<u64>() {
+            Ok(integer) => formatted.push_str(&self.integer(integer)),
+            Err(_) => formatted.push_str(integer),
+        }
+        if !fraction.is_empty() {
+            formatted.push_str(self.locale.decimal());
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Formats a duration as whole seconds, rounded up.
+    pub fn seconds(&self, duration: Duration) -> String {
+        self.integer(duration.as_secs_f64().ceil() as u64)
+    }
+
+    /// Formats a duration as hours, minutes and seconds, omitting hours when it's zero.
+    pub fn duration(&self, duration: Duration) -> String {
+        let seconds = duration.as_secs();
+        let (hours, minutes, seconds) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+        if hours > 0 {
+            format!("{}:{:02}:{:02}", self.integer(hours), minutes, seconds)
+        } else {
+            format!("{:02}:{:02}", minutes, seconds)
+        }
+    }
+}
+
+
 /// Asset locations of locale assets.
 #[derive(Debug, Default, Deref, DerefMut, Resource, Reflect)]
 #[reflect(Resource)]
@@ -455,6 +725,12 @@ pub struct LocaleAssets(pub Vec<&'static str>);
 pub struct LocaleResourceHandles(pub Vec<Handle<ResourceAsset>>);
 
 
+/// Asset handles of locale resources of the current locale, kept to watch them for changes.
+#[cfg(feature = "development")]
+#[derive(Clone, Default, Deref, Resource)]
+pub struct WatchedLocaleResources(pub Vec<Handle<ResourceAsset>>);
+
+
 /// Locales folder.
 #[derive(Clone, Default, Deref, Resource)]
 pub struct LocalesFolder(pub Handle<LoadedFolder>);
@@ -473,11 +749,38 @@ pub struct KeyBindings {
     /// Keys to go right.
     pub right: SmallVec<[KeyCode; 2]>,
     /// Keys to dash.
-    pub dash: SmallVec<[KeyCode; 1]>,
+    pub dash: SmallVec<[KeyCode; 2]>,
     /// Keys to pause the game.
-    pub pause: SmallVec<[KeyCode; 1]>,
+    pub pause: SmallVec<[KeyCode; 2]>,
     /// Keys to open the market, when the game mode allows it.
-    pub market: SmallVec<[KeyCode; 1]>,
+    pub market: SmallVec<[KeyCode; 2]>,
+    /// Keys to fire manually fired weapons, in addition to the left mouse button.
+    pub fire: SmallVec<[KeyCode; 2]>,
+    /// Keys to select in menus.
+    pub select: SmallVec<[KeyCode; 2]>,
+    /// Keys to go back in menus.
+    pub back: SmallVec<[KeyCode; 2]>,
+
+    /// Gamepad buttons to go up.
+    pub gamepad_up: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to go left.
+    pub gamepad_left: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to go down.
+    pub gamepad_down: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to go right.
+    pub gamepad_right: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to dash.
+    pub gamepad_dash: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to pause the game.
+    pub gamepad_pause: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to open the market, when the game mode allows it.
+    pub gamepad_market: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to fire manually fired weapons.
+    pub gamepad_fire: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to select in menus.
+    pub gamepad_select: SmallVec<[GamepadButtonType; 1]>,
+    /// Gamepad buttons to go back in menus.
+    pub gamepad_back: SmallVec<[GamepadButtonType; 1]>,
 }
 
 impl KeyBindings {
@@ -516,6 +819,185 @@ impl Default for KeyBindings {
             dash: smallvec![KeyCode::Space],
             pause: smallvec![KeyCode::Escape],
             market: smallvec![KeyCode::KeyB],
+            fire: smallvec![],
+            select: smallvec![KeyCode::Enter],
+            back: smallvec![KeyCode::Escape],
+
+            gamepad_up: smallvec![GamepadButtonType::DPadUp],
+            gamepad_left: smallvec![GamepadButtonType::DPadLeft],
+            gamepad_down: smallvec![GamepadButtonType::DPadDown],
+            gamepad_right: smallvec![GamepadButtonType::DPadRight],
+            gamepad_dash: smallvec![GamepadButtonType::RightTrigger],
+            gamepad_pause: smallvec![GamepadButtonType::Start],
+            gamepad_market: smallvec![GamepadButtonType::North],
+            gamepad_fire: smallvec![GamepadButtonType::RightTrigger2],
+            gamepad_select: smallvec![GamepadButtonType::South],
+            gamepad_back: smallvec![GamepadButtonType::East],
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Gets the keys of a binding.
+    pub fn keys(&self, binding: KeyBinding) -> &[KeyCode] {
+        match binding {
+            KeyBinding::Up => &self.up,
+            KeyBinding::Left => &self.left,
+            KeyBinding::Down => &self.down,
+            KeyBinding::Right => &self.right,
+            KeyBinding::Dash => &self.dash,
+            KeyBinding::Pause => &self.pause,
+            KeyBinding::Market => &self.market,
+            KeyBinding::Fire => &self.fire,
+            KeyBinding::Select => &self.select,
+            KeyBinding::Back => &self.back,
+        }
+    }
+
+    /// Gets the keys of a binding mutably.
+    pub fn keys_mut(&mut self, binding: KeyBinding) -> &mut SmallVec<[KeyCode; 2]> {
+        match binding {
+            KeyBinding::Up => &mut self.up,
+            KeyBinding::Left => &mut self.left,
+            KeyBinding::Down => &mut self.down,
+            KeyBinding::Right => &mut self.right,
+            KeyBinding::Dash => &mut self.dash,
+            KeyBinding::Pause => &mut self.pause,
+            KeyBinding::Market => &mut self.market,
+            KeyBinding::Fire => &mut self.fire,
+            KeyBinding::Select => &mut self.select,
+            KeyBinding::Back => &mut self.back,
+        }
+    }
+
+    /// Gets the gamepad buttons of a binding.
+    pub fn gamepad_buttons(&self, binding: KeyBinding) -> &SmallVec<[GamepadButtonType; 1]> {
+        match binding {
+            KeyBinding::Up => &self.gamepad_up,
+            KeyBinding::Left => &self.gamepad_left,
+            KeyBinding::Down => &self.gamepad_down,
+            KeyBinding::Right => &self.gamepad_right,
+            KeyBinding::Dash => &self.gamepad_dash,
+            KeyBinding::Pause => &self.gamepad_pause,
+            KeyBinding::Market => &self.gamepad_market,
+            KeyBinding::Fire => &self.gamepad_fire,
+            KeyBinding::Select => &self.gamepad_select,
+            KeyBinding::Back => &self.gamepad_back,
+        }
+    }
+
+    /// Gets the gamepad buttons of a binding mutably.
+    pub fn gamepad_buttons_mut(
+        &mut self,
+        binding: KeyBinding,
+    ) -> &mut SmallVec<[GamepadButtonType; 1]> {
+        match binding {
+            KeyBinding::Up => &mut self.gamepad_up,
+            KeyBinding::Left => &mut self.gamepad_left,
+            KeyBinding::Down => &mut self.gamepad_down,
+            KeyBinding::Right => &mut self.gamepad_right,
+            KeyBinding::Dash => &mut self.gamepad_dash,
+            KeyBinding::Pause => &mut self.gamepad_pause,
+            KeyBinding::Market => &mut self.gamepad_market,
+            KeyBinding::Fire => &mut self.gamepad_fire,
+            KeyBinding::Select => &mut self.gamepad_select,
+            KeyBinding::Back => &mut self.gamepad_back,
+        }
+    }
+
+    /// Finds the binding the key is bound to, among the bindings that are active together with
+    /// the specified binding.
+    pub fn find_key(&self, binding: KeyBinding, key_code: KeyCode) -> Option<KeyBinding> {
+        KeyBinding::iter()
+            .find(|other| binding.conflicts_with(*other) && self.keys(*other).contains(&key_code))
+    }
+
+    /// Finds the binding the gamepad button is bound to, among the bindings that are active
+    /// together with the specified binding.
+    pub fn find_gamepad_button(
+        &self,
+        binding: KeyBinding,
+        gamepad_button: GamepadButtonType,
+    ) -> Option<KeyBinding> {
+        KeyBinding::iter().find(|other| {
+            binding.conflicts_with(*other) && self.gamepad_buttons(*other).contains(&gamepad_button)
+        })
+    }
+}
+
+
+/// Bindings in the key bindings.
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, PartialEq, Reflect)]
+pub enum KeyBinding {
+    Up,
+    Left,
+    Down,
+    Right,
+    Dash,
+    Pause,
+    Market,
+    Fire,
+    Select,
+    Back,
+}
+
+impl KeyBinding {
+    /// Gets the bindings in the order of the actions they are used for.
+    ///
+    /// Bindings of the game actions come first, followed by the bindings that are only
+    /// used in menus.
+    pub fn all() -> impl Iterator<Item = KeyBinding> {
+        GameAction::iter()
+            .filter_map(KeyBinding::of_game_action)
+            .chain([KeyBinding::Select, KeyBinding::Back])
+    }
+
+    /// Gets the binding of a game action, if the action can be bound.
+    pub fn of_game_action(action: GameAction) -> Option<KeyBinding> {
+        match action {
+            GameAction::Pause => Some(KeyBinding::Pause),
+            GameAction::Move => None,
+            GameAction::MoveUp => Some(KeyBinding::Up),
+            GameAction::MoveLeft => Some(KeyBinding::Left),
+            GameAction::MoveDown => Some(KeyBinding::Down),
+            GameAction::MoveRight => Some(KeyBinding::Right),
+            GameAction::Dash => Some(KeyBinding::Dash),
+            GameAction::Fire => Some(KeyBinding::Fire),
+            GameAction::OpenMarket => Some(KeyBinding::Market),
+        }
+    }
+
+    /// Gets whether the binding is used in menus.
+    pub fn is_used_in_menus(&self) -> bool {
+        matches!(
+            self,
+            KeyBinding::Up
+                | KeyBinding::Left
+                | KeyBinding::Down
+                | KeyBinding::Right
+                | KeyBinding::Pause
+                | KeyBinding::Market
+                | KeyBinding::Select
+                | KeyBinding::Back,
+        )
+    }
+
+    /// Gets whether the binding is used in the game.
+    pub fn is_used_in_game(&self) -> bool {
+        !matches!(self, KeyBinding::Select | KeyBinding::Back)
+    }
+
+    /// Gets whether the binding can't share inputs with another binding.
+    ///
+    /// Pause and back can share inputs, as both of them resume the game in the pause menu.
+    pub fn conflicts_with(&self, other: KeyBinding) -> bool {
+        if matches!(
+            (self, other),
+            (KeyBinding::Pause, KeyBinding::Back) | (KeyBinding::Back, KeyBinding::Pause),
+        ) {
+            return false;
         }
+        (self.is_used_in_menus() && other.is_used_in_menus())
+            || (self.is_used_in_game() && other.is_used_in_game())
     }
 }
@@ -1,5 +1,8 @@
 use crate::{
-    configuration::commands::*,
+    configuration::{
+        commands::*,
+        constants::*,
+    },
     prelude::*,
 };
 
@@ -34,6 +37,103 @@ pub fn apply_locale_command(
                     _ => reply!(command, "Requested locale isn't available."),
                 }
             },
+            LocaleCommands::Check { locale: requested } => {
+                #[cfg(feature = "native")]
+                {
+                    let reference_locale = REFERENCE_LOCALE
+                        .parse::<LanguageIdentifier>()
+                        .expect("invalid reference locale constant");
+
+                    let locales_to_check = match requested {
+                        Some(requested) => {
+                            match requested.parse::<LanguageIdentifier>() {
+                                Ok(locale) if supported_locales.contains(&locale) => {
+                                    vec![locale]
+                                },
+                                _ => {
+                                    reply!(command, "Requested locale isn't available.");
+                                    reply!(command, "");
+                                    return;
+                                },
+                            }
+                        },
+                        None => {
+                            supported_locales
+                                .iter()
+                                .filter(|locale| **locale != reference_locale)
+                                .cloned()
+                                .collect()
+                        },
+                    };
+
+                    let reference_keys =
+                        match utils::configuration::collect_locale_keys(&reference_locale) {
+                            Ok(keys) => keys,
+                            Err(error) => {
+                                reply!(
+                                    command,
+                                    "Unable to read {} locale files ({}).",
+                                    reference_locale,
+                                    error,
+                                );
+                                reply!(command, "");
+                                return;
+                            },
+                        };
+
+                    for locale in locales_to_check {
+                        if locale == reference_locale {
+                            reply!(command, "{} is the reference locale.", locale);
+                            continue;
+                        }
+
+                        let keys = match utils::configuration::collect_locale_keys(&locale) {
+                            Ok(keys) => keys,
+                            Err(error) => {
+                                reply!(
+                                    command,
+                                    "Unable to read {} locale files ({}).",
+                                    locale,
+                                    error
+                                );
+                                continue;
+                            },
+                        };
+
+                        let check = utils::configuration::LocaleCheck::new(&reference_keys, &keys);
+                        if check.is_complete() {
+                            reply!(command, "{} is complete.", locale);
+                            continue;
+                        }
+
+                        reply!(
+                            command,
+                            "{} has {} missing file{}, {} missing key{} and {} extra key{}.",
+                            locale,
+                            check.missing_files.len(),
+                            if check.missing_files.len() == 1 { "" } else { "s" },
+                            check.missing_keys.len(),
+                            if check.missing_keys.len() == 1 { "" } else { "s" },
+                            check.extra_keys.len(),
+                            if check.extra_keys.len() == 1 { "" } else { "s" },
+                        );
+                        for file in check.missing_files.iter() {
+                            reply!(command, "  missing file {}", file.display());
+                        }
+                        for (file, key) in check.missing_keys.iter() {
+                            reply!(command, "  missing key {} in {}", key, file.display());
+                        }
+                        for (file, key) in check.extra_keys.iter() {
+                            reply!(command, "  extra key {} in {}", key, file.display());
+                        }
+                    }
+                }
+                #[cfg(feature = "wasm")]
+                {
+                    let _ = requested;
+                    reply!(command, "Not available on the web.");
+                }
+            },
         }
 
         reply!(command, "");
@@ -56,6 +156,9 @@ pub fn load_locale_assets(
         locale_resource_handles.push(asset_server.load::<ResourceAsset>(path));
     }
 
+    #[cfg(feature = "development")]
+    commands.insert_resource(WatchedLocaleResources(locale_resource_handles.clone()));
+
     commands.insert_resource(LocaleResourceHandles(locale_resource_handles));
 }
 
@@ -112,6 +215,32 @@ pub fn transition_to_ready(
     commands.remove_resource::<LocalesFolder>();
 }
 
+/// Reloads the locale when any of its resources is modified.
+#[cfg(feature = "development")]
+pub fn reload_locale_on_change(
+    asset_server: Res<AssetServer>,
+    mut resource_asset_events: EventReader<AssetEvent<ResourceAsset>>,
+    watched_locale_resources: Res<WatchedLocaleResources>,
+    mut next_localization_state: ResMut<NextState<LocalizationState>>,
+) {
+    let mut modified = false;
+    for event in resource_asset_events.read() {
+        if let AssetEvent::Modified { id } = event {
+            if watched_locale_resources.iter().any(|handle| handle.id() == *id) {
+                if let Some(path) = asset_server.get_path(*id) {
+                    log::info!("{} is modified", path);
+                }
+                modified = true;
+            }
+        }
+    }
+
+    if modified {
+        log::info!("reloading the locale");
+        next_localization_state.set(LocalizationState::Loading);
+    }
+}
+
 /// Transitions to the application when locales are ready.
 pub fn transition_to_application(args: Res<Args>, mut next_app_state: ResMut<NextState<AppState>>) {
     // Transition to game mode selection screen when starting in game.
@@ -129,6 +258,7 @@ pub fn set_locale(
     supported_locales: Res<SupportedLocales>,
     mut general_settings: ResMut<Persistent<GeneralSettings>>,
     mut locale: ResMut<Locale>,
+    mut number_format: ResMut<NumberFormat>,
     mut next_localization_state: ResMut<NextState<LocalizationState>>,
 ) {
     let new_locale_string = new_locale.to_string();
@@ -143,6 +273,7 @@ pub fn set_locale(
     general_settings.locale = new_locale_string;
     general_settings.persist().ok();
 
+    *number_format = NumberFormat::new(&new_locale);
     locale.requested = new_locale;
     next_localization_state.set(LocalizationState::Loading);
 }
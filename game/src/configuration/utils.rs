@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+
+/// Gets the variant before `current` in the declaration order, wrapping around to the last one.
+pub fn previous_variant<T: IntoEnumIterator + PartialEq>(current: &T) -> T {
+    let variants = T::iter().collect::<Vec<_>>();
+    let position = variants.iter().position(|variant| variant == current).unwrap_or(0);
+    let previous_position = if position == 0 { variants.len() - 1 } else { position - 1 };
+    variants.into_iter().nth(previous_position).unwrap()
+}
+
+/// Gets the variant after `current` in the declaration order, wrapping around to the first one.
+pub fn next_variant<T: IntoEnumIterator + PartialEq>(current: &T) -> T {
+    let variants = T::iter().collect::<Vec<_>>();
+    let position = variants.iter().position(|variant| variant == current).unwrap_or(0);
+    let next_position = if position == variants.len() - 1 { 0 } else { position + 1 };
+    variants.into_iter().nth(next_position).unwrap()
+}
+
+
+/// Gets the directory of the locales in the assets.
+#[cfg(feature = "native")]
+pub fn locales_directory() -> PathBuf {
+    FileAssetReader::get_base_path().join("assets").join("locales")
+}
+
+/// Discovers the locales in the assets.
+#[cfg(feature = "native")]
+pub fn discover_locales() -> std::io::Result<Vec<LanguageIdentifier>> {
+    let mut locales = Vec::new();
+    for entry in std::fs::read_dir(locales_directory())? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name();
+        match name.to_string_lossy().parse::<LanguageIdentifier>() {
+            Ok(locale) => locales.push(locale),
+            Err(_) => {
+                log::warn!("ignoring locale directory {:?} as it's not a valid locale", name);
+            },
+        }
+    }
+    locales.sort_by_key(|locale| locale.to_string());
+    Ok(locales)
+}
+
+
+/// Collects the message and term keys in the locale files of a locale.
+#[cfg(feature = "native")]
+pub fn collect_locale_keys(
+    locale: &LanguageIdentifier,
+) -> std::io::Result<BTreeMap<PathBuf, BTreeSet<String>>> {
+    fn collect(
+        root: &Path,
+        directory: &Path,
+        keys: &mut BTreeMap<PathBuf, BTreeSet<String>>,
+    ) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                collect(root, &path, keys)?;
+            } else if path.extension().is_some_and(|extension| extension == "ftl") {
+                let source = std::fs::read_to_string(&path)?;
+                let relative_path = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+                keys.insert(relative_path, parse_locale_keys(&source));
+            }
+        }
+        Ok(())
+    }
+
+    let root = locales_directory().join(locale.to_string());
+    let mut keys = BTreeMap::new();
+    collect(&root, &root, &mut keys)?;
+    Ok(keys)
+}
+
+/// Parses the message and term keys in a locale file.
+pub fn parse_locale_keys(source: &str) -> BTreeSet<String> {
+    source
+        .lines()
+        .filter(|line| line.starts_with(|c: char| c.is_ascii_alphabetic() || c == '-'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, _)| key.trim())
+        .filter(|key| {
+            let identifier = key.strip_prefix('-').unwrap_or(key);
+            identifier.starts_with(|c: char| c.is_ascii_alphabetic())
+                && identifier.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+        .map(|key| key.to_owned())
+        .collect()
+}
+
+
+/// Result of checking a locale against the reference locale.
+#[derive(Debug, Default)]
+pub struct LocaleCheck {
+    /// Locale files of the reference locale that are missing in the locale.
+    pub missing_files: Vec<PathBuf>,
+    /// Keys of the reference locale that are missing in the locale.
+    pub missing_keys: Vec<(PathBuf, String)>,
+    /// Keys of the locale that are not in the reference locale.
+    pub extra_keys: Vec<(PathBuf, String)>,
+}
+
+impl LocaleCheck {
+    /// Checks the keys of a locale against the keys of the reference locale.
+    pub fn new(
+        reference: &BTreeMap<PathBuf, BTreeSet<String>>,
+        locale: &BTreeMap<PathBuf, BTreeSet<String>>,
+    ) -> LocaleCheck {
+        let empty = BTreeSet::new();
+        let mut check = LocaleCheck::default();
+
+        for (file, reference_keys) in reference.iter() {
+            let keys = match locale.get(file) {
+                Some(keys) => keys,
+                None => {
+                    check.missing_files.push(file.clone());
+                    &empty
+                },
+            };
+            for key in reference_keys.difference(keys) {
+                check.missing_keys.push((file.clone(), key.clone()));
+            }
+        }
+
+        for (file, keys) in locale.iter() {
+            let reference_keys = reference.get(file).unwrap_or(&empty);
+            for key in keys.difference(reference_keys) {
+                check.extra_keys.push((file.clone(), key.clone()));
+            }
+        }
+
+        check
+    }
+
+    /// Gets whether the locale is complete.
+    pub fn is_complete(&self) -> bool {
+        self.missing_files.is_empty() && self.missing_keys.is_empty() && self.extra_keys.is_empty()
+    }
+}
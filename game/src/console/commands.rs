@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+
+/// Executes the console commands in a script.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "exec")]
+#[command(disable_help_flag = true)]
+pub struct ExecCommand {
+    /// Path of the script, relative to the configuration directory.
+    pub script: PathBuf,
+}
+
+
+/// Controls the console history.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "history")]
+#[command(disable_help_flag = true)]
+pub struct HistoryCommand {
+    #[clap(subcommand)]
+    pub subcommand: HistoryCommands,
+}
+
+/// History commands.
+#[derive(Debug, Subcommand)]
+pub enum HistoryCommands {
+    /// Shows the most recent commands in the console history.
+    Show {
+        #[arg(default_value = "10")]
+        count: usize,
+    },
+    /// Runs a command in the console history again.
+    Run { position: NonZeroUsize },
+    /// Clears the console history.
+    Clear,
+}
+
+
+/// Controls the console aliases.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "alias")]
+#[command(disable_help_flag = true)]
+pub struct AliasCommand {
+    #[clap(subcommand)]
+    pub subcommand: AliasCommands,
+}
+
+/// Alias commands.
+#[derive(Debug, Subcommand)]
+pub enum AliasCommands {
+    /// Lists the console aliases.
+    List,
+    /// Sets a console alias.
+    Set {
+        name: String,
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
+    /// Removes a console alias.
+    Remove { name: String },
+}
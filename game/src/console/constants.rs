@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+
+/// Storage format for console history files.
+pub const CONSOLE_HISTORY_STORAGE_FORMAT: StorageFormat = {
+    #[cfg(feature = "native")]
+    {
+        StorageFormat::Toml
+    }
+    #[cfg(feature = "wasm")]
+    {
+        StorageFormat::Json
+    }
+};
+
+
+/// Extension of console scripts.
+pub const CONSOLE_SCRIPT_EXTENSION: &str = "cfg";
+
+/// Name of the console script executed when the gameplay starts.
+pub const AUTOEXEC_CONSOLE_SCRIPT: &str = "autoexec.cfg";
+
+
+/// Maximum number of console commands waiting to be executed.
+pub const MAX_QUEUED_CONSOLE_COMMANDS: usize = 1000;
+
+/// Maximum number of commands in the console history.
+pub const MAX_CONSOLE_HISTORY_ENTRIES: usize = 500;
+
+/// Number of recent commands in the console history to show when the application starts.
+pub const SEEDED_CONSOLE_HISTORY_ENTRIES: usize = 10;
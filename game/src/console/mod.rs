@@ -1,2 +1,5 @@
+pub mod commands;
+pub mod constants;
 pub mod plugin;
+pub mod resources;
 pub mod systems;
@@ -1,5 +1,8 @@
 use crate::{
-    console::systems::*,
+    console::{
+        commands::*,
+        systems::*,
+    },
     prelude::*,
 };
 
@@ -8,8 +11,19 @@ pub struct ConsolePlugin;
 
 impl Plugin for ConsolePlugin {
     fn build(&self, app: &mut App) {
+        // Register resources.
+        app.register_type::<AutoexecConsoleScriptExecuted>();
+        app.register_type::<ConsoleHistory>();
+        app.register_type::<ConsoleAliases>();
+
+        // Initialize configurations.
+        ConsoleAliases::initialize(app);
+
         // Insert resources.
+        ConsoleHistory::initialize(app);
         app.insert_resource(ConsoleConfiguration::default());
+        app.init_resource::<ConsoleCommandQueue>();
+        app.init_resource::<QueuedConsoleCommand>();
 
         // Add sub-plugins.
         app.add_plugins(BevyConsolePlugin);
@@ -39,11 +53,56 @@ impl Plugin for ConsolePlugin {
             });
         }
 
+        // Add console commands.
+        app.add_console_command::<ExecCommand, _>(apply_exec_command);
+        app.add_console_command::<HistoryCommand, _>(apply_history_command);
+        app.add_console_command::<AliasCommand, _>(apply_alias_command);
+
         // Add systems.
         app.add_systems(
             Update,
             control_physics_time
                 .run_if(|console_state: Res<ConsoleState>| console_state.is_changed()),
         );
+        app.add_systems(
+            PostStartup,
+            (register_console_aliases, update_console_completions, seed_console_history).chain(),
+        );
+        app.add_systems(Update, run_queued_console_command.before(ConsoleSet::Commands));
+        app.add_systems(
+            Update,
+            (record_console_history, expand_console_aliases).in_set(ConsoleSet::Commands),
+        );
+
+        // Execute console scripts.
+        {
+            // Startup console script is queued before the autoexec console script,
+            // so the autoexec console script is executed first.
+            let args = app.world().resource::<Args>();
+            if args.start_in_game {
+                app.add_systems(
+                    OnEnter(GameState::Playing),
+                    (
+                        execute_startup_console_script.run_if(run_once()),
+                        execute_autoexec_console_script
+                            .run_if(not(resource_exists::<AutoexecConsoleScriptExecuted>)),
+                    )
+                        .chain(),
+                );
+            } else {
+                app.add_systems(Startup, execute_startup_console_script);
+                app.add_systems(
+                    OnEnter(GameState::Playing),
+                    execute_autoexec_console_script
+                        .run_if(not(resource_exists::<AutoexecConsoleScriptExecuted>)),
+                );
+            }
+
+            app.add_systems(
+                OnEnter(GameState::Restart),
+                clear_autoexec_console_script_execution.in_set(RestartSystems::Last),
+            );
+            app.add_systems(OnExit(AppState::Game), clear_autoexec_console_script_execution);
+        }
     }
 }
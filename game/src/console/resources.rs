@@ -0,0 +1,227 @@
+use crate::{
+    configuration::constants::CONFIGURATION_STORAGE_FORMAT,
+    console::constants::*,
+    prelude::*,
+};
+
+
+/// Resource for the console commands waiting to be executed from console scripts.
+#[derive(Debug, Default, Deref, DerefMut, Resource)]
+pub struct ConsoleCommandQueue(pub VecDeque<String>);
+
+impl ConsoleCommandQueue {
+    /// Resolves the path of a console script.
+    ///
+    /// Relative paths are resolved from the configuration directory,
+    /// and the console script extension is added if the script doesn't have an extension.
+    pub fn resolve(configuration_directory: &Path, script: &Path) -> PathBuf {
+        let mut path = configuration_directory.join(script);
+        if path.extension().is_none() {
+            path.set_extension(CONSOLE_SCRIPT_EXTENSION);
+        }
+        path
+    }
+
+    /// Queues the console commands in a console script.
+    ///
+    /// Commands of the script are executed before the commands that are already in the queue,
+    /// so scripts executing other scripts behave as if the other scripts are inlined.
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    pub fn execute(&mut self, script: &Path) -> Result<usize, ConsoleScriptError> {
+        let content = std::fs::read_to_string(script).map_err(ConsoleScriptError::Io)?;
+
+        let commands = content
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| line.to_owned())
+            .collect::<Vec<_>>();
+
+        if self.len() + commands.len() > MAX_QUEUED_CONSOLE_COMMANDS {
+            return Err(ConsoleScriptError::TooManyCommands);
+        }
+
+        let number_of_commands = commands.len();
+        for command in commands.into_iter().rev() {
+            self.push_front(command);
+        }
+
+        log::info!(
+            "queued {} console commands from {:?}",
+            number_of_commands,
+            script.display().to_string(),
+        );
+        Ok(number_of_commands)
+    }
+}
+
+
+/// Resource for the console command that is run from the console command queue in this frame.
+///
+/// Queued commands come from console scripts, aliases and the console history,
+/// so they are not recorded to the console history again.
+#[derive(Debug, Default, Resource)]
+pub struct QueuedConsoleCommand(pub Option<(String, Vec<String>)>);
+
+
+/// Errors that can happen when executing console scripts.
+#[derive(Debug)]
+pub enum ConsoleScriptError {
+    /// Console script couldn't be read.
+    Io(std::io::Error),
+    /// Console script would exceed the maximum number of queued console commands.
+    TooManyCommands,
+}
+
+impl Display for ConsoleScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsoleScriptError::Io(error) => write!(f, "{}", error),
+            ConsoleScriptError::TooManyCommands => {
+                write!(f, "more than {} commands are queued", MAX_QUEUED_CONSOLE_COMMANDS)
+            },
+        }
+    }
+}
+
+
+/// Resource for indicating the autoexec console script is executed in the current game.
+#[derive(Debug, Default, Reflect, Resource)]
+pub struct AutoexecConsoleScriptExecuted;
+
+
+/// Resource for the console history, which persists across sessions.
+#[derive(Debug, Default, Deserialize, Reflect, Resource, Serialize)]
+#[serde(default)]
+pub struct ConsoleHistory {
+    pub commands: Vec<String>,
+}
+
+impl ConsoleHistory {
+    /// Initializes the resource in the app.
+    pub fn initialize(app: &mut App) {
+        let args = app.world().resource::<Args>();
+        app.insert_resource(
+            Persistent::<ConsoleHistory>::builder()
+                .name("console history")
+                .format(CONSOLE_HISTORY_STORAGE_FORMAT)
+                .path({
+                    #[cfg(feature = "native")]
+                    {
+                        args.data_directory.join("console-history.toml")
+                    }
+                    #[cfg(feature = "wasm")]
+                    {
+                        args.data_directory.join("console-history")
+                    }
+                })
+                .default(ConsoleHistory::default())
+                .revertible(true)
+                .build()
+                .unwrap_or_else(|_| panic!("fatal: unable to initialize the console history")),
+        );
+    }
+}
+
+impl ConsoleHistory {
+    /// Records a command to the console history.
+    pub fn record(&mut self, command: String) {
+        if self.commands.last() == Some(&command) {
+            return;
+        }
+        self.commands.push(command);
+        if self.commands.len() > MAX_CONSOLE_HISTORY_ENTRIES {
+            let excess = self.commands.len() - MAX_CONSOLE_HISTORY_ENTRIES;
+            self.commands.drain(..excess);
+        }
+    }
+}
+
+
+/// Resource for the console aliases.
+#[derive(Debug, Default, Deserialize, Reflect, Resource, Serialize)]
+#[serde(default)]
+pub struct ConsoleAliases {
+    pub aliases: Vec<ConsoleAlias>,
+}
+
+impl ConsoleAliases {
+    /// Initializes the resource in the app.
+    pub fn initialize(app: &mut App) {
+        let args = app.world().resource::<Args>();
+        app.insert_resource(
+            Persistent::<ConsoleAliases>::builder()
+                .name("console aliases")
+                .format(CONFIGURATION_STORAGE_FORMAT)
+                .path({
+                    #[cfg(feature = "native")]
+                    {
+                        args.configuration_directory.join("console-aliases.toml")
+                    }
+                    #[cfg(feature = "wasm")]
+                    {
+                        args.configuration_directory.join("console-aliases")
+                    }
+                })
+                .default(ConsoleAliases::default())
+                .revertible(true)
+                .build()
+                .unwrap_or_else(|_| panic!("fatal: unable to initialize the console aliases")),
+        );
+    }
+}
+
+impl ConsoleAliases {
+    /// Finds the alias with the specified name.
+    pub fn find(&self, name: &str) -> Option<&ConsoleAlias> {
+        self.aliases.iter().find(|alias| alias.name == name)
+    }
+
+    /// Sets an alias, replacing the existing alias with the same name.
+    pub fn set(&mut self, name: String, command: String) {
+        match self.aliases.iter_mut().find(|alias| alias.name == name) {
+            Some(alias) => {
+                alias.command = command;
+            },
+            None => {
+                self.aliases.push(ConsoleAlias { name, command });
+            },
+        }
+    }
+
+    /// Removes the alias with the specified name.
+    pub fn remove(&mut self, name: &str) -> Option<ConsoleAlias> {
+        let position = self.aliases.iter().position(|alias| alias.name == name)?;
+        Some(self.aliases.remove(position))
+    }
+}
+
+
+/// Console alias.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct ConsoleAlias {
+    /// Name of the alias.
+    pub name: String,
+    /// Command the alias expands to.
+    pub command: String,
+}
+
+impl ConsoleAlias {
+    /// Creates the console command for the alias, to make the console recognize the alias.
+    pub fn console_command(&self) -> (&'static str, clap::Command) {
+        // Console requires command names to be static, and aliases are rarely changed,
+        // so leaking the name of the alias is acceptable.
+        let name: &'static str = Box::leak(self.name.clone().into_boxed_str());
+        let command = clap::Command::new(name)
+            .about(format!("Alias for {:?}.", self.command))
+            .disable_help_flag(true)
+            .arg(
+                clap::Arg::new("args")
+                    .num_args(0..)
+                    .trailing_var_arg(true)
+                    .allow_hyphen_values(true),
+            );
+        (name, command)
+    }
+}
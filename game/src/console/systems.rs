@@ -1,4 +1,10 @@
-use crate::prelude::*;
+use crate::{
+    console::{
+        commands::*,
+        constants::*,
+    },
+    prelude::*,
+};
 
 
 /// (Un)pauses physics time depending on the console state.
@@ -13,3 +19,384 @@ pub fn control_physics_time(
         physics_time.unpause();
     }
 }
+
+
+/// Applies the exec command.
+pub fn apply_exec_command(
+    args: Res<Args>,
+    mut console_command_queue: ResMut<ConsoleCommandQueue>,
+    mut command: ConsoleCommand<ExecCommand>,
+) {
+    if let Some(Ok(ExecCommand { script })) = command.take() {
+        let path = ConsoleCommandQueue::resolve(&args.configuration_directory, &script);
+        match console_command_queue.execute(&path) {
+            Ok(number_of_commands) => {
+                reply!(command, "Executing {} commands.", number_of_commands);
+            },
+            Err(error) => {
+                reply!(
+                    command,
+                    "Failed to execute {:?} ({}).",
+                    script.display().to_string(),
+                    error
+                );
+            },
+        }
+        reply!(command, "");
+    }
+}
+
+
+/// Applies the history command.
+pub fn apply_history_command(
+    mut console_history: ResMut<Persistent<ConsoleHistory>>,
+    mut console_command_queue: ResMut<ConsoleCommandQueue>,
+    mut command: ConsoleCommand<HistoryCommand>,
+) {
+    if let Some(Ok(HistoryCommand { subcommand })) = command.take() {
+        match subcommand {
+            HistoryCommands::Show { count } => {
+                let commands = &console_history.commands;
+                if commands.is_empty() {
+                    reply!(command, "Console history is empty.");
+                } else {
+                    for index in commands.len().saturating_sub(count)..commands.len() {
+                        reply!(command, "{}) {}", index + 1, commands[index]);
+                    }
+                }
+            },
+            HistoryCommands::Run { position } => {
+                match console_history.commands.get(position.get() - 1) {
+                    Some(line) => {
+                        console_command_queue.push_front(line.clone());
+                        reply!(command, "Running {:?}.", line);
+                    },
+                    None => {
+                        reply!(command, "Failed to run {} as it doesn't exist.", position);
+                    },
+                }
+            },
+            HistoryCommands::Clear => {
+                console_history
+                    .update(|console_history| {
+                        console_history.commands.clear();
+                    })
+                    .ok();
+                reply!(command, "Cleared.");
+            },
+        }
+        reply!(command, "");
+    }
+}
+
+/// Applies the alias command.
+pub fn apply_alias_command(
+    mut console_aliases: ResMut<Persistent<ConsoleAliases>>,
+    mut console_configuration: ResMut<ConsoleConfiguration>,
+    mut command: ConsoleCommand<AliasCommand>,
+) {
+    if let Some(Ok(AliasCommand { subcommand })) = command.take() {
+        match subcommand {
+            AliasCommands::List => {
+                if console_aliases.aliases.is_empty() {
+                    reply!(command, "No aliases.");
+                } else {
+                    for alias in console_aliases.aliases.iter() {
+                        reply!(command, "{} = {}", alias.name, alias.command);
+                    }
+                }
+            },
+            AliasCommands::Set { name, command: words } => {
+                let is_alias = console_aliases.find(&name).is_some();
+                if name.is_empty() || name.chars().any(|char| char.is_whitespace()) {
+                    reply!(command, "Failed to set {:?} as it's not a valid name.", name);
+                } else if !is_alias && console_configuration.commands.contains_key(name.as_str()) {
+                    reply!(command, "Failed to set {:?} as it's an existing command.", name);
+                } else {
+                    let line = if words.len() == 1 {
+                        words[0].clone()
+                    } else {
+                        shlex::try_join(words.iter().map(|word| word.as_str()))
+                            .unwrap_or_else(|_| words.join(" "))
+                    };
+
+                    let alias = ConsoleAlias { name: name.clone(), command: line.clone() };
+                    let (alias_name, alias_command) = alias.console_command();
+                    console_configuration.commands.insert(alias_name, alias_command);
+
+                    console_aliases
+                        .update(|console_aliases| {
+                            console_aliases.set(name, line);
+                        })
+                        .ok();
+                    reply!(command, "Set.");
+                }
+            },
+            AliasCommands::Remove { name } => {
+                if console_aliases.find(&name).is_none() {
+                    reply!(command, "Failed to remove {:?} as it doesn't exist.", name);
+                } else {
+                    console_configuration.commands.remove(name.as_str());
+                    console_aliases
+                        .update(|console_aliases| {
+                            console_aliases.remove(&name);
+                        })
+                        .ok();
+                    reply!(command, "Removed.");
+                }
+            },
+        }
+        reply!(command, "");
+    }
+}
+
+
+/// Executes the console script specified in the arguments of the application.
+pub fn execute_startup_console_script(
+    args: Res<Args>,
+    mut console_command_queue: ResMut<ConsoleCommandQueue>,
+) {
+    if let Some(script) = &args.startup_script {
+        let path = ConsoleCommandQueue::resolve(&args.configuration_directory, script);
+        if let Err(error) = console_command_queue.execute(&path) {
+            log::error!(
+                "unable to execute the startup console script {:?} ({})",
+                path.display().to_string(),
+                error,
+            );
+        }
+    }
+}
+
+/// Executes the autoexec console script, if it exists.
+pub fn execute_autoexec_console_script(
+    mut commands: Commands,
+    args: Res<Args>,
+    mut console_command_queue: ResMut<ConsoleCommandQueue>,
+) {
+    commands.insert_resource(AutoexecConsoleScriptExecuted);
+
+    let path = args.configuration_directory.join(AUTOEXEC_CONSOLE_SCRIPT);
+    if !path.exists() {
+        return;
+    }
+
+    if let Err(error) = console_command_queue.execute(&path) {
+        log::error!(
+            "unable to execute the autoexec console script {:?} ({})",
+            path.display().to_string(),
+            error,
+        );
+    }
+}
+
+/// Clears the autoexec console script execution of the current game.
+pub fn clear_autoexec_console_script_execution(mut commands: Commands) {
+    commands.remove_resource::<AutoexecConsoleScriptExecuted>();
+}
+
+
+/// Runs the next console command waiting in the queue.
+///
+/// Commands are run one per frame to preserve their order,
+/// and to let the effects of each command to be applied before the next one.
+pub fn run_queued_console_command(
+    console_configuration: Res<ConsoleConfiguration>,
+    mut console_command_queue: ResMut<ConsoleCommandQueue>,
+    mut queued_console_command: ResMut<QueuedConsoleCommand>,
+    mut console_command_entered_event_writer: EventWriter<ConsoleCommandEntered>,
+    mut print_console_line_event_writer: EventWriter<PrintConsoleLine>,
+) {
+    queued_console_command.0 = None;
+    while let Some(line) = console_command_queue.pop_front() {
+        print_console_line_event_writer
+            .send(PrintConsoleLine::new(format!("{}{}", console_configuration.symbol, line)));
+
+        let mut args = match shlex::split(&line) {
+            Some(args) => args,
+            None => {
+                log::error!("unable to parse the queued console command {:?}", line);
+                print_console_line_event_writer
+                    .send(PrintConsoleLine::new("Failed to parse the command.".to_owned()));
+                continue;
+            },
+        };
+        if args.is_empty() {
+            continue;
+        }
+
+        let command_name = args.remove(0);
+        queued_console_command.0 = Some((command_name.clone(), args.clone()));
+        console_command_entered_event_writer.send(ConsoleCommandEntered { command_name, args });
+        break;
+    }
+}
+
+
+/// Seeds the console with the most recent commands in the console history.
+///
+/// Input history of the console is internal to the console, so the recent commands are shown
+/// with their positions instead, to be run again with "history run <position>".
+pub fn seed_console_history(
+    console_history: Res<Persistent<ConsoleHistory>>,
+    mut print_console_line_event_writer: EventWriter<PrintConsoleLine>,
+) {
+    let commands = &console_history.commands;
+    if commands.is_empty() {
+        return;
+    }
+
+    print_console_line_event_writer.send(PrintConsoleLine::new("Recent commands:".to_owned()));
+    for index in commands.len().saturating_sub(SEEDED_CONSOLE_HISTORY_ENTRIES)..commands.len() {
+        print_console_line_event_writer.send(PrintConsoleLine::new(format!(
+            "{}) {}",
+            index + 1,
+            commands[index]
+        )));
+    }
+    print_console_line_event_writer.send(PrintConsoleLine::new("".to_owned()));
+}
+
+/// Records the entered console commands to the console history.
+///
+/// Commands run from the console command queue are skipped, as they are not typed
+/// (e.g., expansions of aliases and commands in console scripts).
+pub fn record_console_history(
+    mut console_command_entered_event_reader: EventReader<ConsoleCommandEntered>,
+    mut queued_console_command: ResMut<QueuedConsoleCommand>,
+    mut console_history: ResMut<Persistent<ConsoleHistory>>,
+) {
+    let mut lines = Vec::new();
+    for event in console_command_entered_event_reader.read() {
+        if event.command_name == "history" {
+            continue;
+        }
+        if let Some((command_name, args)) = &queued_console_command.0 {
+            if *command_name == event.command_name && *args == event.args {
+                queued_console_command.0 = None;
+                continue;
+            }
+        }
+        let words = std::iter::once(event.command_name.as_str())
+            .chain(event.args.iter().map(|arg| arg.as_str()));
+        if let Ok(line) = shlex::try_join(words) {
+            lines.push(line);
+        }
+    }
+
+    if !lines.is_empty() {
+        console_history
+            .update(|console_history| {
+                for line in lines {
+                    console_history.record(line);
+                }
+            })
+            .ok();
+    }
+}
+
+
+/// Registers the console aliases to the console.
+pub fn register_console_aliases(
+    console_aliases: Res<Persistent<ConsoleAliases>>,
+    mut console_configuration: ResMut<ConsoleConfiguration>,
+) {
+    for alias in console_aliases.aliases.iter() {
+        if console_configuration.commands.contains_key(alias.name.as_str()) {
+            log::warn!("ignoring {:?} console alias as it's an existing command", alias.name);
+            continue;
+        }
+        let (name, command) = alias.console_command();
+        console_configuration.commands.insert(name, command);
+    }
+}
+
+/// Expands the entered console aliases into the commands they stand for.
+pub fn expand_console_aliases(
+    mut console_command_entered_event_reader: EventReader<ConsoleCommandEntered>,
+    console_aliases: Res<Persistent<ConsoleAliases>>,
+    mut console_command_queue: ResMut<ConsoleCommandQueue>,
+) {
+    let mut lines = Vec::new();
+    for event in console_command_entered_event_reader.read() {
+        if let Some(alias) = console_aliases.find(&event.command_name) {
+            let mut line = alias.command.clone();
+            if !event.args.is_empty() {
+                if let Ok(args) = shlex::try_join(event.args.iter().map(|arg| arg.as_str())) {
+                    line.push(' ');
+                    line.push_str(&args);
+                }
+            }
+            lines.push(line);
+        }
+    }
+
+    for line in lines.into_iter().rev() {
+        console_command_queue.push_front(line);
+    }
+}
+
+
+/// Updates the completions of the console with the identifiers in the registries.
+pub fn update_console_completions(
+    mut console_configuration: ResMut<ConsoleConfiguration>,
+    enemy_registry: Res<EnemyRegistry>,
+    item_registry: Res<ItemRegistry>,
+    perk_registry: Res<PerkRegistry>,
+    player_registry: Res<PlayerRegistry>,
+    supported_locales: Res<SupportedLocales>,
+) {
+    let mut ids = HashMap::<&str, Vec<String>>::new();
+    ids.insert(
+        "enemy",
+        enemy_registry
+            .iter()
+            .flat_map(|entry| entry.enemies.iter().map(|enemy| enemy.id().to_string()))
+            .collect(),
+    );
+    ids.insert("item", item_registry.iter().map(|entry| entry.item.id().to_string()).collect());
+    ids.insert("perk", perk_registry.iter().map(|entry| entry.perk.id().to_string()).collect());
+    ids.insert(
+        "player",
+        player_registry
+            .iter()
+            .flat_map(|entry| entry.players.iter().map(|player| player.id().to_string()))
+            .collect(),
+    );
+    ids.insert("locale", supported_locales.iter().map(|locale| locale.to_string()).collect());
+
+    fn collect_completions(
+        path: &mut Vec<String>,
+        command: &clap::Command,
+        ids: &HashMap<&str, Vec<String>>,
+        completions: &mut Vec<Vec<String>>,
+    ) {
+        path.push(command.get_name().to_owned());
+
+        // Only the first positional arguments are completed,
+        // as the values of the preceding arguments cannot be known.
+        if let Some(argument) = command.get_positionals().next() {
+            if let Some(ids) = ids.get(argument.get_id().as_str()) {
+                for id in ids {
+                    let mut completion = path.clone();
+                    completion.push(id.clone());
+                    completions.push(completion);
+                }
+            }
+        }
+
+        for subcommand in command.get_subcommands() {
+            collect_completions(path, subcommand, ids, completions);
+        }
+
+        path.pop();
+    }
+
+    let mut completions = Vec::new();
+    for command in console_configuration.commands.values() {
+        collect_completions(&mut Vec::new(), command, &ids, &mut completions);
+    }
+
+    log::info!("registered {} console completions", completions.len());
+    console_configuration.arg_completions = completions;
+}
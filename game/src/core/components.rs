@@ -35,6 +35,16 @@ impl Rarity {
     pub fn level(&self) -> u8 {
         *self as u8
     }
+
+    /// Gets the rarity right below the rarity.
+    pub fn previous(&self) -> Option<Rarity> {
+        match self {
+            Rarity::Common => None,
+            Rarity::Rare => Some(Rarity::Common),
+            Rarity::Epic => Some(Rarity::Rare),
+            Rarity::Legendary => Some(Rarity::Epic),
+        }
+    }
 }
 
 impl Display for Rarity {
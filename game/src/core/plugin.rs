@@ -49,6 +49,8 @@ impl Plugin for CorePlugin {
         MenuSystems::configure(app);
         MainMenuSystems::configure(app);
         SettingsMenuSystems::configure(app);
+        UnlockScreenSystems::configure(app);
+        AchievementsScreenSystems::configure(app);
         GameModeSelectionScreenSystems::configure(app);
         PlayerSelectionScreenSystems::configure(app);
         EnemySelectionScreenSystems::configure(app);
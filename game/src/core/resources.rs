@@ -5,6 +5,8 @@ use crate::prelude::*;
 #[derive(Debug, Resource)]
 pub struct RegisteredSystems {
     pub configuration: RegisteredConfigurationSystems,
+    pub daily_challenge: RegisteredDailyChallengeSystems,
+    pub enemy: RegisteredEnemySystems,
     pub level_up_screen: RegisteredLevelUpScreenSystems,
     pub leveling: RegisteredLevelingSystems,
     pub market: RegisteredMarketSystems,
@@ -17,6 +19,8 @@ impl RegisteredSystems {
         let systems = app.world_mut().spawn(Name::new("RegisteredSystems")).id();
         RegisteredSystems {
             configuration: RegisteredConfigurationSystems::new(app, systems),
+            daily_challenge: RegisteredDailyChallengeSystems::new(app, systems),
+            enemy: RegisteredEnemySystems::new(app, systems),
             level_up_screen: RegisteredLevelUpScreenSystems::new(app, systems),
             leveling: RegisteredLevelingSystems::new(app, systems),
             market: RegisteredMarketSystems::new(app, systems),
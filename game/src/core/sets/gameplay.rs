@@ -6,6 +6,7 @@ pub enum GameplaySystems {
     Camera,
     Combat,
     Enemy,
+    Feedback,
     GameMode,
     Input,
     Item,
@@ -19,6 +19,8 @@ impl MenuSystems {
                 AppState::LoadingInitialLocalization => false,
                 AppState::MainMenu => true,
                 AppState::SettingsMenu => true,
+                AppState::UnlockScreen => true,
+                AppState::AchievementsScreen => true,
                 AppState::GameModeSelectionScreen => true,
                 AppState::PlayerSelectionScreen => true,
                 AppState::EnemySelectionScreen => true,
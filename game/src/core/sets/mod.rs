@@ -1,3 +1,4 @@
+mod achievements_screen;
 mod enemy_selection_screen;
 mod game_mode_selection_screen;
 mod game_over_menu;
@@ -12,8 +13,10 @@ mod pause_menu;
 mod player_selection_screen;
 mod restart;
 mod settings_menu;
+mod unlock_screen;
 
 pub use self::{
+    achievements_screen::AchievementsScreenSystems,
     enemy_selection_screen::EnemySelectionScreenSystems,
     game_mode_selection_screen::GameModeSelectionScreenSystems,
     game_over_menu::GameOverMenuSystems,
@@ -28,4 +31,5 @@ pub use self::{
     player_selection_screen::PlayerSelectionScreenSystems,
     restart::RestartSystems,
     settings_menu::SettingsMenuSystems,
+    unlock_screen::UnlockScreenSystems,
 };
@@ -15,6 +15,7 @@ pub enum RestartSystems {
     Player,
     Leveling,
     Combat,
+    Feedback,
     Last,
     Done,
 }
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+/// Systems to run in the unlock screen.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub struct UnlockScreenSystems;
+
+impl UnlockScreenSystems {
+    /// Configure the system set.
+    pub fn configure(app: &mut App) {
+        fn run_condition(
+            app_state: Res<State<AppState>>,
+            console_state: Res<ConsoleState>,
+        ) -> bool {
+            *app_state == AppState::UnlockScreen && !console_state.open
+        }
+
+        app.configure_sets(PreUpdate, Self.run_if(run_condition));
+        app.configure_sets(Update, Self.run_if(run_condition));
+        app.configure_sets(PostUpdate, Self.run_if(run_condition));
+    }
+}
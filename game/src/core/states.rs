@@ -8,6 +8,8 @@ pub enum AppState {
     LoadingInitialLocalization,
     MainMenu,
     SettingsMenu,
+    UnlockScreen,
+    AchievementsScreen,
     GameModeSelectionScreen,
     PlayerSelectionScreen,
     EnemySelectionScreen,
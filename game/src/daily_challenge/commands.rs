@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+/// Controls the daily challenges.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "daily-challenge")]
+#[command(disable_help_flag = true)]
+pub struct DailyChallengeCommand {
+    #[clap(subcommand)]
+    pub subcommand: DailyChallengeCommands,
+}
+
+/// Daily challenge commands.
+#[derive(Debug, Subcommand)]
+pub enum DailyChallengeCommands {
+    /// Shows the daily challenge of today.
+    Show,
+    /// Shows the previous attempts.
+    History,
+}
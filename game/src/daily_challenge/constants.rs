@@ -0,0 +1,34 @@
+use crate::prelude::*;
+
+
+/// Storage format for daily challenge files.
+pub const DAILY_CHALLENGES_STORAGE_FORMAT: StorageFormat = {
+    #[cfg(feature = "native")]
+    {
+        StorageFormat::Toml
+    }
+    #[cfg(feature = "wasm")]
+    {
+        StorageFormat::Json
+    }
+};
+
+
+/// Salt mixed into the date to derive the seed of daily challenges.
+pub const DAILY_CHALLENGE_SEED_SALT: &str = "mythmallow-daily-challenge";
+
+/// Number of items in the starting inventory of daily challenges.
+pub const DAILY_CHALLENGE_INVENTORY_SIZE: usize = 2;
+
+/// Number of mutators enabled in daily challenges.
+pub const DAILY_CHALLENGE_MUTATORS: usize = 1;
+
+
+/// Score for each enemy killed in daily challenges.
+pub const DAILY_CHALLENGE_SCORE_PER_KILL: u64 = 10;
+
+/// Score for each level gained in daily challenges.
+pub const DAILY_CHALLENGE_SCORE_PER_LEVEL: u64 = 100;
+
+/// Score for winning daily challenges.
+pub const DAILY_CHALLENGE_SCORE_FOR_WINNING: u64 = 1000;
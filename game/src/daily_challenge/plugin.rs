@@ -0,0 +1,55 @@
+use crate::{
+    daily_challenge::{
+        commands::*,
+        systems::*,
+    },
+    prelude::*,
+};
+
+/// Plugin for managing the daily challenges.
+pub struct DailyChallengePlugin;
+
+impl Plugin for DailyChallengePlugin {
+    fn build(&self, app: &mut App) {
+        // Register resources.
+        app.register_type::<DailyChallenge>();
+        app.register_type::<DailyChallenges>();
+
+        // Insert resources.
+        DailyChallenges::initialize(app);
+
+        // Add console commands.
+        app.add_console_command::<DailyChallengeCommand, _>(apply_daily_challenge_command);
+
+        // Add systems.
+        app.add_systems(
+            OnEnter(GameState::Initialization),
+            prepare_daily_challenge
+                .in_set(InitializationSystems::First)
+                .run_if(resource_exists::<DailyChallenge>),
+        );
+        app.add_systems(
+            OnEnter(GameState::Initialization),
+            load_daily_challenge_inventory
+                .in_set(InitializationSystems::Inventory)
+                .run_if(resource_exists::<DailyChallenge>),
+        );
+        app.add_systems(
+            Last,
+            count_daily_challenge_kills
+                .run_if(on_event::<EnemyDiedEvent>())
+                .run_if(resource_exists::<DailyChallenge>),
+        );
+        app.add_systems(
+            OnEnter(GameState::Over),
+            record_daily_challenge_attempt.run_if(resource_exists::<DailyChallenge>),
+        );
+        app.add_systems(
+            OnEnter(GameState::Restart),
+            abandon_daily_challenge_attempt
+                .in_set(RestartSystems::First)
+                .run_if(resource_exists::<DailyChallenge>),
+        );
+        app.add_systems(OnExit(AppState::Game), clear_daily_challenge);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{
+    daily_challenge::constants::*,
+    prelude::*,
+};
+
+
+/// Database of registered daily challenge systems.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct RegisteredDailyChallengeSystems {
+    pub start_daily_challenge: SystemId,
+}
+
+impl RegisteredDailyChallengeSystems {
+    /// Creates the database.
+    pub fn new(app: &mut App, systems: Entity) -> RegisteredDailyChallengeSystems {
+        use super::systems::*;
+
+        let start_daily_challenge = app.world_mut().register_system(start_daily_challenge);
+        RegisteredSystems::attach(app, systems, start_daily_challenge, "start_daily_challenge");
+
+        RegisteredDailyChallengeSystems { start_daily_challenge }
+    }
+}
+
+
+/// Date of a daily challenge in UTC.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Reflect)]
+pub struct DailyChallengeDate {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl DailyChallengeDate {
+    /// Gets the date of today in UTC.
+    pub fn today() -> DailyChallengeDate {
+        let days_since_unix_epoch = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() / (24 * 60 * 60))
+            .unwrap_or(0);
+        DailyChallengeDate::from_days_since_unix_epoch(days_since_unix_epoch as i64)
+    }
+
+    /// Creates the date from the number of days since 1970-01-01.
+    pub fn from_days_since_unix_epoch(days: i64) -> DailyChallengeDate {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days.
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let day_of_era = z.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u8;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        DailyChallengeDate { year, month, day }
+    }
+}
+
+impl DailyChallengeDate {
+    /// Gets the seed of the daily challenge of the date.
+    ///
+    /// Seed is the 64-bit FNV-1a hash of the salted date,
+    /// so it's the same on every platform and every run.
+    pub fn seed(&self) -> u64 {
+        let mut hash = 0xcbf29ce484222325_u64;
+        for byte in format!("{}-{}", DAILY_CHALLENGE_SEED_SALT, self).bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        hash
+    }
+}
+
+impl Display for DailyChallengeDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+
+/// Resource for the daily challenge that is being played.
+#[derive(Clone, Debug, Reflect, Resource)]
+pub struct DailyChallenge {
+    /// Date of the daily challenge.
+    pub date: DailyChallengeDate,
+    /// Seed of the daily challenge.
+    pub seed: u64,
+    /// Id of the game mode of the daily challenge.
+    pub game_mode: SmolStr,
+    /// Difficulty of the daily challenge.
+    pub difficulty: SelectedDifficulty,
+    /// Number of difficulties of the game mode of the daily challenge.
+    pub difficulties: u8,
+    /// Id of the player of the daily challenge.
+    pub player: SmolStr,
+    /// Id of the enemy pack of the daily challenge.
+    pub enemies: SmolStr,
+    /// Ids of the items in the starting inventory of the daily challenge.
+    pub inventory: Vec<SmolStr>,
+    /// Mutators enabled in the daily challenge.
+    pub mutators: Vec<Mutator>,
+    /// Whether the run is the scored attempt of the day.
+    pub scored: bool,
+    /// Number of enemies killed in the run.
+    pub kills: u32,
+}
+
+impl DailyChallenge {
+    /// Generates the daily challenge of a date.
+    ///
+    /// Everything is picked in the registration order using a random number generator
+    /// seeded with the seed of the date, so everyone gets the identical daily challenge
+    /// as long as they have the same content. Unlocks are ignored for the same reason.
+    pub fn generate(
+        date: DailyChallengeDate,
+        game_mode_registry: &GameModeRegistry,
+        player_registry: &PlayerRegistry,
+        enemy_registry: &EnemyRegistry,
+        item_registry: &ItemRegistry,
+    ) -> Option<DailyChallenge> {
+        let seed = date.seed();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let game_mode = &game_mode_registry.iter().choose(&mut rng)?.game_mode;
+        let difficulties = game_mode.number_of_difficulties().max(1);
+        let difficulty = rng.gen_range(1..=difficulties);
+
+        let player = player_registry
+            .iter()
+            .flat_map(|entry| entry.players.iter())
+            .collect::<Vec<_>>()
+            .choose(&mut rng)
+            .map(|player| player.id())?;
+
+        let enemies = enemy_registry.iter().choose(&mut rng).map(|entry| entry.pack.id())?;
+
+        let inventory = item_registry
+            .iter()
+            .collect::<Vec<_>>()
+            .choose_multiple(&mut rng, DAILY_CHALLENGE_INVENTORY_SIZE)
+            .map(|entry| entry.item.id())
+            .collect();
+
+        let mutators = Mutator::iter()
+            .collect::<Vec<_>>()
+            .choose_multiple(&mut rng, DAILY_CHALLENGE_MUTATORS)
+            .copied()
+            .collect();
+
+        Some(DailyChallenge {
+            date,
+            seed,
+            game_mode: game_mode.id(),
+            difficulty: SelectedDifficulty(NonZeroU8::new(difficulty).unwrap()),
+            difficulties,
+            player,
+            enemies,
+            inventory,
+            mutators,
+            scored: false,
+            kills: 0,
+        })
+    }
+}
+
+impl DailyChallenge {
+    /// Gets the score of the daily challenge.
+    pub fn score(&self, level: u16, won: bool) -> u64 {
+        let mut score = (self.kills as u64) * DAILY_CHALLENGE_SCORE_PER_KILL;
+        score += (level.saturating_sub(1) as u64) * DAILY_CHALLENGE_SCORE_PER_LEVEL;
+        if won {
+            score += DAILY_CHALLENGE_SCORE_FOR_WINNING;
+        }
+        score
+    }
+
+    /// Gets the arguments to start the daily challenge directly in game.
+    ///
+    /// Difficulty is passed to the game mode within the mode argument, as it's a game mode
+    /// argument (e.g., `--mode "survival --difficulty 2"`).
+    pub fn args(&self) -> String {
+        let mode = if self.difficulties > 1 {
+            format!("{} --difficulty {}", self.game_mode, self.difficulty.get())
+        } else {
+            self.game_mode.to_string()
+        };
+        let mut args = format!(
+            "--seed {} --game --mode \"{}\" --player \"{}\" --enemies \"{}\"",
+            self.seed, mode, self.player, self.enemies,
+        );
+        if !self.inventory.is_empty() {
+            args.push_str(&format!(" --inventory \"{}\"", self.inventory.join(",")));
+        }
+        if !self.mutators.is_empty() {
+            let mutators = self.mutators.iter().map(|mutator| mutator.id()).collect::<Vec<_>>();
+            args.push_str(&format!(" --mutators \"{}\"", mutators.join(",")));
+        }
+        args
+    }
+}
+
+
+/// Attempt of a daily challenge.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct DailyChallengeAttempt {
+    /// Date of the daily challenge.
+    pub date: String,
+    /// Seed of the daily challenge.
+    pub seed: u64,
+    /// Whether the attempt is finished.
+    pub finished: bool,
+    /// Whether the daily challenge is won.
+    pub won: bool,
+    /// Level reached in the attempt.
+    pub level: u16,
+    /// Number of enemies killed in the attempt.
+    pub kills: u32,
+    /// Score of the attempt.
+    pub score: u64,
+}
+
+
+/// Resource for the attempts of daily challenges, which persists across runs.
+#[derive(Debug, Default, Deserialize, Reflect, Resource, Serialize)]
+#[serde(default)]
+pub struct DailyChallenges {
+    pub attempts: Vec<DailyChallengeAttempt>,
+}
+
+impl DailyChallenges {
+    /// Initializes the resource in the app.
+    pub fn initialize(app: &mut App) {
+        let args = app.world().resource::<Args>();
+        app.insert_resource(
+            Persistent::<DailyChallenges>::builder()
+                .name("daily challenges")
+                .format(DAILY_CHALLENGES_STORAGE_FORMAT)
+                .path({
+                    #[cfg(feature = "native")]
+                    {
+                        args.data_directory.join("daily-challenges.toml")
+                    }
+                    #[cfg(feature = "wasm")]
+                    {
+                        args.data_directory.join("daily-challenges")
+                    }
+                })
+                .default(DailyChallenges::default())
+                .revertible(true)
+                .build()
+                .unwrap_or_else(|_| panic!("fatal: unable to initialize the daily challenges")),
+        );
+    }
+}
+
+impl DailyChallenges {
+    /// Gets the attempt of the daily challenge of a date.
+    pub fn attempt(&self, date: DailyChallengeDate) -> Option<&DailyChallengeAttempt> {
+        let date = date.to_string();
+        self.attempts.iter().find(|attempt| attempt.date == date)
+    }
+
+    /// Gets whether the daily challenge of a date is attempted.
+    pub fn is_attempted(&self, date: DailyChallengeDate) -> bool {
+        self.attempt(date).is_some()
+    }
+}
+
+impl DailyChallenges {
+    /// Starts the attempt of a daily challenge, returning whether it's the first attempt.
+    pub fn start_attempt(&mut self, daily_challenge: &DailyChallenge) -> bool {
+        if self.is_attempted(daily_challenge.date) {
+            return false;
+        }
+        self.attempts.push(DailyChallengeAttempt {
+            date: daily_challenge.date.to_string(),
+            seed: daily_challenge.seed,
+            finished: false,
+            won: false,
+            level: 1,
+            kills: 0,
+            score: 0,
+        });
+        true
+    }
+
+    /// Finishes the attempt of a daily challenge.
+    pub fn finish_attempt(
+        &mut self,
+        daily_challenge: &DailyChallenge,
+        level: u16,
+        won: bool,
+    ) -> Option<&DailyChallengeAttempt> {
+        let date = daily_challenge.date.to_string();
+        let attempt = self.attempts.iter_mut().find(|attempt| attempt.date == date)?;
+        if attempt.finished {
+            return None;
+        }
+
+        attempt.finished = true;
+        attempt.won = won;
+        attempt.level = level;
+        attempt.kills = daily_challenge.kills;
+        attempt.score = daily_challenge.score(level, won);
+
+        Some(attempt)
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    daily_challenge::commands::*,
+    prelude::*,
+};
+
+
+/// Applies the daily challenge console commands.
+pub fn apply_daily_challenge_command(
+    game_mode_registry: Res<GameModeRegistry>,
+    player_registry: Res<PlayerRegistry>,
+    enemy_registry: Res<EnemyRegistry>,
+    item_registry: Res<ItemRegistry>,
+    daily_challenges: Res<Persistent<DailyChallenges>>,
+    mut command: ConsoleCommand<DailyChallengeCommand>,
+) {
+    if let Some(Ok(DailyChallengeCommand { subcommand })) = command.take() {
+        match subcommand {
+            DailyChallengeCommands::Show => {
+                let date = DailyChallengeDate::today();
+                let daily_challenge = match DailyChallenge::generate(
+                    date,
+                    &game_mode_registry,
+                    &player_registry,
+                    &enemy_registry,
+                    &item_registry,
+                ) {
+                    Some(daily_challenge) => daily_challenge,
+                    None => {
+                        reply!(command, "Daily challenge of {} is not available.", date);
+                        reply!(command, "");
+                        return;
+                    },
+                };
+
+                reply!(command, "date: {}", daily_challenge.date);
+                reply!(command, "seed: {}", daily_challenge.seed);
+                reply!(command, "mode: {}", daily_challenge.game_mode);
+                reply!(command, "difficulty: {}", daily_challenge.difficulty.get());
+                reply!(command, "player: {}", daily_challenge.player);
+                reply!(command, "enemies: {}", daily_challenge.enemies);
+                reply!(command, "inventory: {}", daily_challenge.inventory.join(", "));
+                let mutators = SelectedMutators(daily_challenge.mutators.clone());
+                reply!(command, "mutators: {}", mutators);
+                reply!(command, "args: {}", daily_challenge.args());
+                match daily_challenges.attempt(date) {
+                    Some(attempt) if attempt.finished => {
+                        reply!(command, "attempted: yes (score {})", attempt.score);
+                    },
+                    Some(_) => {
+                        reply!(command, "attempted: yes (unfinished)");
+                    },
+                    None => {
+                        reply!(command, "attempted: no");
+                    },
+                }
+            },
+            DailyChallengeCommands::History => {
+                if daily_challenges.attempts.is_empty() {
+                    reply!(command, "No attempts.");
+                }
+                for attempt in daily_challenges.attempts.iter() {
+                    if attempt.finished {
+                        reply!(
+                            command,
+                            "{}: {} with the score of {} (level {}, {} kills)",
+                            attempt.date,
+                            if attempt.won { "won" } else { "lost" },
+                            attempt.score,
+                            attempt.level,
+                            attempt.kills,
+                        );
+                    } else {
+                        reply!(command, "{}: unfinished", attempt.date);
+                    }
+                }
+            },
+        }
+        reply!(command, "");
+    }
+}
+
+
+/// Starts the daily challenge of today.
+pub fn start_daily_challenge(
+    mut commands: Commands,
+    game_mode_registry: Res<GameModeRegistry>,
+    player_registry: Res<PlayerRegistry>,
+    enemy_registry: Res<EnemyRegistry>,
+    item_registry: Res<ItemRegistry>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut game_state_stack: ResMut<GameStateStack>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    let date = DailyChallengeDate::today();
+    let daily_challenge = match DailyChallenge::generate(
+        date,
+        &game_mode_registry,
+        &player_registry,
+        &enemy_registry,
+        &item_registry,
+    ) {
+        Some(daily_challenge) => daily_challenge,
+        None => {
+            log::error!("unable to start the daily challenge of {} as it's not available", date);
+            return;
+        },
+    };
+
+    let game_mode_index = game_mode_registry
+        .iter()
+        .position(|entry| entry.game_mode.id() == daily_challenge.game_mode)
+        .map(SelectedGameModeIndex)
+        .unwrap();
+    let (mythology_index, player_index) =
+        player_registry.find_player(&daily_challenge.player).unwrap();
+    let enemy_pack_index = enemy_registry
+        .iter()
+        .position(|entry| entry.pack.id() == daily_challenge.enemies)
+        .map(SelectedEnemyPackIndex)
+        .unwrap();
+
+    log::info!("starting the daily challenge of {}\n\n{}\n", date, daily_challenge.args());
+
+    commands.insert_resource(game_mode_index);
+    commands.insert_resource(daily_challenge.difficulty);
+    commands.insert_resource(mythology_index);
+    commands.insert_resource(player_index);
+    commands.insert_resource(enemy_pack_index);
+    commands.insert_resource(SelectedMutators(daily_challenge.mutators.clone()));
+    commands.insert_resource(daily_challenge);
+
+    game_state_stack.push(GameState::Initialization);
+    next_game_state.set(GameState::Transition);
+    next_app_state.set(AppState::Game);
+}
+
+
+/// Seeds the random number generator and starts the attempt of the daily challenge.
+pub fn prepare_daily_challenge(
+    mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
+    mut daily_challenge: ResMut<DailyChallenge>,
+    mut daily_challenges: ResMut<Persistent<DailyChallenges>>,
+) {
+    log::info!(
+        "seeding {} for the daily challenge of {}",
+        daily_challenge.seed,
+        daily_challenge.date
+    );
+    rng.reseed(ChaCha8Rng::seed_from_u64(daily_challenge.seed).gen::<[u8; 32]>());
+
+    let mut first_attempt = false;
+    daily_challenges
+        .update(|daily_challenges| {
+            first_attempt = daily_challenges.start_attempt(&daily_challenge);
+        })
+        .ok();
+
+    daily_challenge.scored = first_attempt;
+    daily_challenge.kills = 0;
+
+    if first_attempt {
+        log::info!("this is the scored attempt of the daily challenge of {}", daily_challenge.date);
+    } else {
+        log::info!(
+            "daily challenge of {} is already attempted, so this attempt won't be scored",
+            daily_challenge.date,
+        );
+    }
+}
+
+/// Adds the starting items of the daily challenge to the inventory of every player.
+pub fn load_daily_challenge_inventory(
+    daily_challenge: Res<DailyChallenge>,
+    item_registry: Res<ItemRegistry>,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
+) {
+    for item_id in &daily_challenge.inventory {
+        match item_registry.find_item_by_id(item_id) {
+            Some(item) => {
+                for mut inventory in inventory_query.iter_mut() {
+                    inventory.add(item.instantiate());
+                }
+            },
+            None => {
+                log::error!(
+                    "unable to add \"{}\" to the inventory as it's not registered",
+                    item_id,
+                );
+            },
+        }
+    }
+}
+
+
+/// Counts the enemies killed in the daily challenge.
+pub fn count_daily_challenge_kills(
+    mut daily_challenge: ResMut<DailyChallenge>,
+    mut enemy_died_event_reader: EventReader<EnemyDiedEvent>,
+) {
+    daily_challenge.kills += enemy_died_event_reader.read().count() as u32;
+}
+
+
+/// Records the attempt of the daily challenge at the end of the run.
+pub fn record_daily_challenge_attempt(
+    primary_player_query: Query<&Level, With<PrimaryPlayer>>,
+    game_result: Res<GameResult>,
+    daily_challenge: Res<DailyChallenge>,
+    mut daily_challenges: ResMut<Persistent<DailyChallenges>>,
+) {
+    if !daily_challenge.scored {
+        return;
+    }
+
+    let level = primary_player_query.get_single().map(|level| level.get()).unwrap_or(1);
+    let won = *game_result == GameResult::Won;
+
+    let mut score = None;
+    daily_challenges
+        .update(|daily_challenges| {
+            score = daily_challenges
+                .finish_attempt(&daily_challenge, level, won)
+                .map(|attempt| attempt.score);
+        })
+        .ok();
+
+    if let Some(score) = score {
+        log::info!(
+            "finished the daily challenge of {} with the score of {}",
+            daily_challenge.date,
+            score,
+        );
+    }
+}
+
+
+/// Records the scored attempt of the daily challenge as lost when the run is restarted,
+/// as the restarted run is not scored.
+pub fn abandon_daily_challenge_attempt(
+    primary_player_query: Query<&Level, With<PrimaryPlayer>>,
+    mut daily_challenge: ResMut<DailyChallenge>,
+    mut daily_challenges: ResMut<Persistent<DailyChallenges>>,
+) {
+    if !daily_challenge.scored {
+        return;
+    }
+    daily_challenge.scored = false;
+
+    let level = primary_player_query.get_single().map(|level| level.get()).unwrap_or(1);
+
+    let mut score = None;
+    daily_challenges
+        .update(|daily_challenges| {
+            score = daily_challenges
+                .finish_attempt(&daily_challenge, level, false)
+                .map(|attempt| attempt.score);
+        })
+        .ok();
+
+    if let Some(score) = score {
+        log::info!(
+            "abandoned the daily challenge of {} with the score of {}",
+            daily_challenge.date,
+            score,
+        );
+    }
+}
+
+
+/// Clears the daily challenge.
+pub fn clear_daily_challenge(mut commands: Commands) {
+    commands.remove_resource::<DailyChallenge>();
+}
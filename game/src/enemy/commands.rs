@@ -0,0 +1,80 @@
+use crate::prelude::*;
+
+
+/// Controls the enemies.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "enemy")]
+#[command(disable_help_flag = true)]
+pub struct EnemyCommand {
+    #[clap(subcommand)]
+    pub subcommand: EnemyCommands,
+}
+
+/// Enemy commands.
+#[derive(Debug, Subcommand)]
+pub enum EnemyCommands {
+    /// Spawns enemies around the player or at a position.
+    #[clap(allow_negative_numbers = true)]
+    Spawn {
+        enemy: SmolStr,
+        #[arg(default_value = "1")]
+        count: NonZeroU32,
+        #[arg(long, num_args = 2, value_names = ["X", "Y"])]
+        at: Option<Vec<f32>>,
+    },
+    /// Lists the alive enemies.
+    List,
+    /// Kills all the enemies.
+    KillAll,
+    /// Controls the enemy AI.
+    Ai {
+        #[clap(subcommand)]
+        subcommand: EnemyAiCommands,
+    },
+}
+
+/// Enemy AI commands.
+#[derive(Debug, Subcommand)]
+pub enum EnemyAiCommands {
+    /// Shows the status of the enemy AI.
+    Status,
+    /// Freezes the enemy AI.
+    Freeze,
+    /// Unfreezes the enemy AI.
+    Unfreeze,
+}
+
+
+/// Controls the enemy spawn pattern.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "spawn-pattern")]
+#[command(disable_help_flag = true)]
+pub struct SpawnPatternCommand {
+    #[clap(subcommand)]
+    pub subcommand: SpawnPatternCommands,
+}
+
+/// Spawn pattern commands.
+#[derive(Debug, Subcommand)]
+pub enum SpawnPatternCommands {
+    /// Shows the enemy spawn pattern.
+    Show,
+    /// Adds a spawn to the enemy spawn pattern.
+    Add {
+        enemy: SmolStr,
+        #[arg(long, default_value = "0")]
+        delay: f32,
+        #[arg(long, default_value = "1")]
+        count: NonZeroU32,
+        #[arg(long)]
+        interval: Option<f32>,
+        #[arg(long)]
+        spread: Option<f32>,
+        #[arg(long)]
+        repeat: Option<f32>,
+    },
+    /// Removes a spawn from the enemy spawn pattern.
+    Remove { position: NonZeroUsize },
+    /// Clears the enemy spawn pattern.
+    Clear,
+}
@@ -6,6 +6,11 @@ use crate::prelude::*;
 pub struct Enemy;
 
 
+/// Tag component for enemies that are bosses.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct Boss;
+
+
 /// Tag component for hit boxes of enemies.
 #[derive(Component, Debug, Default, Reflect)]
 pub struct EnemyHitBox;
@@ -25,6 +30,11 @@ impl EnemyHitBox {
 }
 
 
+/// Tag component for health bars of enemies.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct EnemyHealthBar;
+
+
 /// Tag component for entities that apply damage to enemies on contact.
 #[derive(Component, Debug, Default, Reflect)]
 pub struct DamageEnemiesOnContact;
@@ -53,6 +63,7 @@ impl<E: Component + IEnemy> EnemyBundle<E> {
         counter.increment();
 
         let id = self.enemy.id();
+        let is_boss = self.enemy.is_boss();
 
         let contact_damage = self.enemy.contact_damage();
         let health = self.enemy.health();
@@ -105,6 +116,10 @@ impl<E: Component + IEnemy> EnemyBundle<E> {
             enemy.insert((Attack::Contact, DamagePlayerOnContact, damage, cooldown));
         }
 
+        if is_boss {
+            enemy.insert(Boss);
+        }
+
         enemy
     }
 }
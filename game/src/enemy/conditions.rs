@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+
+/// Condition to run when the enemy AI is frozen.
+pub fn enemy_ai_is_frozen(enemy_ai: Res<EnemyAi>) -> bool {
+    enemy_ai.is_frozen
+}
+
+/// Condition to run when the enemy AI is not frozen.
+pub fn enemy_ai_is_not_frozen(enemy_ai: Res<EnemyAi>) -> bool {
+    !enemy_ai.is_frozen
+}
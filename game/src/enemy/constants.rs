@@ -1,6 +1,3 @@
-/// Tag for bosses.
-pub const BOSS_TAG: &str = "boss";
-
 /// Tag for melee enemies.
 pub const MELEE_ENEMY_TAG: &str = "melee";
 
@@ -10,3 +7,32 @@ pub const RANGED_ENEMY_TAG: &str = "ranged";
 
 /// Minimum distance from the player to spawn the enemies.
 pub const MINIMUM_ENEMY_SPAWN_DISTANCE: f32 = 100.00;
+
+
+/// Minimum distance from the player to spawn the enemies using the console.
+pub const MINIMUM_CONSOLE_ENEMY_SPAWN_DISTANCE: f32 = 150.00;
+
+/// Maximum distance from the player to spawn the enemies using the console.
+pub const MAXIMUM_CONSOLE_ENEMY_SPAWN_DISTANCE: f32 = 300.00;
+
+/// Spread of the enemies spawned using the console.
+pub const CONSOLE_ENEMY_SPAWN_SPREAD: f32 = 100.00;
+
+/// Maximum number of tries to spawn each enemy of a group.
+pub const MAXIMUM_ENEMY_SPAWN_TRIES: u32 = 10;
+
+
+/// Width of the health bars of enemies.
+pub const ENEMY_HEALTH_BAR_WIDTH: f32 = 30.00;
+
+/// Height of the health bars of enemies.
+pub const ENEMY_HEALTH_BAR_HEIGHT: f32 = 5.00;
+
+/// Margin between the health bars of enemies and the top of the enemies.
+pub const ENEMY_HEALTH_BAR_MARGIN: f32 = 5.00;
+
+/// Horizontal border of the health bars of enemies relative to their width.
+pub const ENEMY_HEALTH_BAR_BORDER_X: f32 = 0.035;
+
+/// Vertical border of the health bars of enemies relative to their height.
+pub const ENEMY_HEALTH_BAR_BORDER_Y: f32 = 0.20;
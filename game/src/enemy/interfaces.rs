@@ -21,6 +21,11 @@ pub trait IEnemy: Debug + Send + Sync + 'static {
     /// Gets the localized name of the enemy.
     fn name(&self) -> LocalizedText;
 
+    /// Gets whether the enemy is a boss.
+    fn is_boss(&self) -> bool {
+        false
+    }
+
     /// Gets the contact damage of the enemy.
     fn contact_damage(&self) -> Option<(Damage, DamageCooldown)> {
         None
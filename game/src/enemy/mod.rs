@@ -1,4 +1,6 @@
+pub mod commands;
 pub mod components;
+pub mod conditions;
 pub mod constants;
 pub mod interfaces;
 pub mod plugin;
@@ -1,5 +1,9 @@
 use crate::{
-    enemy::systems::*,
+    enemy::{
+        commands::*,
+        systems::*,
+    },
+    movement::systems::attraction,
     prelude::*,
 };
 
@@ -10,24 +14,51 @@ impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         // Register components.
         app.register_type::<Enemy>();
+        app.register_type::<Boss>();
         app.register_type::<EnemyHitBox>();
+        app.register_type::<EnemyHealthBar>();
         app.register_type::<DamageEnemiesOnContact>();
         app.register_type::<DamageEnemiesOnContactStarted>();
 
         // Register resources.
+        app.register_type::<EnemyAi>();
         app.register_type::<EnemyCounter>();
         app.register_type::<SelectedEnemyPackIndex>();
 
         // Initialize registry.
         app.init_resource::<EnemyRegistry>();
 
+        // Insert resources.
+        app.init_resource::<EnemyAi>();
+
+        // Add console commands.
+        app.add_console_command::<EnemyCommand, _>(apply_enemy_command);
+        app.add_console_command::<SpawnPatternCommand, _>(apply_spawn_pattern_command);
+
         // Add systems.
         app.add_systems(
             OnEnter(GameState::Loading),
             (initialize_enemy_counter, initialize_enemy_spawn_pattern)
                 .in_set(LoadingSystems::Enemy),
         );
-        app.add_systems(Update, spawn_enemies.in_set(GameplaySystems::Enemy));
+        app.add_systems(
+            Update,
+            (spawn_enemies, target_closest_player.run_if(enemy_ai_is_not_frozen))
+                .in_set(GameplaySystems::Enemy),
+        );
+        app.add_systems(
+            PostUpdate,
+            (spawn_enemy_health_bars, update_enemy_health_bars)
+                .chain()
+                .in_set(GameplaySystems::Enemy),
+        );
+        app.add_systems(
+            Update,
+            stop_frozen_enemies
+                .in_set(GameplaySystems::Enemy)
+                .after(attraction)
+                .run_if(enemy_ai_is_frozen),
+        );
         app.add_systems(
             OnEnter(GameState::Won),
             (despawn_enemies, clear_enemy_counter, clear_enemy_spawn_pattern),
@@ -48,6 +79,7 @@ impl Plugin for EnemyPlugin {
                 clear_enemy_counter,
                 clear_enemy_spawn_pattern,
                 clear_enemy_pack_selection,
+                reset_enemy_ai,
             ),
         );
     }
@@ -54,6 +54,18 @@ impl EnemyRegistry {
     pub fn number_of_enemies(&self) -> usize {
         self.0.iter().map(|entry| entry.enemies.len()).sum()
     }
+
+    /// Finds the enemy with the specified id.
+    pub fn find_enemy_by_id(&self, enemy_id: &str) -> Option<&RegisteredEnemy> {
+        for entry in self.iter() {
+            for enemy in entry.enemies.iter() {
+                if enemy.id() == enemy_id {
+                    return Some(enemy);
+                }
+            }
+        }
+        None
+    }
 }
 
 impl Index<SelectedEnemyPackIndex> for EnemyRegistry {
@@ -1,6 +1,25 @@
 use crate::prelude::*;
 
 
+/// Database of registered enemy systems.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct RegisteredEnemySystems {
+    pub spawn_enemy_group: SystemId<EnemySpawn>,
+}
+
+impl RegisteredEnemySystems {
+    /// Creates the database.
+    pub fn new(app: &mut App, systems: Entity) -> RegisteredEnemySystems {
+        use super::systems::*;
+
+        let spawn_enemy_group = app.world_mut().register_system(spawn_enemy_group);
+        RegisteredSystems::attach(app, systems, spawn_enemy_group, "spawn_enemy_group");
+
+        RegisteredEnemySystems { spawn_enemy_group }
+    }
+}
+
+
 /// Resource for the index of the selected enemy pack.
 #[derive(Clone, Copy, Debug, Deref, Reflect, Resource)]
 pub struct SelectedEnemyPackIndex(pub usize);
@@ -23,6 +42,13 @@ impl EnemyCounter {
 }
 
 
+/// Resource for the enemy AI.
+#[derive(Clone, Copy, Debug, Default, Reflect, Resource)]
+pub struct EnemyAi {
+    pub is_frozen: bool,
+}
+
+
 /// Resource for the enemy spawn pattern for the selected game mode and the selected enemy pack.
 #[derive(Clone, Resource)]
 pub struct EnemySpawnPattern {
@@ -1,9 +1,221 @@
 use crate::{
-    enemy::constants::*,
+    enemy::{
+        commands::*,
+        constants::*,
+    },
     prelude::*,
 };
 
 
+/// Applies the enemy command.
+pub fn apply_enemy_command(
+    mut commands: Commands,
+    mut enemy_query: Query<(&Name, &Position, &Health, &mut RemainingHealth), With<Enemy>>,
+    app_state: Res<State<AppState>>,
+    enemy_registry: Res<EnemyRegistry>,
+    mut enemy_ai: ResMut<EnemyAi>,
+    registered_systems: Res<RegisteredSystems>,
+    mut command: ConsoleCommand<EnemyCommand>,
+) {
+    if let Some(Ok(EnemyCommand { subcommand })) = command.take() {
+        if *app_state.get() != AppState::Game {
+            reply!(command, "Not available outside the game.");
+            reply!(command, "");
+            return;
+        }
+
+        match subcommand {
+            EnemyCommands::Spawn { enemy, count, at } => {
+                match enemy_registry.find_enemy_by_id(&enemy) {
+                    Some(registered_enemy) => {
+                        let position = match at.as_deref() {
+                            Some([x, y]) => {
+                                EnemySpawnPosition::At(Position::new(Vector::new(*x, *y)))
+                            },
+                            _ => {
+                                EnemySpawnPosition::AroundPlayer {
+                                    near: MINIMUM_CONSOLE_ENEMY_SPAWN_DISTANCE,
+                                    far: MAXIMUM_CONSOLE_ENEMY_SPAWN_DISTANCE,
+                                }
+                            },
+                        };
+                        let spawn = EnemySpawn::new_dyn(Duration::ZERO, &registered_enemy.enemy)
+                            .count(count.get())
+                            .position(position)
+                            .spread(EnemySpawnSpread::square(CONSOLE_ENEMY_SPAWN_SPREAD));
+
+                        commands.run_system_with_input(
+                            registered_systems.enemy.spawn_enemy_group,
+                            spawn,
+                        );
+                        reply!(command, "Spawned.");
+                    },
+                    None => {
+                        reply!(command, "Failed to spawn {:?} as it doesn't exist.", enemy);
+                    },
+                }
+            },
+            EnemyCommands::List => {
+                if enemy_query.is_empty() {
+                    reply!(command, "No enemies.");
+                } else {
+                    for (name, position, health, remaining_health) in enemy_query.iter() {
+                        reply!(
+                            command,
+                            "{} at ({:.2}, {:.2}) with {:.2}/{:.2} health",
+                            name,
+                            position.x,
+                            position.y,
+                            remaining_health.0,
+                            health.0,
+                        );
+                    }
+                }
+            },
+            EnemyCommands::KillAll => {
+                let mut killed = 0;
+                for (_, _, _, mut remaining_health) in enemy_query.iter_mut() {
+                    remaining_health.0 = 0.00;
+                    killed += 1;
+                }
+                reply!(command, "Killed {} enemies.", killed);
+            },
+            EnemyCommands::Ai { subcommand } => {
+                match subcommand {
+                    EnemyAiCommands::Status => {
+                        let status = if enemy_ai.is_frozen { "Frozen" } else { "Not frozen" };
+                        reply!(command, "{}.", status);
+                    },
+                    EnemyAiCommands::Freeze => {
+                        if enemy_ai.is_frozen {
+                            reply!(command, "Already frozen.");
+                        } else {
+                            enemy_ai.is_frozen = true;
+                            reply!(command, "Frozen.");
+                        }
+                    },
+                    EnemyAiCommands::Unfreeze => {
+                        if enemy_ai.is_frozen {
+                            enemy_ai.is_frozen = false;
+                            reply!(command, "Unfrozen.");
+                        } else {
+                            reply!(command, "Already not frozen.");
+                        }
+                    },
+                }
+            },
+        }
+
+        reply!(command, "");
+    }
+}
+
+/// Applies the spawn pattern command.
+pub fn apply_spawn_pattern_command(
+    enemy_spawn_pattern: Option<Res<EnemySpawnPattern>>,
+    enemy_registry: Res<EnemyRegistry>,
+    mut command: ConsoleCommand<SpawnPatternCommand>,
+) {
+    if let Some(Ok(SpawnPatternCommand { subcommand })) = command.take() {
+        let enemy_spawn_pattern = match enemy_spawn_pattern {
+            Some(enemy_spawn_pattern) => enemy_spawn_pattern,
+            None => {
+                reply!(command, "Not available outside the game.");
+                reply!(command, "");
+                return;
+            },
+        };
+        let mut spawns = enemy_spawn_pattern.spawns.lock().unwrap();
+
+        match subcommand {
+            SpawnPatternCommands::Show => {
+                if spawns.is_empty() {
+                    reply!(command, "Spawn pattern is empty.");
+                } else {
+                    for (index, spawn) in spawns.iter().enumerate() {
+                        reply!(command, "{}) {:?}", index + 1, spawn);
+                    }
+                }
+            },
+            SpawnPatternCommands::Add { enemy, delay, count, interval, spread, repeat } => {
+                let registered_enemy = match enemy_registry.find_enemy_by_id(&enemy) {
+                    Some(registered_enemy) => registered_enemy,
+                    None => {
+                        reply!(command, "Failed to add {:?} as it doesn't exist.", enemy);
+                        reply!(command, "");
+                        return;
+                    },
+                };
+
+                let delay = match Duration::try_from_secs_f32(delay) {
+                    Ok(delay) => delay,
+                    Err(_) => {
+                        reply!(command, "Delay must be a non-negative number of seconds.");
+                        reply!(command, "");
+                        return;
+                    },
+                };
+
+                let mut spawn =
+                    EnemySpawn::new_dyn(delay, &registered_enemy.enemy).count(count.get());
+                if let Some(interval) = interval {
+                    match Duration::try_from_secs_f32(interval) {
+                        Ok(interval) if !interval.is_zero() => {
+                            spawn = spawn.interval(interval);
+                        },
+                        _ => {
+                            reply!(command, "Interval must be a positive number of seconds.");
+                            reply!(command, "");
+                            return;
+                        },
+                    }
+                }
+                if let Some(spread) = spread {
+                    if spread < 0.00 {
+                        reply!(command, "Spread must be non-negative.");
+                        reply!(command, "");
+                        return;
+                    }
+                    spawn = spawn.spread(EnemySpawnSpread::square(spread));
+                }
+                if let Some(repeat) = repeat {
+                    match Duration::try_from_secs_f32(repeat) {
+                        Ok(repeat) if !repeat.is_zero() => {
+                            spawn = spawn.repeat(repeat);
+                        },
+                        _ => {
+                            reply!(command, "Repeat must be a positive number of seconds.");
+                            reply!(command, "");
+                            return;
+                        },
+                    }
+                }
+
+                log::info!("adding {:?} to the enemy spawn pattern", spawn);
+                spawns.push(spawn);
+                reply!(command, "Added as {}.", spawns.len());
+            },
+            SpawnPatternCommands::Remove { position } => {
+                if position.get() > spawns.len() {
+                    reply!(command, "Failed to remove {} as it doesn't exist.", position);
+                } else {
+                    let spawn = spawns.remove(position.get() - 1);
+                    log::info!("removed {:?} from the enemy spawn pattern", spawn);
+                    reply!(command, "Removed.");
+                }
+            },
+            SpawnPatternCommands::Clear => {
+                spawns.clear();
+                log::info!("cleared the enemy spawn pattern");
+                reply!(command, "Cleared.");
+            },
+        }
+
+        reply!(command, "");
+    }
+}
+
+
 /// Initializes the enemy counter.
 pub fn initialize_enemy_counter(mut commands: Commands) {
     commands.insert_resource(EnemyCounter::default());
@@ -87,11 +299,18 @@ pub fn spawn_enemy(world: &mut World, map_bounds: &MapBounds, spawn: &mut EnemyS
         let mut group_position = match spawn.position {
             EnemySpawnPosition::At(position) => position,
             _ => {
-                let player_position =
-                    match world.query_filtered::<&Position, With<Player>>().get_single(world) {
-                        Ok(player_position) => *player_position,
-                        Err(_) => return,
-                    };
+                let player_positions = world
+                    .query_filtered::<&Position, (With<Player>, Without<Downed>)>()
+                    .iter(world)
+                    .copied()
+                    .collect::<Vec<_>>();
+                let player_position = {
+                    let mut rng = world.resource_mut::<GlobalEntropy<ChaCha8Rng>>();
+                    match player_positions.choose(rng.deref_mut()) {
+                        Some(player_position) => *player_position,
+                        None => return,
+                    }
+                };
 
                 let enemy_direction = {
                     let EnemySpawnDirection { from_degrees, to_degrees } = spawn.direction;
@@ -206,6 +425,162 @@ pub fn spawn_enemy(world: &mut World, map_bounds: &MapBounds, spawn: &mut EnemyS
     spawn.spawned += 1;
 }
 
+/// Spawns a group of enemies immediately.
+pub fn spawn_enemy_group(In(mut spawn): In<EnemySpawn>, world: &mut World) {
+    let map_bounds = match world.get_resource::<MapBounds>() {
+        Some(map_bounds) => *map_bounds,
+        None => return,
+    };
+
+    spawn.remaining = spawn.count;
+
+    let mut tries = 0;
+    while spawn.remaining != 0 && tries < spawn.count * MAXIMUM_ENEMY_SPAWN_TRIES {
+        spawn_enemy(world, &map_bounds, &mut spawn);
+        tries += 1;
+    }
+
+    if spawn.remaining != 0 {
+        log::warn!(
+            "couldn't spawn {} of {} {:?} as there wasn't enough free space",
+            spawn.remaining,
+            spawn.count,
+            spawn.enemy.id(),
+        );
+    }
+}
+
+
+/// Makes the enemies target the closest player that is not down.
+pub fn target_closest_player(
+    mut enemy_query: Query<(&Position, &mut AttractedTo), With<Enemy>>,
+    player_query: Query<(Entity, &Position), (With<Player>, Without<Downed>)>,
+) {
+    for (enemy_position, mut enemy_target) in enemy_query.iter_mut() {
+        if let Some((player_entity, _)) =
+            utils::player::find_closest_player(enemy_position.xy(), &player_query)
+        {
+            if enemy_target.0 != player_entity {
+                enemy_target.0 = player_entity;
+            }
+        }
+    }
+}
+
+
+/// Stops the enemies when the enemy AI is frozen.
+pub fn stop_frozen_enemies(mut enemy_query: Query<&mut LinearVelocity, With<Enemy>>) {
+    for mut velocity in enemy_query.iter_mut() {
+        velocity.0 = Vector::ZERO;
+    }
+}
+
+
+/// Spawns the health bars of the spawned enemies.
+pub fn spawn_enemy_health_bars(
+    mut commands: Commands,
+    enemy_query: Query<(Entity, &Collider, &Health, &RemainingHealth), Added<Enemy>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut health_bar_materials: ResMut<Assets<HealthBarMaterial>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    mut mesh: Local<Option<Handle<Mesh>>>,
+) {
+    let foreground_color = general_settings.color_palette.health_bar().to_linear();
+    for (enemy_entity, collider, health, remaining_health) in enemy_query.iter() {
+        let mesh = mesh
+            .get_or_insert_with(|| {
+                meshes.add(Rectangle::new(ENEMY_HEALTH_BAR_WIDTH, ENEMY_HEALTH_BAR_HEIGHT))
+            })
+            .clone();
+        let material = health_bar_materials.add(HealthBarMaterial {
+            foreground_color: Vec4::new(
+                foreground_color.red,
+                foreground_color.green,
+                foreground_color.blue,
+                foreground_color.alpha,
+            ),
+            percent: (remaining_health.0 / health.0).clamp(0.00, 1.00),
+            border_x: ENEMY_HEALTH_BAR_BORDER_X,
+            border_y: ENEMY_HEALTH_BAR_BORDER_Y,
+            ..default()
+        });
+
+        let top = collider.aabb(Vector::ZERO, Rotation::default()).max.y;
+        let offset = top + ENEMY_HEALTH_BAR_MARGIN + (ENEMY_HEALTH_BAR_HEIGHT / 2.00);
+
+        let damaged = remaining_health.0 < health.0;
+        let visibility = if general_settings.enemy_health_bars.is_visible(damaged) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        commands.entity(enemy_entity).with_children(|parent| {
+            parent.spawn((
+                Name::new("Health Bar"),
+                EnemyHealthBar,
+                MaterialMesh2dBundle {
+                    mesh: mesh.into(),
+                    material,
+                    transform: Transform::from_xyz(0.00, offset, 0.50),
+                    visibility,
+                    ..default()
+                },
+            ));
+        });
+    }
+}
+
+/// Updates the health bars of the enemies.
+pub fn update_enemy_health_bars(
+    enemy_query: Query<(Ref<Health>, Ref<RemainingHealth>, &Children), With<Enemy>>,
+    mut health_bar_query: Query<
+        (&Handle<HealthBarMaterial>, &mut Visibility),
+        With<EnemyHealthBar>,
+    >,
+    mut health_bar_materials: ResMut<Assets<HealthBarMaterial>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    let foreground_color = general_settings.color_palette.health_bar().to_linear();
+    for (health, remaining_health, children) in enemy_query.iter() {
+        if !(general_settings.is_changed() || health.is_changed() || remaining_health.is_changed())
+        {
+            continue;
+        }
+
+        let damaged = remaining_health.0 < health.0;
+        let visibility = if general_settings.enemy_health_bars.is_visible(damaged) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        for &child in children.iter() {
+            let (health_bar_handle, mut health_bar_visibility) =
+                match health_bar_query.get_mut(child) {
+                    Ok(query_result) => query_result,
+                    Err(_) => continue,
+                };
+
+            health_bar_visibility.set_if_neq(visibility);
+            if visibility == Visibility::Hidden {
+                continue;
+            }
+
+            if let Some(health_bar) = health_bar_materials.get_mut(health_bar_handle) {
+                health_bar.foreground_color = Vec4::new(
+                    foreground_color.red,
+                    foreground_color.green,
+                    foreground_color.blue,
+                    foreground_color.alpha,
+                );
+                health_bar.percent = (remaining_health.0 / health.0).clamp(0.00, 1.00);
+            }
+        }
+    }
+}
+
+
 /// Finds a free space to spawn an enemy.
 pub fn find_free_space(
     In((target_transform, collider, margin)): In<(Transform, Collider, Scalar)>,
@@ -278,6 +653,11 @@ pub fn clear_enemy_spawn_pattern(mut commands: Commands) {
     commands.remove_resource::<EnemySpawnPattern>();
 }
 
+/// Resets the enemy AI.
+pub fn reset_enemy_ai(mut enemy_ai: ResMut<EnemyAi>) {
+    *enemy_ai = EnemyAi::default();
+}
+
 /// Clears the enemy pack selection.
 pub fn clear_enemy_pack_selection(mut commands: Commands) {
     commands.remove_resource::<SelectedEnemyPackIndex>();
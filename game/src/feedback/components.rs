@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+
+/// Component for the floating numbers that are shown when entities are damaged or healed.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DamageNumber {
+    /// Timer for the lifetime of the number.
+    pub timer: Timer,
+    /// Velocity of the number.
+    pub velocity: Vec2,
+    /// Color of the number when it's fully visible.
+    pub color: Color,
+}
+
+impl Default for DamageNumber {
+    fn default() -> DamageNumber {
+        DamageNumber {
+            timer: Timer::from_seconds(0.00, TimerMode::Once),
+            velocity: Vec2::ZERO,
+            color: Color::WHITE,
+        }
+    }
+}
+
+
+/// Component for the flash of entities that are hit.
+#[derive(Component, Debug, Reflect)]
+#[component(storage = "SparseSet")]
+#[reflect(Component)]
+pub struct HitFlash {
+    /// Timer for the duration of the flash.
+    pub timer: Timer,
+}
+
+
+/// Component for the effects that are shown when enemies die.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DeathEffect {
+    /// Timer for the lifetime of the effect.
+    pub timer: Timer,
+}
+
+impl Default for DeathEffect {
+    fn default() -> DeathEffect {
+        DeathEffect { timer: Timer::from_seconds(0.00, TimerMode::Once) }
+    }
+}
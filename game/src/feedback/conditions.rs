@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+
+/// Condition to run when damage numbers are enabled.
+pub fn damage_numbers_are_enabled(general_settings: Res<Persistent<GeneralSettings>>) -> bool {
+    general_settings.damage_numbers
+}
+
+/// Condition to run when hit flashes are enabled.
+pub fn hit_flashes_are_enabled(general_settings: Res<Persistent<GeneralSettings>>) -> bool {
+    general_settings.hit_flashes
+}
+
+/// Condition to run when death effects are enabled.
+pub fn death_effects_are_enabled(general_settings: Res<Persistent<GeneralSettings>>) -> bool {
+    general_settings.death_effects
+}
+
+/// Condition to run when screen effects are not reduced.
+pub fn screen_effects_are_not_reduced(general_settings: Res<Persistent<GeneralSettings>>) -> bool {
+    !general_settings.reduce_screen_effects
+}
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+
+/// Maximum number of damage numbers that can be shown at the same time.
+pub const DAMAGE_NUMBER_POOL_CAPACITY: usize = 256;
+
+/// Duration of damage numbers in seconds.
+pub const DAMAGE_NUMBER_DURATION: f32 = 0.75;
+
+/// Font size of damage numbers in pt.
+pub const DAMAGE_NUMBER_FONT_SIZE: f32 = 20.0;
+
+/// Vertical offset of damage numbers from the damaged entities.
+pub const DAMAGE_NUMBER_OFFSET: f32 = 20.0;
+
+/// Vertical speed of damage numbers.
+pub const DAMAGE_NUMBER_SPEED: f32 = 40.0;
+
+/// Horizontal spread of consecutive damage numbers.
+pub const DAMAGE_NUMBER_SPREAD: f32 = 8.0;
+
+/// Color of the damage numbers of enemies.
+pub const ENEMY_DAMAGE_NUMBER_COLOR: Color = Color::WHITE;
+
+/// Color of the damage numbers of players.
+pub const PLAYER_DAMAGE_NUMBER_COLOR: Color = Color::srgba(1.00, 0.35, 0.35, 1.00);
+
+/// Color of the numbers of dodged damage.
+pub const MISS_NUMBER_COLOR: Color = Color::srgba(0.70, 0.70, 0.70, 1.00);
+
+/// Interval to accumulate regenerated health before showing it in seconds.
+pub const HEAL_NUMBER_INTERVAL: f32 = 1.00;
+
+/// Minimum amount of regenerated health to show.
+pub const MIN_HEAL_NUMBER_AMOUNT: f32 = 1.00;
+
+
+/// Duration of hit flashes in seconds.
+pub const HIT_FLASH_DURATION: f32 = 0.10;
+
+/// Color of hit flashes.
+pub const HIT_FLASH_COLOR: Color = Color::WHITE;
+
+
+/// Maximum number of death effects that can be shown at the same time.
+pub const DEATH_EFFECT_POOL_CAPACITY: usize = 64;
+
+/// Duration of death effects in seconds.
+pub const DEATH_EFFECT_DURATION: f32 = 0.35;
+
+/// Initial radius of death effects.
+pub const DEATH_EFFECT_RADIUS: f32 = 15.0;
+
+/// Final scale of death effects.
+pub const DEATH_EFFECT_SCALE: f32 = 2.50;
+
+/// Color of death effects.
+pub const DEATH_EFFECT_COLOR: Color = Color::srgba(1.00, 1.00, 1.00, 0.60);
@@ -0,0 +1,7 @@
+use crate::prelude::*;
+
+
+/// Gets the localized text of dodged damage.
+pub fn miss() -> LocalizedText {
+    LocalizedText::Localized { key: "feedback-miss", args: smallvec![], fallback: "Miss".into() }
+}
@@ -0,0 +1,7 @@
+pub mod components;
+pub mod conditions;
+pub mod constants;
+pub mod localization;
+pub mod plugin;
+pub mod resources;
+pub mod systems;
@@ -0,0 +1,49 @@
+use crate::{
+    feedback::{
+        conditions::*,
+        constants::*,
+        systems::*,
+    },
+    prelude::*,
+};
+
+/// Plugin for managing the visual feedback of the combat.
+pub struct FeedbackPlugin;
+
+impl Plugin for FeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<DamageNumber>();
+        app.register_type::<HitFlash>();
+        app.register_type::<DeathEffect>();
+
+        // Insert resources.
+        app.insert_resource(EntityPool::<DamageNumber>::new(DAMAGE_NUMBER_POOL_CAPACITY));
+        app.insert_resource(EntityPool::<DeathEffect>::new(DEATH_EFFECT_POOL_CAPACITY));
+
+        // Setup localization.
+        app.world_mut().resource_mut::<LocaleAssets>().push("feedback.ftl");
+
+        // Add systems.
+        app.add_systems(
+            PostUpdate,
+            (
+                (show_damage_numbers, show_heal_numbers).run_if(damage_numbers_are_enabled),
+                flash_hit_enemies
+                    .run_if(hit_flashes_are_enabled.and_then(screen_effects_are_not_reduced)),
+                show_death_effects
+                    .run_if(death_effects_are_enabled.and_then(screen_effects_are_not_reduced)),
+                animate_damage_numbers,
+                update_hit_flashes,
+                animate_death_effects,
+            )
+                .in_set(GameplaySystems::Feedback)
+                .after(GameplaySystems::Combat),
+        );
+        app.add_systems(
+            OnEnter(GameState::Restart),
+            despawn_feedback.in_set(RestartSystems::Feedback),
+        );
+        app.add_systems(OnExit(AppState::Game), despawn_feedback);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::prelude::*;
+
+
+/// Resource for the pool of reusable entities with a component.
+#[derive(Debug, Resource)]
+pub struct EntityPool<T: Component> {
+    capacity: usize,
+    available: Vec<Entity>,
+    active: VecDeque<Entity>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> EntityPool<T> {
+    /// Creates a new pool with a capacity.
+    pub fn new(capacity: usize) -> EntityPool<T> {
+        EntityPool {
+            capacity,
+            available: Vec::new(),
+            active: VecDeque::with_capacity(capacity),
+            marker: PhantomData,
+        }
+    }
+
+    /// Acquires an entity from the pool.
+    ///
+    /// Oldest active entity is reused when the pool is full.
+    /// Returns `None` if a new entity needs to be spawned and added to the pool.
+    pub fn acquire(&mut self) -> Option<Entity> {
+        let entity = self.available.pop().or_else(|| {
+            if self.active.len() >= self.capacity { self.active.pop_front() } else { None }
+        })?;
+        self.active.push_back(entity);
+        Some(entity)
+    }
+
+    /// Adds a newly spawned active entity to the pool.
+    pub fn add(&mut self, entity: Entity) {
+        self.active.push_back(entity);
+    }
+
+    /// Releases an active entity back to the pool.
+    pub fn release(&mut self, entity: Entity) {
+        if let Some(position) = self.active.iter().position(|&active| active == entity) {
+            self.active.remove(position);
+            self.available.push(entity);
+        }
+    }
+
+    /// Clears the pool.
+    pub fn clear(&mut self) {
+        self.available.clear();
+        self.active.clear();
+    }
+}
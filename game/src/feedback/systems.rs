@@ -0,0 +1,344 @@
+use crate::{
+    feedback::{
+        constants::*,
+        localization,
+    },
+    prelude::*,
+};
+
+
+/// Shows damage numbers when damage is received.
+pub fn show_damage_numbers(
+    mut commands: Commands,
+    mut damage_number_query: Query<(&mut DamageNumber, &mut Text, &mut Transform, &mut Visibility)>,
+    player_query: Query<(), With<Player>>,
+    asset_server: Res<AssetServer>,
+    localization: Res<Localization>,
+    number_format: Res<NumberFormat>,
+    mut damage_number_pool: ResMut<EntityPool<DamageNumber>>,
+    mut damage_received_event_reader: EventReader<DamageReceivedEvent>,
+    mut counter: Local<u32>,
+) {
+    for event in damage_received_event_reader.read() {
+        let (text, color) = if event.dodged {
+            (localization::miss().get(&localization).to_string(), MISS_NUMBER_COLOR)
+        } else {
+            let color = if player_query.contains(event.entity) {
+                PLAYER_DAMAGE_NUMBER_COLOR
+            } else {
+                ENEMY_DAMAGE_NUMBER_COLOR
+            };
+            (number_format.decimal(event.damage.0.ceil() as f64, 0), color)
+        };
+        show_damage_number(
+            &mut commands,
+            &mut damage_number_query,
+            &asset_server,
+            &mut damage_number_pool,
+            &mut counter,
+            event.position.0,
+            text,
+            color,
+        );
+    }
+}
+
+/// Shows heal numbers when health is regenerated.
+pub fn show_heal_numbers(
+    mut commands: Commands,
+    mut damage_number_query: Query<(&mut DamageNumber, &mut Text, &mut Transform, &mut Visibility)>,
+    healed_entity_query: Query<&Position>,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    number_format: Res<NumberFormat>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    mut damage_number_pool: ResMut<EntityPool<DamageNumber>>,
+    mut health_regenerated_event_reader: EventReader<HealthRegeneratedEvent>,
+    mut accumulated_healing: Local<HashMap<Entity, (f32, f32)>>,
+    mut counter: Local<u32>,
+) {
+    for event in health_regenerated_event_reader.read() {
+        accumulated_healing.entry(event.entity).or_default().0 += event.amount;
+    }
+
+    let color = general_settings.color_palette.health_bar();
+    accumulated_healing.retain(|&entity, (amount, elapsed)| {
+        let position = match healed_entity_query.get(entity) {
+            Ok(position) => position,
+            Err(_) => return false,
+        };
+
+        *elapsed += time.delta_seconds();
+        if *elapsed < HEAL_NUMBER_INTERVAL {
+            return true;
+        }
+        *elapsed = 0.00;
+
+        if *amount >= MIN_HEAL_NUMBER_AMOUNT {
+            show_damage_number(
+                &mut commands,
+                &mut damage_number_query,
+                &asset_server,
+                &mut damage_number_pool,
+                &mut counter,
+                position.0,
+                format!("+{}", number_format.decimal(amount.floor() as f64, 0)),
+                color,
+            );
+            *amount -= amount.floor();
+        }
+        true
+    });
+}
+
+/// Shows a damage number using a pooled entity if possible.
+fn show_damage_number(
+    commands: &mut Commands,
+    damage_number_query: &mut Query<(
+        &mut DamageNumber,
+        &mut Text,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+    asset_server: &AssetServer,
+    damage_number_pool: &mut EntityPool<DamageNumber>,
+    counter: &mut u32,
+    position: Vector,
+    text: String,
+    color: Color,
+) {
+    // Spread consecutive numbers horizontally so they don't overlap.
+    *counter = counter.wrapping_add(1);
+    let spread = ((*counter % 5) as f32 - 2.00) * DAMAGE_NUMBER_SPREAD;
+
+    let translation =
+        Vec3::new(position.x + spread, position.y + DAMAGE_NUMBER_OFFSET, Depth::Top.z());
+    let damage_number = DamageNumber {
+        timer: Timer::from_seconds(DAMAGE_NUMBER_DURATION, TimerMode::Once),
+        velocity: Vec2::new(spread, DAMAGE_NUMBER_SPEED),
+        color,
+    };
+
+    if let Some(entity) = damage_number_pool.acquire() {
+        if let Ok((mut existing_damage_number, mut existing_text, mut transform, mut visibility)) =
+            damage_number_query.get_mut(entity)
+        {
+            *existing_damage_number = damage_number;
+            existing_text.sections[0].value = text;
+            existing_text.sections[0].style.color = color;
+            transform.translation = translation;
+            *visibility = Visibility::Inherited;
+            return;
+        }
+        damage_number_pool.release(entity);
+    }
+
+    let entity = commands
+        .spawn((
+            Name::new("Damage Number"),
+            damage_number,
+            Text2dBundle {
+                text: Text::from_section(
+                    text,
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: DAMAGE_NUMBER_FONT_SIZE,
+                        color,
+                    },
+                ),
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+        ))
+        .id();
+    damage_number_pool.add(entity);
+}
+
+/// Moves and fades the damage numbers, and releases them when they expire.
+pub fn animate_damage_numbers(
+    mut damage_number_query: Query<(
+        Entity,
+        &mut DamageNumber,
+        &mut Text,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+    time: Res<Time>,
+    mut damage_number_pool: ResMut<EntityPool<DamageNumber>>,
+) {
+    for (entity, mut damage_number, mut text, mut transform, mut visibility) in
+        damage_number_query.iter_mut()
+    {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        damage_number.timer.tick(time.delta());
+        if damage_number.timer.finished() {
+            *visibility = Visibility::Hidden;
+            damage_number_pool.release(entity);
+            continue;
+        }
+
+        transform.translation += (damage_number.velocity * time.delta_seconds()).extend(0.00);
+
+        let alpha = damage_number.color.alpha() * damage_number.timer.fraction_remaining();
+        text.sections[0].style.color = damage_number.color.with_alpha(alpha);
+    }
+}
+
+
+/// Flashes the enemies when they are hit.
+pub fn flash_hit_enemies(
+    mut commands: Commands,
+    enemy_query: Query<&Handle<ColorMaterial>, With<Enemy>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut damage_received_event_reader: EventReader<DamageReceivedEvent>,
+) {
+    for event in damage_received_event_reader.read() {
+        if event.dodged {
+            continue;
+        }
+        if let Ok(material_handle) = enemy_query.get(event.entity) {
+            if let Some(material) = materials.get_mut(material_handle) {
+                material.color = HIT_FLASH_COLOR;
+            }
+            commands.entity(event.entity).try_insert(HitFlash {
+                timer: Timer::from_seconds(HIT_FLASH_DURATION, TimerMode::Once),
+            });
+        }
+    }
+}
+
+/// Restores the colors of the flashing entities when their flash ends.
+pub fn update_hit_flashes(
+    mut commands: Commands,
+    mut hit_flash_query: Query<(
+        Entity,
+        &mut HitFlash,
+        &Handle<ColorMaterial>,
+        Option<&OriginalColor>,
+    )>,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    for (entity, mut hit_flash, material_handle, original_color) in hit_flash_query.iter_mut() {
+        hit_flash.timer.tick(time.delta());
+        if !hit_flash.timer.finished() {
+            continue;
+        }
+
+        if let (Some(material), Some(original_color)) =
+            (materials.get_mut(material_handle), original_color)
+        {
+            material.color = general_settings.color_palette.tint(original_color.0);
+        }
+        commands.entity(entity).remove::<HitFlash>();
+    }
+}
+
+
+/// Shows death effects when enemies die.
+pub fn show_death_effects(
+    mut commands: Commands,
+    mut death_effect_query: Query<(
+        &mut DeathEffect,
+        &mut Transform,
+        &Handle<ColorMaterial>,
+        &mut Visibility,
+    )>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut death_effect_pool: ResMut<EntityPool<DeathEffect>>,
+    mut enemy_died_event_reader: EventReader<EnemyDiedEvent>,
+    mut mesh: Local<Option<Handle<Mesh>>>,
+) {
+    for event in enemy_died_event_reader.read() {
+        let translation = event.position.extend(Depth::Enemy.z());
+        let death_effect =
+            DeathEffect { timer: Timer::from_seconds(DEATH_EFFECT_DURATION, TimerMode::Once) };
+
+        if let Some(entity) = death_effect_pool.acquire() {
+            if let Ok((mut existing_death_effect, mut transform, material_handle, mut visibility)) =
+                death_effect_query.get_mut(entity)
+            {
+                *existing_death_effect = death_effect;
+                *transform = Transform::from_translation(translation);
+                if let Some(material) = materials.get_mut(material_handle) {
+                    material.color = DEATH_EFFECT_COLOR;
+                }
+                *visibility = Visibility::Inherited;
+                continue;
+            }
+            death_effect_pool.release(entity);
+        }
+
+        let mesh = mesh.get_or_insert_with(|| meshes.add(Circle::new(DEATH_EFFECT_RADIUS))).clone();
+        let entity = commands
+            .spawn((
+                Name::new("Death Effect"),
+                death_effect,
+                MaterialMesh2dBundle {
+                    mesh: mesh.into(),
+                    material: materials.add(ColorMaterial::from(DEATH_EFFECT_COLOR)),
+                    transform: Transform::from_translation(translation),
+                    ..default()
+                },
+            ))
+            .id();
+        death_effect_pool.add(entity);
+    }
+}
+
+/// Expands and fades the death effects, and releases them when they expire.
+pub fn animate_death_effects(
+    mut death_effect_query: Query<(
+        Entity,
+        &mut DeathEffect,
+        &mut Transform,
+        &Handle<ColorMaterial>,
+        &mut Visibility,
+    )>,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut death_effect_pool: ResMut<EntityPool<DeathEffect>>,
+) {
+    for (entity, mut death_effect, mut transform, material_handle, mut visibility) in
+        death_effect_query.iter_mut()
+    {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        death_effect.timer.tick(time.delta());
+        if death_effect.timer.finished() {
+            *visibility = Visibility::Hidden;
+            death_effect_pool.release(entity);
+            continue;
+        }
+
+        let progress = death_effect.timer.fraction();
+        transform.scale = Vec3::splat(1.00 + (DEATH_EFFECT_SCALE - 1.00) * progress);
+
+        if let Some(material) = materials.get_mut(material_handle) {
+            let alpha = DEATH_EFFECT_COLOR.alpha() * (1.00 - progress);
+            material.color = DEATH_EFFECT_COLOR.with_alpha(alpha);
+        }
+    }
+}
+
+
+/// Despawns the damage numbers and the death effects.
+pub fn despawn_feedback(
+    mut commands: Commands,
+    feedback_query: Query<Entity, Or<(With<DamageNumber>, With<DeathEffect>)>>,
+    mut damage_number_pool: ResMut<EntityPool<DamageNumber>>,
+    mut death_effect_pool: ResMut<EntityPool<DeathEffect>>,
+) {
+    for entity in feedback_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    damage_number_pool.clear();
+    death_effect_pool.clear();
+}
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// Actions that can be performed in the achievements screen.
+#[derive(Actionlike, Clone, Copy, Debug, Eq, Hash, PartialEq, Reflect)]
+pub enum AchievementsScreenAction {
+    Back,
+    Select,
+}
+
+impl AchievementsScreenAction {
+    /// Sets up the action.
+    pub fn setup(app: &mut App) {
+        // Add input manager plugin.
+        app.add_plugins(InputManagerPlugin::<AchievementsScreenAction>::default());
+
+        // Create the input map.
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = AchievementsScreenAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<AchievementsScreenAction> {
+        let mut input_map = InputMap::default();
+
+        // Extend the input map from key bindings.
+        for key_code in key_bindings.back.iter().cloned() {
+            input_map.insert(AchievementsScreenAction::Back, key_code);
+        }
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(AchievementsScreenAction::Select, key_code);
+        }
+
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_back.iter().cloned() {
+            input_map.insert(AchievementsScreenAction::Back, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(AchievementsScreenAction::Select, gamepad_button);
+        }
+
+        input_map
+    }
+}
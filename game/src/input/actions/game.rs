@@ -1,14 +1,16 @@
 use crate::prelude::*;
 
 /// Actions that can be performed in the game.
-#[derive(Actionlike, Clone, Copy, Debug, Eq, Hash, PartialEq, Reflect)]
+#[derive(Actionlike, Clone, Copy, Debug, EnumIter, Eq, Hash, PartialEq, Reflect)]
 pub enum GameAction {
     Pause,
+    Move,
     MoveUp,
     MoveLeft,
     MoveDown,
     MoveRight,
     Dash,
+    Fire,
     OpenMarket,
 }
 
@@ -17,35 +19,90 @@ impl GameAction {
     pub fn setup(app: &mut App) {
         // Add input manager plugin.
         app.add_plugins(InputManagerPlugin::<GameAction>::default());
+    }
 
-        // Create the input map.
+    /// Creates the input map of a local player from key bindings.
+    ///
+    /// When there is a single player, the player can use the keyboard and any of the gamepads.
+    /// Otherwise, the first player uses the keyboard and the other players use the gamepads
+    /// assigned to them as they are connected (e.g., the second player uses the first gamepad).
+    pub fn input_map_of(
+        player_index: PlayerIndex,
+        number_of_players: NumberOfPlayers,
+        key_bindings: &KeyBindings,
+        player_gamepads: &PlayerGamepads,
+    ) -> InputMap<GameAction> {
         let mut input_map = InputMap::default();
 
-        // Extend the input map from key bindings.
-        let key_bindings = app.world_mut().resource::<Persistent<KeyBindings>>();
-        for key_code in key_bindings.up.iter().cloned() {
-            input_map.insert(GameAction::MoveUp, key_code);
-        }
-        for key_code in key_bindings.left.iter().cloned() {
-            input_map.insert(GameAction::MoveLeft, key_code);
-        }
-        for key_code in key_bindings.down.iter().cloned() {
-            input_map.insert(GameAction::MoveDown, key_code);
-        }
-        for key_code in key_bindings.right.iter().cloned() {
-            input_map.insert(GameAction::MoveRight, key_code);
-        }
-        for key_code in key_bindings.dash.iter().cloned() {
-            input_map.insert(GameAction::Dash, key_code);
-        }
-        for key_code in key_bindings.pause.iter().cloned() {
-            input_map.insert(GameAction::Pause, key_code);
+        let assigned_gamepad = player_gamepads.gamepad_of(player_index);
+
+        let uses_keyboard = player_index.0 == 0;
+        let uses_gamepad = number_of_players.0 == 1 || assigned_gamepad.is_some();
+
+        if uses_keyboard {
+            // Extend the input map from key bindings.
+            for key_code in key_bindings.up.iter().cloned() {
+                input_map.insert(GameAction::MoveUp, key_code);
+            }
+            for key_code in key_bindings.left.iter().cloned() {
+                input_map.insert(GameAction::MoveLeft, key_code);
+            }
+            for key_code in key_bindings.down.iter().cloned() {
+                input_map.insert(GameAction::MoveDown, key_code);
+            }
+            for key_code in key_bindings.right.iter().cloned() {
+                input_map.insert(GameAction::MoveRight, key_code);
+            }
+            for key_code in key_bindings.dash.iter().cloned() {
+                input_map.insert(GameAction::Dash, key_code);
+            }
+            for key_code in key_bindings.pause.iter().cloned() {
+                input_map.insert(GameAction::Pause, key_code);
+            }
+            for key_code in key_bindings.market.iter().cloned() {
+                input_map.insert(GameAction::OpenMarket, key_code);
+            }
+            for key_code in key_bindings.fire.iter().cloned() {
+                input_map.insert(GameAction::Fire, key_code);
+            }
+
+            // Fire manually fired weapons with the mouse.
+            input_map.insert(GameAction::Fire, MouseButton::Left);
         }
-        for key_code in key_bindings.market.iter().cloned() {
-            input_map.insert(GameAction::OpenMarket, key_code);
+
+        if uses_gamepad {
+            // Extend the input map from gamepad bindings.
+            input_map.insert(GameAction::Move, GamepadStick::LEFT);
+            for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+                input_map.insert(GameAction::MoveUp, gamepad_button);
+            }
+            for gamepad_button in key_bindings.gamepad_left.iter().cloned() {
+                input_map.insert(GameAction::MoveLeft, gamepad_button);
+            }
+            for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+                input_map.insert(GameAction::MoveDown, gamepad_button);
+            }
+            for gamepad_button in key_bindings.gamepad_right.iter().cloned() {
+                input_map.insert(GameAction::MoveRight, gamepad_button);
+            }
+            for gamepad_button in key_bindings.gamepad_dash.iter().cloned() {
+                input_map.insert(GameAction::Dash, gamepad_button);
+            }
+            for gamepad_button in key_bindings.gamepad_pause.iter().cloned() {
+                input_map.insert(GameAction::Pause, gamepad_button);
+            }
+            for gamepad_button in key_bindings.gamepad_market.iter().cloned() {
+                input_map.insert(GameAction::OpenMarket, gamepad_button);
+            }
+            for gamepad_button in key_bindings.gamepad_fire.iter().cloned() {
+                input_map.insert(GameAction::Fire, gamepad_button);
+            }
+
+            if let Some(gamepad) = assigned_gamepad {
+                input_map.set_gamepad(gamepad);
+            }
         }
 
-        // Insert the input map resource.
-        app.insert_resource(input_map);
+        input_map
     }
 }
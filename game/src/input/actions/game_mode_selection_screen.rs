@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+/// Actions that can be performed in the game mode selection screen.
+#[derive(Actionlike, Clone, Copy, Debug, Eq, Hash, PartialEq, Reflect)]
+pub enum GameModeSelectionScreenAction {
+    Back,
+    Up,
+    Down,
+    Select,
+}
+
+impl GameModeSelectionScreenAction {
+    /// Sets up the action.
+    pub fn setup(app: &mut App) {
+        // Add input manager plugin.
+        app.add_plugins(InputManagerPlugin::<GameModeSelectionScreenAction>::default());
+
+        // Create the input map.
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = GameModeSelectionScreenAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<GameModeSelectionScreenAction> {
+        let mut input_map = InputMap::default();
+
+        // Extend the input map from key bindings.
+        for key_code in key_bindings.back.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Back, key_code);
+        }
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Select, key_code);
+        }
+        for key_code in key_bindings.up.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Up, key_code);
+        }
+        for key_code in key_bindings.down.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Down, key_code);
+        }
+
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_back.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Back, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Up, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Down, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(GameModeSelectionScreenAction::Select, gamepad_button);
+        }
+
+        input_map
+    }
+}
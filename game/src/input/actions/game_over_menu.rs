@@ -15,10 +15,21 @@ impl GameOverMenuAction {
         app.add_plugins(InputManagerPlugin::<GameOverMenuAction>::default());
 
         // Create the input map.
-        let mut input_map = InputMap::new([(GameOverMenuAction::Select, KeyCode::Enter)]);
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = GameOverMenuAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<GameOverMenuAction> {
+        let mut input_map = InputMap::default();
 
         // Extend the input map from key bindings.
-        let key_bindings = app.world_mut().resource::<Persistent<KeyBindings>>();
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(GameOverMenuAction::Select, key_code);
+        }
         for key_code in key_bindings.up.iter().cloned() {
             input_map.insert(GameOverMenuAction::Up, key_code);
         }
@@ -26,7 +37,17 @@ impl GameOverMenuAction {
             input_map.insert(GameOverMenuAction::Down, key_code);
         }
 
-        // Insert the input map resource.
-        app.insert_resource(input_map);
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+            input_map.insert(GameOverMenuAction::Up, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+            input_map.insert(GameOverMenuAction::Down, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(GameOverMenuAction::Select, gamepad_button);
+        }
+
+        input_map
     }
 }
@@ -18,10 +18,21 @@ impl LevelUpScreenAction {
         app.add_plugins(InputManagerPlugin::<LevelUpScreenAction>::default());
 
         // Create the input map.
-        let mut input_map = InputMap::new([(LevelUpScreenAction::Select, KeyCode::Enter)]);
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = LevelUpScreenAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<LevelUpScreenAction> {
+        let mut input_map = InputMap::default();
 
         // Extend the input map from key bindings.
-        let key_bindings = app.world_mut().resource::<Persistent<KeyBindings>>();
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(LevelUpScreenAction::Select, key_code);
+        }
         for key_code in key_bindings.pause.iter().cloned() {
             input_map.insert(LevelUpScreenAction::Pause, key_code);
         }
@@ -38,7 +49,26 @@ impl LevelUpScreenAction {
             input_map.insert(LevelUpScreenAction::Right, key_code);
         }
 
-        // Insert the input map resource.
-        app.insert_resource(input_map);
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_pause.iter().cloned() {
+            input_map.insert(LevelUpScreenAction::Pause, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+            input_map.insert(LevelUpScreenAction::Up, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+            input_map.insert(LevelUpScreenAction::Down, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_left.iter().cloned() {
+            input_map.insert(LevelUpScreenAction::Left, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_right.iter().cloned() {
+            input_map.insert(LevelUpScreenAction::Right, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(LevelUpScreenAction::Select, gamepad_button);
+        }
+
+        input_map
     }
 }
@@ -19,10 +19,21 @@ impl MarketAction {
         app.add_plugins(InputManagerPlugin::<MarketAction>::default());
 
         // Create the input map.
-        let mut input_map = InputMap::new([(MarketAction::Select, KeyCode::Enter)]);
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = MarketAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<MarketAction> {
+        let mut input_map = InputMap::default();
 
         // Extend the input map from key bindings.
-        let key_bindings = app.world_mut().resource::<Persistent<KeyBindings>>();
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(MarketAction::Select, key_code);
+        }
         for key_code in key_bindings.pause.iter().cloned() {
             input_map.insert(MarketAction::Pause, key_code);
         }
@@ -42,7 +53,32 @@ impl MarketAction {
             input_map.insert(MarketAction::Right, key_code);
         }
 
-        // Insert the input map resource.
-        app.insert_resource(input_map);
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_pause.iter().cloned() {
+            input_map.insert(MarketAction::Pause, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_market.iter().cloned() {
+            input_map.insert(MarketAction::Close, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_back.iter().cloned() {
+            input_map.insert(MarketAction::Close, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+            input_map.insert(MarketAction::Up, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+            input_map.insert(MarketAction::Down, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_left.iter().cloned() {
+            input_map.insert(MarketAction::Left, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_right.iter().cloned() {
+            input_map.insert(MarketAction::Right, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(MarketAction::Select, gamepad_button);
+        }
+
+        input_map
     }
 }
@@ -1,4 +1,6 @@
+mod achievements_screen;
 mod game;
+mod game_mode_selection_screen;
 mod game_over_menu;
 mod global;
 mod level_up_screen;
@@ -7,9 +9,12 @@ mod market;
 mod pause_menu;
 mod player_selection_screen;
 mod settings_menu;
+mod unlock_screen;
 
 pub use {
+    achievements_screen::AchievementsScreenAction,
     game::GameAction,
+    game_mode_selection_screen::GameModeSelectionScreenAction,
     game_over_menu::GameOverMenuAction,
     global::GlobalAction,
     level_up_screen::LevelUpScreenAction,
@@ -18,4 +23,5 @@ pub use {
     pause_menu::PauseMenuAction,
     player_selection_screen::PlayerSelectionScreenAction,
     settings_menu::SettingsMenuAction,
+    unlock_screen::UnlockScreenAction,
 };
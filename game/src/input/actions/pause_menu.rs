@@ -16,13 +16,27 @@ impl PauseMenuAction {
         app.add_plugins(InputManagerPlugin::<PauseMenuAction>::default());
 
         // Create the input map.
-        let mut input_map = InputMap::new([
-            (PauseMenuAction::Resume, KeyCode::Escape),
-            (PauseMenuAction::Select, KeyCode::Enter),
-        ]);
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = PauseMenuAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<PauseMenuAction> {
+        let mut input_map = InputMap::default();
 
         // Extend the input map from key bindings.
-        let key_bindings = app.world_mut().resource::<Persistent<KeyBindings>>();
+        for key_code in key_bindings.pause.iter().cloned() {
+            input_map.insert(PauseMenuAction::Resume, key_code);
+        }
+        for key_code in key_bindings.back.iter().cloned() {
+            input_map.insert(PauseMenuAction::Resume, key_code);
+        }
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(PauseMenuAction::Select, key_code);
+        }
         for key_code in key_bindings.up.iter().cloned() {
             input_map.insert(PauseMenuAction::Up, key_code);
         }
@@ -30,7 +44,23 @@ impl PauseMenuAction {
             input_map.insert(PauseMenuAction::Down, key_code);
         }
 
-        // Insert the input map resource.
-        app.insert_resource(input_map);
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_pause.iter().cloned() {
+            input_map.insert(PauseMenuAction::Resume, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_back.iter().cloned() {
+            input_map.insert(PauseMenuAction::Resume, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+            input_map.insert(PauseMenuAction::Up, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+            input_map.insert(PauseMenuAction::Down, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(PauseMenuAction::Select, gamepad_button);
+        }
+
+        input_map
     }
 }
@@ -16,13 +16,24 @@ impl PlayerSelectionScreenAction {
         app.add_plugins(InputManagerPlugin::<PlayerSelectionScreenAction>::default());
 
         // Create the input map.
-        let mut input_map = InputMap::new([
-            (PlayerSelectionScreenAction::Back, KeyCode::Escape),
-            (PlayerSelectionScreenAction::Select, KeyCode::Enter),
-        ]);
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = PlayerSelectionScreenAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<PlayerSelectionScreenAction> {
+        let mut input_map = InputMap::default();
 
         // Extend the input map from key bindings.
-        let key_bindings = app.world_mut().resource::<Persistent<KeyBindings>>();
+        for key_code in key_bindings.back.iter().cloned() {
+            input_map.insert(PlayerSelectionScreenAction::Back, key_code);
+        }
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(PlayerSelectionScreenAction::Select, key_code);
+        }
         for key_code in key_bindings.up.iter().cloned() {
             input_map.insert(PlayerSelectionScreenAction::Up, key_code);
         }
@@ -30,7 +41,20 @@ impl PlayerSelectionScreenAction {
             input_map.insert(PlayerSelectionScreenAction::Down, key_code);
         }
 
-        // Insert the input map resource.
-        app.insert_resource(input_map);
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_back.iter().cloned() {
+            input_map.insert(PlayerSelectionScreenAction::Back, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+            input_map.insert(PlayerSelectionScreenAction::Up, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+            input_map.insert(PlayerSelectionScreenAction::Down, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(PlayerSelectionScreenAction::Select, gamepad_button);
+        }
+
+        input_map
     }
 }
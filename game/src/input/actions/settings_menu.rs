@@ -18,13 +18,24 @@ impl SettingsMenuAction {
         app.add_plugins(InputManagerPlugin::<SettingsMenuAction>::default());
 
         // Create the input map.
-        let mut input_map = InputMap::new([
-            (SettingsMenuAction::Back, KeyCode::Escape),
-            (SettingsMenuAction::Select, KeyCode::Enter),
-        ]);
+        let key_bindings = app.world().resource::<Persistent<KeyBindings>>();
+        let input_map = SettingsMenuAction::input_map(key_bindings);
+
+        // Insert the input map resource.
+        app.insert_resource(input_map);
+    }
+
+    /// Creates the input map from key bindings.
+    pub fn input_map(key_bindings: &KeyBindings) -> InputMap<SettingsMenuAction> {
+        let mut input_map = InputMap::default();
 
         // Extend the input map from key bindings.
-        let key_bindings = app.world_mut().resource::<Persistent<KeyBindings>>();
+        for key_code in key_bindings.back.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Back, key_code);
+        }
+        for key_code in key_bindings.select.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Select, key_code);
+        }
         for key_code in key_bindings.up.iter().cloned() {
             input_map.insert(SettingsMenuAction::Up, key_code);
         }
@@ -38,7 +49,26 @@ impl SettingsMenuAction {
             input_map.insert(SettingsMenuAction::Right, key_code);
         }
 
-        // Insert the input map resource.
-        app.insert_resource(input_map);
+        // Extend the input map from gamepad bindings.
+        for gamepad_button in key_bindings.gamepad_back.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Back, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_up.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Up, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_down.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Down, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_left.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Left, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_right.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Right, gamepad_button);
+        }
+        for gamepad_button in key_bindings.gamepad_select.iter().cloned() {
+            input_map.insert(SettingsMenuAction::Select, gamepad_button);
+        }
+
+        input_map
     }
 }
@@ -12,6 +12,9 @@ impl Plugin for InputPlugin {
         GlobalAction::setup(app);
         MainMenuAction::setup(app);
         SettingsMenuAction::setup(app);
+        UnlockScreenAction::setup(app);
+        AchievementsScreenAction::setup(app);
+        GameModeSelectionScreenAction::setup(app);
         PlayerSelectionScreenAction::setup(app);
         GameAction::setup(app);
         LevelUpScreenAction::setup(app);
@@ -21,8 +24,17 @@ impl Plugin for InputPlugin {
 
         // Add systems.
         {
+            app.add_systems(Update, assign_gamepads);
             app.add_systems(Update, pause_on_losing_focus.in_set(GameplaySystems::Input));
 
+            app.add_systems(
+                PostUpdate,
+                rebuild_input_maps.run_if(
+                    resource_changed::<Persistent<KeyBindings>>
+                        .and_then(not(resource_added::<Persistent<KeyBindings>>)),
+                ),
+            );
+
             app.add_systems(Update, toggle_fullscreen);
             app.add_systems(Update, toggle_diagnostics_overlay);
 
@@ -22,6 +22,130 @@ pub fn pause_on_losing_focus(
 }
 
 
+/// Rebuilds input maps from key bindings.
+pub fn rebuild_input_maps(
+    mut commands: Commands,
+    key_bindings: Res<Persistent<KeyBindings>>,
+    number_of_players: Res<NumberOfPlayers>,
+    player_gamepads: Res<PlayerGamepads>,
+    mut main_menu_input_map_query: Query<&mut InputMap<MainMenuAction>>,
+    mut settings_menu_input_map_query: Query<&mut InputMap<SettingsMenuAction>>,
+    mut unlock_screen_input_map_query: Query<&mut InputMap<UnlockScreenAction>>,
+    mut achievements_screen_input_map_query: Query<&mut InputMap<AchievementsScreenAction>>,
+    mut game_mode_selection_screen_input_map_query: Query<
+        &mut InputMap<GameModeSelectionScreenAction>,
+    >,
+    mut player_selection_screen_input_map_query: Query<&mut InputMap<PlayerSelectionScreenAction>>,
+    mut game_input_map_query: Query<(&PlayerIndex, &mut InputMap<GameAction>)>,
+    mut level_up_screen_input_map_query: Query<&mut InputMap<LevelUpScreenAction>>,
+    mut market_input_map_query: Query<&mut InputMap<MarketAction>>,
+    mut pause_menu_input_map_query: Query<&mut InputMap<PauseMenuAction>>,
+    mut game_over_menu_input_map_query: Query<&mut InputMap<GameOverMenuAction>>,
+) {
+    let main_menu_input_map = MainMenuAction::input_map(&key_bindings);
+    for mut input_map in main_menu_input_map_query.iter_mut() {
+        *input_map = main_menu_input_map.clone();
+    }
+    commands.insert_resource(main_menu_input_map);
+
+    let settings_menu_input_map = SettingsMenuAction::input_map(&key_bindings);
+    for mut input_map in settings_menu_input_map_query.iter_mut() {
+        *input_map = settings_menu_input_map.clone();
+    }
+    commands.insert_resource(settings_menu_input_map);
+
+    let unlock_screen_input_map = UnlockScreenAction::input_map(&key_bindings);
+    for mut input_map in unlock_screen_input_map_query.iter_mut() {
+        *input_map = unlock_screen_input_map.clone();
+    }
+    commands.insert_resource(unlock_screen_input_map);
+
+    let achievements_screen_input_map = AchievementsScreenAction::input_map(&key_bindings);
+    for mut input_map in achievements_screen_input_map_query.iter_mut() {
+        *input_map = achievements_screen_input_map.clone();
+    }
+    commands.insert_resource(achievements_screen_input_map);
+
+    let game_mode_selection_screen_input_map =
+        GameModeSelectionScreenAction::input_map(&key_bindings);
+    for mut input_map in game_mode_selection_screen_input_map_query.iter_mut() {
+        *input_map = game_mode_selection_screen_input_map.clone();
+    }
+    commands.insert_resource(game_mode_selection_screen_input_map);
+
+    let player_selection_screen_input_map = PlayerSelectionScreenAction::input_map(&key_bindings);
+    for mut input_map in player_selection_screen_input_map_query.iter_mut() {
+        *input_map = player_selection_screen_input_map.clone();
+    }
+    commands.insert_resource(player_selection_screen_input_map);
+
+    for (player_index, mut input_map) in game_input_map_query.iter_mut() {
+        *input_map = GameAction::input_map_of(
+            *player_index,
+            *number_of_players,
+            &key_bindings,
+            &player_gamepads,
+        );
+    }
+
+    let level_up_screen_input_map = LevelUpScreenAction::input_map(&key_bindings);
+    for mut input_map in level_up_screen_input_map_query.iter_mut() {
+        *input_map = level_up_screen_input_map.clone();
+    }
+    commands.insert_resource(level_up_screen_input_map);
+
+    let market_input_map = MarketAction::input_map(&key_bindings);
+    for mut input_map in market_input_map_query.iter_mut() {
+        *input_map = market_input_map.clone();
+    }
+    commands.insert_resource(market_input_map);
+
+    let pause_menu_input_map = PauseMenuAction::input_map(&key_bindings);
+    for mut input_map in pause_menu_input_map_query.iter_mut() {
+        *input_map = pause_menu_input_map.clone();
+    }
+    commands.insert_resource(pause_menu_input_map);
+
+    let game_over_menu_input_map = GameOverMenuAction::input_map(&key_bindings);
+    for mut input_map in game_over_menu_input_map_query.iter_mut() {
+        *input_map = game_over_menu_input_map.clone();
+    }
+    commands.insert_resource(game_over_menu_input_map);
+}
+
+
+/// Assigns connected gamepads to the local players.
+pub fn assign_gamepads(
+    mut gamepad_connection_event_reader: EventReader<GamepadConnectionEvent>,
+    mut game_input_map_query: Query<(&PlayerIndex, &mut InputMap<GameAction>)>,
+    key_bindings: Res<Persistent<KeyBindings>>,
+    number_of_players: Res<NumberOfPlayers>,
+    mut player_gamepads: ResMut<PlayerGamepads>,
+) {
+    let mut assignments_changed = false;
+    for event in gamepad_connection_event_reader.read() {
+        if event.connected() {
+            assignments_changed |= player_gamepads.connect(event.gamepad);
+        } else {
+            assignments_changed |= player_gamepads.disconnect(event.gamepad);
+        }
+    }
+
+    if !assignments_changed {
+        return;
+    }
+
+    for (player_index, mut input_map) in game_input_map_query.iter_mut() {
+        *input_map = GameAction::input_map_of(
+            *player_index,
+            *number_of_players,
+            &key_bindings,
+            &player_gamepads,
+        );
+    }
+}
+
+
 /// Toggles the window mode between fullscreen and windowed.
 #[cfg(feature = "native")]
 pub fn toggle_fullscreen(
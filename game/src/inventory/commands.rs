@@ -5,6 +5,9 @@ use crate::prelude::*;
 #[command(name = "inventory")]
 #[command(disable_help_flag = true)]
 pub struct InventoryCommand {
+    /// Player to control the inventory of (e.g., 2 for the second player).
+    #[arg(short, long, default_value = "1")]
+    pub player: usize,
     #[clap(subcommand)]
     pub subcommand: InventoryCommands,
 }
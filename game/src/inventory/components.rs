@@ -4,3 +4,50 @@ use crate::prelude::*;
 /// Component for the base orientation of weapons in the inventory.
 #[derive(Clone, Copy, Component, Debug, Deref, DerefMut, Reflect)]
 pub struct BaseOrientation(pub Quat);
+
+
+/// Component for the inventory of a player.
+#[derive(Component, Debug, Default)]
+pub struct Inventory {
+    pub items: Vec<Arc<ItemInstance>>,
+    pub items_to_add: Vec<ItemInstance>,
+    pub items_to_remove: Vec<Arc<ItemInstance>>,
+    pub stashed_items: Vec<ItemInstance>,
+}
+
+impl Inventory {
+    /// Adds an item to the inventory.
+    pub fn add(&mut self, item: ItemInstance) {
+        self.items_to_add.push(item);
+    }
+
+    /// Removes an item from the inventory.
+    pub fn remove(&mut self, item: Arc<ItemInstance>) {
+        self.items_to_remove.push(item);
+    }
+
+    /// Stashes the items in the inventory until they are restored.
+    ///
+    /// Stashed items are released from the world, so they don't attack, but they are kept
+    /// to be acquired again when the inventory is restored (e.g., when a downed player is revived).
+    pub fn stash(&mut self) {
+        for item in self.items.clone() {
+            self.stashed_items.push(item.instantiate());
+            self.remove(item);
+        }
+    }
+
+    /// Restores the stashed items to the inventory.
+    pub fn restore(&mut self) {
+        let stashed_items = std::mem::take(&mut self.stashed_items);
+        self.items_to_add.extend(stashed_items);
+    }
+}
+
+impl Deref for Inventory {
+    type Target = Vec<Arc<ItemInstance>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
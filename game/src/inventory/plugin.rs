@@ -6,7 +6,7 @@ use crate::{
     prelude::*,
 };
 
-/// Plugin for managing the inventory of the player.
+/// Plugin for managing the inventories of the players.
 pub struct InventoryPlugin;
 
 impl Plugin for InventoryPlugin {
@@ -14,9 +14,6 @@ impl Plugin for InventoryPlugin {
         // Register components.
         app.register_type::<BaseOrientation>();
 
-        // Insert resources.
-        app.init_resource::<Inventory>();
-
         // Add console commands.
         app.add_console_command::<InventoryCommand, _>(apply_inventory_command);
 
@@ -30,7 +27,11 @@ impl Plugin for InventoryPlugin {
         app.add_systems(
             Last,
             (
-                acquire_release_items.run_if(|inventory: Res<Inventory>| inventory.is_changed()),
+                acquire_release_items.run_if(
+                    |inventory_query: Query<Entity, Changed<Inventory>>| {
+                        !inventory_query.is_empty()
+                    },
+                ),
                 reposition_weapons.run_if(
                     |weapon_query: Query<Entity, Added<Weapon>>,
                      player_query: Query<&Collider, (With<Player>, Changed<Collider>)>| {
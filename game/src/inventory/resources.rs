@@ -22,32 +22,3 @@ impl Deref for ItemInstance {
         &self.item
     }
 }
-
-
-/// Resource for the inventory.
-#[derive(Debug, Default, Resource)]
-pub struct Inventory {
-    pub items: Vec<Arc<ItemInstance>>,
-    pub items_to_add: Vec<ItemInstance>,
-    pub items_to_remove: Vec<Arc<ItemInstance>>,
-}
-
-impl Inventory {
-    /// Adds an item to the inventory.
-    pub fn add(&mut self, item: ItemInstance) {
-        self.items_to_add.push(item);
-    }
-
-    /// Removes an item from the inventory.
-    pub fn remove(&mut self, item: Arc<ItemInstance>) {
-        self.items_to_remove.push(item);
-    }
-}
-
-impl Deref for Inventory {
-    type Target = Vec<Arc<ItemInstance>>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.items
-    }
-}
@@ -6,11 +6,27 @@ use crate::{
 
 /// Applies the inventory console commands.
 pub fn apply_inventory_command(
-    mut inventory: ResMut<Inventory>,
+    mut player_query: Query<(&PlayerIndex, &mut Inventory), With<Player>>,
     item_registry: Res<ItemRegistry>,
     mut command: ConsoleCommand<InventoryCommand>,
 ) {
-    if let Some(Ok(InventoryCommand { subcommand })) = command.take() {
+    if let Some(Ok(InventoryCommand { player, subcommand })) = command.take() {
+        if player_query.is_empty() {
+            reply!(command, "Not available outside the game.");
+            reply!(command, "");
+            return;
+        }
+
+        let mut inventory =
+            match player_query.iter_mut().find(|(player_index, _)| player_index.0 + 1 == player) {
+                Some((_, inventory)) => inventory,
+                None => {
+                    reply!(command, "Player {} doesn't exist.", player);
+                    reply!(command, "");
+                    return;
+                },
+            };
+
         match subcommand {
             InventoryCommands::List => {
                 if inventory.is_empty() {
@@ -43,11 +59,11 @@ pub fn apply_inventory_command(
 }
 
 
-/// Adds the items specified in the inventory argument to the inventory.
+/// Adds the items specified in the inventory argument to the inventory of every player.
 pub fn load_inventory_when_starting_in_game(
     args: Res<Args>,
     item_registry: Res<ItemRegistry>,
-    mut inventory: ResMut<Inventory>,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
 ) {
     if !args.start_in_game {
         return;
@@ -58,7 +74,9 @@ pub fn load_inventory_when_starting_in_game(
         for item_id in &args.start_in_game_inventory {
             match item_registry.find_item_by_id(item_id) {
                 Some(item) => {
-                    inventory.add(item.instantiate());
+                    for mut inventory in inventory_query.iter_mut() {
+                        inventory.add(item.instantiate());
+                    }
                 },
                 None => {
                     log::error!(
@@ -74,56 +92,72 @@ pub fn load_inventory_when_starting_in_game(
 
 /// Acquires and releases items.
 pub fn acquire_release_items(world: &mut World) {
-    let mut inventory = world.resource_mut::<Inventory>();
+    let player_entities =
+        world.query_filtered::<Entity, With<Player>>().iter(world).collect::<Vec<_>>();
+
+    for player_entity in player_entities {
+        let mut inventory = match world.get_mut::<Inventory>(player_entity) {
+            Some(inventory) => inventory,
+            None => continue,
+        };
+        if inventory.items_to_add.is_empty() && inventory.items_to_remove.is_empty() {
+            continue;
+        }
 
-    let items_to_acquire = std::mem::take(&mut inventory.items_to_add);
-    let items_to_release = std::mem::take(&mut inventory.items_to_remove);
+        let items_to_acquire = std::mem::take(&mut inventory.items_to_add);
+        let items_to_release = std::mem::take(&mut inventory.items_to_remove);
 
-    for item_to_release in &items_to_release {
-        if let Some(index) =
-            inventory.items.iter().position(|item| Arc::ptr_eq(item, item_to_release))
-        {
-            inventory.items.remove(index);
+        for item_to_release in &items_to_release {
+            if let Some(index) =
+                inventory.items.iter().position(|item| Arc::ptr_eq(item, item_to_release))
+            {
+                inventory.items.remove(index);
+            }
         }
-    }
-    for item_to_release in items_to_release {
-        if let Some(entity) = item_to_release.entity {
-            item_to_release.release(world, entity);
+        let number_of_existing_items = inventory.items.len();
+
+        for item_to_release in items_to_release {
+            if let Some(entity) = item_to_release.entity {
+                item_to_release.release(world, entity);
+            }
         }
-    }
 
-    let mut new_items = Vec::with_capacity(items_to_acquire.len());
-    for mut item_to_acquire in items_to_acquire {
-        let new_item_entity = item_to_acquire.acquire(world);
+        let mut new_items = Vec::with_capacity(items_to_acquire.len());
+        for mut item_to_acquire in items_to_acquire {
+            let new_item_entity = item_to_acquire.acquire(world);
+            let new_item_index = number_of_existing_items + new_items.len() + 1;
 
-        let mut new_item_entity_commands = world.entity_mut(new_item_entity);
-        new_item_entity_commands.insert(Item);
+            let mut new_item_entity_commands = world.entity_mut(new_item_entity);
+            new_item_entity_commands.insert((
+                Name::new(format!("Item {} [{}]", new_item_index, item_to_acquire.id())),
+                Item,
+            ));
 
-        if item_to_acquire.is_weapon() {
-            new_item_entity_commands.insert(Weapon);
-        }
+            if item_to_acquire.is_weapon() {
+                new_item_entity_commands.insert(Weapon);
+            }
 
-        item_to_acquire.entity = Some(new_item_entity);
-        new_items.push(Arc::new(item_to_acquire));
-    }
+            item_to_acquire.entity = Some(new_item_entity);
+            new_items.push(Arc::new(item_to_acquire));
+        }
 
-    if let Ok(player_entity) = world.query_filtered::<Entity, With<Player>>().get_single(world) {
         for new_item in &new_items {
             if let Some(new_item_entity) = new_item.entity {
                 world.entity_mut(player_entity).add_child(new_item_entity);
             }
         }
-    }
 
-    let mut inventory = world.resource_mut::<Inventory>();
-    inventory.items.extend(new_items);
+        if let Some(mut inventory) = world.get_mut::<Inventory>(player_entity) {
+            inventory.items.extend(new_items);
+        }
+    }
 }
 
 
-/// Repositions the weapons around the player.
+/// Repositions the weapons around the players.
 pub fn reposition_weapons(
     mut commands: Commands,
-    player_query: Query<(&GlobalTransform, &Collider), With<Player>>,
+    player_query: Query<(&GlobalTransform, &Collider, &Children), With<Player>>,
     mut weapon_query: Query<(Entity, &mut Transform), (With<Weapon>, Without<Player>)>,
     mut spatial_query: SpatialQuery,
 ) {
@@ -135,60 +169,80 @@ pub fn reposition_weapons(
 
     log::info!("repositioning weapons");
 
-    let (player_global_transform, player_collider) = match player_query.get_single() {
-        Ok(query_result) => query_result,
-        Err(_) => return,
-    };
-    let player_position = player_global_transform.translation().xy();
+    for (player_global_transform, player_collider, player_children) in player_query.iter() {
+        let player_position = player_global_transform.translation().xy();
 
-    let player_aabb = player_collider.aabb(player_position.xy(), 0.00);
-    let max_distance = player_aabb.min.distance(player_aabb.max);
+        let player_aabb = player_collider.aabb(player_position.xy(), 0.00);
+        let max_distance = player_aabb.min.distance(player_aabb.max);
 
-    let mut direction = Vec2::X;
-    let rotation = Rotation::degrees(360.00 / (weapon_query.iter().len() as f32));
+        let player_weapons = player_children
+            .iter()
+            .filter(|&&child| weapon_query.contains(child))
+            .cloned()
+            .collect::<Vec<_>>();
+        if player_weapons.is_empty() {
+            continue;
+        }
 
-    for (weapon_index, (weapon_entity, mut weapon_transform)) in weapon_query.iter_mut().enumerate()
-    {
-        let distance = spatial_query
-            .cast_ray(
-                player_position.xy(),
-                Dir2::new(direction).unwrap(),
-                max_distance,
-                false,
-                SpatialQueryFilter::from_mask([Layer::Player]),
-            )
-            .map(|hit| hit.time_of_impact)
-            .unwrap_or(max_distance);
-
-        let new_weapon_translation = (direction * distance) * 1.05;
-
-        weapon_transform.translation.x = new_weapon_translation.x;
-        weapon_transform.translation.y = new_weapon_translation.y;
-
-        weapon_transform.rotation =
-            Quat::from_rotation_z(rotation.as_radians() * (weapon_index as Scalar));
-        commands
-            .entity(weapon_entity)
-            .insert(BaseOrientation(weapon_transform.rotation))
-            .remove::<EasingComponent<Transform>>()
-            .remove::<EasingChainComponent<Transform>>();
-
-        direction = rotation * direction;
+        let mut direction = Vec2::X;
+        let rotation = Rotation::degrees(360.00 / (player_weapons.len() as f32));
+
+        for (weapon_index, weapon_entity) in player_weapons.into_iter().enumerate() {
+            let (weapon_entity, mut weapon_transform) =
+                weapon_query.get_mut(weapon_entity).unwrap();
+
+            let distance = spatial_query
+                .cast_ray(
+                    player_position.xy(),
+                    Dir2::new(direction).unwrap(),
+                    max_distance,
+                    false,
+                    SpatialQueryFilter::from_mask([Layer::Player]),
+                )
+                .map(|hit| hit.time_of_impact)
+                .unwrap_or(max_distance);
+
+            let new_weapon_translation = (direction * distance) * 1.05;
+
+            weapon_transform.translation.x = new_weapon_translation.x;
+            weapon_transform.translation.y = new_weapon_translation.y;
+
+            weapon_transform.rotation =
+                Quat::from_rotation_z(rotation.as_radians() * (weapon_index as Scalar));
+            commands
+                .entity(weapon_entity)
+                .insert(BaseOrientation(weapon_transform.rotation))
+                .remove::<EasingComponent<Transform>>()
+                .remove::<EasingChainComponent<Transform>>();
+
+            direction = rotation * direction;
+        }
     }
 }
 
-/// Orients weapons toward enemies.
+/// Orients weapons toward enemies, or toward the aimed direction when aimed manually.
 pub fn orient_weapons(
     mut weapon_query: Query<
-        (&GlobalTransform, &mut Transform, &Range, Option<&BaseOrientation>),
+        (&GlobalTransform, &mut Transform, &Range, Option<&BaseOrientation>, Option<&ManualAim>),
         (With<Weapon>, Without<Attack>),
     >,
     enemy_hit_box_query: Query<&Position, With<EnemyHitBox>>,
     spatial_query: SpatialQuery,
 ) {
-    for (item_global_transform, mut item_transform, item_range, item_base_orientation) in
-        weapon_query.iter_mut()
+    for (
+        item_global_transform,
+        mut item_transform,
+        item_range,
+        item_base_orientation,
+        item_manual_aim,
+    ) in weapon_query.iter_mut()
     {
+        if let Some(item_manual_aim) = item_manual_aim {
+            item_transform.rotation =
+                Quat::from_rotation_z(Vec2::X.angle_between(item_manual_aim.as_vec2()));
+            continue;
+        }
+
         let search_area = Collider::circle(item_range.0 * 2.00);
 
         let item_position = Position(item_global_transform.translation().xy());
@@ -216,16 +270,25 @@ pub fn orient_weapons(
 }
 
 
-/// Clears the inventory.
+/// Clears the inventories.
 pub fn clear_inventory(world: &mut World) {
-    let mut inventory = world.resource_mut::<Inventory>();
-
-    inventory.items_to_add = Vec::new();
-    inventory.items_to_remove = Vec::new();
-
-    for item in std::mem::take(&mut inventory.items) {
-        if let Some(entity) = item.entity {
-            item.release(world, entity);
+    let player_entities =
+        world.query_filtered::<Entity, With<Player>>().iter(world).collect::<Vec<_>>();
+
+    for player_entity in player_entities {
+        let mut inventory = match world.get_mut::<Inventory>(player_entity) {
+            Some(inventory) => inventory,
+            None => continue,
+        };
+
+        inventory.items_to_add = Vec::new();
+        inventory.items_to_remove = Vec::new();
+        inventory.stashed_items = Vec::new();
+
+        for item in std::mem::take(&mut inventory.items) {
+            if let Some(entity) = item.entity {
+                item.release(world, entity);
+            }
         }
     }
 }
@@ -74,6 +74,7 @@ pub struct RegisteredItem {
     pub tags: SmallVec<[SmolStr; 3]>,
     pub base_price: Balance,
     pub commonness: u64,
+    pub unlock_cost: Option<Ambrosia>,
 }
 
 impl RegisteredItem {
@@ -81,7 +82,13 @@ impl RegisteredItem {
     pub fn new(item: impl IItem) -> RegisteredItem {
         let base_price = item.base_price();
         let commonness = item.commonness();
-        RegisteredItem { item: Arc::new(item), tags: SmallVec::new(), base_price, commonness }
+        RegisteredItem {
+            item: Arc::new(item),
+            tags: SmallVec::new(),
+            base_price,
+            commonness,
+            unlock_cost: None,
+        }
     }
 }
 
@@ -91,6 +98,17 @@ impl RegisteredItem {
         self.tags.push(tag.to_string().into());
         self
     }
+
+    /// Makes the item unlockable for the specified cost.
+    pub fn unlockable(&mut self, cost: Ambrosia) -> &mut RegisteredItem {
+        self.unlock_cost = Some(cost);
+        self
+    }
+
+    /// Gets whether the item is available in the progression.
+    pub fn is_available(&self, progression: &Progression) -> bool {
+        progression.is_available(UnlockableKind::Item, &self.id(), self.unlock_cost)
+    }
 }
 
 impl Deref for RegisteredItem {
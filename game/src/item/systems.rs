@@ -24,6 +24,9 @@ pub fn apply_item_command(
                         "    - commonness: {:}",
                         item.commonness.to_formatted_string(&NumLocale::es_US),
                     );
+                    if let Some(unlock_cost) = item.unlock_cost {
+                        reply!(command, "    - unlock cost: {}", unlock_cost);
+                    }
                 }
             },
             ItemCommands::BasePrice { subcommand } => {
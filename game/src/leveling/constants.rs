@@ -16,3 +16,13 @@ pub const DEFAULT_EXPERIENCE_POINT_ACCELERATION_PER_SECOND: Speed = Speed(1600.0
 
 /// Default maximum speed of experience points.
 pub const DEFAULT_EXPERIENCE_POINT_MAX_SPEED: Speed = Speed(900.00);
+
+
+/// Interval of merging experience points.
+pub const EXPERIENCE_POINT_MERGING_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Number of experience points on the map after which experience points start to merge.
+pub const EXPERIENCE_POINT_MERGING_THRESHOLD: usize = 100;
+
+/// Size of the cells experience points are merged within.
+pub const EXPERIENCE_POINT_MERGING_CELL_SIZE: f32 = 50.00;
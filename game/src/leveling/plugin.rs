@@ -22,6 +22,14 @@ impl Plugin for LevelingPlugin {
         app.register_type::<EnemyCounter>();
         app.register_type::<ExperienceRequiredToGetToCurrentLevel>();
         app.register_type::<ExperienceRequiredToLevelUp>();
+        app.register_type::<LeftoverExperiencePoints>();
+        app.register_type::<CarriedOverExperiencePoints>();
+        app.register_type::<ExperiencePointMergingTimer>();
+
+        // Insert resources.
+        app.init_resource::<LeftoverExperiencePoints>();
+        app.init_resource::<CarriedOverExperiencePoints>();
+        app.init_resource::<ExperiencePointMergingTimer>();
 
         // Add events.
         app.add_event::<ExperienceGainedEvent>();
@@ -40,9 +48,15 @@ impl Plugin for LevelingPlugin {
             OnEnter(GameState::Loading),
             initialize_experience_point_counter.in_set(LoadingSystems::Leveling),
         );
+        app.add_systems(
+            OnEnter(GameState::Loading),
+            spawn_carried_over_experience_points
+                .after(LoadingSystems::Map)
+                .before(LoadingSystems::Done),
+        );
         app.add_systems(
             Update,
-            (attract_experience_points, collect_experience_points)
+            (merge_experience_points, attract_experience_points, collect_experience_points)
                 .chain()
                 .in_set(GameplaySystems::Leveling),
         );
@@ -58,9 +72,22 @@ impl Plugin for LevelingPlugin {
             )
                 .chain(),
         );
+        app.add_systems(
+            PostUpdate,
+            (
+                record_perks_obtained_by_leveling_up,
+                record_skipped_level_ups,
+                apply_level_up_rewards,
+            )
+                .run_if(resource_exists::<LevelUpRewards>),
+        );
         app.add_systems(
             OnEnter(GameState::Won),
-            (despawn_experience_points, clear_experience_point_counter),
+            (
+                handle_leftover_experience_points,
+                (despawn_experience_points, clear_experience_point_counter),
+            )
+                .chain(),
         );
         app.add_systems(
             OnEnter(GameState::Over),
@@ -68,6 +95,9 @@ impl Plugin for LevelingPlugin {
                 despawn_experience_points,
                 clear_experience_point_counter,
                 clear_player_level_structure,
+                clear_carried_over_experience_points,
+                reset_leftover_experience_points,
+                clear_level_up_rewards,
             ),
         );
         app.add_systems(
@@ -76,6 +106,9 @@ impl Plugin for LevelingPlugin {
                 despawn_experience_points,
                 clear_experience_point_counter,
                 clear_player_level_structure,
+                clear_carried_over_experience_points,
+                reset_leftover_experience_points,
+                clear_level_up_rewards,
             )
                 .in_set(RestartSystems::Leveling),
         );
@@ -85,6 +118,9 @@ impl Plugin for LevelingPlugin {
                 despawn_experience_points,
                 clear_experience_point_counter,
                 clear_player_level_structure,
+                clear_carried_over_experience_points,
+                reset_leftover_experience_points,
+                clear_level_up_rewards,
             ),
         );
     }
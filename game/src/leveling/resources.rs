@@ -1,4 +1,7 @@
-use crate::prelude::*;
+use crate::{
+    leveling::constants::*,
+    prelude::*,
+};
 
 
 /// Database of registered leveling systems.
@@ -58,3 +61,79 @@ pub struct ExperienceRequiredToGetToCurrentLevel(pub Experience);
 #[derive(Clone, Copy, Debug, Default, Deref, DerefMut, Reflect, Resource)]
 #[reflect(Resource)]
 pub struct ExperienceRequiredToLevelUp(pub Experience);
+
+
+/// Resource for how experience points left on the map are handled when a wave is won.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect, Resource, ValueEnum)]
+#[reflect(Resource)]
+pub enum LeftoverExperiencePoints {
+    /// Leftover experience points are lost.
+    #[default]
+    Discard,
+    /// Leftover experience points are collected into the experience of the player.
+    Collect,
+    /// Leftover experience points are converted to balance.
+    Convert,
+    /// Leftover experience points are kept on the map in the next wave.
+    CarryOver,
+}
+
+impl Display for LeftoverExperiencePoints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeftoverExperiencePoints::Discard => write!(f, "discard"),
+            LeftoverExperiencePoints::Collect => write!(f, "collect"),
+            LeftoverExperiencePoints::Convert => write!(f, "convert"),
+            LeftoverExperiencePoints::CarryOver => write!(f, "carry-over"),
+        }
+    }
+}
+
+
+/// Experience point carried over to the next wave.
+#[derive(Clone, Debug, Reflect)]
+pub struct CarriedOverExperiencePoint {
+    pub position: Position,
+    pub experience: Experience,
+    pub size: f32,
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<ColorMaterial>,
+}
+
+
+/// Resource for the experience points carried over to the next wave.
+#[derive(Debug, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct CarriedOverExperiencePoints(pub Vec<CarriedOverExperiencePoint>);
+
+
+/// Resource for the timer of merging experience points.
+#[derive(Debug, Deref, DerefMut, Reflect, Resource)]
+pub struct ExperiencePointMergingTimer(pub Timer);
+
+impl Default for ExperiencePointMergingTimer {
+    fn default() -> ExperiencePointMergingTimer {
+        ExperiencePointMergingTimer(Timer::new(
+            EXPERIENCE_POINT_MERGING_INTERVAL,
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+
+/// Resource for the level up rewards obtained since the start of the game.
+///
+/// Game modes insert this resource during initialization to grant a point of health
+/// for each level up and to keep track of the perks obtained by leveling up.
+#[derive(Clone, Debug, Resource)]
+pub struct LevelUpRewards {
+    pub health: Health,
+    /// Perks obtained by leveling up, `None` for skipped level ups.
+    pub perks: Vec<Option<Arc<dyn IPerk>>>,
+}
+
+impl Default for LevelUpRewards {
+    fn default() -> LevelUpRewards {
+        LevelUpRewards { health: Health(0.00), perks: Vec::new() }
+    }
+}
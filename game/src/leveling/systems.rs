@@ -1,12 +1,15 @@
 use crate::{
-    leveling::commands::*,
+    leveling::{
+        commands::*,
+        constants::*,
+    },
     prelude::*,
 };
 
 
 /// Applies the experience console commands.
 pub fn apply_experience_command(
-    player_query: Query<(Entity, &Experience), With<Player>>,
+    player_query: Query<(Entity, &Experience), With<PrimaryPlayer>>,
     mut experience_gained_event_writer: EventWriter<ExperienceGainedEvent>,
     mut command: ConsoleCommand<ExperienceCommand>,
 ) {
@@ -41,7 +44,7 @@ pub fn apply_experience_command(
 /// Applies the level console commands.
 pub fn apply_level_command(
     mut commands: Commands,
-    mut player_query: Query<&Level, With<Player>>,
+    mut player_query: Query<&Level, With<PrimaryPlayer>>,
     registered_systems: Res<RegisteredSystems>,
     mut command: ConsoleCommand<LevelCommand>,
 ) {
@@ -69,7 +72,7 @@ pub fn apply_level_command(
 }
 
 
-/// Sets the level of the player.
+/// Sets the level of the party.
 pub fn set_level(In(mut level): In<Level>, world: &mut World) {
     let player_level_structure = world.resource::<PlayerLevelStructure>().clone();
 
@@ -93,7 +96,7 @@ pub fn set_level(In(mut level): In<Level>, world: &mut World) {
     };
 
     let (mut player_level, mut player_experience) = world
-        .query_filtered::<(&mut Level, &mut Experience), With<Player>>()
+        .query_filtered::<(&mut Level, &mut Experience), With<PrimaryPlayer>>()
         .get_single_mut(world)
         .unwrap();
     let old_level = *player_level;
@@ -111,7 +114,7 @@ pub fn set_level(In(mut level): In<Level>, world: &mut World) {
     world.insert_resource(ExperienceRequiredToLevelUp(experience_required_to_level_up));
 
     if level.0 > old_level.0 {
-        let player_entity = world.query_filtered::<Entity, With<Player>>().single(world);
+        let player_entity = world.query_filtered::<Entity, With<PrimaryPlayer>>().single(world);
         let mut leveled_up_events = world.resource_mut::<Events<LeveledUpEvent>>();
 
         let number_of_level_ups = level.get() - old_level.get();
@@ -181,7 +184,7 @@ pub fn initialize_player_level_structure(world: &mut World) {
     }
 
     let (mut player_level, mut player_experience) = world
-        .query_filtered::<(&mut Level, &mut Experience), With<Player>>()
+        .query_filtered::<(&mut Level, &mut Experience), With<PrimaryPlayer>>()
         .get_single_mut(world)
         .unwrap();
 
@@ -197,7 +200,7 @@ pub fn initialize_player_level_structure(world: &mut World) {
     world.insert_resource(ExperienceRequiredToLevelUp(experience_required_to_level_up));
     world.insert_resource(player_level_structure);
 
-    let player_entity = world.query_filtered::<Entity, With<Player>>().single(world);
+    let player_entity = world.query_filtered::<Entity, With<PrimaryPlayer>>().single(world);
     let mut leveled_up_events = world.resource_mut::<Events<LeveledUpEvent>>();
     for player_new_level in 2..=set_level.get() {
         leveled_up_events.send(LeveledUpEvent {
@@ -213,12 +216,12 @@ pub fn initialize_experience_point_counter(mut commands: Commands) {
 }
 
 
-/// Attracts the experience points inside player pickup area towards the player.
+/// Attracts the experience points inside player pickup areas towards the players.
 pub fn attract_experience_points(
     mut commands: Commands,
     experience_point_query: Query<Entity, With<ExperiencePoint>>,
     player_pickup_area_query: Query<&Parent, With<PlayerPickupArea>>,
-    player_query: Query<Entity, With<Player>>,
+    player_query: Query<Entity, (With<Player>, Without<Downed>)>,
     mut collision_started_event_reader: EventReader<CollisionStarted>,
 ) {
     let mut attract_if_applicable = |player_pickup_area_entity, experience_point_entity| {
@@ -226,8 +229,11 @@ pub fn attract_experience_points(
             Ok(query_result) => query_result,
             Err(_) => return,
         };
-        let player_entity = match player_pickup_area_query.get(player_pickup_area_entity) {
-            Ok(parent) => player_query.get(parent.get()).unwrap(),
+        let player_entity = match player_pickup_area_query
+            .get(player_pickup_area_entity)
+            .and_then(|parent| player_query.get(parent.get()))
+        {
+            Ok(player_entity) => player_entity,
             Err(_) => return,
         };
         commands.entity(experience_point_entity).insert(AttractedTo(player_entity));
@@ -240,9 +246,13 @@ pub fn attract_experience_points(
 }
 
 /// Collects experience points.
+///
+/// Experience collected by any of the players is gained by the primary player,
+/// as the level is shared by the party.
 pub fn collect_experience_points(
     mut commands: Commands,
-    player_query: Query<&Player>,
+    player_query: Query<&Player, Without<Downed>>,
+    primary_player_query: Query<Entity, With<PrimaryPlayer>>,
     experience_point_query: Query<(&Name, &Experience), (With<ExperiencePoint>, Without<Player>)>,
     mut collision_started_event_reader: EventReader<CollisionStarted>,
     mut experience_gained_event_writer: EventWriter<ExperienceGainedEvent>,
@@ -251,13 +261,17 @@ pub fn collect_experience_points(
         if player_query.get(player_entity).is_err() {
             return;
         }
+        let primary_player_entity = match primary_player_query.get_single() {
+            Ok(primary_player_entity) => primary_player_entity,
+            Err(_) => return,
+        };
         let (experience_point_name, experience_reward) =
             match experience_point_query.get(experience_point_entity) {
                 Ok(query_result) => query_result,
                 Err(_) => return,
             };
         experience_gained_event_writer.send(ExperienceGainedEvent {
-            entity: player_entity,
+            entity: primary_player_entity,
             experience: *experience_reward,
             by: format!("collecting \"{}\"", experience_point_name),
         });
@@ -287,11 +301,11 @@ pub fn gain_player_experience(
     }
 }
 
-/// Levels up the player.
+/// Levels up the party.
 pub fn level_player_up(world: &mut World) {
     loop {
         let mut system_state: SystemState<(
-            Query<(Entity, &Experience, &mut Level), With<Player>>,
+            Query<(Entity, &Experience, &mut Level), With<PrimaryPlayer>>,
             Res<ExperienceRequiredToLevelUp>,
             Res<PlayerLevelStructure>,
             EventWriter<LeveledUpEvent>,
@@ -357,6 +371,279 @@ pub fn level_player_up(world: &mut World) {
 }
 
 
+/// Merges experience points close to each other when there are too many of them on the map.
+///
+/// Experience points being attracted to players are never merged.
+pub fn merge_experience_points(
+    mut commands: Commands,
+    mut experience_point_query: Query<
+        (Entity, &Position, &mut Experience),
+        (With<ExperiencePoint>, Without<AttractedTo>),
+    >,
+    time: Res<Time>,
+    mut experience_point_merging_timer: ResMut<ExperiencePointMergingTimer>,
+) {
+    experience_point_merging_timer.tick(time.delta());
+    if !experience_point_merging_timer.just_finished() {
+        return;
+    }
+
+    if experience_point_query.iter().len() <= EXPERIENCE_POINT_MERGING_THRESHOLD {
+        return;
+    }
+
+    let mut cells = HashMap::<IVec2, Entity>::new();
+    let mut merged_experience = HashMap::<Entity, f64>::new();
+    let mut number_of_merged_experience_points = 0;
+
+    for (entity, position, experience) in experience_point_query.iter() {
+        let cell = (position.0 / EXPERIENCE_POINT_MERGING_CELL_SIZE).floor().as_ivec2();
+        match cells.get(&cell) {
+            Some(&target) => {
+                *merged_experience.entry(target).or_default() += experience.0;
+                commands.entity(entity).despawn_recursive();
+                number_of_merged_experience_points += 1;
+            },
+            None => {
+                cells.insert(cell, entity);
+            },
+        }
+    }
+
+    for (target, experience) in merged_experience {
+        if let Ok((_, _, mut target_experience)) = experience_point_query.get_mut(target) {
+            target_experience.0 += experience;
+        }
+    }
+
+    if number_of_merged_experience_points > 0 {
+        log::info!(
+            "merged {} experience points into {} experience points",
+            number_of_merged_experience_points + cells.len(),
+            cells.len(),
+        );
+    }
+}
+
+
+/// Applies the level up rewards when the level of the party changes.
+pub fn apply_level_up_rewards(
+    mut commands: Commands,
+    primary_player_query: Query<&Level, (With<PrimaryPlayer>, Changed<Level>)>,
+    mut player_query: Query<(&mut Health, &mut RemainingHealth), With<Player>>,
+    mut level_up_rewards: ResMut<LevelUpRewards>,
+    registered_systems: Res<RegisteredSystems>,
+) {
+    if let Ok(player_level) = primary_player_query.get_single() {
+        {
+            let expected_bonus_health = (player_level.get() as f32) - 1.00;
+            if level_up_rewards.health.0 != expected_bonus_health {
+                let difference = expected_bonus_health - level_up_rewards.health.0;
+
+                if difference > 0.00 {
+                    log::info!(
+                        "increasing the player health by {} for leveling up to level {}",
+                        difference,
+                        player_level.get(),
+                    );
+                } else {
+                    log::info!(
+                        "decreasing the player health by {} for leveling down to level {}",
+                        difference.abs(),
+                        player_level.get(),
+                    );
+                }
+
+                for (mut player_health, mut player_remaining_health) in player_query.iter_mut() {
+                    player_health.0 += difference;
+                    player_remaining_health.0 += difference;
+                }
+
+                level_up_rewards.health.0 += difference;
+            }
+        }
+
+        {
+            let expected_number_of_perks = (player_level.get() as usize) - 1;
+            if level_up_rewards.perks.len() > expected_number_of_perks {
+                let mut level_to_lose = level_up_rewards.perks.len();
+                for perk in level_up_rewards.perks[expected_number_of_perks..].iter_mut().rev() {
+                    if let Some(perk) = perk {
+                        commands.run_system_with_input(
+                            registered_systems.perk.lose_perk,
+                            (
+                                perk.clone(),
+                                ObtainLosePerkReason::LevelingDown {
+                                    to: Level::new(level_to_lose as u16),
+                                },
+                            ),
+                        );
+                    }
+                    level_to_lose -= 1;
+                }
+            }
+            level_up_rewards.perks.truncate(expected_number_of_perks);
+        }
+    }
+}
+
+/// Records the perks obtained by leveling up to the level up rewards.
+pub fn record_perks_obtained_by_leveling_up(
+    mut level_up_rewards: ResMut<LevelUpRewards>,
+    mut perk_obtained_event_reader: EventReader<PerkObtainedEvent>,
+) {
+    for event in perk_obtained_event_reader.read() {
+        if matches!(event.reason, ObtainLosePerkReason::LevelingUp { .. }) {
+            level_up_rewards.perks.push(Some(event.perk.clone()));
+        }
+    }
+}
+
+/// Records the skipped level ups to the level up rewards.
+pub fn record_skipped_level_ups(
+    mut level_up_rewards: ResMut<LevelUpRewards>,
+    mut level_up_skipped_event_reader: EventReader<LevelUpSkippedEvent>,
+) {
+    for event in level_up_skipped_event_reader.read() {
+        if event.level.is_some() {
+            level_up_rewards.perks.push(None);
+        }
+    }
+}
+
+
+/// Handles the experience points left on the map when the wave is won.
+pub fn handle_leftover_experience_points(world: &mut World) {
+    let mut system_state: SystemState<(
+        Query<
+            (&Position, &Experience, &Collider, &Mesh2dHandle, &Handle<ColorMaterial>),
+            With<ExperiencePoint>,
+        >,
+        Query<&mut Experience, (With<PrimaryPlayer>, Without<ExperiencePoint>)>,
+        Res<LeftoverExperiencePoints>,
+        Res<ExperienceToBalanceRatio>,
+        ResMut<Balance>,
+        ResMut<CarriedOverExperiencePoints>,
+    )> = SystemState::new(world);
+
+    let (
+        experience_point_query,
+        mut player_query,
+        leftover_experience_points,
+        experience_to_balance_ratio,
+        mut balance,
+        mut carried_over_experience_points,
+    ) = system_state.get_mut(world);
+
+    let number_of_leftover_experience_points = experience_point_query.iter().len();
+    if number_of_leftover_experience_points == 0 {
+        return;
+    }
+
+    let leftover_experience = Experience(
+        experience_point_query.iter().map(|(_, experience, _, _, _)| experience.0).sum(),
+    );
+
+    let mut collected = false;
+    match *leftover_experience_points {
+        LeftoverExperiencePoints::Discard => {
+            log::info!(
+                "discarding {} leftover experience points with {} experience",
+                number_of_leftover_experience_points,
+                leftover_experience,
+            );
+        },
+        LeftoverExperiencePoints::Collect => {
+            if let Ok(mut player_experience) = player_query.get_single_mut() {
+                player_experience.0 += leftover_experience.0;
+                log::info!(
+                    "player gained {} experience by collecting {} leftover experience points",
+                    leftover_experience,
+                    number_of_leftover_experience_points,
+                );
+                collected = true;
+            }
+        },
+        LeftoverExperiencePoints::Convert => {
+            balance.gain(
+                Balance(leftover_experience.0 * experience_to_balance_ratio.0),
+                format!(
+                    "converting {} leftover experience points with {} experience",
+                    number_of_leftover_experience_points, leftover_experience,
+                ),
+            );
+        },
+        LeftoverExperiencePoints::CarryOver => {
+            log::info!(
+                "carrying over {} leftover experience points with {} experience to the next wave",
+                number_of_leftover_experience_points,
+                leftover_experience,
+            );
+            for (position, experience, collider, mesh, material) in experience_point_query.iter() {
+                carried_over_experience_points.0.push(CarriedOverExperiencePoint {
+                    position: *position,
+                    experience: *experience,
+                    size: collider
+                        .shape()
+                        .as_ball()
+                        .map(|ball| ball.radius)
+                        .unwrap_or(DEFAULT_EXPERIENCE_POINT_SIZE),
+                    mesh: mesh.0.clone(),
+                    material: material.clone(),
+                });
+            }
+        },
+    }
+
+    if collected {
+        level_player_up(world);
+    }
+}
+
+/// Spawns the experience points carried over from the previous wave.
+pub fn spawn_carried_over_experience_points(
+    mut commands: Commands,
+    map_query: Query<Entity, With<Map>>,
+    mut carried_over_experience_points: ResMut<CarriedOverExperiencePoints>,
+    mut experience_point_counter: ResMut<ExperiencePointCounter>,
+) {
+    if carried_over_experience_points.0.is_empty() {
+        return;
+    }
+
+    let map_entity = match map_query.get_single() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+
+    log::info!(
+        "spawning {} experience points carried over from the previous wave",
+        carried_over_experience_points.0.len(),
+    );
+
+    for experience_point in carried_over_experience_points.0.drain(..) {
+        let mesh = MaterialMesh2dBundle {
+            mesh: experience_point.mesh.into(),
+            material: experience_point.material,
+            transform: Transform::from_translation(
+                experience_point.position.extend(Depth::ExperiencePoint.z()),
+            ),
+            ..default()
+        };
+        let experience_point_bundle = ExperiencePointBundle {
+            position: experience_point.position,
+            attraction_speed: ExperiencePointAttractionSpeed::default().0,
+            mesh,
+            collider: Collider::circle(experience_point.size),
+            experience: experience_point.experience,
+        };
+        experience_point_bundle
+            .spawn(&mut commands, &mut experience_point_counter)
+            .set_parent(map_entity);
+    }
+}
+
+
 /// Despawns experience points.
 pub fn despawn_experience_points(
     mut commands: Commands,
@@ -378,3 +665,18 @@ pub fn clear_player_level_structure(mut commands: Commands) {
     commands.remove_resource::<ExperienceRequiredToLevelUp>();
     commands.remove_resource::<PlayerLevelStructure>();
 }
+
+/// Clears the experience points carried over to the next wave.
+pub fn clear_carried_over_experience_points(mut commands: Commands) {
+    commands.insert_resource(CarriedOverExperiencePoints::default());
+}
+
+/// Resets the handling of the experience points left on the map when the wave is won.
+pub fn reset_leftover_experience_points(mut commands: Commands) {
+    commands.insert_resource(LeftoverExperiencePoints::default());
+}
+
+/// Clears the level up rewards.
+pub fn clear_level_up_rewards(mut commands: Commands) {
+    commands.remove_resource::<LevelUpRewards>();
+}
@@ -1,9 +1,14 @@
+pub mod accessibility;
+pub mod achievement;
+pub mod audio;
 pub mod camera;
 pub mod combat;
 pub mod configuration;
 pub mod console;
 pub mod core;
+pub mod daily_challenge;
 pub mod enemy;
+pub mod feedback;
 pub mod input;
 pub mod inventory;
 pub mod item;
@@ -12,11 +17,15 @@ pub mod map;
 pub mod market;
 pub mod mode;
 pub mod movement;
+pub mod mutator;
 pub mod perk;
 pub mod physics;
 pub mod player;
 pub mod plugin;
 pub mod prelude;
+pub mod progression;
 pub mod property;
 pub mod status_effect;
 pub mod ui;
+#[cfg(feature = "native")]
+pub mod video;
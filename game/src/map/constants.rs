@@ -1,2 +1,9 @@
 /// Thickness of map bounds.
 pub const BOUND_THICKNESS: f32 = 50.00;
+
+
+/// Default amount of space between grid elements.
+pub const DEFAULT_GRID_SPACING: f32 = 50.0;
+
+/// Default thickness of grid elements.
+pub const DEFAULT_GRID_WIDTH: f32 = 2.0;
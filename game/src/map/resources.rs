@@ -1,4 +1,7 @@
-use crate::prelude::*;
+use crate::{
+    map::constants::*,
+    prelude::*,
+};
 
 
 /// Resource for the bounds of the map.
@@ -9,3 +12,42 @@ pub struct MapBounds {
     pub y_min: f32,
     pub y_max: f32,
 }
+
+
+/// Grid of square cells to use as the map.
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct MapGrid {
+    /// Size of the grid. Value of 10 means the map will be a 10x10 grid of squares.
+    pub size: i32,
+    /// Amount of space between grid elements.
+    pub spacing: f32,
+    /// Thickness of grid elements.
+    pub width: f32,
+    /// Color of the grid.
+    pub color: Color,
+}
+
+impl MapGrid {
+    /// Creates a grid with the default spacing and width.
+    pub const fn new(size: i32, color: Color) -> MapGrid {
+        MapGrid { size, spacing: DEFAULT_GRID_SPACING, width: DEFAULT_GRID_WIDTH, color }
+    }
+}
+
+impl MapGrid {
+    /// Gets the length of the sides of the grid.
+    pub const fn length(&self) -> f32 {
+        (self.size as f32) * self.spacing
+    }
+
+    /// Gets the bounds of the map covered by the grid.
+    pub const fn bounds(&self) -> MapBounds {
+        let length = self.length();
+        MapBounds {
+            x_min: -(length / 2.00),
+            x_max: (length / 2.00),
+            y_min: -(length / 2.00),
+            y_max: (length / 2.00),
+        }
+    }
+}
@@ -16,3 +16,49 @@ pub fn find_obstacle(
         SpatialQueryFilter::from_mask([Layer::MapObstacle]),
     )
 }
+
+
+/// Spawns a map made of the `grid` and sets the bounds of the map accordingly.
+pub fn spawn_grid_map(commands: &mut Commands, grid: MapGrid) {
+    commands.insert_resource(grid.bounds());
+    commands.spawn((Name::new("Map"), Map, SpatialBundle::default())).with_children(|parent| {
+        // Spawn horizontal lines.
+        for i in 0..=grid.size {
+            parent.spawn((
+                Name::new(format!("Horizontal Line {}", i + 1)),
+                SpriteBundle {
+                    transform: Transform::from_translation(Vec3::new(
+                        0.00,
+                        (((grid.size as f32) / 2.00) - (i as f32)) * grid.spacing,
+                        Depth::Map.z(),
+                    )),
+                    sprite: Sprite {
+                        color: grid.color,
+                        custom_size: Some(Vec2::new(grid.length(), grid.width)),
+                        ..default()
+                    },
+                    ..default()
+                },
+            ));
+        }
+        // Spawn vertical lines.
+        for i in 0..=grid.size {
+            parent.spawn((
+                Name::new(format!("Vertical Line {}", i + 1)),
+                SpriteBundle {
+                    transform: Transform::from_translation(Vec3::new(
+                        ((i as f32) - ((grid.size as f32) / 2.00)) * grid.spacing,
+                        0.00,
+                        Depth::Map.z(),
+                    )),
+                    sprite: Sprite {
+                        color: grid.color,
+                        custom_size: Some(Vec2::new(grid.width, grid.length())),
+                        ..default()
+                    },
+                    ..default()
+                },
+            ));
+        }
+    });
+}
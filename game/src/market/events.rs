@@ -0,0 +1,9 @@
+use crate::prelude::*;
+
+
+/// Event for purchasing an item from the market.
+#[derive(Debug, Event, Reflect)]
+pub struct ItemPurchasedEvent {
+    pub player: Entity,
+    pub item_id: SmolStr,
+}
@@ -22,6 +22,9 @@ impl Plugin for MarketPlugin {
         app.init_resource::<MarketConfiguration>();
         app.init_resource::<MarketState>();
 
+        // Add events.
+        app.add_event::<ItemPurchasedEvent>();
+
         // Add console commands.
         app.add_console_command::<MarketCommand, _>(apply_market_command);
 
@@ -59,6 +62,12 @@ impl Plugin for MarketPlugin {
             ),
         );
         app.add_systems(PostUpdate, open_market.in_set(GameplaySystems::Market));
+        app.add_systems(
+            OnEnter(GameState::Market),
+            skip_market.run_if(|market_configuration: Res<MarketConfiguration>| {
+                !market_configuration.is_enabled
+            }),
+        );
         app.add_systems(OnEnter(GameState::Over), reset_market);
         app.add_systems(OnEnter(GameState::Restart), reset_market.in_set(RestartSystems::Market));
         app.add_systems(OnExit(AppState::Game), reset_market);
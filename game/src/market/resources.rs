@@ -5,6 +5,7 @@ use crate::prelude::*;
 #[derive(Clone, Copy, Debug, Resource)]
 pub struct RegisteredMarketSystems {
     pub refresh_market: SystemId,
+    pub take_market_break: SystemId<MarketBreak>,
 }
 
 impl RegisteredMarketSystems {
@@ -15,11 +16,24 @@ impl RegisteredMarketSystems {
         let refresh_market = app.world_mut().register_system(refresh_market);
         RegisteredSystems::attach(app, systems, refresh_market, "refresh_market");
 
-        RegisteredMarketSystems { refresh_market }
+        let take_market_break = app.world_mut().register_system(take_market_break);
+        RegisteredSystems::attach(app, systems, take_market_break, "take_market_break");
+
+        RegisteredMarketSystems { refresh_market, take_market_break }
     }
 }
 
 
+/// Market break taken between the stages of a game mode.
+#[derive(Clone, Copy, Debug)]
+pub struct MarketBreak {
+    /// Base cost of refreshing the market and rerolling the level up screen.
+    pub base_cost: Balance,
+    /// Balance gained by skipping a level up.
+    pub skip_reward: Balance,
+}
+
+
 /// Resource for available balance to spend in the market.
 #[derive(Clone, Copy, Debug, Default, Deref, DerefMut, PartialOrd, PartialEq, Reflect, Resource)]
 pub struct Balance(pub f64);
@@ -129,6 +143,11 @@ impl Default for ExperienceToBalanceRatio {
 /// ```
 #[derive(Debug, Reflect, Resource)]
 pub struct MarketConfiguration {
+    /// Whether the market is enabled.
+    ///
+    /// Market is skipped whenever it's opened while it's disabled.
+    pub is_enabled: bool,
+
     /// Whether the market can be opened by the player using the market keys.
     pub can_be_opened_by_player: bool,
 
@@ -285,6 +304,7 @@ impl MarketConfiguration {
 impl Default for MarketConfiguration {
     fn default() -> MarketConfiguration {
         MarketConfiguration {
+            is_enabled: true,
             can_be_opened_by_player: true,
             number_of_items: 4,
             refresh_cost: MarketRefreshCost::default(),
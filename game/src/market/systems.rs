@@ -364,10 +364,16 @@ pub fn gain_balance(
 
 
 /// Processes acquirements from the market.
+///
+/// Acquired items are added to the inventory of the living player with the least items.
 pub fn process_acquirements(
     mut market_state: ResMut<MarketState>,
-    mut inventory: ResMut<Inventory>,
+    mut inventory_query: Query<
+        (Entity, &PlayerIndex, &mut Inventory),
+        (With<Player>, Without<Downed>),
+    >,
     item_registry: Res<ItemRegistry>,
+    mut item_purchased_event_writer: EventWriter<ItemPurchasedEvent>,
 ) {
     while market_state.processed_acquirements < market_state.acquired_item_indices.len() {
         let index_of_item_to_acquire =
@@ -375,7 +381,17 @@ pub fn process_acquirements(
 
         let item_id_to_acquire = &market_state.offered_item_ids[index_of_item_to_acquire];
         if let Some(item_to_acquire) = item_registry.find_item_by_id(item_id_to_acquire) {
-            inventory.add(item_to_acquire.instantiate());
+            let recipient =
+                inventory_query.iter_mut().min_by_key(|(_, player_index, inventory)| {
+                    (inventory.len() + inventory.items_to_add.len(), player_index.0)
+                });
+            if let Some((player_entity, _, mut inventory)) = recipient {
+                inventory.add(item_to_acquire.instantiate());
+                item_purchased_event_writer.send(ItemPurchasedEvent {
+                    player: player_entity,
+                    item_id: item_id_to_acquire.clone(),
+                });
+            }
         }
 
         market_state.processed_acquirements += 1;
@@ -413,8 +429,11 @@ pub fn refresh_market_automatically(world: &mut World) {
 pub fn refresh_market(
     mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
     item_registry: Res<ItemRegistry>,
+    player_query: Query<(&Luck, Option<&Children>), With<Player>>,
+    item_query: Query<&Luck, (With<Item>, Without<Player>)>,
     market_configuration: Res<MarketConfiguration>,
     mut market_state: ResMut<MarketState>,
+    progression: Res<Persistent<Progression>>,
 ) {
     log::info!("refreshing the market to offer {} items", market_configuration.number_of_items);
 
@@ -471,6 +490,10 @@ pub fn refresh_market(
     if new_offered_item_ids.len() < (market_configuration.number_of_items as usize) {
         let mut commonness_of_items_that_can_be_offered = Vec::new();
         for entry in item_registry.iter() {
+            if !entry.item.is_available(&progression) {
+                continue;
+            }
+
             let commonness = market_configuration.commonness_of(&entry.item);
             if commonness != 0 {
                 commonness_of_items_that_can_be_offered.push((
@@ -480,6 +503,17 @@ pub fn refresh_market(
                 ));
             }
         }
+
+        let luck = utils::property::find_luck_of_players(&player_query, &item_query);
+        if luck.0 != 0.00 {
+            log::info!("biasing the commonness of items with {:.2} luck", luck.0);
+            luck.apply(
+                commonness_of_items_that_can_be_offered
+                    .iter_mut()
+                    .map(|(_, _, commonness)| commonness),
+            );
+        }
+
         commonness_of_items_that_can_be_offered.sort_by(
             |(id1, _, commonness1), (id2, _, commonness)| {
                 if commonness1 == commonness {
@@ -568,6 +602,61 @@ pub fn refresh_market(
 }
 
 
+/// Takes a market break, which restores the health of the players and opens the market
+/// after the level up screens of the pending level ups.
+pub fn take_market_break(
+    In(market_break): In<MarketBreak>,
+    mut commands: Commands,
+    primary_player_query: Query<&Level, With<PrimaryPlayer>>,
+    mut player_query: Query<(&mut RemainingHealth, &Health), (With<Player>, Without<Downed>)>,
+    level_up_rewards: Option<Res<LevelUpRewards>>,
+    mut market_configuration: ResMut<MarketConfiguration>,
+    mut level_up_screen_configuration: ResMut<LevelUpScreenConfiguration>,
+    mut game_state_stack: ResMut<GameStateStack>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    registered_systems: Res<RegisteredSystems>,
+) {
+    let refresh_cost = MarketRefreshCost::exponential(market_break.base_cost, 1.50, None);
+    log::info!("setting the refresh cost model of the market to {}", refresh_cost);
+    market_configuration.refresh_cost = refresh_cost;
+
+    commands.run_system(registered_systems.market.refresh_market);
+
+    let reroll_cost = LevelUpScreenRerollCost::exponential(market_break.base_cost, 1.50, None);
+    log::info!("setting the reroll cost model of the level up screen to {}", reroll_cost);
+    level_up_screen_configuration.reroll_cost = reroll_cost;
+
+    log::info!("setting the skip reward of the level up screen to {}", market_break.skip_reward);
+    level_up_screen_configuration.skip_reward = market_break.skip_reward;
+
+    game_state_stack.pop();
+    game_state_stack.push(GameState::Loading);
+    game_state_stack.push(GameState::Market);
+
+    for (mut player_remaining_health, player_health) in player_query.iter_mut() {
+        log::info!("resetting player health to {}", player_health.0);
+        player_remaining_health.0 = player_health.0;
+    }
+
+    if let (Ok(player_level), Some(level_up_rewards)) =
+        (primary_player_query.get_single(), level_up_rewards)
+    {
+        let reward_count = ((player_level.get() as usize) - 1) - level_up_rewards.perks.len();
+        for _ in 0..reward_count {
+            game_state_stack.push(GameState::LevelUpScreen);
+        }
+
+        if reward_count > 0 {
+            commands.insert_resource(LevelUpScreenReason::LevelingUp {
+                to: Level::new(level_up_rewards.perks.len() as u16 + 2),
+            });
+        }
+    }
+
+    next_game_state.set(GameState::Transition);
+}
+
+
 /// Opens the market.
 pub fn open_market(
     game_action_state_query: Query<&ActionState<GameAction>, With<Player>>,
@@ -576,9 +665,11 @@ pub fn open_market(
     mut game_state_stack: ResMut<GameStateStack>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    if let Ok(game_action_state) = game_action_state_query.get_single() {
+    for game_action_state in game_action_state_query.iter() {
         if game_action_state.just_pressed(&GameAction::OpenMarket) {
-            if market_configuration.can_be_opened_by_player {
+            if !market_configuration.is_enabled {
+                log::warn!("unable to open the market: it's disabled");
+            } else if market_configuration.can_be_opened_by_player {
                 log::info!("opening the market");
                 game_state_stack.push(GameState::Market);
                 next_game_state.set(GameState::Transition);
@@ -588,11 +679,23 @@ pub fn open_market(
                     selected_game_mode_id.0,
                 );
             }
+            return;
         }
     }
 }
 
 
+/// Skips the market when it's disabled.
+pub fn skip_market(
+    mut game_state_stack: ResMut<GameStateStack>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    log::info!("skipping the market as it's disabled");
+    game_state_stack.pop();
+    next_game_state.set(GameState::Transition);
+}
+
+
 /// Resets the market.
 pub fn reset_market(mut commands: Commands) {
     commands.insert_resource(Balance::default());
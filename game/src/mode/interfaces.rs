@@ -14,6 +14,14 @@ pub trait IGameMode: Debug + Send + Sync + 'static {
     fn default_enemy_spawn_pattern(&self, world: &World) -> EnemySpawnPattern;
     /// Gets the player level structure of the game mode.
     fn player_level_structure(&self) -> PlayerLevelStructure;
+    /// Gets the number of difficulty tiers of the game mode.
+    fn number_of_difficulties(&self) -> u8 {
+        1
+    }
+
+    /// Adds the console commands of the game mode.
+    #[allow(unused_variables)]
+    fn add_console_commands(&self, app: &mut App) {}
 
     /// Initializes the game mode.
     fn initialize(&self, world: &mut World);
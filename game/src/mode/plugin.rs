@@ -11,6 +11,7 @@ impl Plugin for ModePlugin {
         // Register resources.
         app.register_type::<SelectedGameModeIndex>();
         app.register_type::<SelectedGameModeId>();
+        app.register_type::<SelectedDifficulty>();
 
         // Initialize registry.
         app.init_resource::<GameModeRegistry>();
@@ -26,4 +27,17 @@ impl Plugin for ModePlugin {
         );
         app.add_systems(OnExit(AppState::Game), deinitialize_game_mode);
     }
+
+    fn finish(&self, app: &mut App) {
+        // Add console commands of the game modes.
+        let game_modes = app
+            .world()
+            .resource::<GameModeRegistry>()
+            .iter()
+            .map(|entry| Arc::clone(&entry.game_mode))
+            .collect::<Vec<_>>();
+        for game_mode in game_modes {
+            game_mode.add_console_commands(app);
+        }
+    }
 }
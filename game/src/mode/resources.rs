@@ -11,6 +11,23 @@ pub struct SelectedGameModeIndex(pub usize);
 pub struct SelectedGameModeId(pub SmolStr);
 
 
+/// Resource for the selected difficulty tier of the selected game mode.
+#[derive(Clone, Copy, Debug, Deref, Eq, PartialEq, Reflect, Resource)]
+pub struct SelectedDifficulty(pub NonZeroU8);
+
+impl Default for SelectedDifficulty {
+    fn default() -> SelectedDifficulty {
+        SelectedDifficulty(NonZeroU8::new(1).unwrap())
+    }
+}
+
+impl Display for SelectedDifficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "difficulty {}", self.0)
+    }
+}
+
+
 /// Resource for the current game mode.
 #[derive(Debug, Default, Deref, Reflect, Resource)]
 pub struct GameMode<M: IGameMode>(pub M);
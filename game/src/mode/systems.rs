@@ -7,6 +7,10 @@ pub fn initialize_game_mode(world: &mut World) {
     let selected_game_mode_index = world.resource::<SelectedGameModeIndex>();
 
     let selected_game_mode = game_mode_registry[*selected_game_mode_index].clone();
+
+    let selected_difficulty = *world.get_resource_or_insert_with(SelectedDifficulty::default);
+    log::info!("initializing {:?} game mode in {}", selected_game_mode.id(), selected_difficulty);
+
     selected_game_mode.initialize(world);
 
     let market_can_be_opened_by_player = selected_game_mode.market_can_be_opened_by_player();
@@ -33,4 +37,5 @@ pub fn deinitialize_game_mode(world: &mut World) {
 
     world.remove_resource::<SelectedGameModeIndex>();
     world.remove_resource::<SelectedGameModeId>();
+    world.remove_resource::<SelectedDifficulty>();
 }
@@ -19,6 +19,28 @@ pub enum AttractionSpeed {
     },
 }
 
+impl AttractionSpeed {
+    /// Scales all the speeds of the attraction by a multiplier.
+    pub fn scale(&mut self, multiplier: f32) {
+        match self {
+            AttractionSpeed::Constant(speed) => {
+                speed.0 *= multiplier;
+            },
+            AttractionSpeed::Accelerating {
+                min_speed,
+                acceleration_per_second,
+                current_speed,
+                max_speed,
+            } => {
+                min_speed.0 *= multiplier;
+                acceleration_per_second.0 *= multiplier;
+                current_speed.0 *= multiplier;
+                max_speed.0 *= multiplier;
+            },
+        }
+    }
+}
+
 
 /// Component for the ideal distance to the attracted object.
 #[derive(Clone, Copy, Component, Debug, Default, Deref, DerefMut, Reflect)]
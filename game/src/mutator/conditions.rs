@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+
+/// Condition to run when a mutator is enabled.
+pub fn mutator_is_enabled(mutator: Mutator) -> impl Fn(Res<SelectedMutators>) -> bool {
+    move |selected_mutators: Res<SelectedMutators>| selected_mutators.is_enabled(mutator)
+}
+
+/// Condition to run when a mutator is disabled.
+pub fn mutator_is_disabled(mutator: Mutator) -> impl Fn(Res<SelectedMutators>) -> bool {
+    move |selected_mutators: Res<SelectedMutators>| !selected_mutators.is_enabled(mutator)
+}
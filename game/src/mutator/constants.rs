@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+
+/// Multiplier of the attraction speed of enemies when double enemy speed mutator is enabled.
+pub const DOUBLE_ENEMY_SPEED_MULTIPLIER: f32 = 2.00;
+
+
+/// Multiplier of the health of players when glass cannon mutator is enabled.
+pub const GLASS_CANNON_HEALTH_MULTIPLIER: f32 = 0.50;
+
+/// Multiplier of the damage of players when glass cannon mutator is enabled.
+pub const GLASS_CANNON_DAMAGE_MULTIPLIER: f32 = 2.00;
+
+
+/// Interval of experience decay when experience decay mutator is enabled.
+pub const EXPERIENCE_DECAY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Ratio of the experience required for the next level lost every decay interval.
+pub const EXPERIENCE_DECAY_RATIO: f64 = 0.02;
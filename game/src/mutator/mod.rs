@@ -0,0 +1,5 @@
+pub mod conditions;
+pub mod constants;
+pub mod plugin;
+pub mod resources;
+pub mod systems;
@@ -0,0 +1,59 @@
+use crate::{
+    mutator::systems::*,
+    prelude::*,
+};
+
+/// Plugin for managing the mutators.
+pub struct MutatorPlugin;
+
+impl Plugin for MutatorPlugin {
+    fn build(&self, app: &mut App) {
+        // Register resources.
+        app.register_type::<SelectedMutators>();
+        app.register_type::<ExperienceDecayTimer>();
+
+        // Insert resources.
+        let args = app.world().resource::<Args>();
+        let mut selected_mutators = SelectedMutators::default();
+        for mutator in args.mutators.iter().copied() {
+            selected_mutators.enable(mutator);
+        }
+        app.insert_resource(selected_mutators);
+        app.init_resource::<ExperienceDecayTimer>();
+
+        // Setup localization.
+        app.world_mut().resource_mut::<LocaleAssets>().push("content/mutators.ftl");
+
+        // Add systems.
+        app.add_systems(
+            OnEnter(GameState::Initialization),
+            initialize_mutators.in_set(InitializationSystems::First),
+        );
+        app.add_systems(
+            OnEnter(GameState::Initialization),
+            disable_market
+                .in_set(InitializationSystems::Market)
+                .run_if(mutator_is_enabled(Mutator::NoMarket)),
+        );
+        app.add_systems(
+            PostUpdate,
+            double_enemy_speed
+                .run_if(in_state(AppState::Game))
+                .run_if(mutator_is_enabled(Mutator::DoubleEnemySpeed)),
+        );
+        app.add_systems(
+            PostUpdate,
+            (reduce_player_health, increase_player_damage)
+                .run_if(in_state(AppState::Game))
+                .run_if(mutator_is_enabled(Mutator::GlassCannon)),
+        );
+        app.add_systems(
+            Update,
+            decay_experience
+                .in_set(GameplaySystems::Leveling)
+                .run_if(mutator_is_enabled(Mutator::ExperienceDecay))
+                .run_if(resource_exists::<ExperienceRequiredToGetToCurrentLevel>)
+                .run_if(resource_exists::<ExperienceRequiredToLevelUp>),
+        );
+    }
+}
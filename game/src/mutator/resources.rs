@@ -0,0 +1,136 @@
+use crate::{
+    mutator::constants::*,
+    prelude::*,
+};
+
+
+/// Mutators that can be enabled to change the rules of a run.
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, PartialEq, Reflect, ValueEnum)]
+pub enum Mutator {
+    /// Enemies move twice as fast.
+    DoubleEnemySpeed,
+    /// Market is never opened.
+    NoMarket,
+    /// Players have half the health but deal double the damage.
+    GlassCannon,
+    /// Players cannot dash.
+    NoDashing,
+    /// Experience of players decays over time.
+    ExperienceDecay,
+}
+
+impl Mutator {
+    /// Gets the unique identifier of the mutator.
+    pub fn id(&self) -> SmolStr {
+        match self {
+            Mutator::DoubleEnemySpeed => "double-enemy-speed".into(),
+            Mutator::NoMarket => "no-market".into(),
+            Mutator::GlassCannon => "glass-cannon".into(),
+            Mutator::NoDashing => "no-dashing".into(),
+            Mutator::ExperienceDecay => "experience-decay".into(),
+        }
+    }
+
+    /// Gets the localized name of the mutator.
+    pub fn name(&self) -> LocalizedText {
+        match self {
+            Mutator::DoubleEnemySpeed => {
+                LocalizedText::Localized {
+                    key: "mutator-double-enemy-speed-name",
+                    args: smallvec![],
+                    fallback: "Double Enemy Speed".into(),
+                }
+            },
+            Mutator::NoMarket => {
+                LocalizedText::Localized {
+                    key: "mutator-no-market-name",
+                    args: smallvec![],
+                    fallback: "No Market".into(),
+                }
+            },
+            Mutator::GlassCannon => {
+                LocalizedText::Localized {
+                    key: "mutator-glass-cannon-name",
+                    args: smallvec![],
+                    fallback: "Glass Cannon".into(),
+                }
+            },
+            Mutator::NoDashing => {
+                LocalizedText::Localized {
+                    key: "mutator-no-dashing-name",
+                    args: smallvec![],
+                    fallback: "No Dashing".into(),
+                }
+            },
+            Mutator::ExperienceDecay => {
+                LocalizedText::Localized {
+                    key: "mutator-experience-decay-name",
+                    args: smallvec![],
+                    fallback: "Experience Decay".into(),
+                }
+            },
+        }
+    }
+}
+
+impl Display for Mutator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+
+/// Resource for the mutators selected for the runs.
+#[derive(Clone, Debug, Default, Reflect, Resource)]
+pub struct SelectedMutators(pub Vec<Mutator>);
+
+impl SelectedMutators {
+    /// Gets whether a mutator is enabled.
+    pub fn is_enabled(&self, mutator: Mutator) -> bool {
+        self.0.contains(&mutator)
+    }
+
+    /// Enables a mutator.
+    pub fn enable(&mut self, mutator: Mutator) {
+        if !self.is_enabled(mutator) {
+            self.0.push(mutator);
+        }
+    }
+
+    /// Disables a mutator.
+    pub fn disable(&mut self, mutator: Mutator) {
+        self.0.retain(|enabled_mutator| *enabled_mutator != mutator);
+    }
+
+    /// Toggles a mutator.
+    pub fn toggle(&mut self, mutator: Mutator) {
+        if self.is_enabled(mutator) {
+            self.disable(mutator);
+        } else {
+            self.enable(mutator);
+        }
+    }
+
+    /// Gets the enabled mutators in a consistent order.
+    pub fn iter(&self) -> impl Iterator<Item = Mutator> + '_ {
+        Mutator::iter().filter(|mutator| self.is_enabled(*mutator))
+    }
+}
+
+impl Display for SelectedMutators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids = self.iter().map(|mutator| mutator.id()).collect::<Vec<_>>();
+        if ids.is_empty() { write!(f, "no mutators") } else { write!(f, "{}", ids.join(", ")) }
+    }
+}
+
+
+/// Resource for the timer of the experience decay mutator.
+#[derive(Debug, Deref, DerefMut, Reflect, Resource)]
+pub struct ExperienceDecayTimer(pub Timer);
+
+impl Default for ExperienceDecayTimer {
+    fn default() -> ExperienceDecayTimer {
+        ExperienceDecayTimer(Timer::new(EXPERIENCE_DECAY_INTERVAL, TimerMode::Repeating))
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    mutator::constants::*,
+    prelude::*,
+};
+
+
+/// Initializes the selected mutators.
+pub fn initialize_mutators(
+    selected_mutators: Res<SelectedMutators>,
+    mut experience_decay_timer: ResMut<ExperienceDecayTimer>,
+) {
+    log::info!("initializing the game with {}", *selected_mutators);
+    experience_decay_timer.reset();
+}
+
+
+/// Disables the market for the no market mutator.
+pub fn disable_market(mut market_configuration: ResMut<MarketConfiguration>) {
+    log::info!("disabling the market as no market mutator is enabled");
+    market_configuration.is_enabled = false;
+    market_configuration.can_be_opened_by_player = false;
+}
+
+
+/// Doubles the speed of the spawned enemies for the double enemy speed mutator.
+pub fn double_enemy_speed(mut enemy_query: Query<&mut AttractionSpeed, Added<Enemy>>) {
+    for mut attraction_speed in enemy_query.iter_mut() {
+        attraction_speed.scale(DOUBLE_ENEMY_SPEED_MULTIPLIER);
+    }
+}
+
+
+/// Reduces the health of the spawned players for the glass cannon mutator.
+pub fn reduce_player_health(
+    mut player_query: Query<(&mut Health, &mut RemainingHealth), Added<Player>>,
+) {
+    for (mut health, mut remaining_health) in player_query.iter_mut() {
+        health.0 *= GLASS_CANNON_HEALTH_MULTIPLIER;
+        remaining_health.0 = health.0;
+    }
+}
+
+/// Increases the damage of the attacks of the players for the glass cannon mutator.
+pub fn increase_player_damage(
+    mut attack_query: Query<
+        &mut Damage,
+        (Added<Damage>, Or<(With<DamageEnemiesOnContact>, With<DamageEnemiesOnContactStarted>)>),
+    >,
+) {
+    for mut damage in attack_query.iter_mut() {
+        damage.0 *= GLASS_CANNON_DAMAGE_MULTIPLIER;
+    }
+}
+
+
+/// Decays the experience of the players for the experience decay mutator.
+///
+/// Experience never decays below the experience required to get to the current level,
+/// so players never lose levels.
+pub fn decay_experience(
+    mut player_query: Query<&mut Experience, With<Player>>,
+    time: Res<Time>,
+    mut experience_decay_timer: ResMut<ExperienceDecayTimer>,
+    experience_required_to_get_to_current_level: Res<ExperienceRequiredToGetToCurrentLevel>,
+    experience_required_to_level_up: Res<ExperienceRequiredToLevelUp>,
+) {
+    experience_decay_timer.tick(time.delta());
+
+    let times_finished = experience_decay_timer.times_finished_this_tick();
+    if times_finished == 0 {
+        return;
+    }
+
+    let minimum_experience = experience_required_to_get_to_current_level.0.0;
+    let experience_required_for_level_up = experience_required_to_level_up.0.0 - minimum_experience;
+
+    let decay = experience_required_for_level_up * EXPERIENCE_DECAY_RATIO * (times_finished as f64);
+
+    for mut player_experience in player_query.iter_mut() {
+        let decayed_experience = (player_experience.0 - decay).max(minimum_experience);
+        if decayed_experience < player_experience.0 {
+            player_experience.0 = decayed_experience;
+        }
+    }
+}
@@ -24,6 +24,27 @@ pub trait IPerk: Debug + Send + Sync + 'static {
         commonness
     }
 
+    /// Gets the identifiers of the perks which need to be obtained before the perk is offered.
+    fn prerequisites(&self) -> Vec<SmolStr> {
+        Vec::new()
+    }
+    /// Gets the identifiers of the perks which cannot be obtained together with the perk.
+    fn exclusions(&self) -> Vec<SmolStr> {
+        Vec::new()
+    }
+    /// Gets the identifier of the perk which is upgraded by the perk.
+    fn upgrades(&self) -> Option<SmolStr> {
+        None
+    }
+    /// Gets whether the perk replaces the perk it upgrades when it's obtained.
+    ///
+    /// By default, upgrades stack with the perks they upgrade, so the effects of the lower
+    /// tiers are kept. Perks which replace the perk they upgrade make it lose its effect
+    /// until the upgrade is lost.
+    fn replaces_upgraded_perk(&self) -> bool {
+        false
+    }
+
     /// Obtains the perk.
     fn obtain(&self, world: &mut World);
     /// Loses the perk.
@@ -14,11 +14,19 @@ impl Plugin for PerkPlugin {
         // Initialize registry.
         app.init_resource::<PerkRegistry>();
 
+        // Insert resources.
+        app.init_resource::<ObtainedPerks>();
+
         // Add events.
         app.add_event::<PerkObtainedEvent>();
         app.add_event::<PerkLostEvent>();
 
         // Add console commands.
         app.add_console_command::<PerkCommand, _>(apply_perk_command);
+
+        // Add systems.
+        app.add_systems(Startup, validate_perk_registry);
+        app.add_systems(OnEnter(GameState::Restart), clear_obtained_perks);
+        app.add_systems(OnExit(AppState::Game), clear_obtained_perks);
     }
 }
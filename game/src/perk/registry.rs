@@ -52,6 +52,168 @@ impl PerkRegistry {
     }
 }
 
+impl PerkRegistry {
+    /// Gets the identifiers of the perks which are upgraded by a perk, from the closest to the
+    /// farthest (e.g., `["speedy-ii", "speedy-i"]` for `"speedy-iii"`).
+    pub fn upgrade_chain(&self, perk_id: &str) -> Vec<SmolStr> {
+        let mut chain = Vec::<SmolStr>::new();
+        let mut current = self.find_perk_by_id(perk_id).and_then(|perk| perk.upgrades.clone());
+        while let Some(upgraded_perk_id) = current {
+            if upgraded_perk_id == perk_id || chain.contains(&upgraded_perk_id) {
+                break;
+            }
+            current =
+                self.find_perk_by_id(&upgraded_perk_id).and_then(|perk| perk.upgrades.clone());
+            chain.push(upgraded_perk_id);
+        }
+        chain
+    }
+
+    /// Gets the identifiers of the perks which are replaced by a perk when it's obtained,
+    /// from the closest to the farthest.
+    ///
+    /// Replacement follows the upgrade chain as long as each perk replaces the perk it upgrades.
+    pub fn replacement_chain(&self, perk_id: &str) -> Vec<SmolStr> {
+        let mut chain = Vec::<SmolStr>::new();
+        let mut current = self.find_perk_by_id(perk_id);
+        while let Some(perk) = current {
+            if !perk.replaces_upgraded_perk {
+                break;
+            }
+            let upgraded_perk_id = match &perk.upgrades {
+                Some(upgraded_perk_id) => upgraded_perk_id.clone(),
+                None => break,
+            };
+            if upgraded_perk_id == perk_id || chain.contains(&upgraded_perk_id) {
+                break;
+            }
+            current = self.find_perk_by_id(&upgraded_perk_id);
+            chain.push(upgraded_perk_id);
+        }
+        chain
+    }
+
+    /// Gets whether two perks exclude each other.
+    pub fn are_mutually_exclusive(&self, perk_id1: &str, perk_id2: &str) -> bool {
+        let excludes = |perk_id: &str, other_perk_id: &str| {
+            self.find_perk_by_id(perk_id)
+                .map(|perk| perk.exclusions.iter().any(|excluded| excluded == other_perk_id))
+                .unwrap_or(false)
+        };
+        excludes(perk_id1, perk_id2) || excludes(perk_id2, perk_id1)
+    }
+
+    /// Gets whether a perk can be offered with the obtained perks.
+    pub fn can_be_offered(&self, perk: &RegisteredPerk, obtained_perks: &ObtainedPerks) -> bool {
+        let perk_id = perk.id();
+
+        if perk.prerequisites.iter().any(|prerequisite| !obtained_perks.contains(prerequisite)) {
+            return false;
+        }
+
+        for obtained_perk_id in obtained_perks.ids() {
+            if self.are_mutually_exclusive(&perk_id, &obtained_perk_id) {
+                return false;
+            }
+        }
+
+        for active_perk in obtained_perks.iter() {
+            if self.upgrade_chain(&active_perk.id()).contains(&perk_id) {
+                // perk is already upgraded to a better one.
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl PerkRegistry {
+    /// Validates the relationships of the perks in the perk registry.
+    pub fn validate(&self) -> Vec<PerkRegistryError> {
+        let mut errors = Vec::new();
+
+        for entry in self.iter() {
+            let perk = &entry.perk;
+            let perk_id = perk.id();
+
+            let mut references = Vec::new();
+            for prerequisite in perk.prerequisites.iter() {
+                references.push((PerkRelationship::Prerequisite, prerequisite.clone()));
+            }
+            for exclusion in perk.exclusions.iter() {
+                references.push((PerkRelationship::Exclusion, exclusion.clone()));
+            }
+            if let Some(upgrade) = &perk.upgrades {
+                references.push((PerkRelationship::Upgrade, upgrade.clone()));
+            }
+
+            for (relationship, referenced_perk_id) in references {
+                if referenced_perk_id == perk_id {
+                    errors.push(PerkRegistryError::SelfReference {
+                        perk: perk_id.clone(),
+                        relationship,
+                    });
+                } else if self.find_perk_by_id(&referenced_perk_id).is_none() {
+                    errors.push(PerkRegistryError::UnknownReference {
+                        perk: perk_id.clone(),
+                        relationship,
+                        referenced_perk: referenced_perk_id,
+                    });
+                }
+            }
+
+            for prerequisite in perk.prerequisites.iter() {
+                if self.are_mutually_exclusive(&perk_id, prerequisite) {
+                    errors.push(PerkRegistryError::ExcludedPrerequisite {
+                        perk: perk_id.clone(),
+                        prerequisite: prerequisite.clone(),
+                    });
+                }
+            }
+
+            if let Some(upgrade) = &perk.upgrades {
+                if self.are_mutually_exclusive(&perk_id, upgrade) {
+                    errors.push(PerkRegistryError::ExcludedUpgrade {
+                        perk: perk_id.clone(),
+                        upgrade: upgrade.clone(),
+                    });
+                }
+                if self.upgrade_chain(upgrade).contains(&perk_id) {
+                    errors.push(PerkRegistryError::CyclicUpgrades { perk: perk_id.clone() });
+                }
+            }
+
+            if self.has_cyclic_prerequisites(&perk_id) {
+                errors.push(PerkRegistryError::CyclicPrerequisites { perk: perk_id.clone() });
+            }
+        }
+
+        errors
+    }
+
+    /// Gets whether a perk is a prerequisite of itself through other perks.
+    fn has_cyclic_prerequisites(&self, perk_id: &str) -> bool {
+        let mut visited = HashSet::<SmolStr>::new();
+        let mut stack = self
+            .find_perk_by_id(perk_id)
+            .map(|perk| perk.prerequisites.clone())
+            .unwrap_or_default();
+        while let Some(prerequisite) = stack.pop() {
+            if prerequisite == perk_id {
+                return true;
+            }
+            if !visited.insert(prerequisite.clone()) {
+                continue;
+            }
+            if let Some(prerequisite) = self.find_perk_by_id(&prerequisite) {
+                stack.extend(prerequisite.prerequisites.iter().cloned());
+            }
+        }
+        false
+    }
+}
+
 
 /// Container for the entries of the iem registry.
 #[derive(Debug)]
@@ -75,6 +237,11 @@ pub struct RegisteredPerk {
     pub description: LocalizedText,
     pub rarity: Rarity,
     pub commonness: u64,
+    pub prerequisites: Vec<SmolStr>,
+    pub exclusions: Vec<SmolStr>,
+    pub upgrades: Option<SmolStr>,
+    pub replaces_upgraded_perk: bool,
+    pub unlock_cost: Option<Ambrosia>,
 }
 
 impl RegisteredPerk {
@@ -84,7 +251,35 @@ impl RegisteredPerk {
         let description = perk.description();
         let rarity = perk.rarity();
         let commonness = perk.commonness();
-        RegisteredPerk { perk: Arc::new(perk), name, description, rarity, commonness }
+        let prerequisites = perk.prerequisites();
+        let exclusions = perk.exclusions();
+        let upgrades = perk.upgrades();
+        let replaces_upgraded_perk = perk.replaces_upgraded_perk();
+        RegisteredPerk {
+            perk: Arc::new(perk),
+            name,
+            description,
+            rarity,
+            commonness,
+            prerequisites,
+            exclusions,
+            upgrades,
+            replaces_upgraded_perk,
+            unlock_cost: None,
+        }
+    }
+}
+
+impl RegisteredPerk {
+    /// Makes the perk unlockable for the specified cost.
+    pub fn unlockable(&mut self, cost: Ambrosia) -> &mut RegisteredPerk {
+        self.unlock_cost = Some(cost);
+        self
+    }
+
+    /// Gets whether the perk is available in the progression.
+    pub fn is_available(&self, progression: &Progression) -> bool {
+        progression.is_available(UnlockableKind::Perk, &self.id(), self.unlock_cost)
     }
 }
 
@@ -95,3 +290,256 @@ impl Deref for RegisteredPerk {
         &self.perk
     }
 }
+
+
+/// Relationships between perks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PerkRelationship {
+    Prerequisite,
+    Exclusion,
+    Upgrade,
+}
+
+impl Display for PerkRelationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PerkRelationship::Prerequisite => write!(f, "prerequisite"),
+            PerkRelationship::Exclusion => write!(f, "exclusion"),
+            PerkRelationship::Upgrade => write!(f, "upgrade"),
+        }
+    }
+}
+
+
+/// Errors in the relationships of the perks in the perk registry.
+#[derive(Clone, Debug)]
+pub enum PerkRegistryError {
+    UnknownReference { perk: SmolStr, relationship: PerkRelationship, referenced_perk: SmolStr },
+    SelfReference { perk: SmolStr, relationship: PerkRelationship },
+    ExcludedPrerequisite { perk: SmolStr, prerequisite: SmolStr },
+    ExcludedUpgrade { perk: SmolStr, upgrade: SmolStr },
+    CyclicPrerequisites { perk: SmolStr },
+    CyclicUpgrades { perk: SmolStr },
+}
+
+impl PerkRegistryError {
+    /// Gets the identifier of the perk with the error.
+    pub fn perk(&self) -> &SmolStr {
+        match self {
+            PerkRegistryError::UnknownReference { perk, .. } => perk,
+            PerkRegistryError::SelfReference { perk, .. } => perk,
+            PerkRegistryError::ExcludedPrerequisite { perk, .. } => perk,
+            PerkRegistryError::ExcludedUpgrade { perk, .. } => perk,
+            PerkRegistryError::CyclicPrerequisites { perk } => perk,
+            PerkRegistryError::CyclicUpgrades { perk } => perk,
+        }
+    }
+}
+
+impl Display for PerkRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PerkRegistryError::UnknownReference { perk, relationship, referenced_perk } => {
+                write!(
+                    f,
+                    "{:?} perk has unknown {:?} perk as {}",
+                    perk, referenced_perk, relationship
+                )
+            },
+            PerkRegistryError::SelfReference { perk, relationship } => {
+                write!(f, "{:?} perk has itself as {}", perk, relationship)
+            },
+            PerkRegistryError::ExcludedPrerequisite { perk, prerequisite } => {
+                write!(f, "{:?} perk excludes its prerequisite {:?} perk", perk, prerequisite)
+            },
+            PerkRegistryError::ExcludedUpgrade { perk, upgrade } => {
+                write!(f, "{:?} perk excludes {:?} perk it upgrades", perk, upgrade)
+            },
+            PerkRegistryError::CyclicPrerequisites { perk } => {
+                write!(f, "{:?} perk is a prerequisite of itself", perk)
+            },
+            PerkRegistryError::CyclicUpgrades { perk } => {
+                write!(f, "{:?} perk is an upgrade of itself", perk)
+            },
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct TestPerk {
+        id: &'static str,
+        prerequisites: Vec<&'static str>,
+        exclusions: Vec<&'static str>,
+        upgrades: Option<&'static str>,
+        replaces_upgraded_perk: bool,
+    }
+
+    impl IPerk for TestPerk {
+        fn id(&self) -> SmolStr {
+            SmolStr::new(self.id)
+        }
+
+        fn name(&self) -> LocalizedText {
+            self.id.into()
+        }
+
+        fn description(&self) -> LocalizedText {
+            self.id.into()
+        }
+
+        fn rarity(&self) -> Rarity {
+            Rarity::Common
+        }
+
+        fn prerequisites(&self) -> Vec<SmolStr> {
+            self.prerequisites.iter().map(|id| SmolStr::new(id)).collect()
+        }
+
+        fn exclusions(&self) -> Vec<SmolStr> {
+            self.exclusions.iter().map(|id| SmolStr::new(id)).collect()
+        }
+
+        fn upgrades(&self) -> Option<SmolStr> {
+            self.upgrades.map(SmolStr::new)
+        }
+
+        fn replaces_upgraded_perk(&self) -> bool {
+            self.replaces_upgraded_perk
+        }
+
+        fn obtain(&self, _world: &mut World) {}
+
+        fn lose(&self, _world: &mut World) {}
+    }
+
+    fn tiers(replaces_upgraded_perk: bool) -> PerkRegistry {
+        let mut perk_registry = PerkRegistry::default();
+        perk_registry.register(TestPerk { id: "test-i", ..default() });
+        perk_registry.register(TestPerk {
+            id: "test-ii",
+            upgrades: Some("test-i"),
+            replaces_upgraded_perk,
+            ..default()
+        });
+        perk_registry.register(TestPerk {
+            id: "test-iii",
+            upgrades: Some("test-ii"),
+            replaces_upgraded_perk,
+            ..default()
+        });
+        perk_registry
+    }
+
+    #[test]
+    fn upgrade_chain_is_ordered_from_the_closest_to_the_farthest() {
+        let perk_registry = tiers(false);
+        assert_eq!(perk_registry.upgrade_chain("test-iii"), ["test-ii", "test-i"]);
+        assert_eq!(perk_registry.upgrade_chain("test-ii"), ["test-i"]);
+        assert!(perk_registry.upgrade_chain("test-i").is_empty());
+        assert!(perk_registry.upgrade_chain("unknown").is_empty());
+    }
+
+    #[test]
+    fn upgrade_chain_stops_at_cycles() {
+        let mut perk_registry = PerkRegistry::default();
+        perk_registry.register(TestPerk { id: "a", upgrades: Some("b"), ..default() });
+        perk_registry.register(TestPerk { id: "b", upgrades: Some("c"), ..default() });
+        perk_registry.register(TestPerk { id: "c", upgrades: Some("b"), ..default() });
+        assert_eq!(perk_registry.upgrade_chain("a"), ["b", "c"]);
+        assert_eq!(perk_registry.upgrade_chain("b"), ["c"]);
+    }
+
+    #[test]
+    fn replacement_chain_follows_replacing_upgrades() {
+        assert_eq!(tiers(true).replacement_chain("test-iii"), ["test-ii", "test-i"]);
+        assert!(tiers(false).replacement_chain("test-iii").is_empty());
+    }
+
+    #[test]
+    fn validate_accepts_valid_relationships() {
+        let mut perk_registry = tiers(true);
+        perk_registry.register(TestPerk {
+            id: "other",
+            prerequisites: vec!["test-i"],
+            exclusions: vec!["test-iii"],
+            ..default()
+        });
+        assert!(perk_registry.validate().is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_references() {
+        let mut perk_registry = PerkRegistry::default();
+        perk_registry.register(TestPerk {
+            id: "a",
+            prerequisites: vec!["b"],
+            exclusions: vec!["c"],
+            upgrades: Some("d"),
+            ..default()
+        });
+
+        let errors = perk_registry.validate();
+        assert_eq!(errors.len(), 3);
+        for (error, (expected_relationship, expected_referenced_perk)) in errors.iter().zip([
+            (PerkRelationship::Prerequisite, "b"),
+            (PerkRelationship::Exclusion, "c"),
+            (PerkRelationship::Upgrade, "d"),
+        ]) {
+            match error {
+                PerkRegistryError::UnknownReference { perk, relationship, referenced_perk } => {
+                    assert_eq!(perk, "a");
+                    assert_eq!(*relationship, expected_relationship);
+                    assert_eq!(referenced_perk, expected_referenced_perk);
+                },
+                _ => panic!("unexpected error {:?}", error),
+            }
+        }
+    }
+
+    #[test]
+    fn validate_rejects_cyclic_prerequisites() {
+        let mut perk_registry = PerkRegistry::default();
+        perk_registry.register(TestPerk { id: "a", prerequisites: vec!["b"], ..default() });
+        perk_registry.register(TestPerk { id: "b", prerequisites: vec!["c"], ..default() });
+        perk_registry.register(TestPerk { id: "c", prerequisites: vec!["a"], ..default() });
+
+        let errors = perk_registry.validate();
+        assert_eq!(errors.len(), 3);
+        for (error, expected_perk) in errors.iter().zip(["a", "b", "c"]) {
+            assert!(matches!(error, PerkRegistryError::CyclicPrerequisites { .. }));
+            assert_eq!(error.perk(), expected_perk);
+        }
+    }
+
+    #[test]
+    fn validate_rejects_cyclic_upgrades() {
+        let mut perk_registry = PerkRegistry::default();
+        perk_registry.register(TestPerk { id: "a", upgrades: Some("b"), ..default() });
+        perk_registry.register(TestPerk { id: "b", upgrades: Some("a"), ..default() });
+
+        let errors = perk_registry.validate();
+        assert_eq!(errors.len(), 2);
+        for (error, expected_perk) in errors.iter().zip(["a", "b"]) {
+            assert!(matches!(error, PerkRegistryError::CyclicUpgrades { .. }));
+            assert_eq!(error.perk(), expected_perk);
+        }
+    }
+
+    #[test]
+    fn validate_rejects_self_references() {
+        let mut perk_registry = PerkRegistry::default();
+        perk_registry.register(TestPerk { id: "a", exclusions: vec!["a"], ..default() });
+
+        let errors = perk_registry.validate();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            PerkRegistryError::SelfReference { relationship: PerkRelationship::Exclusion, .. },
+        ));
+    }
+}
@@ -24,6 +24,107 @@ impl RegisteredPerkSystems {
 }
 
 
+/// Resource for the perks obtained in the game.
+#[derive(Debug, Default, Resource)]
+pub struct ObtainedPerks(Vec<ObtainedPerk>);
+
+impl ObtainedPerks {
+    /// Gets the active perks (i.e., obtained perks which are not replaced by their upgrades).
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn IPerk>> {
+        self.0.iter().map(|obtained_perk| &obtained_perk.perk)
+    }
+
+    /// Gets the identifiers of all obtained perks, including the ones replaced by upgrades.
+    pub fn ids(&self) -> Vec<SmolStr> {
+        let mut ids = Vec::new();
+        let mut stack = self.0.iter().collect::<Vec<_>>();
+        while let Some(obtained_perk) = stack.pop() {
+            ids.push(obtained_perk.perk.id());
+            stack.extend(obtained_perk.replaced.iter());
+        }
+        ids
+    }
+
+    /// Gets whether a perk is obtained, including the ones replaced by upgrades.
+    pub fn contains(&self, perk_id: &str) -> bool {
+        self.ids().iter().any(|id| id == perk_id)
+    }
+}
+
+impl ObtainedPerks {
+    /// Adds an obtained perk.
+    ///
+    /// Active perks in the replacement chain of the perk are replaced by the perk,
+    /// and they are returned so they can be lost.
+    pub fn obtain(
+        &mut self,
+        perk: Arc<dyn IPerk>,
+        replacement_chain: &[SmolStr],
+    ) -> Vec<Arc<dyn IPerk>> {
+        let mut replaced = Vec::new();
+        let mut index = 0;
+        while index < self.0.len() {
+            if replacement_chain.contains(&self.0[index].perk.id()) {
+                replaced.push(self.0.remove(index));
+            } else {
+                index += 1;
+            }
+        }
+
+        let replaced_perks =
+            replaced.iter().map(|obtained_perk| obtained_perk.perk.clone()).collect();
+        self.0.push(ObtainedPerk { perk, replaced });
+        replaced_perks
+    }
+
+    /// Removes a lost perk.
+    ///
+    /// If the perk was active, perks replaced by it become active again,
+    /// and they are returned so they can be obtained again.
+    pub fn lose(&mut self, perk_id: &str) -> Vec<Arc<dyn IPerk>> {
+        if let Some(index) =
+            self.0.iter().rposition(|obtained_perk| obtained_perk.perk.id() == perk_id)
+        {
+            let lost = self.0.remove(index);
+            let restored_perks =
+                lost.replaced.iter().map(|obtained_perk| obtained_perk.perk.clone()).collect();
+            self.0.extend(lost.replaced);
+            return restored_perks;
+        }
+
+        let mut stack = self.0.iter_mut().collect::<Vec<_>>();
+        while let Some(obtained_perk) = stack.pop() {
+            if let Some(index) = obtained_perk
+                .replaced
+                .iter()
+                .rposition(|replaced_perk| replaced_perk.perk.id() == perk_id)
+            {
+                let lost = obtained_perk.replaced.remove(index);
+                obtained_perk.replaced.extend(lost.replaced);
+                break;
+            }
+            stack.extend(obtained_perk.replaced.iter_mut());
+        }
+        Vec::new()
+    }
+
+    /// Clears the obtained perks.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+
+/// Container for obtained perks.
+#[derive(Debug)]
+pub struct ObtainedPerk {
+    /// Obtained perk.
+    pub perk: Arc<dyn IPerk>,
+    /// Obtained perks which are replaced by the perk.
+    pub replaced: Vec<ObtainedPerk>,
+}
+
+
 /// Reason for obtaining/losing a perk.
 #[derive(Debug)]
 pub enum ObtainLosePerkReason {
@@ -31,6 +132,8 @@ pub enum ObtainLosePerkReason {
     LevelingDown { to: Level },
     Cheating,
     OppositeOfCheating,
+    Upgrading { to: SmolStr },
+    Downgrading { from: SmolStr },
 }
 
 impl Display for ObtainLosePerkReason {
@@ -44,6 +147,10 @@ impl Display for ObtainLosePerkReason {
             },
             ObtainLosePerkReason::Cheating => write!(f, "by cheating :)"),
             ObtainLosePerkReason::OppositeOfCheating => write!(f, "by cheating :|"),
+            ObtainLosePerkReason::Upgrading { to } => write!(f, "for upgrading to {:?}", to),
+            ObtainLosePerkReason::Downgrading { from } => {
+                write!(f, "for losing its upgrade {:?}", from)
+            },
         }
     }
 }
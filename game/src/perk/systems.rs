@@ -26,6 +26,22 @@ pub fn apply_perk_command(
                         "    - commonness: {}",
                         perk.commonness.to_formatted_string(&NumLocale::es_US),
                     );
+                    if !perk.prerequisites.is_empty() {
+                        reply!(command, "    - prerequisites: {}", perk.prerequisites.join(", "));
+                    }
+                    if !perk.exclusions.is_empty() {
+                        reply!(command, "    - exclusions: {}", perk.exclusions.join(", "));
+                    }
+                    if let Some(upgrades) = &perk.upgrades {
+                        if perk.replaces_upgraded_perk {
+                            reply!(command, "    - upgrades: {} (replaces it)", upgrades);
+                        } else {
+                            reply!(command, "    - upgrades: {}", upgrades);
+                        }
+                    }
+                    if let Some(unlock_cost) = perk.unlock_cost {
+                        reply!(command, "    - unlock cost: {}", unlock_cost);
+                    }
                 }
             },
             PerkCommands::Obtain { perk } => {
@@ -98,12 +114,41 @@ pub fn apply_perk_command(
 }
 
 
+/// Validates the perk registry.
+///
+/// Perks with invalid relationships are never offered.
+pub fn validate_perk_registry(mut perk_registry: ResMut<PerkRegistry>) {
+    for error in perk_registry.validate() {
+        log::error!("invalid perk registry: {}", error);
+        if let Some(perk) = perk_registry.find_perk_mut_by_id(error.perk()) {
+            perk.commonness = 0;
+        }
+    }
+}
+
+
 /// Obtains a perk.
+///
+/// Active perks in the replacement chain of the perk are lost.
 pub fn obtain_perk(
     In((perk, reason)): In<(Arc<dyn IPerk>, ObtainLosePerkReason)>,
     world: &mut World,
 ) {
-    log::info!("obtaining {:?} perk {}", perk.id(), reason);
+    let perk_id = perk.id();
+    let replacement_chain = world.resource::<PerkRegistry>().replacement_chain(&perk_id);
+    let replaced_perks =
+        world.resource_mut::<ObtainedPerks>().obtain(perk.clone(), &replacement_chain);
+
+    for replaced_perk in replaced_perks {
+        let reason = ObtainLosePerkReason::Upgrading { to: perk_id.clone() };
+        log::info!("losing {:?} perk {}", replaced_perk.id(), reason);
+        replaced_perk.lose(world);
+
+        let mut perk_lost_events = world.resource_mut::<Events<PerkLostEvent>>();
+        perk_lost_events.send(PerkLostEvent { perk: replaced_perk, reason });
+    }
+
+    log::info!("obtaining {:?} perk {}", perk_id, reason);
     perk.obtain(world);
 
     let mut perk_obtained_events = world.resource_mut::<Events<PerkObtainedEvent>>();
@@ -111,13 +156,33 @@ pub fn obtain_perk(
 }
 
 /// Loses a perk.
+///
+/// Perks replaced by the perk are obtained again.
 pub fn lose_perk(
     In((perk, reason)): In<(Arc<dyn IPerk>, ObtainLosePerkReason)>,
     world: &mut World,
 ) {
-    log::info!("losing {:?} perk {}", perk.id(), reason);
+    let perk_id = perk.id();
+    let restored_perks = world.resource_mut::<ObtainedPerks>().lose(&perk_id);
+
+    log::info!("losing {:?} perk {}", perk_id, reason);
     perk.lose(world);
 
     let mut perk_lost_events = world.resource_mut::<Events<PerkLostEvent>>();
     perk_lost_events.send(PerkLostEvent { perk, reason });
+
+    for restored_perk in restored_perks {
+        let reason = ObtainLosePerkReason::Downgrading { from: perk_id.clone() };
+        log::info!("obtaining {:?} perk {}", restored_perk.id(), reason);
+        restored_perk.obtain(world);
+
+        let mut perk_obtained_events = world.resource_mut::<Events<PerkObtainedEvent>>();
+        perk_obtained_events.send(PerkObtainedEvent { perk: restored_perk, reason });
+    }
+}
+
+
+/// Clears the obtained perks.
+pub fn clear_obtained_perks(mut obtained_perks: ResMut<ObtainedPerks>) {
+    obtained_perks.clear();
 }
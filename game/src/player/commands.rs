@@ -17,6 +17,11 @@ pub enum PlayerCommands {
         #[clap(subcommand)]
         subcommand: GodModeCommands,
     },
+    /// Controls the number of local players.
+    Count {
+        #[clap(subcommand)]
+        subcommand: CountCommands,
+    },
 }
 
 /// God mode commands.
@@ -29,3 +34,12 @@ pub enum GodModeCommands {
     /// Disables god mode.
     Disable,
 }
+
+/// Player count commands.
+#[derive(Debug, Subcommand)]
+pub enum CountCommands {
+    /// Shows the number of local players.
+    Show,
+    /// Sets the number of local players.
+    Set { count: usize },
+}
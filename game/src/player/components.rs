@@ -6,6 +6,21 @@ use crate::prelude::*;
 pub struct Player;
 
 
+/// Component for the index of local players.
+#[derive(Clone, Copy, Component, Debug, Default, Deref, Eq, PartialEq, Reflect)]
+pub struct PlayerIndex(pub usize);
+
+
+/// Tag component for the primary player, whose level is shared by the party.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct PrimaryPlayer;
+
+
+/// Tag component for players that are down.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct Downed;
+
+
 /// Tag component for the hit box of the player.
 #[derive(Component, Debug, Default, Reflect)]
 pub struct PlayerHitBox;
@@ -60,6 +75,7 @@ pub struct DamagePlayerOnContactStarted;
 #[derive(Bundle, TypedBuilder)]
 pub struct PlayerBundle<P: Component + IPlayer> {
     pub player: P,
+    pub index: PlayerIndex,
     pub mesh: MaterialMesh2dBundle<ColorMaterial>,
     #[builder(setter(transform =
         |input_map: InputMap<GameAction>| {
@@ -67,18 +83,22 @@ pub struct PlayerBundle<P: Component + IPlayer> {
         }
     ))]
     pub input: InputManagerBundle<GameAction>,
+    #[builder(default)]
+    pub inventory: Inventory,
 }
 
 impl<P: Component + IPlayer> PlayerBundle<P> {
     /// Spawns the player.
     pub fn spawn<'c>(self, commands: &'c mut Commands) -> EntityCommands<'c> {
-        let name = format!("Player [{}]", self.player.id());
+        let name = format!("Player {} [{}]", *self.index + 1, self.player.id());
+        let is_primary = *self.index == 0;
         let health = self.player.health();
         let pickup_range = self.player.pickup_range();
         let speed = self.player.speed();
         let speed_multiplier = self.player.speed_multiplier();
         let dodge_chance = self.player.dodge_chance();
         let hp_regeneration = self.player.hp_regeneration();
+        let luck = self.player.luck();
         let collider = self.player.collider();
 
         let mut player = commands.spawn((
@@ -93,6 +113,7 @@ impl<P: Component + IPlayer> PlayerBundle<P> {
             speed_multiplier,
             dodge_chance,
             hp_regeneration,
+            luck,
             // Combat
             RemainingHealth(*health),
             // Leveling
@@ -109,6 +130,10 @@ impl<P: Component + IPlayer> PlayerBundle<P> {
             ),
         ));
 
+        if is_primary {
+            player.insert(PrimaryPlayer);
+        }
+
         player.with_children(|parent| {
             parent.spawn(PlayerHitBox::bundle(collider));
             parent.spawn(PlayerPickupArea::bundle(Collider::circle(*pickup_range)));
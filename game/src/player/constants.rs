@@ -1,6 +1,16 @@
 use crate::prelude::*;
 
 
+/// Minimum number of local players.
+pub const MIN_NUMBER_OF_PLAYERS: usize = 1;
+
+/// Maximum number of local players.
+pub const MAX_NUMBER_OF_PLAYERS: usize = 4;
+
+/// Horizontal distance between the players when they are placed at the start of a level.
+pub const DISTANCE_BETWEEN_PLAYERS: f32 = 75.00;
+
+
 /// Base health of players.
 pub const BASE_HEALTH: f32 = 10.00;
 
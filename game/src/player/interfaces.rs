@@ -44,10 +44,14 @@ pub trait IPlayer: Debug + Send + Sync + 'static {
     fn hp_regeneration(&self) -> HpRegeneration {
         HpRegeneration::default()
     }
+    /// Gets the base luck of the player.
+    fn luck(&self) -> Luck {
+        Luck::default()
+    }
 
 
     /// Gets the collider of the player.
     fn collider(&self) -> Collider;
-    /// Spawns the player.
-    fn spawn(&self, world: &mut World);
+    /// Spawns the player as the local player with the specified index.
+    fn spawn(&self, world: &mut World, index: PlayerIndex);
 }
@@ -7,3 +7,4 @@ pub mod plugin;
 pub mod registry;
 pub mod resources;
 pub mod systems;
+pub mod utils;
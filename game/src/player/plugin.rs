@@ -1,6 +1,7 @@
 use crate::{
     player::{
         commands::*,
+        constants::*,
         systems::*,
     },
     prelude::*,
@@ -13,17 +14,38 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         // Register components.
         app.register_type::<Player>();
+        app.register_type::<PlayerIndex>();
+        app.register_type::<PrimaryPlayer>();
+        app.register_type::<Downed>();
         app.register_type::<DamagePlayerOnContact>();
         app.register_type::<DamagePlayerOnContactStarted>();
         app.register_type::<SelectedMythologyIndex>();
         app.register_type::<SelectedPlayerIndex>();
 
         // Register Resources.
+        app.register_type::<NumberOfPlayers>();
+        app.register_type::<PlayerGamepads>();
         app.register_type::<GodMode>();
 
         // Insert resources.
         let args = app.world().resource::<Args>();
+        let number_of_players = match args.players {
+            Some(players) => {
+                if !(MIN_NUMBER_OF_PLAYERS..=MAX_NUMBER_OF_PLAYERS).contains(&players) {
+                    log::error!(
+                        "unable to use {} players as it's not between {} and {}",
+                        players,
+                        MIN_NUMBER_OF_PLAYERS,
+                        MAX_NUMBER_OF_PLAYERS,
+                    );
+                }
+                NumberOfPlayers(players.clamp(MIN_NUMBER_OF_PLAYERS, MAX_NUMBER_OF_PLAYERS))
+            },
+            None => NumberOfPlayers::default(),
+        };
         app.insert_resource(GodMode { is_enabled: args.enable_god_mode });
+        app.insert_resource(number_of_players);
+        app.init_resource::<PlayerGamepads>();
 
         // Initialize registry.
         app.init_resource::<PlayerRegistry>();
@@ -48,10 +70,17 @@ impl Plugin for PlayerPlugin {
             );
 
             app.add_systems(PreUpdate, cooldown::<Dashing>.in_set(GameplaySystems::Player));
-            app.add_systems(Update, (movement, dash).in_set(GameplaySystems::Player));
+            app.add_systems(
+                Update,
+                (movement, dash.run_if(mutator_is_disabled(Mutator::NoDashing)))
+                    .in_set(GameplaySystems::Player),
+            );
             app.add_systems(PostUpdate, pause.in_set(GameplaySystems::Player));
 
-            app.add_systems(OnEnter(GameState::Won), turn_player_visibility_off);
+            app.add_systems(
+                OnEnter(GameState::Won),
+                (turn_player_visibility_off, revive_downed_players),
+            );
 
             app.add_systems(OnEnter(GameState::Over), despawn_player);
             app.add_systems(
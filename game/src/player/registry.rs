@@ -84,6 +84,15 @@ impl PlayerRegistry {
         }
         None
     }
+
+    /// Tries to find the selected player in the player registry.
+    pub fn find_selected_player(
+        &self,
+        mythology_index: SelectedMythologyIndex,
+        player_index: SelectedPlayerIndex,
+    ) -> Option<&RegisteredPlayer> {
+        self.get(*mythology_index).and_then(|entry| entry.players.get(*player_index))
+    }
 }
 
 impl Index<SelectedMythologyIndex> for PlayerRegistry {
@@ -152,12 +161,26 @@ impl Deref for RegisteredMythology {
 #[derive(Debug)]
 pub struct RegisteredPlayer {
     pub player: Arc<dyn IPlayer>,
+    pub unlock_cost: Option<Ambrosia>,
 }
 
 impl RegisteredPlayer {
     /// Creates a new registered player.
     pub fn new(player: impl IPlayer) -> RegisteredPlayer {
-        RegisteredPlayer { player: Arc::new(player) }
+        RegisteredPlayer { player: Arc::new(player), unlock_cost: None }
+    }
+}
+
+impl RegisteredPlayer {
+    /// Makes the player unlockable for the specified cost.
+    pub fn unlockable(&mut self, cost: Ambrosia) -> &mut RegisteredPlayer {
+        self.unlock_cost = Some(cost);
+        self
+    }
+
+    /// Gets whether the player is available in the progression.
+    pub fn is_available(&self, progression: &Progression) -> bool {
+        progression.is_available(UnlockableKind::Player, &self.id(), self.unlock_cost)
     }
 }
 
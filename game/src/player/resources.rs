@@ -11,6 +11,59 @@ pub struct SelectedMythologyIndex(pub usize);
 pub struct SelectedPlayerIndex(pub usize);
 
 
+/// Resource for the number of local players.
+#[derive(Clone, Copy, Debug, Deref, Reflect, Resource)]
+pub struct NumberOfPlayers(pub usize);
+
+impl Default for NumberOfPlayers {
+    fn default() -> NumberOfPlayers {
+        NumberOfPlayers(1)
+    }
+}
+
+
+/// Resource for the gamepads assigned to the local players other than the first one.
+///
+/// Gamepads are assigned in the order they are connected, and the slot of a disconnected gamepad
+/// is kept until another gamepad is connected, so the other players keep their gamepads.
+#[derive(Clone, Debug, Default, Reflect, Resource)]
+pub struct PlayerGamepads(pub Vec<Option<Gamepad>>);
+
+impl PlayerGamepads {
+    /// Assigns a connected gamepad to the first player without a gamepad.
+    ///
+    /// Returns whether the gamepad is newly assigned.
+    pub fn connect(&mut self, gamepad: Gamepad) -> bool {
+        if self.0.contains(&Some(gamepad)) {
+            return false;
+        }
+        match self.0.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => *slot = Some(gamepad),
+            None => self.0.push(Some(gamepad)),
+        }
+        true
+    }
+
+    /// Unassigns a disconnected gamepad.
+    ///
+    /// Returns whether the gamepad was assigned.
+    pub fn disconnect(&mut self, gamepad: Gamepad) -> bool {
+        match self.0.iter_mut().find(|slot| **slot == Some(gamepad)) {
+            Some(slot) => {
+                *slot = None;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Gets the gamepad assigned to a local player.
+    pub fn gamepad_of(&self, player_index: PlayerIndex) -> Option<Gamepad> {
+        player_index.0.checked_sub(1).and_then(|slot| self.0.get(slot).copied().flatten())
+    }
+}
+
+
 /// Resource for god mode.
 #[derive(Clone, Copy, Debug, Reflect, Resource)]
 pub struct GodMode {
@@ -11,6 +11,7 @@ use crate::{
 pub fn apply_player_command(
     mut command: ConsoleCommand<PlayerCommand>,
     mut god_mode: ResMut<GodMode>,
+    mut number_of_players: ResMut<NumberOfPlayers>,
 ) {
     if let Some(Ok(PlayerCommand { subcommand })) = command.take() {
         match subcommand {
@@ -38,107 +39,169 @@ pub fn apply_player_command(
                     },
                 }
             },
+            PlayerCommands::Count { subcommand } => {
+                match subcommand {
+                    CountCommands::Show => {
+                        reply!(command, "{}", number_of_players.0);
+                    },
+                    CountCommands::Set { count } => {
+                        if !(MIN_NUMBER_OF_PLAYERS..=MAX_NUMBER_OF_PLAYERS).contains(&count) {
+                            reply!(
+                                command,
+                                "Number of players must be between {} and {}.",
+                                MIN_NUMBER_OF_PLAYERS,
+                                MAX_NUMBER_OF_PLAYERS,
+                            );
+                        } else {
+                            number_of_players.0 = count;
+                            reply!(command, "Set, will take effect in the next game.");
+                        }
+                    },
+                }
+            },
         }
         reply!(command, "");
     }
 }
 
 
-/// Spawns the player.
+/// Spawns the players.
 pub fn spawn_player(world: &mut World) {
-    let player_registry = world.resource::<PlayerRegistry>();
-    let selected_mythology_index = world.resource::<SelectedMythologyIndex>();
-    let selected_player_index = world.resource::<SelectedPlayerIndex>();
-    player_registry[*selected_mythology_index][*selected_player_index].clone().spawn(world);
+    let number_of_players = *world.resource::<NumberOfPlayers>();
+    for index in 0..*number_of_players {
+        let player_registry = world.resource::<PlayerRegistry>();
+        let selected_mythology_index = world.resource::<SelectedMythologyIndex>();
+        let selected_player_index = world.resource::<SelectedPlayerIndex>();
+        player_registry[*selected_mythology_index][*selected_player_index]
+            .clone()
+            .spawn(world, PlayerIndex(index));
+    }
 }
 
-/// Despawns the player.
+/// Despawns the players.
 pub fn despawn_player(mut commands: Commands, player_query: Query<Entity, With<Player>>) {
-    if let Ok(entity) = player_query.get_single() {
+    for entity in player_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
 }
 
 
-/// Makes the player hidden.
+/// Makes the players hidden.
 pub fn turn_player_visibility_off(mut player_query: Query<&mut Visibility, With<Player>>) {
-    if let Ok(mut player_visibility) = player_query.get_single_mut() {
+    for mut player_visibility in player_query.iter_mut() {
         *player_visibility = Visibility::Hidden;
     }
 }
 
-/// Resets the player position.
-pub fn reset_player_position(mut player_query: Query<&mut Position, With<Player>>) {
-    if let Ok(mut player_position) = player_query.get_single_mut() {
-        player_position.x = 0.00;
+/// Resets the player positions.
+pub fn reset_player_position(
+    mut player_query: Query<(&PlayerIndex, &mut Position), With<Player>>,
+    number_of_players: Res<NumberOfPlayers>,
+) {
+    for (player_index, mut player_position) in player_query.iter_mut() {
+        let offset = (player_index.0 as f32) - ((number_of_players.0 - 1) as f32) / 2.00;
+        player_position.x = offset * DISTANCE_BETWEEN_PLAYERS;
         player_position.y = 0.00;
     }
 }
 
-/// Makes the player visible.
-pub fn turn_player_visibility_on(mut player_query: Query<&mut Visibility, With<Player>>) {
-    if let Ok(mut player_visibility) = player_query.get_single_mut() {
+/// Makes the players that are not down visible.
+pub fn turn_player_visibility_on(
+    mut player_query: Query<&mut Visibility, (With<Player>, Without<Downed>)>,
+) {
+    for mut player_visibility in player_query.iter_mut() {
         *player_visibility = Visibility::Visible;
     }
 }
 
+/// Revives the downed players.
+pub fn revive_downed_players(
+    mut commands: Commands,
+    mut player_query: Query<
+        (Entity, &Name, &Health, &mut RemainingHealth, &mut Inventory),
+        (With<Player>, With<Downed>),
+    >,
+) {
+    for (
+        player_entity,
+        player_name,
+        player_health,
+        mut player_remaining_health,
+        mut player_inventory,
+    ) in player_query.iter_mut()
+    {
+        log::info!("reviving {:?}", player_name);
+
+        player_remaining_health.0 = player_health.0;
+        player_inventory.restore();
+
+        commands.entity(player_entity).remove::<Downed>().insert(CollisionLayers::new(
+            [Layer::Player],
+            [Layer::MapBound, Layer::ExperiencePoint],
+        ));
+    }
+}
+
 
-/// Moves the player.
+/// Moves the players.
 pub fn movement(
     mut player_query: Query<
         (&ActionState<GameAction>, &Speed, &SpeedMultiplier, &mut LinearVelocity),
-        (With<Player>, Without<Dashing>),
+        (With<Player>, Without<Dashing>, Without<Downed>),
     >,
 ) {
-    let (action_state, speed, speed_multiplier, mut velocity) = match player_query.get_single_mut()
-    {
-        Ok(query_result) => query_result,
-        Err(_) => return,
-    };
+    for (action_state, speed, speed_multiplier, mut velocity) in player_query.iter_mut() {
+        let analog_change = action_state
+            .axis_pair(&GameAction::Move)
+            .map(|axis_pair| axis_pair.xy().clamp_length_max(1.00))
+            .unwrap_or(Vec2::ZERO);
+
+        if analog_change != Vec2::ZERO {
+            velocity.0 = analog_change * (speed.0 * speed_multiplier.0);
+            continue;
+        }
 
-    let mut change = Vec2::ZERO;
+        let mut change = Vec2::ZERO;
 
-    if action_state.pressed(&GameAction::MoveUp) {
-        change.y += 1.0;
-    }
-    if action_state.pressed(&GameAction::MoveLeft) {
-        change.x -= 1.0;
-    }
-    if action_state.pressed(&GameAction::MoveDown) {
-        change.y -= 1.0;
-    }
-    if action_state.pressed(&GameAction::MoveRight) {
-        change.x += 1.0;
-    }
+        if action_state.pressed(&GameAction::MoveUp) {
+            change.y += 1.0;
+        }
+        if action_state.pressed(&GameAction::MoveLeft) {
+            change.x -= 1.0;
+        }
+        if action_state.pressed(&GameAction::MoveDown) {
+            change.y -= 1.0;
+        }
+        if action_state.pressed(&GameAction::MoveRight) {
+            change.x += 1.0;
+        }
 
-    velocity.0 = if change == Vec2::ZERO {
-        Vec2::ZERO
-    } else {
-        change.normalize() * (speed.0 * speed_multiplier.0)
+        velocity.0 = if change == Vec2::ZERO {
+            Vec2::ZERO
+        } else {
+            change.normalize() * (speed.0 * speed_multiplier.0)
+        }
     }
 }
 
-/// Activates dashing for the player.
+/// Activates dashing for the players.
 pub fn dash(
     mut commands: Commands,
     player_query: Query<
         (Entity, &ActionState<GameAction>, &LinearVelocity),
-        (With<Player>, Without<Cooldown<Dashing>>),
+        (With<Player>, Without<Cooldown<Dashing>>, Without<Downed>),
     >,
 ) {
-    let (entity, action_state, velocity) = match player_query.get_single() {
-        Ok(query_result) => query_result,
-        Err(_) => return,
-    };
-
-    if action_state.just_pressed(&GameAction::Dash) {
-        if velocity.0 == Vec2::ZERO {
-            return;
+    for (entity, action_state, velocity) in player_query.iter() {
+        if action_state.just_pressed(&GameAction::Dash) {
+            if velocity.0 == Vec2::ZERO {
+                continue;
+            }
+            commands.entity(entity).insert((
+                Dashing { timer: Timer::new(BASE_DASH_DURATION, TimerMode::Once) },
+                Cooldown::<Dashing>::new(BASE_DASH_COOLDOWN),
+            ));
         }
-        commands.entity(entity).insert((
-            Dashing { timer: Timer::new(BASE_DASH_DURATION, TimerMode::Once) },
-            Cooldown::<Dashing>::new(BASE_DASH_COOLDOWN),
-        ));
     }
 }
 
@@ -149,10 +212,11 @@ pub fn pause(
     mut game_state_stack: ResMut<GameStateStack>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    if let Ok(game_action_state) = game_action_state_query.get_single() {
+    for game_action_state in game_action_state_query.iter() {
         if game_action_state.just_pressed(&GameAction::Pause) {
             game_state_stack.push(GameState::Paused);
             next_game_state.set(GameState::Transition);
+            return;
         }
     }
 }
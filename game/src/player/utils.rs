@@ -0,0 +1,17 @@
+use crate::prelude::*;
+
+
+/// Finds the closest player to a position among the players that are not down.
+pub fn find_closest_player(
+    position: Vec2,
+    player_query: &Query<(Entity, &Position), (With<Player>, Without<Downed>)>,
+) -> Option<(Entity, Position)> {
+    player_query
+        .iter()
+        .map(|(player_entity, &player_position)| (player_entity, player_position))
+        .min_by(|(_, player_position1), (_, player_position2)| {
+            let distance1 = player_position1.distance_squared(position);
+            let distance2 = player_position2.distance_squared(position);
+            distance1.partial_cmp(&distance2).unwrap_or(Ordering::Equal)
+        })
+}
@@ -1,10 +1,15 @@
 use crate::{
+    accessibility::plugin::AccessibilityPlugin,
+    achievement::plugin::AchievementPlugin,
+    audio::plugin::AudioPlugin,
     camera::plugin::CameraPlugin,
     combat::plugin::CombatPlugin,
     configuration::plugin::ConfigurationPlugin,
     console::plugin::ConsolePlugin,
     core::plugin::CorePlugin,
+    daily_challenge::plugin::DailyChallengePlugin,
     enemy::plugin::EnemyPlugin,
+    feedback::plugin::FeedbackPlugin,
     input::plugin::InputPlugin,
     inventory::plugin::InventoryPlugin,
     item::plugin::ItemPlugin,
@@ -13,15 +18,20 @@ use crate::{
     market::plugin::MarketPlugin,
     mode::plugin::ModePlugin,
     movement::plugin::MovementPlugin,
+    mutator::plugin::MutatorPlugin,
     perk::plugin::PerkPlugin,
     physics::plugin::PhysicsPlugin,
     player::plugin::PlayerPlugin,
     prelude::*,
+    progression::plugin::ProgressionPlugin,
     property::plugin::PropertyPlugin,
     status_effect::plugin::StatusEffectPlugin,
     ui::plugin::UiPlugin,
 };
 
+#[cfg(feature = "native")]
+use crate::video::plugin::VideoPlugin;
+
 /// Main plugin.
 pub struct MythmallowPlugin;
 
@@ -31,11 +41,19 @@ impl Plugin for MythmallowPlugin {
         app.add_plugins(ConsolePlugin);
         app.add_plugins(ConfigurationPlugin);
         app.add_plugins(CorePlugin);
+        app.add_plugins(ProgressionPlugin);
+        app.add_plugins(AchievementPlugin);
+        app.add_plugins(DailyChallengePlugin);
         app.add_plugins(InputPlugin);
         app.add_plugins(CameraPlugin);
+        app.add_plugins(AudioPlugin);
+        app.add_plugins(AccessibilityPlugin);
+        #[cfg(feature = "native")]
+        app.add_plugins(VideoPlugin);
         app.add_plugins(UiPlugin);
         app.add_plugins(PhysicsPlugin);
         app.add_plugins(ModePlugin);
+        app.add_plugins(MutatorPlugin);
         app.add_plugins(ItemPlugin);
         app.add_plugins(PerkPlugin);
         app.add_plugins(MarketPlugin);
@@ -48,5 +66,6 @@ impl Plugin for MythmallowPlugin {
         app.add_plugins(PlayerPlugin);
         app.add_plugins(EnemyPlugin);
         app.add_plugins(CombatPlugin);
+        app.add_plugins(FeedbackPlugin);
     }
 }
@@ -1,8 +1,24 @@
 #[doc(inline)]
 pub use crate::{
+    accessibility::components::*,
+    achievement::{
+        events::*,
+        interfaces::*,
+        registry::*,
+        resources::*,
+    },
+    audio::{
+        components::*,
+        events::*,
+        resources::*,
+    },
     camera::components::*,
-    combat::components::*,
+    combat::{
+        components::*,
+        events::*,
+    },
     configuration::resources::*,
+    console::resources::*,
     core::{
         components::*,
         depths::*,
@@ -10,12 +26,18 @@ pub use crate::{
         sets::*,
         states::*,
     },
+    daily_challenge::resources::*,
     enemy::{
         components::*,
+        conditions::*,
         interfaces::*,
         registry::*,
         resources::*,
     },
+    feedback::{
+        components::*,
+        resources::*,
+    },
     input::actions::*,
     inventory::{
         components::*,
@@ -35,7 +57,10 @@ pub use crate::{
         components::*,
         resources::*,
     },
-    market::resources::*,
+    market::{
+        events::*,
+        resources::*,
+    },
     mode::{
         conditions::in_game_mode,
         interfaces::*,
@@ -43,6 +68,10 @@ pub use crate::{
         resources::*,
     },
     movement::components::*,
+    mutator::{
+        conditions::*,
+        resources::*,
+    },
     perk::{
         events::*,
         interfaces::*,
@@ -58,12 +87,18 @@ pub use crate::{
         resources::*,
     },
     plugin::MythmallowPlugin,
+    progression::{
+        events::*,
+        resources::*,
+    },
     property::components::*,
     status_effect::{
         components::*,
         systems::cooldown,
     },
     ui::{
+        achievement_toast::components::*,
+        achievements_screen::components::*,
         diagnostics_overlay::components::*,
         enemy_selection_screen::components::*,
         game_mode_selection_screen::components::*,
@@ -74,6 +109,7 @@ pub use crate::{
         },
         level_up_screen::{
             components::*,
+            events::*,
             resources::*,
         },
         main_menu::components::*,
@@ -86,7 +122,11 @@ pub use crate::{
             resources::*,
         },
         player_selection_screen::components::*,
-        settings_menu::components::*,
+        settings_menu::{
+            components::*,
+            resources::*,
+        },
+        unlock_screen::components::*,
         widget::components::*,
     },
 };
@@ -94,7 +134,10 @@ pub use crate::{
 pub mod utils {
     pub use crate::{
         combat::utils as combat,
+        configuration::utils as configuration,
         map::utils as map,
+        player::utils as player,
+        property::utils as property,
     };
 }
 
@@ -107,6 +150,7 @@ pub use {
     },
     bevy::{
         app::AppExit,
+        audio::Volume,
         asset::{
             LoadState,
             LoadedFolder,
@@ -126,7 +170,10 @@ pub use {
                 SystemState,
             },
         },
-        input::mouse::MouseMotion,
+        input::{
+            gamepad::GamepadConnectionEvent,
+            mouse::MouseMotion,
+        },
         log::{
             self,
             LogPlugin,
@@ -137,7 +184,12 @@ pub use {
             AsBindGroup,
             ShaderRef,
         },
-        sprite::MaterialMesh2dBundle,
+        sprite::{
+            Material2d,
+            Material2dPlugin,
+            MaterialMesh2dBundle,
+            Mesh2dHandle,
+        },
         transform::TransformSystem,
         ui::Display as UiDisplay,
         utils::{
@@ -150,13 +202,16 @@ pub use {
         },
     },
     bevy_console::{
+        self,
         reply,
         AddConsoleCommand,
         ConsoleCommand,
+        ConsoleCommandEntered,
         ConsoleConfiguration,
         ConsoleOpen as ConsoleState,
         ConsolePlugin as BevyConsolePlugin,
         ConsoleSet,
+        PrintConsoleLine,
     },
     bevy_easings::{
         Ease,
@@ -179,6 +234,7 @@ pub use {
         CommandFactory,
         Parser,
         Subcommand,
+        ValueEnum,
     },
     core::num::NonZeroU8,
     fluent::{
@@ -225,6 +281,11 @@ pub use {
         },
         borrow::Cow,
         cmp::Ordering,
+        collections::{
+            BTreeMap,
+            BTreeSet,
+            VecDeque,
+        },
         fmt::{
             self,
             Debug,
@@ -233,6 +294,7 @@ pub use {
         marker::PhantomData,
         num::{
             NonZeroU16,
+            NonZeroU32,
             NonZeroUsize,
         },
         ops::{
@@ -240,7 +302,10 @@ pub use {
             DerefMut,
             Index,
         },
-        path::PathBuf,
+        path::{
+            Path,
+            PathBuf,
+        },
         sync::{
             atomic::{
                 AtomicBool,
@@ -260,19 +325,28 @@ pub use {
 #[cfg(feature = "native")]
 #[doc(inline)]
 pub use {
-    bevy::window::{
-        ExitCondition,
-        WindowMode,
+    bevy::{
+        asset::io::file::FileAssetReader,
+        window::{
+            ExitCondition,
+            PresentMode,
+            WindowMode,
+        },
     },
     bevy_persistent_windows::prelude::*,
-    std::time::Instant,
+    std::time::{
+        Instant,
+        SystemTime,
+    },
 };
 
 #[cfg(feature = "wasm")]
 #[doc(inline)]
 pub use {
-    instant::Instant,
-    std::path::Path,
+    instant::{
+        Instant,
+        SystemTime,
+    },
     web_sys::{
         self,
         wasm_bindgen::JsCast,
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+/// Controls the meta-progression.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "progression")]
+#[command(disable_help_flag = true)]
+pub struct ProgressionCommand {
+    #[clap(subcommand)]
+    pub subcommand: ProgressionCommands,
+}
+
+/// Progression commands.
+#[derive(Debug, Subcommand)]
+pub enum ProgressionCommands {
+    /// Shows the ambrosia and the unlocked content.
+    Show,
+    /// Earns ambrosia.
+    #[clap(arg_required_else_help = true)]
+    Earn { amount: u64 },
+    /// Unlocks a content without spending ambrosia.
+    #[clap(arg_required_else_help = true)]
+    Unlock { kind: UnlockableKind, id: SmolStr },
+    /// Locks a content without refunding ambrosia.
+    #[clap(arg_required_else_help = true)]
+    Lock { kind: UnlockableKind, id: SmolStr },
+    /// Resets the meta-progression.
+    Reset,
+}
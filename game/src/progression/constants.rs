@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+
+/// Storage format for progression files.
+pub const PROGRESSION_STORAGE_FORMAT: StorageFormat = {
+    #[cfg(feature = "native")]
+    {
+        StorageFormat::Toml
+    }
+    #[cfg(feature = "wasm")]
+    {
+        StorageFormat::Json
+    }
+};
+
+
+/// Amount of ambrosia earned for each level gained during a run.
+pub const AMBROSIA_PER_LEVEL: Ambrosia = Ambrosia(2);
+
+/// Amount of ambrosia earned for winning a run.
+pub const AMBROSIA_FOR_WINNING: Ambrosia = Ambrosia(25);
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+
+/// Event for earning ambrosia at the end of a run.
+#[derive(Debug, Event, Reflect)]
+pub struct AmbrosiaEarnedEvent {
+    pub amount: Ambrosia,
+}
+
+
+/// Event for unlocking a player, an item or a perk.
+#[derive(Debug, Event, Reflect)]
+pub struct UnlockedEvent {
+    pub kind: UnlockableKind,
+    pub id: SmolStr,
+}
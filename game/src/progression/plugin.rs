@@ -0,0 +1,30 @@
+use crate::{
+    prelude::*,
+    progression::{
+        commands::*,
+        systems::*,
+    },
+};
+
+/// Plugin for managing the meta-progression.
+pub struct ProgressionPlugin;
+
+impl Plugin for ProgressionPlugin {
+    fn build(&self, app: &mut App) {
+        // Register resources.
+        app.register_type::<Progression>();
+
+        // Insert resources.
+        Progression::initialize(app);
+
+        // Add events.
+        app.add_event::<AmbrosiaEarnedEvent>();
+        app.add_event::<UnlockedEvent>();
+
+        // Add console commands.
+        app.add_console_command::<ProgressionCommand, _>(apply_progression_command);
+
+        // Add systems.
+        app.add_systems(OnEnter(GameState::Over), (award_ambrosia, record_won_difficulty));
+    }
+}
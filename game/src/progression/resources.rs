@@ -0,0 +1,207 @@
+use crate::{
+    prelude::*,
+    progression::constants::*,
+};
+
+
+/// Container for the meta-progression currency.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deref,
+    DerefMut,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Reflect,
+    Serialize
+)]
+#[serde(transparent)]
+pub struct Ambrosia(pub u64);
+
+impl Display for Ambrosia {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ambrosia", self.0)
+    }
+}
+
+
+/// Kinds of content that can be unlocked.
+#[derive(Clone, Copy, Debug, EnumIter, Eq, PartialEq, Reflect, ValueEnum)]
+pub enum UnlockableKind {
+    Player,
+    Item,
+    Perk,
+}
+
+impl Display for UnlockableKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnlockableKind::Player => write!(f, "player"),
+            UnlockableKind::Item => write!(f, "item"),
+            UnlockableKind::Perk => write!(f, "perk"),
+        }
+    }
+}
+
+
+/// Resource for the meta-progression of the game, which persists across runs.
+#[derive(Debug, Default, Deserialize, Reflect, Resource, Serialize)]
+#[serde(default)]
+pub struct Progression {
+    pub ambrosia: Ambrosia,
+    pub unlocked_players: Vec<String>,
+    pub unlocked_items: Vec<String>,
+    pub unlocked_perks: Vec<String>,
+    pub game_modes: Vec<GameModeProgression>,
+}
+
+impl Progression {
+    /// Initializes the resource in the app.
+    pub fn initialize(app: &mut App) {
+        let args = app.world().resource::<Args>();
+        app.insert_resource(
+            Persistent::<Progression>::builder()
+                .name("progression")
+                .format(PROGRESSION_STORAGE_FORMAT)
+                .path({
+                    #[cfg(feature = "native")]
+                    {
+                        args.data_directory.join("progression.toml")
+                    }
+                    #[cfg(feature = "wasm")]
+                    {
+                        args.data_directory.join("progression")
+                    }
+                })
+                .default(Progression::default())
+                .revertible(true)
+                .build()
+                .unwrap_or_else(|_| panic!("fatal: unable to initialize the progression")),
+        );
+    }
+}
+
+impl Progression {
+    /// Gets the identifiers of the unlocked content of a kind.
+    pub fn unlocked(&self, kind: UnlockableKind) -> &Vec<String> {
+        match kind {
+            UnlockableKind::Player => &self.unlocked_players,
+            UnlockableKind::Item => &self.unlocked_items,
+            UnlockableKind::Perk => &self.unlocked_perks,
+        }
+    }
+
+    /// Gets the identifiers of the unlocked content of a kind mutably.
+    pub fn unlocked_mut(&mut self, kind: UnlockableKind) -> &mut Vec<String> {
+        match kind {
+            UnlockableKind::Player => &mut self.unlocked_players,
+            UnlockableKind::Item => &mut self.unlocked_items,
+            UnlockableKind::Perk => &mut self.unlocked_perks,
+        }
+    }
+
+    /// Gets whether a content is unlocked.
+    pub fn is_unlocked(&self, kind: UnlockableKind, id: &str) -> bool {
+        self.unlocked(kind).iter().any(|unlocked_id| unlocked_id == id)
+    }
+
+    /// Gets whether a content with an optional unlock cost is available.
+    pub fn is_available(
+        &self,
+        kind: UnlockableKind,
+        id: &str,
+        unlock_cost: Option<Ambrosia>,
+    ) -> bool {
+        unlock_cost.is_none() || self.is_unlocked(kind, id)
+    }
+}
+
+impl Progression {
+    /// Gets the highest difficulty won in a game mode, or zero if the game mode is never won.
+    pub fn highest_won_difficulty(&self, game_mode_id: &str) -> u8 {
+        self.game_modes
+            .iter()
+            .find(|game_mode| game_mode.id == game_mode_id)
+            .map(|game_mode| game_mode.highest_won_difficulty)
+            .unwrap_or(0)
+    }
+
+    /// Gets whether a difficulty of a game mode is unlocked.
+    ///
+    /// First difficulty is always unlocked and each difficulty is unlocked by winning the
+    /// previous one.
+    pub fn is_difficulty_unlocked(&self, game_mode_id: &str, difficulty: u8) -> bool {
+        difficulty <= self.highest_won_difficulty(game_mode_id).saturating_add(1)
+    }
+}
+
+impl Progression {
+    /// Earns ambrosia.
+    pub fn earn(&mut self, amount: Ambrosia) {
+        self.ambrosia.0 = self.ambrosia.0.saturating_add(amount.0);
+    }
+
+    /// Unlocks a content by spending ambrosia.
+    pub fn unlock(&mut self, kind: UnlockableKind, id: &str, cost: Ambrosia) -> UnlockStatus {
+        if self.is_unlocked(kind, id) {
+            return UnlockStatus::AlreadyUnlocked;
+        }
+        if self.ambrosia < cost {
+            return UnlockStatus::NotEnoughAmbrosia;
+        }
+
+        self.ambrosia.0 -= cost.0;
+        self.unlocked_mut(kind).push(id.to_owned());
+
+        UnlockStatus::Unlocked
+    }
+
+    /// Records winning a difficulty of a game mode, returning whether a new difficulty is won.
+    pub fn win_difficulty(&mut self, game_mode_id: &str, difficulty: u8) -> bool {
+        match self.game_modes.iter_mut().find(|game_mode| game_mode.id == game_mode_id) {
+            Some(game_mode) => {
+                if game_mode.highest_won_difficulty >= difficulty {
+                    return false;
+                }
+                game_mode.highest_won_difficulty = difficulty;
+            },
+            None => {
+                self.game_modes.push(GameModeProgression {
+                    id: game_mode_id.to_owned(),
+                    highest_won_difficulty: difficulty,
+                });
+            },
+        }
+        true
+    }
+
+    /// Locks a content without refunding ambrosia.
+    pub fn lock(&mut self, kind: UnlockableKind, id: &str) -> bool {
+        let unlocked = self.unlocked_mut(kind);
+        let length_before = unlocked.len();
+        unlocked.retain(|unlocked_id| unlocked_id != id);
+        unlocked.len() != length_before
+    }
+}
+
+
+/// Container for the meta-progression of a game mode.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct GameModeProgression {
+    pub id: String,
+    pub highest_won_difficulty: u8,
+}
+
+
+/// Status of unlocking a content.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnlockStatus {
+    AlreadyUnlocked,
+    NotEnoughAmbrosia,
+    Unlocked,
+}
@@ -0,0 +1,163 @@
+use crate::{
+    prelude::*,
+    progression::{
+        commands::*,
+        constants::*,
+    },
+};
+
+
+/// Applies the progression console commands.
+pub fn apply_progression_command(
+    mut progression: ResMut<Persistent<Progression>>,
+    player_registry: Res<PlayerRegistry>,
+    item_registry: Res<ItemRegistry>,
+    perk_registry: Res<PerkRegistry>,
+    mut unlocked_event_writer: EventWriter<UnlockedEvent>,
+    mut command: ConsoleCommand<ProgressionCommand>,
+) {
+    if let Some(Ok(ProgressionCommand { subcommand })) = command.take() {
+        match subcommand {
+            ProgressionCommands::Show => {
+                reply!(command, "{}", progression.ambrosia);
+                for game_mode in progression.game_modes.iter() {
+                    reply!(
+                        command,
+                        "    - highest won difficulty in {:?} mode: {}",
+                        game_mode.id,
+                        game_mode.highest_won_difficulty,
+                    );
+                }
+                for kind in UnlockableKind::iter() {
+                    let unlocked = progression.unlocked(kind);
+                    if unlocked.is_empty() {
+                        reply!(command, "    - unlocked {}s: none", kind);
+                    } else {
+                        reply!(command, "    - unlocked {}s: {}", kind, unlocked.join(", "));
+                    }
+                }
+            },
+            ProgressionCommands::Earn { amount } => {
+                progression.update(|progression| progression.earn(Ambrosia(amount))).ok();
+                reply!(command, "Earned.");
+            },
+            ProgressionCommands::Unlock { kind, id } => {
+                let unlock_cost = match kind {
+                    UnlockableKind::Player => {
+                        player_registry.find_player(&id).map(|(mythology_index, player_index)| {
+                            player_registry[mythology_index][player_index].unlock_cost
+                        })
+                    },
+                    UnlockableKind::Item => {
+                        item_registry.find_item_by_id(&id).map(|item| item.unlock_cost)
+                    },
+                    UnlockableKind::Perk => {
+                        perk_registry.find_perk_by_id(&id).map(|perk| perk.unlock_cost)
+                    },
+                };
+                match unlock_cost {
+                    None => {
+                        reply!(command, "Failed to unlock {:?} {} as it doesn't exist.", id, kind);
+                    },
+                    Some(None) => {
+                        reply!(
+                            command,
+                            "Failed to unlock {:?} {} as it's not unlockable.",
+                            id,
+                            kind
+                        );
+                    },
+                    Some(Some(_)) => {
+                        let mut status = UnlockStatus::AlreadyUnlocked;
+                        progression
+                            .update(|progression| {
+                                status = progression.unlock(kind, &id, Ambrosia(0));
+                            })
+                            .ok();
+                        match status {
+                            UnlockStatus::AlreadyUnlocked => {
+                                reply!(command, "{:?} {} is already unlocked.", id, kind);
+                            },
+                            _ => {
+                                unlocked_event_writer.send(UnlockedEvent { kind, id });
+                                reply!(command, "Unlocked.");
+                            },
+                        }
+                    },
+                }
+            },
+            ProgressionCommands::Lock { kind, id } => {
+                let mut locked = false;
+                progression
+                    .update(|progression| {
+                        locked = progression.lock(kind, &id);
+                    })
+                    .ok();
+                if locked {
+                    reply!(command, "Locked.");
+                } else {
+                    reply!(command, "{:?} {} is not unlocked.", id, kind);
+                }
+            },
+            ProgressionCommands::Reset => {
+                progression.update(|progression| *progression = Progression::default()).ok();
+                reply!(command, "Reset.");
+            },
+        }
+        reply!(command, "");
+    }
+}
+
+
+/// Awards ambrosia at the end of the run.
+pub fn award_ambrosia(
+    primary_player_query: Query<&Level, With<PrimaryPlayer>>,
+    game_result: Res<GameResult>,
+    mut progression: ResMut<Persistent<Progression>>,
+    mut ambrosia_earned_event_writer: EventWriter<AmbrosiaEarnedEvent>,
+) {
+    let levels_gained =
+        primary_player_query.get_single().map(|level| (level.get() as u64) - 1).unwrap_or(0);
+
+    let mut amount = Ambrosia(AMBROSIA_PER_LEVEL.0 * levels_gained);
+    if *game_result == GameResult::Won {
+        amount.0 += AMBROSIA_FOR_WINNING.0;
+    }
+
+    if amount.0 == 0 {
+        return;
+    }
+
+    log::info!("earned {} at the end of the run", amount);
+    progression.update(|progression| progression.earn(amount)).ok();
+    ambrosia_earned_event_writer.send(AmbrosiaEarnedEvent { amount });
+}
+
+
+/// Records the won difficulty of the selected game mode at the end of the run.
+pub fn record_won_difficulty(
+    game_result: Res<GameResult>,
+    selected_game_mode_id: Res<SelectedGameModeId>,
+    selected_difficulty: Res<SelectedDifficulty>,
+    mut progression: ResMut<Persistent<Progression>>,
+) {
+    if *game_result != GameResult::Won {
+        return;
+    }
+
+    let mut newly_won = false;
+    progression
+        .update(|progression| {
+            newly_won =
+                progression.win_difficulty(&selected_game_mode_id, selected_difficulty.get());
+        })
+        .ok();
+
+    if newly_won {
+        log::info!(
+            "won {} of {:?} mode for the first time",
+            *selected_difficulty,
+            selected_game_mode_id.0
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+
+/// Controls the luck of the players.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "luck")]
+#[command(disable_help_flag = true)]
+pub struct LuckCommand {
+    #[clap(subcommand)]
+    pub subcommand: LuckCommands,
+}
+
+/// Luck commands.
+#[derive(Debug, Subcommand)]
+pub enum LuckCommands {
+    /// Shows the luck of the players.
+    Show,
+    /// Sets the luck of the players, excluding the luck provided by their items.
+    #[clap(arg_required_else_help = true, allow_negative_numbers = true)]
+    Set { luck: f32 },
+    /// Shows the effective odds of selecting entries with the luck of the players.
+    Odds {
+        #[clap(subcommand)]
+        subcommand: LuckOddsCommands,
+    },
+}
+
+/// Luck odds commands.
+#[derive(Debug, Subcommand)]
+pub enum LuckOddsCommands {
+    /// Shows the effective odds of perks in the level up screen.
+    Perks,
+    /// Shows the effective odds of items in the market.
+    Items,
+}
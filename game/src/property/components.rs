@@ -1,4 +1,7 @@
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    property::constants::*,
+};
 
 
 /// Component for damage.
@@ -16,6 +19,38 @@ pub struct DodgeChance(pub f32);
 pub struct Health(pub f32);
 
 
+/// Component for luck.
+#[derive(Clone, Copy, Component, Debug, Default, Deref, DerefMut, Reflect)]
+pub struct Luck(pub f32);
+
+impl Luck {
+    /// Biases the commonness of an entry toward the commonness of the most common entry.
+    ///
+    /// Positive luck makes rarer entries more likely to be selected,
+    /// and luck of [LUCK_FOR_EQUAL_ODDS] makes all entries equally likely.
+    /// Negative luck makes rarer entries even less likely to be selected.
+    pub fn bias(&self, commonness: u64, max_commonness: u64) -> u64 {
+        if commonness == 0 || commonness >= max_commonness {
+            return commonness;
+        }
+
+        let exponent = (self.0 / LUCK_FOR_EQUAL_ODDS).clamp(-1.00, 1.00) as f64;
+        let ratio = (max_commonness as f64) / (commonness as f64);
+
+        ((commonness as f64) * ratio.powf(exponent)).round().max(1.00) as u64
+    }
+
+    /// Biases the commonness of all entries toward the commonness of the most common entry.
+    pub fn apply<'a>(&self, commonnesses: impl IntoIterator<Item = &'a mut u64>) {
+        let mut commonnesses = commonnesses.into_iter().collect::<Vec<_>>();
+        let max_commonness = commonnesses.iter().map(|commonness| **commonness).max().unwrap_or(0);
+        for commonness in commonnesses.iter_mut() {
+            **commonness = self.bias(**commonness, max_commonness);
+        }
+    }
+}
+
+
 /// Component for experience point pickup range.
 #[derive(Clone, Copy, Component, Debug, Deref, DerefMut, Reflect)]
 pub struct PickupRange(pub f32);
@@ -0,0 +1,2 @@
+/// Amount of luck which makes all entries equally likely to be selected.
+pub const LUCK_FOR_EQUAL_ODDS: f32 = 100.00;
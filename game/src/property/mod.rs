@@ -1,3 +1,6 @@
+pub mod commands;
 pub mod components;
+pub mod constants;
 pub mod plugin;
 pub mod systems;
+pub mod utils;
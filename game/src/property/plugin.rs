@@ -1,6 +1,9 @@
 use crate::{
     prelude::*,
-    property::systems::*,
+    property::{
+        commands::*,
+        systems::*,
+    },
 };
 
 /// Plugin for managing the properties of game objects.
@@ -17,6 +20,10 @@ impl Plugin for PropertyPlugin {
         app.register_type::<Speed>();
         app.register_type::<SpeedMultiplier>();
         app.register_type::<HpRegeneration>();
+        app.register_type::<Luck>();
+
+        // Add console commands.
+        app.add_console_command::<LuckCommand, _>(apply_luck_command);
 
         // Add systems.
         app.add_systems(PreUpdate, hp_regeneration.in_set(GameplaySystems::Property));
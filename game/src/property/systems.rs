@@ -1,16 +1,152 @@
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    property::commands::*,
+};
+
+
+/// Applies the luck console commands.
+pub fn apply_luck_command(
+    mut player_queries: ParamSet<(
+        Query<(&Luck, Option<&Children>), With<Player>>,
+        Query<&mut Luck, With<Player>>,
+    )>,
+    item_query: Query<&Luck, (With<Item>, Without<Player>)>,
+    perk_registry: Res<PerkRegistry>,
+    obtained_perks: Res<ObtainedPerks>,
+    level_up_screen_state: Option<Res<LevelUpScreenState>>,
+    item_registry: Res<ItemRegistry>,
+    market_configuration: Res<MarketConfiguration>,
+    progression: Res<Persistent<Progression>>,
+    mut command: ConsoleCommand<LuckCommand>,
+) {
+    if let Some(Ok(LuckCommand { subcommand })) = command.take() {
+        match subcommand {
+            LuckCommands::Show => {
+                let player_query = player_queries.p0();
+                if player_query.is_empty() {
+                    reply!(command, "No players.");
+                } else {
+                    let luck = utils::property::find_luck_of_players(&player_query, &item_query);
+                    reply!(command, "{:.2}", luck.0);
+                }
+            },
+            LuckCommands::Set { luck } => {
+                let mut player_query = player_queries.p1();
+                if player_query.is_empty() {
+                    reply!(command, "No players.");
+                } else {
+                    for mut player_luck in player_query.iter_mut() {
+                        player_luck.0 = luck;
+                    }
+                    reply!(command, "Set.");
+                }
+            },
+            LuckCommands::Odds { subcommand } => {
+                let luck = utils::property::find_luck_of_players(&player_queries.p0(), &item_query);
+
+                let (kind, mut entries) = match subcommand {
+                    LuckOddsCommands::Perks => {
+                        let mut entries = Vec::new();
+                        for entry in perk_registry.iter() {
+                            let perk_id = entry.perk.id();
+                            if !entry.perk.is_available(&progression) {
+                                continue;
+                            }
+                            if let Some(level_up_screen_state) = &level_up_screen_state {
+                                if level_up_screen_state.is_banished(&perk_id) {
+                                    continue;
+                                }
+                            }
+
+                            let commonness = entry.perk.commonness;
+                            if commonness != 0
+                                && perk_registry.can_be_offered(&entry.perk, &obtained_perks)
+                            {
+                                entries.push((perk_id, commonness, commonness));
+                            }
+                        }
+                        ("Perk", entries)
+                    },
+                    LuckOddsCommands::Items => {
+                        let mut entries = Vec::new();
+                        for entry in item_registry.iter() {
+                            if !entry.item.is_available(&progression) {
+                                continue;
+                            }
+
+                            let commonness = market_configuration.commonness_of(&entry.item);
+                            if commonness != 0 {
+                                entries.push((entry.item.id(), commonness, commonness));
+                            }
+                        }
+                        ("Item", entries)
+                    },
+                };
+                if entries.is_empty() {
+                    reply!(command, "No entries are eligible to be selected.");
+                } else {
+                    luck.apply(entries.iter_mut().map(|(_, _, lucky_commonness)| lucky_commonness));
+                    entries.sort_by(|(id1, _, commonness1), (id2, _, commonness)| {
+                        if commonness1 == commonness {
+                            id1.cmp(id2)
+                        } else {
+                            commonness1.cmp(commonness).reverse()
+                        }
+                    });
+
+                    let total_commonness =
+                        entries.iter().map(|(_, commonness, _)| commonness).sum::<u64>();
+                    let total_lucky_commonness = entries
+                        .iter()
+                        .map(|(_, _, lucky_commonness)| lucky_commonness)
+                        .sum::<u64>();
+
+                    let mut odds_table = Table::new();
+                    odds_table.add_row(row![c -> kind, c -> "Base", c -> "Luck", c -> "Effective"]);
+                    for (id, commonness, lucky_commonness) in entries.iter() {
+                        let probability = (*commonness as f64) / (total_commonness as f64);
+                        let lucky_probability =
+                            (*lucky_commonness as f64) / (total_lucky_commonness as f64);
+                        odds_table.add_row(row![
+                            l -> id,
+                            r -> format!("{:.4}%", probability * 100.00),
+                            r -> format!("{:+.4}%", (lucky_probability - probability) * 100.00),
+                            r -> format!("{:.4}%", lucky_probability * 100.00),
+                        ]);
+                    }
+
+                    reply!(command, "Luck: {:.2}", luck.0);
+                    for line in odds_table.to_string().trim_end().lines() {
+                        reply!(command, "{}", line);
+                    }
+                }
+            },
+        }
+        reply!(command, "");
+    }
+}
+
 
 /// Regenerates the health of entities that have health regeneration.
 pub fn hp_regeneration(
     time: Res<Time>,
-    mut query: Query<(&HpRegeneration, &Health, &mut RemainingHealth)>,
+    mut query: Query<(Entity, &HpRegeneration, &Health, &mut RemainingHealth)>,
+    mut health_regenerated_event_writer: EventWriter<HealthRegeneratedEvent>,
 ) {
-    for (hp_regeneration, health, mut remaining_health) in query.iter_mut() {
+    for (entity, hp_regeneration, health, mut remaining_health) in query.iter_mut() {
+        let previous_remaining_health = remaining_health.0;
+
         let health_to_regenerate = hp_regeneration.0.max(0.00) * time.delta_seconds();
         remaining_health.0 += health_to_regenerate;
 
         if remaining_health.0 > health.0 {
             remaining_health.0 = health.0;
         }
+
+        let regenerated_health = remaining_health.0 - previous_remaining_health;
+        if regenerated_health > 0.00 {
+            health_regenerated_event_writer
+                .send(HealthRegeneratedEvent { entity, amount: regenerated_health });
+        }
     }
 }
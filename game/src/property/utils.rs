@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+
+/// Finds the luck of the luckiest player, including the luck provided by the items of the players.
+pub fn find_luck_of_players(
+    player_query: &Query<(&Luck, Option<&Children>), With<Player>>,
+    item_query: &Query<&Luck, (With<Item>, Without<Player>)>,
+) -> Luck {
+    player_query
+        .iter()
+        .map(|(player_luck, player_children)| {
+            let item_luck = player_children
+                .map(|children| {
+                    children
+                        .iter()
+                        .filter_map(|&child| item_query.get(child).ok())
+                        .map(|item_luck| item_luck.0)
+                        .sum::<f32>()
+                })
+                .unwrap_or(0.00);
+            Luck(player_luck.0 + item_luck)
+        })
+        .max_by(|luck1, luck2| luck1.0.partial_cmp(&luck2.0).unwrap_or(Ordering::Equal))
+        .unwrap_or_default()
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+
+
+/// Tag component for the container of achievement toasts.
+#[derive(Component, Debug, Reflect)]
+pub struct AchievementToasts;
+
+
+/// Component for achievement toasts.
+#[derive(Component, Debug, Reflect)]
+pub struct AchievementToast {
+    /// Timer until the toast disappears.
+    pub timer: Timer,
+}
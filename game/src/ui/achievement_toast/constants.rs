@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+
+/// Duration of achievement toasts in seconds.
+pub const TOAST_DURATION: f32 = 4.00;
+
+/// Background color of achievement toasts.
+pub const TOAST_BACKGROUND_COLOR: Color = Color::srgba(0.10, 0.10, 0.10, 0.90);
+
+/// Border color of achievement toasts.
+pub const TOAST_BORDER_COLOR: Color = Color::srgb(0.85, 0.70, 0.20);
+
+
+/// Font size of the title of achievement toasts in pt.
+pub const TITLE_FONT_SIZE: f32 = 18.0;
+
+/// Color of the title of achievement toasts.
+pub const TITLE_COLOR: Color = Color::srgb(0.85, 0.70, 0.20);
+
+
+/// Font size of the name of achievement toasts in pt.
+pub const NAME_FONT_SIZE: f32 = 24.0;
+
+/// Color of the name of achievement toasts.
+pub const NAME_COLOR: Color = Color::WHITE;
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+
+/// Gets the localized text of the title of achievement toasts.
+pub fn title() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "achievement-toast-title",
+        args: smallvec![],
+        fallback: "Achievement Unlocked".into(),
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    prelude::*,
+    ui::achievement_toast::systems::*,
+};
+
+/// Plugin for managing the achievement toasts.
+pub struct AchievementToastPlugin;
+
+impl Plugin for AchievementToastPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<AchievementToasts>();
+        app.register_type::<AchievementToast>();
+
+        // Setup localization.
+        app.world_mut().resource_mut::<LocaleAssets>().push("ui/achievement_toast.ftl");
+
+        // Add systems.
+        app.add_systems(Startup, spawn_achievement_toasts);
+        app.add_systems(
+            Update,
+            (
+                show_achievement_toasts.run_if(on_event::<AchievementUnlockedEvent>()),
+                despawn_expired_achievement_toasts,
+            ),
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+
+/// Gets the style of the container of achievement toasts.
+pub fn container() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        top: Val::Percent(2.00),
+        right: Val::Percent(1.50),
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::End,
+        row_gap: Val::Px(10.00),
+        ..default()
+    }
+}
+
+
+/// Gets the style of achievement toasts.
+pub fn toast() -> Style {
+    Style {
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::Start,
+        min_width: Val::Px(280.00),
+        padding: UiRect::all(Val::Px(10.00)),
+        border: UiRect::all(Val::Px(2.00)),
+        row_gap: Val::Px(4.00),
+        ..default()
+    }
+}
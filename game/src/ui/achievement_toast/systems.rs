@@ -0,0 +1,99 @@
+use crate::{
+    prelude::*,
+    ui::achievement_toast::{
+        constants::*,
+        localization,
+        styles,
+    },
+};
+
+
+/// Spawns the container of achievement toasts.
+pub fn spawn_achievement_toasts(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Achievement Toasts"),
+        AchievementToasts,
+        NodeBundle { style: styles::container(), z_index: ZIndex::Global(10), ..default() },
+    ));
+}
+
+
+/// Shows a toast for each unlocked achievement.
+pub fn show_achievement_toasts(
+    mut commands: Commands,
+    container_query: Query<Entity, With<AchievementToasts>>,
+    mut achievement_unlocked_event_reader: EventReader<AchievementUnlockedEvent>,
+    asset_server: Res<AssetServer>,
+    achievement_registry: Res<AchievementRegistry>,
+    localization: Res<Localization>,
+) {
+    let container = match container_query.get_single() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+
+    for event in achievement_unlocked_event_reader.read() {
+        let achievement = match achievement_registry.find_achievement_by_id(&event.id) {
+            Some(achievement) => achievement,
+            None => continue,
+        };
+
+        let title_text = localization::title();
+        let name_text = achievement.name();
+
+        let toast = commands
+            .spawn((
+                Name::new(format!("Achievement Toast [{}]", event.id)),
+                AchievementToast { timer: Timer::from_seconds(TOAST_DURATION, TimerMode::Once) },
+                NodeBundle {
+                    style: styles::toast(),
+                    background_color: TOAST_BACKGROUND_COLOR.into(),
+                    border_color: TOAST_BORDER_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Name::new("Title"),
+                    TextBundle::from_section(
+                        title_text.get(&localization),
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: TITLE_FONT_SIZE,
+                            color: TITLE_COLOR,
+                        },
+                    ),
+                    title_text,
+                ));
+                parent.spawn((
+                    Name::new("Name"),
+                    TextBundle::from_section(
+                        name_text.get(&localization),
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: NAME_FONT_SIZE,
+                            color: NAME_COLOR,
+                        },
+                    ),
+                    name_text,
+                ));
+            })
+            .id();
+
+        commands.entity(container).add_child(toast);
+    }
+}
+
+/// Despawns achievement toasts once their timers finish.
+pub fn despawn_expired_achievement_toasts(
+    mut commands: Commands,
+    mut toast_query: Query<(Entity, &mut AchievementToast)>,
+    time: Res<Time<Real>>,
+) {
+    for (toast_entity, mut toast) in toast_query.iter_mut() {
+        toast.timer.tick(time.delta());
+        if toast.timer.finished() {
+            commands.entity(toast_entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+
+/// Tag component for the achievements screen.
+#[derive(Component, Debug, Reflect)]
+pub struct AchievementsScreen;
+
+
+/// Tag component for the back button in the achievements screen.
+#[derive(Component, Debug, Reflect)]
+pub struct AchievementsScreenBackButton;
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+
+/// Font size of the title in the achievements screen in pt.
+pub const TITLE_FONT_SIZE: f32 = 64.0;
+
+/// Font size of the summary in the achievements screen in pt.
+pub const SUMMARY_FONT_SIZE: f32 = 32.0;
+
+
+/// Font size of the achievement names in the achievements screen in pt.
+pub const ACHIEVEMENT_NAME_FONT_SIZE: f32 = 24.0;
+
+/// Font size of the achievement descriptions in the achievements screen in pt.
+pub const ACHIEVEMENT_DESCRIPTION_FONT_SIZE: f32 = 18.0;
+
+/// Color of the texts of unlocked achievements in the achievements screen.
+pub const UNLOCKED_ACHIEVEMENT_COLOR: Color = Color::WHITE;
+
+/// Color of the texts of locked achievements in the achievements screen.
+pub const LOCKED_ACHIEVEMENT_COLOR: Color = Color::srgb(0.50, 0.50, 0.50);
+
+
+/// Font size of the buttons in the achievements screen in pt.
+pub const BUTTON_FONT_SIZE: f32 = 32.0;
@@ -0,0 +1,64 @@
+use crate::prelude::*;
+
+
+/// Gets the localized text of the title.
+pub fn title() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "achievements-screen-title",
+        args: smallvec![],
+        fallback: "Achievements".into(),
+    }
+}
+
+/// Gets the localized text of the summary.
+pub fn summary(unlocked: usize, total: usize) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "achievements-screen-summary",
+        args: smallvec![
+            ("unlocked", format_smolstr!("{}", unlocked)),
+            ("total", format_smolstr!("{}", total)),
+        ],
+        fallback: format!("Unlocked: {} / {}", unlocked, total).into(),
+    }
+}
+
+
+/// Gets the localized text of the status of unlocked achievements.
+pub fn unlocked_status() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "achievements-screen-unlocked-status",
+        args: smallvec![],
+        fallback: "Unlocked".into(),
+    }
+}
+
+/// Gets the localized text of the status of locked achievements.
+pub fn locked_status() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "achievements-screen-locked-status",
+        args: smallvec![],
+        fallback: "Locked".into(),
+    }
+}
+
+/// Gets the localized text of the status of locked achievements with progress.
+pub fn progress_status(current: u64, target: u64) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "achievements-screen-progress-status",
+        args: smallvec![
+            ("current", format_smolstr!("{}", current)),
+            ("target", format_smolstr!("{}", target)),
+        ],
+        fallback: format!("{} / {}", current, target).into(),
+    }
+}
+
+
+/// Gets the localized text of the back button.
+pub fn back_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "achievements-screen-back-button",
+        args: smallvec![],
+        fallback: "Back".into(),
+    }
+}
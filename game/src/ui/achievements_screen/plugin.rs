@@ -0,0 +1,24 @@
+use crate::{
+    prelude::*,
+    ui::achievements_screen::systems::*,
+};
+
+/// Plugin for managing the achievements screen.
+pub struct AchievementsScreenPlugin;
+
+impl Plugin for AchievementsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<AchievementsScreen>();
+        app.register_type::<AchievementsScreenBackButton>();
+
+        // Setup localization.
+        app.world_mut().resource_mut::<LocaleAssets>().push("ui/achievements_screen.ftl");
+
+        // Add systems.
+        app.add_systems(OnEnter(AppState::AchievementsScreen), spawn_achievements_screen);
+        app.add_systems(Update, navigation.in_set(AchievementsScreenSystems));
+        app.add_systems(Update, back_button_interaction.in_set(AchievementsScreenSystems));
+        app.add_systems(OnExit(AppState::AchievementsScreen), despawn_achievements_screen);
+    }
+}
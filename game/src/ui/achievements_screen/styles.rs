@@ -0,0 +1,62 @@
+use crate::prelude::*;
+
+
+/// Gets the style of the root container of the achievements screen.
+pub fn root() -> Style {
+    Style {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Percent(1.50),
+        ..default()
+    }
+}
+
+
+/// Gets the style of the texts in the achievements screen.
+pub fn text() -> Style {
+    Style { justify_content: JustifyContent::Center, align_items: AlignItems::Center, ..default() }
+}
+
+
+/// Gets the style of the achievements container in the achievements screen.
+pub fn achievements_container() -> Style {
+    Style {
+        width: Val::Percent(60.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(8.00),
+        ..default()
+    }
+}
+
+/// Gets the style of the achievements in the achievements screen.
+pub fn achievement() -> Style {
+    Style {
+        width: Val::Percent(100.00),
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Row,
+        ..default()
+    }
+}
+
+/// Gets the style of the name and the description of achievements in the achievements screen.
+pub fn achievement_details() -> Style {
+    Style { flex_direction: FlexDirection::Column, row_gap: Val::Px(2.00), ..default() }
+}
+
+
+/// Gets the style of the buttons in the achievements screen.
+pub fn button() -> Style {
+    Style {
+        width: Val::Percent(16.00),
+        height: Val::Percent(9.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    prelude::*,
+    ui::achievements_screen::{
+        constants::*,
+        localization,
+        styles,
+    },
+};
+
+
+/// Spawns the achievements screen.
+pub fn spawn_achievements_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    achievements_screen_action_input_map: Res<InputMap<AchievementsScreenAction>>,
+    achievement_registry: Res<AchievementRegistry>,
+    achievements: Res<Persistent<Achievements>>,
+    localization: Res<Localization>,
+) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    let spawn_text =
+        |commands: &mut Commands, name: &str, text: LocalizedText, size: f32, color| {
+            commands
+                .spawn((
+                    Name::new(name.to_owned()),
+                    TextBundle {
+                        style: styles::text(),
+                        text: Text::from_section(
+                            text.get(&localization),
+                            TextStyle { font: font.clone(), font_size: size, color },
+                        ),
+                        ..default()
+                    },
+                    text,
+                ))
+                .id()
+        };
+
+    let mut number_of_unlocked_achievements = 0;
+    let mut achievement_entities = Vec::new();
+    for entry in achievement_registry.iter() {
+        let achievement = &entry.achievement;
+        let achievement_id = achievement.id();
+
+        let is_unlocked = achievements.is_unlocked(&achievement_id);
+        let (status, color) = if is_unlocked {
+            number_of_unlocked_achievements += 1;
+            (localization::unlocked_status(), UNLOCKED_ACHIEVEMENT_COLOR)
+        } else {
+            match achievements.progress(&achievement.condition) {
+                Some((current, target)) => {
+                    (localization::progress_status(current, target), LOCKED_ACHIEVEMENT_COLOR)
+                },
+                None => (localization::locked_status(), LOCKED_ACHIEVEMENT_COLOR),
+            }
+        };
+
+        let name = spawn_text(
+            &mut commands,
+            "Name",
+            achievement.name(),
+            ACHIEVEMENT_NAME_FONT_SIZE,
+            color,
+        );
+        let description = spawn_text(
+            &mut commands,
+            "Description",
+            achievement.description(),
+            ACHIEVEMENT_DESCRIPTION_FONT_SIZE,
+            color,
+        );
+        let status = spawn_text(&mut commands, "Status", status, ACHIEVEMENT_NAME_FONT_SIZE, color);
+
+        let details = commands
+            .spawn((
+                Name::new("Details"),
+                NodeBundle { style: styles::achievement_details(), ..default() },
+            ))
+            .add_child(name)
+            .add_child(description)
+            .id();
+
+        let achievement_entity = commands
+            .spawn((
+                Name::new(format!("Achievement [{}]", achievement_id)),
+                NodeBundle { style: styles::achievement(), ..default() },
+            ))
+            .add_child(details)
+            .add_child(status)
+            .id();
+
+        achievement_entities.push(achievement_entity);
+    }
+
+    let achievements_container = commands
+        .spawn((
+            Name::new("Achievements"),
+            NodeBundle { style: styles::achievements_container(), ..default() },
+        ))
+        .push_children(&achievement_entities)
+        .id();
+
+    let title =
+        spawn_text(&mut commands, "Title", localization::title(), TITLE_FONT_SIZE, Color::WHITE);
+    let summary = spawn_text(
+        &mut commands,
+        "Summary",
+        localization::summary(
+            number_of_unlocked_achievements,
+            achievement_registry.number_of_achievements(),
+        ),
+        SUMMARY_FONT_SIZE,
+        Color::WHITE,
+    );
+
+    let button_style = styles::button();
+    let button_colors = WidgetColors::button();
+    let button_font_size = BUTTON_FONT_SIZE;
+
+    let back_button = Widget::button(
+        &mut commands,
+        (
+            Name::new("Back Button"),
+            AchievementsScreenBackButton,
+            Widget::default().selected(),
+            WidgetSelected::now(),
+        ),
+        &button_style,
+        button_colors,
+        &font,
+        button_font_size,
+        localization::back_button(),
+        &localization,
+    );
+
+    let pressed = ActionData { state: ButtonState::Pressed, ..default() };
+    let mut action_state = ActionState::default();
+
+    action_state.set_action_data(AchievementsScreenAction::Back, pressed.clone());
+    action_state.set_action_data(AchievementsScreenAction::Select, pressed);
+
+    commands
+        .spawn((
+            Name::new("Achievements Screen"),
+            AchievementsScreen,
+            InputManagerBundle::<AchievementsScreenAction> {
+                action_state,
+                input_map: achievements_screen_action_input_map.clone(),
+            },
+            NodeBundle { style: styles::root(), ..default() },
+        ))
+        .add_child(title)
+        .add_child(summary)
+        .add_child(achievements_container)
+        .add_child(back_button);
+}
+
+/// Despawns the achievements screen.
+pub fn despawn_achievements_screen(
+    mut commands: Commands,
+    achievements_screen_query: Query<Entity, With<AchievementsScreen>>,
+) {
+    if let Ok(entity) = achievements_screen_query.get_single() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+
+/// Navigates the achievements screen using achievements screen actions.
+pub fn navigation(
+    mut achievements_screen_query: Query<
+        &ActionState<AchievementsScreenAction>,
+        With<AchievementsScreen>,
+    >,
+    mut selected_widget_query: Query<&mut Widget, With<WidgetSelected>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let achievements_screen_action_state = match achievements_screen_query.get_single_mut() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+
+    if achievements_screen_action_state.just_pressed(&AchievementsScreenAction::Back) {
+        next_app_state.set(AppState::MainMenu);
+        return;
+    }
+
+    if achievements_screen_action_state.just_pressed(&AchievementsScreenAction::Select) {
+        if let Ok(mut selected_widget) = selected_widget_query.get_single_mut() {
+            selected_widget.clicked = true;
+        }
+    }
+}
+
+
+/// Returns to the main menu.
+pub fn back_button_interaction(
+    mut back_button_query: Query<
+        &mut Widget,
+        (Changed<Widget>, With<AchievementsScreenBackButton>),
+    >,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok(mut button) = back_button_query.get_single_mut() {
+        button.on_click(|| {
+            next_app_state.set(AppState::MainMenu);
+        });
+    }
+}
@@ -4,3 +4,31 @@ use crate::prelude::*;
 /// Tag component for the game mode selection screen.
 #[derive(Component, Debug, Reflect)]
 pub struct GameModeSelectionScreen;
+
+
+/// Component for game mode buttons in the game mode selection screen.
+#[derive(Component, Debug, Reflect)]
+pub struct GameModeSelectionScreenGameModeButton {
+    /// Index of the game mode the button represents.
+    pub game_mode_index: SelectedGameModeIndex,
+    /// Difficulty of the game mode the button represents.
+    pub difficulty: SelectedDifficulty,
+}
+
+
+/// Tag component for the mutators container in the game mode selection screen.
+#[derive(Component, Debug, Reflect)]
+pub struct GameModeSelectionScreenMutatorsContainer;
+
+
+/// Component for mutator buttons in the game mode selection screen.
+#[derive(Component, Debug, Reflect)]
+pub struct GameModeSelectionScreenMutatorButton {
+    /// Mutator the button toggles.
+    pub mutator: Mutator,
+}
+
+
+/// Tag component for the back button in the game mode selection screen.
+#[derive(Component, Debug, Reflect)]
+pub struct GameModeSelectionScreenBackButton;
@@ -0,0 +1,5 @@
+/// Font size of the buttons in the game mode selection screen in pt.
+pub const BUTTON_FONT_SIZE: f32 = 32.0;
+
+/// Font size of the mutator buttons in the game mode selection screen in pt.
+pub const MUTATOR_BUTTON_FONT_SIZE: f32 = 18.0;
@@ -0,0 +1,63 @@
+use crate::prelude::*;
+
+
+/// Gets the localized text of the buttons of game modes with a single difficulty.
+pub fn game_mode_button(name: impl Display) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "game-mode-selection-screen-game-mode-button",
+        args: smallvec![("name", format_smolstr!("{}", name))],
+        fallback: format!("{}", name).into(),
+    }
+}
+
+/// Gets the localized text of the buttons of game modes with multiple difficulties.
+pub fn game_mode_difficulty_button(name: impl Display, difficulty: u8) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "game-mode-selection-screen-game-mode-difficulty-button",
+        args: smallvec![
+            ("name", format_smolstr!("{}", name)),
+            ("difficulty", format_smolstr!("{}", difficulty)),
+        ],
+        fallback: format!("{} - Difficulty {}", name, difficulty).into(),
+    }
+}
+
+/// Gets the localized text of the buttons of locked game mode difficulties.
+pub fn locked_game_mode_difficulty_button(name: impl Display, difficulty: u8) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "game-mode-selection-screen-locked-game-mode-difficulty-button",
+        args: smallvec![
+            ("name", format_smolstr!("{}", name)),
+            ("difficulty", format_smolstr!("{}", difficulty)),
+        ],
+        fallback: format!("{} - Difficulty {} (Locked)", name, difficulty).into(),
+    }
+}
+
+
+/// Gets the localized text of the mutator buttons.
+pub fn mutator_button(name: impl Display, is_enabled: bool) -> LocalizedText {
+    if is_enabled {
+        LocalizedText::Localized {
+            key: "game-mode-selection-screen-enabled-mutator-button",
+            args: smallvec![("name", format_smolstr!("{}", name))],
+            fallback: format!("{} (On)", name).into(),
+        }
+    } else {
+        LocalizedText::Localized {
+            key: "game-mode-selection-screen-disabled-mutator-button",
+            args: smallvec![("name", format_smolstr!("{}", name))],
+            fallback: format!("{} (Off)", name).into(),
+        }
+    }
+}
+
+
+/// Gets the localized text of the back button.
+pub fn back_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "game-mode-selection-screen-back-button",
+        args: smallvec![],
+        fallback: "Back".into(),
+    }
+}
@@ -1,3 +1,6 @@
 pub mod components;
+pub mod constants;
+pub mod localization;
 pub mod plugin;
+pub mod styles;
 pub mod systems;
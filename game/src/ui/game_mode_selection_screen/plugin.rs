@@ -10,12 +10,25 @@ impl Plugin for GameModeSelectionScreenPlugin {
     fn build(&self, app: &mut App) {
         // Register components.
         app.register_type::<GameModeSelectionScreen>();
+        app.register_type::<GameModeSelectionScreenGameModeButton>();
+        app.register_type::<GameModeSelectionScreenMutatorsContainer>();
+        app.register_type::<GameModeSelectionScreenMutatorButton>();
+        app.register_type::<GameModeSelectionScreenBackButton>();
+
+        // Setup localization.
+        app.world_mut().resource_mut::<LocaleAssets>().push("ui/game_mode_selection_screen.ftl");
 
         // Add systems.
         app.add_systems(
             OnEnter(AppState::GameModeSelectionScreen),
             spawn_game_mode_selection_screen,
         );
+        app.add_systems(Update, navigation.in_set(GameModeSelectionScreenSystems));
+        app.add_systems(
+            Update,
+            (game_mode_button_interaction, mutator_button_interaction, back_button_interaction)
+                .in_set(GameModeSelectionScreenSystems),
+        );
         app.add_systems(
             PostUpdate,
             game_mode_selected.in_set(GameModeSelectionScreenSystems).run_if(
@@ -32,7 +45,9 @@ impl Plugin for GameModeSelectionScreenPlugin {
         if args.start_in_game {
             app.add_systems(
                 OnEnter(AppState::GameModeSelectionScreen),
-                select_game_mode_when_starting_in_game.run_if(run_once()),
+                select_game_mode_when_starting_in_game
+                    .after(spawn_game_mode_selection_screen)
+                    .run_if(run_once()),
             );
         }
     }
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+
+
+/// Gets the style of the root container of the game mode selection screen.
+pub fn root() -> Style {
+    Style {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Percent(1.50),
+        ..default()
+    }
+}
+
+
+/// Gets the style of the game mode buttons in the game mode selection screen.
+pub fn game_mode_button() -> Style {
+    Style {
+        width: Val::Percent(32.00),
+        height: Val::Percent(9.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+/// Gets the style of the mutators container in the game mode selection screen.
+pub fn mutators_container() -> Style {
+    Style {
+        width: Val::Percent(100.00),
+        height: Val::Percent(7.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Row,
+        column_gap: Val::Percent(0.50),
+        ..default()
+    }
+}
+
+/// Gets the style of the mutator buttons in the game mode selection screen.
+pub fn mutator_button() -> Style {
+    Style {
+        width: Val::Percent(19.00),
+        height: Val::Percent(100.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+/// Gets the style of the buttons in the game mode selection screen.
+pub fn button() -> Style {
+    Style {
+        width: Val::Percent(16.00),
+        height: Val::Percent(9.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
@@ -1,24 +1,178 @@
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    ui::game_mode_selection_screen::{
+        constants::*,
+        localization,
+        styles,
+    },
+};
 
 
 /// Spawns the game mode selection screen.
 pub fn spawn_game_mode_selection_screen(
     mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_mode_selection_screen_action_input_map: Res<InputMap<GameModeSelectionScreenAction>>,
     game_mode_registry: Res<GameModeRegistry>,
+    progression: Res<Persistent<Progression>>,
+    selected_mutators: Res<SelectedMutators>,
+    localization: Res<Localization>,
 ) {
     if game_mode_registry.is_empty() {
         // TODO: Replace panic with a proper error communicated through the UI.
         panic!("no game modes are available");
     }
 
-    if game_mode_registry.len() == 1 {
-        let selected_game_mode_index = SelectedGameModeIndex(0);
-        commands.insert_resource(selected_game_mode_index);
+    commands.remove_resource::<SelectedGameModeIndex>();
+    commands.remove_resource::<SelectedDifficulty>();
+
+    if game_mode_registry.len() == 1
+        && game_mode_registry.iter().all(|entry| entry.game_mode.number_of_difficulties() <= 1)
+    {
+        commands.insert_resource(SelectedGameModeIndex(0));
+        commands.insert_resource(SelectedDifficulty::default());
         return;
     }
 
-    // TODO: Add support for multiple game modes and a nice game mode selection screen.
-    panic!("multiple game modes are not supported at the moment")
+    let game_mode_button_style = styles::game_mode_button();
+    let button_style = styles::button();
+    let button_colors = WidgetColors::button();
+    let button_font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let button_font_size = BUTTON_FONT_SIZE;
+
+    let mutator_button_style = styles::mutator_button();
+    let mutator_button_font_size = MUTATOR_BUTTON_FONT_SIZE;
+
+    let mut children = Vec::new();
+    let mut entities = Vec::new();
+    let mut first = true;
+
+    for (game_mode_index, entry) in game_mode_registry.iter().enumerate() {
+        let game_mode = &entry.game_mode;
+        let game_mode_id = game_mode.id();
+        let game_mode_name = game_mode.name().get(&localization);
+        let number_of_difficulties = game_mode.number_of_difficulties().max(1);
+
+        for difficulty in 1..=number_of_difficulties {
+            let is_unlocked = progression.is_difficulty_unlocked(&game_mode_id, difficulty);
+            let text = if number_of_difficulties == 1 {
+                localization::game_mode_button(&game_mode_name)
+            } else if is_unlocked {
+                localization::game_mode_difficulty_button(&game_mode_name, difficulty)
+            } else {
+                localization::locked_game_mode_difficulty_button(&game_mode_name, difficulty)
+            };
+
+            let game_mode_button = Widget::button(
+                &mut commands,
+                (
+                    Name::new(format!("Game Mode Button [{} {}]", game_mode_id, difficulty)),
+                    GameModeSelectionScreenGameModeButton {
+                        game_mode_index: SelectedGameModeIndex(game_mode_index),
+                        difficulty: SelectedDifficulty(NonZeroU8::new(difficulty).unwrap()),
+                    },
+                    Widget::default(),
+                ),
+                &game_mode_button_style,
+                button_colors,
+                &button_font,
+                button_font_size,
+                text,
+                &localization,
+            );
+
+            if !is_unlocked {
+                commands.entity(game_mode_button).insert(WidgetDisabled);
+            } else if first {
+                first = false;
+                commands
+                    .entity(game_mode_button)
+                    .insert((Widget::default().selected(), WidgetSelected::now()));
+            }
+
+            children.push(game_mode_button);
+            entities.push(game_mode_button);
+        }
+    }
+
+    let mut mutator_buttons = Vec::new();
+    for mutator in Mutator::iter() {
+        let mutator_button = Widget::button(
+            &mut commands,
+            (
+                Name::new(format!("Mutator Button [{}]", mutator.id())),
+                GameModeSelectionScreenMutatorButton { mutator },
+                Widget::default(),
+            ),
+            &mutator_button_style,
+            button_colors,
+            &button_font,
+            mutator_button_font_size,
+            localization::mutator_button(
+                mutator.name().get(&localization),
+                selected_mutators.is_enabled(mutator),
+            ),
+            &localization,
+        );
+        mutator_buttons.push(mutator_button);
+    }
+    entities.extend(mutator_buttons.iter().copied());
+
+    let mutators_container = commands
+        .spawn((
+            Name::new("Mutators"),
+            GameModeSelectionScreenMutatorsContainer,
+            NodeBundle { style: styles::mutators_container(), ..default() },
+        ))
+        .push_children(&mutator_buttons)
+        .id();
+    children.push(mutators_container);
+
+    let back_button = Widget::button(
+        &mut commands,
+        (Name::new("Back Button"), GameModeSelectionScreenBackButton, Widget::default()),
+        &button_style,
+        button_colors,
+        &button_font,
+        button_font_size,
+        localization::back_button(),
+        &localization,
+    );
+    if first {
+        commands.entity(back_button).insert((Widget::default().selected(), WidgetSelected::now()));
+    }
+    children.push(back_button);
+    entities.push(back_button);
+
+    for i in 0..entities.len() {
+        let up = if i != 0 { entities[i - 1] } else { entities[entities.len() - 1] };
+        let current = entities[i];
+        let down = if i != entities.len() - 1 { entities[i + 1] } else { entities[0] };
+
+        commands.entity(current).insert((WidgetUp(up), WidgetDown(down)));
+    }
+
+    let pressed = ActionData { state: ButtonState::Pressed, ..default() };
+    let mut action_state = ActionState::default();
+
+    action_state.set_action_data(GameModeSelectionScreenAction::Back, pressed.clone());
+    action_state.set_action_data(GameModeSelectionScreenAction::Up, pressed.clone());
+    action_state.set_action_data(GameModeSelectionScreenAction::Down, pressed.clone());
+    action_state.set_action_data(GameModeSelectionScreenAction::Select, pressed);
+
+    let mut game_mode_selection_screen = commands.spawn((
+        Name::new("Game Mode Selection Screen"),
+        GameModeSelectionScreen,
+        InputManagerBundle::<GameModeSelectionScreenAction> {
+            action_state,
+            input_map: game_mode_selection_screen_action_input_map.clone(),
+        },
+        NodeBundle { style: styles::root(), ..default() },
+    ));
+
+    for entity in children {
+        game_mode_selection_screen.add_child(entity);
+    }
 }
 
 /// Despawns the game mode selection screen.
@@ -32,6 +186,115 @@ pub fn despawn_game_mode_selection_screen(
 }
 
 
+/// Navigates the game mode selection screen using game mode selection screen actions.
+pub fn navigation(
+    mut commands: Commands,
+    mut game_mode_selection_screen_query: Query<
+        &ActionState<GameModeSelectionScreenAction>,
+        With<GameModeSelectionScreen>,
+    >,
+    mut selected_widget_query: Query<(&mut Widget, &WidgetUp, &WidgetDown), With<WidgetSelected>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let game_mode_selection_screen_action_state =
+        match game_mode_selection_screen_query.get_single_mut() {
+            Ok(query_result) => query_result,
+            Err(_) => return,
+        };
+    let (mut selected_widget, up_widget, down_widget) = match selected_widget_query.get_single_mut()
+    {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+
+    if game_mode_selection_screen_action_state.just_pressed(&GameModeSelectionScreenAction::Back) {
+        next_app_state.set(AppState::MainMenu);
+        return;
+    }
+
+    if game_mode_selection_screen_action_state.just_pressed(&GameModeSelectionScreenAction::Select)
+    {
+        selected_widget.clicked = true;
+        return;
+    }
+
+    let go_up =
+        game_mode_selection_screen_action_state.just_pressed(&GameModeSelectionScreenAction::Up);
+    let go_down =
+        game_mode_selection_screen_action_state.just_pressed(&GameModeSelectionScreenAction::Down);
+
+    if (go_up || go_down) && !(go_up && go_down) {
+        if go_down {
+            commands.entity(down_widget.0).insert(WidgetSelected::now());
+        } else {
+            commands.entity(up_widget.0).insert(WidgetSelected::now());
+        }
+    }
+}
+
+
+/// Returns to the main menu.
+pub fn back_button_interaction(
+    mut back_button_query: Query<
+        &mut Widget,
+        (Changed<Widget>, With<GameModeSelectionScreenBackButton>),
+    >,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok(mut button) = back_button_query.get_single_mut() {
+        button.on_click(|| {
+            next_app_state.set(AppState::MainMenu);
+        });
+    }
+}
+
+/// Selects the game mode and its difficulty.
+pub fn game_mode_button_interaction(
+    mut commands: Commands,
+    mut game_mode_button_query: Query<
+        (&mut Widget, &GameModeSelectionScreenGameModeButton),
+        (Changed<Widget>, Without<WidgetDisabled>),
+    >,
+) {
+    for (mut button, metadata) in &mut game_mode_button_query {
+        button.on_click(|| {
+            commands.insert_resource(metadata.game_mode_index);
+            commands.insert_resource(metadata.difficulty);
+        });
+    }
+}
+
+/// Toggles the mutators.
+pub fn mutator_button_interaction(
+    mut mutator_button_query: Query<
+        (&mut Widget, &GameModeSelectionScreenMutatorButton, &Children),
+        Changed<Widget>,
+    >,
+    mut mutator_button_text_query: Query<&mut LocalizedText>,
+    mut selected_mutators: ResMut<SelectedMutators>,
+    localization: Res<Localization>,
+) {
+    for (mut button, metadata, children) in &mut mutator_button_query {
+        button.on_click(|| {
+            let mutator = metadata.mutator;
+            selected_mutators.toggle(mutator);
+
+            let is_enabled = selected_mutators.is_enabled(mutator);
+            log::info!(
+                "{} {:?} mutator",
+                if is_enabled { "enabled" } else { "disabled" },
+                mutator.id(),
+            );
+
+            let mut text_iter = mutator_button_text_query.iter_many_mut(children);
+            while let Some(mut text) = text_iter.fetch_next() {
+                *text = localization::mutator_button(mutator.name().get(&localization), is_enabled);
+            }
+        });
+    }
+}
+
+
 /// Transitions to the player selection screen.
 pub fn game_mode_selected(mut next_app_state: ResMut<NextState<AppState>>) {
     next_app_state.set(AppState::PlayerSelectionScreen);
@@ -54,6 +317,7 @@ pub fn select_game_mode_when_starting_in_game(
 
                     let selected_game_mode_index = SelectedGameModeIndex(game_mode_index);
                     commands.insert_resource(selected_game_mode_index);
+                    commands.insert_resource(SelectedDifficulty::default());
                     return;
                 }
             }
@@ -79,6 +343,7 @@ pub fn select_game_mode_when_starting_in_game(
             log::info!("randomly selected {:?} game mode", selected_game_mode.name());
 
             commands.insert_resource(selected_game_mode_index);
+            commands.insert_resource(SelectedDifficulty::default());
         },
     }
 }
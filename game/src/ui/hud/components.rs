@@ -7,16 +7,16 @@ use crate::prelude::*;
 pub struct Hud;
 
 
-/// Tag component for the health bar in the HUD.
+/// Component for the health bars of local players in the HUD.
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
-pub struct HudHealthBar;
+pub struct HudHealthBar(pub PlayerIndex);
 
 
-/// Tag component for the health bar text in the HUD.
+/// Component for the health bar texts of local players in the HUD.
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
-pub struct HudHealthBarText;
+pub struct HudHealthBarText(pub PlayerIndex);
 
 
 /// Tag component for the experience bar in the HUD.
@@ -41,3 +41,39 @@ pub struct HudBalanceContainer;
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
 pub struct HudBalanceText;
+
+
+/// Tag component for the mutators container in the HUD.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct HudMutatorsContainer;
+
+
+/// Tag component for the mutator texts in the HUD.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct HudMutatorText;
+
+
+/// Tag component for the minimap in the HUD.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct HudMinimap;
+
+
+/// Component for the markers in the minimap in the HUD.
+#[derive(Clone, Copy, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct HudMinimapMarker {
+    /// Entity the marker shows.
+    pub target: Entity,
+}
+
+
+/// Component for the indicators of off-screen bosses in the HUD.
+#[derive(Clone, Copy, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct HudOffScreenIndicator {
+    /// Entity the indicator points to.
+    pub target: Entity,
+}
@@ -20,3 +20,57 @@ pub const BALANCE_TEXT_FONT_SIZE: f32 = 28.0;
 
 /// Color of the balance text in the HUD.
 pub const BALANCE_TEXT_COLOR: Color = Color::WHITE;
+
+
+/// Font size of the mutator texts in the HUD in pt.
+pub const MUTATOR_TEXT_FONT_SIZE: f32 = 20.0;
+
+/// Color of the mutator texts in the HUD.
+pub const MUTATOR_TEXT_COLOR: Color = Color::WHITE;
+
+
+/// Width of the minimap in the HUD in px.
+pub const MINIMAP_WIDTH: f32 = 200.00;
+
+/// Background color of the minimap in the HUD.
+pub const MINIMAP_BACKGROUND_COLOR: Color = Color::srgba(0.00, 0.00, 0.00, 0.50);
+
+/// Border color of the minimap in the HUD.
+pub const MINIMAP_BORDER_COLOR: Color = Color::srgba(1.00, 1.00, 1.00, 0.75);
+
+/// Size of the player markers in the minimap in px.
+pub const MINIMAP_PLAYER_MARKER_SIZE: f32 = 8.00;
+
+/// Color of the player markers in the minimap.
+pub const MINIMAP_PLAYER_MARKER_COLOR: Color = Color::srgb(0.20, 0.60, 1.00);
+
+/// Size of the enemy markers in the minimap in px.
+pub const MINIMAP_ENEMY_MARKER_SIZE: f32 = 4.00;
+
+/// Color of the enemy markers in the minimap.
+pub const MINIMAP_ENEMY_MARKER_COLOR: Color = Color::srgb(1.00, 0.20, 0.20);
+
+/// Size of the boss markers in the minimap in px.
+pub const MINIMAP_BOSS_MARKER_SIZE: f32 = 8.00;
+
+/// Color of the boss markers in the minimap.
+pub const MINIMAP_BOSS_MARKER_COLOR: Color = Color::srgb(1.00, 0.55, 0.00);
+
+/// Size of the experience point markers in the minimap in px.
+pub const MINIMAP_EXPERIENCE_POINT_MARKER_SIZE: f32 = 3.00;
+
+/// Color of the experience point markers in the minimap.
+pub const MINIMAP_EXPERIENCE_POINT_MARKER_COLOR: Color = Color::srgb(0.20, 1.00, 0.20);
+
+
+/// Font size of the off-screen indicators in the HUD in pt.
+pub const OFF_SCREEN_INDICATOR_FONT_SIZE: f32 = 40.0;
+
+/// Color of the off-screen indicators in the HUD.
+pub const OFF_SCREEN_INDICATOR_COLOR: Color = Color::srgb(1.00, 0.55, 0.00);
+
+/// Distance of the off-screen indicators to the edges of the screen in px.
+pub const OFF_SCREEN_INDICATOR_MARGIN: f32 = 30.00;
+
+/// Size of the off-screen indicators in the HUD in px.
+pub const OFF_SCREEN_INDICATOR_SIZE: f32 = 40.00;
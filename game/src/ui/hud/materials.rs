@@ -1,6 +1,14 @@
 use crate::prelude::*;
 
 
+/// Resource for the shader of the bars, which is imported by the shaders of the bar materials.
+///
+/// Shaders that are only imported are not loaded automatically,
+/// so the handle is kept to keep the shader loaded.
+#[derive(Debug, Resource)]
+pub struct BarShader(pub Handle<Shader>);
+
+
 /// Material for the health bar.
 #[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
 pub struct HealthBarMaterial {
@@ -38,6 +46,12 @@ impl UiMaterial for HealthBarMaterial {
     }
 }
 
+impl Material2d for HealthBarMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/enemy/health-bar.wgsl".into()
+    }
+}
+
 
 /// Material for the experience bar.
 #[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
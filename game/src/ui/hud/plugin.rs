@@ -16,12 +16,22 @@ impl Plugin for HudPlugin {
         app.register_type::<HudExperienceBarText>();
         app.register_type::<HudBalanceContainer>();
         app.register_type::<HudBalanceText>();
+        app.register_type::<HudMutatorsContainer>();
+        app.register_type::<HudMutatorText>();
+        app.register_type::<HudMinimap>();
+        app.register_type::<HudMinimapMarker>();
+        app.register_type::<HudOffScreenIndicator>();
 
         // Setup localization.
         app.world_mut().resource_mut::<LocaleAssets>().push("ui/hud.ftl");
 
+        // Load shaders.
+        let bar_shader = app.world().resource::<AssetServer>().load("shaders/ui/hud/bar.wgsl");
+        app.insert_resource(BarShader(bar_shader));
+
         // Add materials.
         app.add_plugins(UiMaterialPlugin::<HealthBarMaterial>::default());
+        app.add_plugins(Material2dPlugin::<HealthBarMaterial>::default());
         app.add_plugins(UiMaterialPlugin::<ExperienceBarMaterial>::default());
 
         // Add systems.
@@ -38,7 +48,17 @@ impl Plugin for HudPlugin {
                 .run_if(resource_exists::<ExperienceRequiredToGetToCurrentLevel>)
                 .run_if(resource_exists::<ExperienceRequiredToLevelUp>),
         );
-        app.add_systems(PostUpdate, update_balance.run_if(in_state(AppState::Game)));
+        app.add_systems(
+            PostUpdate,
+            update_balance
+                .run_if(in_state(AppState::Game))
+                .run_if(resource_changed::<Balance>.or_else(resource_changed::<NumberFormat>)),
+        );
+        app.add_systems(PostUpdate, update_bar_colors.run_if(in_state(AppState::Game)));
+        app.add_systems(
+            PostUpdate,
+            (update_minimap, update_off_screen_indicators).run_if(in_state(AppState::Game)),
+        );
         app.add_systems(OnExit(GameState::Playing), hide_hud);
         app.add_systems(OnEnter(GameState::Over), despawn_hud);
         app.add_systems(OnEnter(GameState::Restart), despawn_hud.in_set(RestartSystems::Hud));
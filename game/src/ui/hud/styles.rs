@@ -1,4 +1,7 @@
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    ui::hud::constants::*,
+};
 
 
 /// Gets the style of the HUD.
@@ -13,8 +16,45 @@ pub fn hud() -> Style {
 }
 
 
-/// Gets the style of the health bar in the HUD.
-pub fn health_bar() -> Style {
+/// Gets the style of the health bar of a local player in the HUD.
+///
+/// Health bar of the first player is on the top left above the experience bar, and the health
+/// bars of the other players are stacked on the bottom left.
+pub fn health_bar(player_index: PlayerIndex) -> Style {
+    match player_index.0 {
+        0 => {
+            Style {
+                position_type: PositionType::Absolute,
+                align_self: AlignSelf::Start,
+                justify_self: JustifySelf::Start,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                width: Val::Percent(15.00),
+                height: Val::Percent(5.00),
+                top: Val::Percent(4.00),
+                left: Val::Percent(1.50),
+                ..default()
+            }
+        },
+        index => {
+            Style {
+                position_type: PositionType::Absolute,
+                align_self: AlignSelf::End,
+                justify_self: JustifySelf::Start,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                width: Val::Percent(15.00),
+                height: Val::Percent(5.00),
+                bottom: Val::Percent(4.00 + (index - 1) as f32 * 5.50),
+                left: Val::Percent(1.50),
+                ..default()
+            }
+        },
+    }
+}
+
+/// Gets the style of the experience bar in the HUD.
+pub fn experience_bar() -> Style {
     Style {
         position_type: PositionType::Absolute,
         align_self: AlignSelf::Start,
@@ -23,14 +63,14 @@ pub fn health_bar() -> Style {
         justify_content: JustifyContent::Center,
         width: Val::Percent(15.00),
         height: Val::Percent(5.00),
-        top: Val::Percent(4.00),
+        top: Val::Percent(9.50),
         left: Val::Percent(1.50),
         ..default()
     }
 }
 
-/// Gets the style of the experience bar in the HUD.
-pub fn experience_bar() -> Style {
+/// Gets the style of the balance container in the HUD.
+pub fn balance_container() -> Style {
     Style {
         position_type: PositionType::Absolute,
         align_self: AlignSelf::Start,
@@ -39,24 +79,61 @@ pub fn experience_bar() -> Style {
         justify_content: JustifyContent::Center,
         width: Val::Percent(15.00),
         height: Val::Percent(5.00),
-        top: Val::Percent(9.50),
+        top: Val::Percent(14.00),
         left: Val::Percent(1.50),
         ..default()
     }
 }
 
-/// Gets the style of the balance container in the HUD.
-pub fn balance_container() -> Style {
+/// Gets the style of the mutators container in the HUD.
+pub fn mutators_container() -> Style {
     Style {
         position_type: PositionType::Absolute,
         align_self: AlignSelf::Start,
         justify_self: JustifySelf::Start,
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::Start,
+        row_gap: Val::Percent(0.50),
+        top: Val::Percent(19.50),
+        left: Val::Percent(1.50),
+        ..default()
+    }
+}
+
+/// Gets the style of the minimap in the HUD.
+pub fn minimap() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        align_self: AlignSelf::Start,
+        justify_self: JustifySelf::End,
+        width: Val::Px(MINIMAP_WIDTH),
+        aspect_ratio: Some(1.00),
+        top: Val::Percent(4.00),
+        right: Val::Percent(1.50),
+        border: UiRect::all(Val::Px(2.00)),
+        ..default()
+    }
+}
+
+/// Gets the style of a marker in the minimap in the HUD.
+pub fn minimap_marker(size: f32) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        width: Val::Px(size),
+        height: Val::Px(size),
+        margin: UiRect { left: Val::Px(-size / 2.00), top: Val::Px(-size / 2.00), ..default() },
+        ..default()
+    }
+}
+
+/// Gets the style of an off-screen indicator in the HUD.
+pub fn off_screen_indicator() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
         align_items: AlignItems::Center,
         justify_content: JustifyContent::Center,
-        width: Val::Percent(15.00),
-        height: Val::Percent(5.00),
-        top: Val::Percent(14.00),
-        left: Val::Percent(1.50),
+        width: Val::Px(OFF_SCREEN_INDICATOR_SIZE),
+        height: Val::Px(OFF_SCREEN_INDICATOR_SIZE),
         ..default()
     }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    configuration::localization::currency,
     prelude::*,
     ui::hud::{
         constants::*,
@@ -11,58 +12,76 @@ use crate::{
 /// Spawns the HUD.
 pub fn spawn_hud(
     mut commands: Commands,
-    player_query: Query<(&Health, &RemainingHealth, &Level), With<Player>>,
+    player_query: Query<(&PlayerIndex, &Health, &RemainingHealth), With<Player>>,
+    primary_player_query: Query<&Level, With<PrimaryPlayer>>,
     asset_server: Res<AssetServer>,
     mut health_bar_materials: ResMut<Assets<HealthBarMaterial>>,
     mut experience_bar_materials: ResMut<Assets<ExperienceBarMaterial>>,
     balance: Res<Balance>,
+    selected_mutators: Res<SelectedMutators>,
+    number_format: Res<NumberFormat>,
+    number_of_players: Res<NumberOfPlayers>,
     localization: Res<Localization>,
 ) {
-    let (health_bar_text, experience_bar_text) = match player_query.get_single() {
-        Ok((health, remaining_health, level)) => {
-            (
-                format!("{} / {}", remaining_health.ceil(), health.ceil()),
-                localization::experience_bar(level),
-            )
-        },
-        Err(_) => ("? / ?".to_owned(), "?".into()),
+    let health_bar_texts = (0..number_of_players.0)
+        .map(|index| {
+            let player_index = PlayerIndex(index);
+            let health_bar_text = player_query
+                .iter()
+                .find(|(index, _, _)| **index == player_index)
+                .map(|(_, health, remaining_health)| {
+                    format!(
+                        "{} / {}",
+                        number_format.decimal(remaining_health.ceil() as f64, 0),
+                        number_format.decimal(health.ceil() as f64, 0),
+                    )
+                })
+                .unwrap_or_else(|| "? / ?".to_owned());
+            (player_index, health_bar_text)
+        })
+        .collect::<Vec<_>>();
+    let experience_bar_text = match primary_player_query.get_single() {
+        Ok(level) => localization::experience_bar(level),
+        Err(_) => "?".into(),
     };
-    let balance_text = format!("{}", *balance);
+    let balance_text = currency(balance.0, &number_format);
 
     commands
         .spawn((Name::new("HUD"), Hud, NodeBundle { style: styles::hud(), ..default() }))
         .with_children(|parent| {
-            parent
-                .spawn((
-                    Name::new("Health Bar"),
-                    HudHealthBar,
-                    MaterialNodeBundle {
-                        style: styles::health_bar(),
-                        material: health_bar_materials.add(HealthBarMaterial::default()),
-                        ..default()
-                    },
-                ))
-                .with_children(|parent| {
-                    parent.spawn((
-                        Name::new("Text"),
-                        HudHealthBarText,
-                        TextBundle {
-                            text: Text {
-                                sections: vec![TextSection::new(
-                                    health_bar_text,
-                                    TextStyle {
-                                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                                        font_size: HEALTH_BAR_TEXT_FONT_SIZE,
-                                        color: HEALTH_BAR_TEXT_COLOR,
-                                    },
-                                )],
-                                justify: JustifyText::Center,
-                                ..default()
-                            },
+            for (player_index, health_bar_text) in health_bar_texts {
+                parent
+                    .spawn((
+                        Name::new(format!("Health Bar {}", player_index.0 + 1)),
+                        HudHealthBar(player_index),
+                        MaterialNodeBundle {
+                            style: styles::health_bar(player_index),
+                            material: health_bar_materials.add(HealthBarMaterial::default()),
                             ..default()
                         },
-                    ));
-                });
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Name::new("Text"),
+                            HudHealthBarText(player_index),
+                            TextBundle {
+                                text: Text {
+                                    sections: vec![TextSection::new(
+                                        health_bar_text,
+                                        TextStyle {
+                                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                            font_size: HEALTH_BAR_TEXT_FONT_SIZE,
+                                            color: HEALTH_BAR_TEXT_COLOR,
+                                        },
+                                    )],
+                                    justify: JustifyText::Center,
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                        ));
+                    });
+            }
 
             parent
                 .spawn((
@@ -110,7 +129,7 @@ pub fn spawn_hud(
                         TextBundle {
                             text: Text {
                                 sections: vec![TextSection::new(
-                                    balance_text,
+                                    balance_text.get(&localization),
                                     TextStyle {
                                         font: asset_server.load("fonts/FiraSans-Bold.ttf"),
                                         font_size: BALANCE_TEXT_FONT_SIZE,
@@ -122,8 +141,54 @@ pub fn spawn_hud(
                             },
                             ..default()
                         },
+                        balance_text,
                     ));
                 });
+
+            parent.spawn((
+                Name::new("Minimap"),
+                HudMinimap,
+                NodeBundle {
+                    style: styles::minimap(),
+                    background_color: MINIMAP_BACKGROUND_COLOR.into(),
+                    border_color: MINIMAP_BORDER_COLOR.into(),
+                    ..default()
+                },
+            ));
+
+            if selected_mutators.0.is_empty() {
+                return;
+            }
+            parent
+                .spawn((
+                    Name::new("Mutators"),
+                    HudMutatorsContainer,
+                    NodeBundle { style: styles::mutators_container(), ..default() },
+                ))
+                .with_children(|parent| {
+                    for mutator in selected_mutators.iter() {
+                        let mutator_name = mutator.name();
+                        parent.spawn((
+                            Name::new(format!("Mutator [{}]", mutator.id())),
+                            HudMutatorText,
+                            TextBundle {
+                                text: Text {
+                                    sections: vec![TextSection::new(
+                                        mutator_name.get(&localization),
+                                        TextStyle {
+                                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                            font_size: MUTATOR_TEXT_FONT_SIZE,
+                                            color: MUTATOR_TEXT_COLOR,
+                                        },
+                                    )],
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            mutator_name,
+                        ));
+                    }
+                });
         });
 }
 
@@ -155,45 +220,44 @@ pub fn despawn_hud(mut commands: Commands, hud_query: Query<Entity, With<Hud>>)
 }
 
 
-/// Updates the health bar.
+/// Updates the health bars.
 pub fn update_health_bar(
     player_query: Query<
-        (&Health, &RemainingHealth),
+        (&PlayerIndex, &Health, &RemainingHealth),
         (With<Player>, Or<(Changed<Health>, Changed<RemainingHealth>)>),
     >,
-    health_bar_query: Query<&Handle<HealthBarMaterial>, With<HudHealthBar>>,
-    mut health_bar_text_query: Query<&mut Text, With<HudHealthBarText>>,
+    health_bar_query: Query<(&HudHealthBar, &Handle<HealthBarMaterial>)>,
+    mut health_bar_text_query: Query<(&HudHealthBarText, &mut Text)>,
     mut health_bar_materials: ResMut<Assets<HealthBarMaterial>>,
+    number_format: Res<NumberFormat>,
 ) {
-    let (player_health, player_remaining_health) = match player_query.get_single() {
-        Ok(query_result) => query_result,
-        Err(_) => return,
-    };
-
-    let health_bar_handle = match health_bar_query.get_single() {
-        Ok(query_result) => query_result,
-        Err(_) => return,
-    };
-    let health_bar = match health_bar_materials.get_mut(health_bar_handle) {
-        Some(asset) => asset,
-        None => return,
-    };
+    for (player_index, player_health, player_remaining_health) in player_query.iter() {
+        let health_bar = health_bar_query
+            .iter()
+            .find(|(health_bar, _)| health_bar.0 == *player_index)
+            .and_then(|(_, health_bar_handle)| health_bar_materials.get_mut(health_bar_handle));
+        if let Some(health_bar) = health_bar {
+            health_bar.percent = (player_remaining_health.0 / player_health.0).clamp(0.00, 1.00);
+        }
 
-    let mut health_bar_text = match health_bar_text_query.get_single_mut() {
-        Ok(query_result) => query_result,
-        Err(_) => return,
-    };
-
-    health_bar.percent = (player_remaining_health.0 / player_health.0).clamp(0.00, 1.00);
-    health_bar_text.sections[0].value =
-        format!("{} / {}", player_remaining_health.ceil(), player_health.ceil());
+        let health_bar_text = health_bar_text_query
+            .iter_mut()
+            .find(|(health_bar_text, _)| health_bar_text.0 == *player_index);
+        if let Some((_, mut health_bar_text)) = health_bar_text {
+            health_bar_text.sections[0].value = format!(
+                "{} / {}",
+                number_format.decimal(player_remaining_health.ceil() as f64, 0),
+                number_format.decimal(player_health.ceil() as f64, 0),
+            );
+        }
+    }
 }
 
 /// Updates the experience bar.
 pub fn update_experience_bar(
     player_query: Query<
         (&Experience, &Level),
-        (With<Player>, Or<(Changed<Experience>, Changed<Level>)>),
+        (With<PrimaryPlayer>, Or<(Changed<Experience>, Changed<Level>)>),
     >,
     experience_bar_query: Query<&Handle<ExperienceBarMaterial>, With<HudExperienceBar>>,
     mut experience_bar_text_query: Query<&mut LocalizedText, With<HudExperienceBarText>>,
@@ -231,12 +295,284 @@ pub fn update_experience_bar(
     *experience_bar_text = localization::experience_bar(player_level);
 }
 
+/// Updates the colors of the health and experience bars according to the color palette.
+pub fn update_bar_colors(
+    health_bar_query: Query<Ref<Handle<HealthBarMaterial>>, With<HudHealthBar>>,
+    experience_bar_query: Query<Ref<Handle<ExperienceBarMaterial>>, With<HudExperienceBar>>,
+    mut health_bar_materials: ResMut<Assets<HealthBarMaterial>>,
+    mut experience_bar_materials: ResMut<Assets<ExperienceBarMaterial>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    let to_vec4 = |color: Color| {
+        let color = color.to_linear();
+        Vec4::new(color.red, color.green, color.blue, color.alpha)
+    };
+
+    for health_bar_handle in health_bar_query.iter() {
+        if !(general_settings.is_changed() || health_bar_handle.is_added()) {
+            continue;
+        }
+        if let Some(health_bar) = health_bar_materials.get_mut(&*health_bar_handle) {
+            health_bar.foreground_color = to_vec4(general_settings.color_palette.health_bar());
+        }
+    }
+
+    for experience_bar_handle in experience_bar_query.iter() {
+        if !(general_settings.is_changed() || experience_bar_handle.is_added()) {
+            continue;
+        }
+        if let Some(experience_bar) = experience_bar_materials.get_mut(&*experience_bar_handle) {
+            experience_bar.foreground_color =
+                to_vec4(general_settings.color_palette.experience_bar());
+        }
+    }
+}
+
 /// Updates the balance.
 pub fn update_balance(
-    mut balance_text_query: Query<&mut Text, With<HudBalanceText>>,
+    mut balance_text_query: Query<&mut LocalizedText, With<HudBalanceText>>,
     balance: Res<Balance>,
+    number_format: Res<NumberFormat>,
 ) {
     if let Ok(mut balance_text) = balance_text_query.get_single_mut() {
-        balance_text.sections[0].value = format!("{}", *balance);
+        *balance_text = currency(balance.0, &number_format);
+    }
+}
+
+
+/// Updates the minimap to show the players, the enemies and the experience points.
+pub fn update_minimap(
+    mut commands: Commands,
+    mut minimap_query: Query<(Entity, Ref<HudMinimap>, &mut Style)>,
+    mut minimap_marker_query: Query<
+        (&mut Style, &mut BackgroundColor),
+        (With<HudMinimapMarker>, Without<HudMinimap>),
+    >,
+    player_query: Query<(Entity, &Position), With<Player>>,
+    enemy_query: Query<(Entity, &Position, Has<Boss>), With<Enemy>>,
+    experience_point_query: Query<(Entity, &Position), With<ExperiencePoint>>,
+    map_bounds: Option<Res<MapBounds>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    mut minimap_markers: Local<HashMap<Entity, Entity>>,
+    mut shown_targets: Local<HashSet<Entity>>,
+    mut previous_minimap: Local<Option<Entity>>,
+) {
+    let (minimap_entity, minimap, mut minimap_style) = match minimap_query.get_single_mut() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+    let map_bounds = match map_bounds {
+        Some(map_bounds) => map_bounds,
+        None => return,
+    };
+
+    // Markers of the previous minimap are despawned with it.
+    if previous_minimap.replace(minimap_entity) != Some(minimap_entity) {
+        minimap_markers.clear();
+    }
+
+    let map_width = map_bounds.x_max - map_bounds.x_min;
+    let map_height = map_bounds.y_max - map_bounds.y_min;
+    if map_width <= 0.00 || map_height <= 0.00 {
+        return;
+    }
+    if minimap.is_added() || map_bounds.is_changed() {
+        minimap_style.aspect_ratio = Some(map_width / map_height);
+    }
+
+    let targets = experience_point_query
+        .iter()
+        .map(|(entity, position)| {
+            (
+                entity,
+                position,
+                MINIMAP_EXPERIENCE_POINT_MARKER_SIZE,
+                MINIMAP_EXPERIENCE_POINT_MARKER_COLOR,
+                0,
+            )
+        })
+        .chain(enemy_query.iter().map(|(entity, position, is_boss)| {
+            if is_boss {
+                (entity, position, MINIMAP_BOSS_MARKER_SIZE, MINIMAP_BOSS_MARKER_COLOR, 2)
+            } else {
+                (entity, position, MINIMAP_ENEMY_MARKER_SIZE, MINIMAP_ENEMY_MARKER_COLOR, 1)
+            }
+        }))
+        .chain(player_query.iter().map(|(entity, position)| {
+            (entity, position, MINIMAP_PLAYER_MARKER_SIZE, MINIMAP_PLAYER_MARKER_COLOR, 3)
+        }));
+
+    // Markers are recolored when the color palette might have changed.
+    let recolor_markers = general_settings.is_changed();
+
+    shown_targets.clear();
+    for (target, position, size, color, z_index) in targets {
+        shown_targets.insert(target);
+        let color = general_settings.color_palette.tint(color);
+
+        let left = Val::Percent(
+            ((position.x - map_bounds.x_min) / map_width * 100.00).clamp(0.00, 100.00),
+        );
+        let top = Val::Percent(
+            ((map_bounds.y_max - position.y) / map_height * 100.00).clamp(0.00, 100.00),
+        );
+
+        let existing_marker = minimap_markers
+            .get(&target)
+            .and_then(|&marker| minimap_marker_query.get_mut(marker).ok());
+        match existing_marker {
+            Some((mut marker_style, mut marker_background_color)) => {
+                if marker_style.left != left || marker_style.top != top {
+                    marker_style.left = left;
+                    marker_style.top = top;
+                }
+                if recolor_markers {
+                    marker_background_color.0 = color;
+                }
+            },
+            None => {
+                let marker = commands
+                    .spawn((
+                        Name::new("Marker"),
+                        HudMinimapMarker { target },
+                        NodeBundle {
+                            style: Style { left, top, ..styles::minimap_marker(size) },
+                            background_color: color.into(),
+                            z_index: ZIndex::Local(z_index),
+                            ..default()
+                        },
+                    ))
+                    .set_parent(minimap_entity)
+                    .id();
+                minimap_markers.insert(target, marker);
+            },
+        }
     }
+
+    minimap_markers.retain(|target, marker| {
+        if shown_targets.contains(target) {
+            return true;
+        }
+        commands.entity(*marker).despawn_recursive();
+        false
+    });
+}
+
+/// Updates the indicators that point to the bosses outside the screen.
+pub fn update_off_screen_indicators(
+    mut commands: Commands,
+    hud_query: Query<Entity, With<Hud>>,
+    mut indicator_query: Query<
+        (&mut Style, &mut Transform, &mut Visibility),
+        With<HudOffScreenIndicator>,
+    >,
+    boss_query: Query<(Entity, &GlobalTransform), With<Boss>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    asset_server: Res<AssetServer>,
+    ui_scale: Res<UiScale>,
+    mut indicators: Local<HashMap<Entity, Entity>>,
+    mut shown_bosses: Local<HashSet<Entity>>,
+    mut previous_hud: Local<Option<Entity>>,
+) {
+    let hud_entity = match hud_query.get_single() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+    let (camera, camera_transform) = match camera_query.get_single() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+    let viewport_size = match camera.logical_viewport_size() {
+        Some(viewport_size) => viewport_size,
+        None => return,
+    };
+
+    // Indicators of the previous HUD are despawned with it.
+    if previous_hud.replace(hud_entity) != Some(hud_entity) {
+        indicators.clear();
+    }
+
+    shown_bosses.clear();
+    for (boss_entity, boss_transform) in boss_query.iter() {
+        shown_bosses.insert(boss_entity);
+
+        let viewport_position =
+            match camera.world_to_viewport(camera_transform, boss_transform.translation()) {
+                Some(viewport_position) => viewport_position,
+                None => continue,
+            };
+
+        let existing_indicator = indicators
+            .get(&boss_entity)
+            .and_then(|&indicator| indicator_query.get_mut(indicator).ok());
+
+        let is_on_screen = viewport_position.x >= 0.00
+            && viewport_position.y >= 0.00
+            && viewport_position.x <= viewport_size.x
+            && viewport_position.y <= viewport_size.y;
+        if is_on_screen {
+            if let Some((_, _, mut indicator_visibility)) = existing_indicator {
+                indicator_visibility.set_if_neq(Visibility::Hidden);
+            }
+            continue;
+        }
+
+        // Project the boss to the edges of the screen from the center of the screen.
+        let center = viewport_size / 2.00;
+        let direction = viewport_position - center;
+        let bounds = (center - Vec2::splat(OFF_SCREEN_INDICATOR_MARGIN)).max(Vec2::ZERO);
+        let scale = (bounds.x / direction.x.abs()).min(bounds.y / direction.y.abs());
+        let edge = center + direction * scale;
+
+        let left = Val::Px((edge.x / ui_scale.0) - (OFF_SCREEN_INDICATOR_SIZE / 2.00));
+        let top = Val::Px((edge.y / ui_scale.0) - (OFF_SCREEN_INDICATOR_SIZE / 2.00));
+        let rotation = Quat::from_rotation_z(direction.y.atan2(direction.x));
+
+        match existing_indicator {
+            Some((mut indicator_style, mut indicator_transform, mut indicator_visibility)) => {
+                indicator_visibility.set_if_neq(Visibility::Inherited);
+                if indicator_style.left != left || indicator_style.top != top {
+                    indicator_style.left = left;
+                    indicator_style.top = top;
+                }
+                indicator_transform.rotation = rotation;
+            },
+            None => {
+                let indicator = commands
+                    .spawn((
+                        Name::new("Off-Screen Indicator"),
+                        HudOffScreenIndicator { target: boss_entity },
+                        NodeBundle {
+                            style: Style { left, top, ..styles::off_screen_indicator() },
+                            transform: Transform::from_rotation(rotation),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Name::new("Arrow"),
+                            TextBundle::from_section(
+                                ">",
+                                TextStyle {
+                                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                    font_size: OFF_SCREEN_INDICATOR_FONT_SIZE,
+                                    color: OFF_SCREEN_INDICATOR_COLOR,
+                                },
+                            ),
+                        ));
+                    })
+                    .set_parent(hud_entity)
+                    .id();
+                indicators.insert(boss_entity, indicator);
+            },
+        }
+    }
+
+    indicators.retain(|boss_entity, indicator| {
+        if shown_bosses.contains(boss_entity) {
+            return true;
+        }
+        commands.entity(*indicator).despawn_recursive();
+        false
+    });
 }
@@ -24,6 +24,14 @@ pub enum LevelUpScreenCommands {
     Reroll,
     /// Offers a perk in the level up screen.
     Offer { position: NonZeroUsize, perk: SmolStr },
+    /// Locks a perk offered in the level up screen.
+    Lock { position: NonZeroUsize },
+    /// Unlocks a perk offered in the level up screen.
+    Unlock { position: NonZeroUsize },
+    /// Banishes a perk offered in the level up screen for the rest of the game.
+    Banish { position: NonZeroUsize },
+    /// Shows the banished perks.
+    Banished,
     /// Controls the number of perks offered in the level up screen.
     NumberOfPerks {
         #[clap(subcommand)]
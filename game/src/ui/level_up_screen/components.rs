@@ -44,6 +44,22 @@ pub struct LevelUpScreenSelectButton {
 }
 
 
+/// Tag component for lock buttons in the level up screen.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct LevelUpScreenLockButton {
+    pub perk_index: usize,
+}
+
+
+/// Tag component for banish buttons in the level up screen.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct LevelUpScreenBanishButton {
+    pub perk_index: usize,
+}
+
+
 /// Tag component for the footer container in the level up screen.
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
@@ -68,3 +84,11 @@ pub struct LevelUpScreenBalanceText;
 pub struct LevelUpScreenRerollButton {
     pub cost: Balance,
 }
+
+
+/// Tag component for the skip button in the level up screen.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct LevelUpScreenSkipButton {
+    pub reward: Balance,
+}
@@ -24,9 +24,17 @@ pub const PERK_DESCRIPTION_FONT_SIZE: f32 = 24.00;
 pub const SELECT_BUTTON_FONT_SIZE: f32 = 22.00;
 
 
+/// Font size of lock and banish buttons in the level up screen in pt.
+pub const PERK_ACTION_BUTTON_FONT_SIZE: f32 = 18.00;
+
+
 /// Font size of the balance text in the level up screen in pt.
 pub const BALANCE_TEXT_FONT_SIZE: f32 = 32.00;
 
 
 /// Font size of the reroll button in the level up screen in pt.
 pub const REROLL_BUTTON_FONT_SIZE: f32 = 32.00;
+
+
+/// Font size of the skip button in the level up screen in pt.
+pub const SKIP_BUTTON_FONT_SIZE: f32 = 32.00;
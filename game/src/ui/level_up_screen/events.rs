@@ -0,0 +1,9 @@
+use crate::prelude::*;
+
+
+/// Event for skipping a level up in the level up screen.
+#[derive(Debug, Event, Reflect)]
+pub struct LevelUpSkippedEvent {
+    /// Level the skipped level up was for, if the level up screen was opened for leveling up.
+    pub level: Option<Level>,
+}
@@ -11,11 +11,53 @@ pub fn select_button() -> LocalizedText {
 }
 
 
+/// Gets the localized text of lock buttons.
+pub fn lock_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "level-up-screen-lock-button",
+        args: smallvec![],
+        fallback: "Lock".into(),
+    }
+}
+
+/// Gets the localized text of unlock buttons.
+pub fn unlock_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "level-up-screen-unlock-button",
+        args: smallvec![],
+        fallback: "Unlock".into(),
+    }
+}
+
+
+/// Gets the localized text of banish buttons.
+pub fn banish_button(remaining_banishes: usize, number_format: &NumberFormat) -> LocalizedText {
+    let remaining = number_format.integer(remaining_banishes);
+    LocalizedText::Localized {
+        key: "level-up-screen-banish-button",
+        args: smallvec![("remaining", remaining.to_smolstr())],
+        fallback: format!("Banish ({})", remaining).into(),
+    }
+}
+
+
+/// Gets the localized text of the skip button.
+pub fn skip_button(skip_reward: Balance, number_format: &NumberFormat) -> LocalizedText {
+    let amount = number_format.decimal(skip_reward.0, 2);
+    LocalizedText::Localized {
+        key: "level-up-screen-skip-button",
+        args: smallvec![("amount", amount.to_smolstr())],
+        fallback: format!("Skip + {} $", amount).into(),
+    }
+}
+
+
 /// Gets the localized text of the reroll button.
-pub fn reroll_button(reroll_cost: Balance) -> LocalizedText {
+pub fn reroll_button(reroll_cost: Balance, number_format: &NumberFormat) -> LocalizedText {
+    let amount = number_format.decimal(reroll_cost.0, 2);
     LocalizedText::Localized {
         key: "level-up-screen-reroll-button",
-        args: smallvec![("cost", format_smolstr!("{}", reroll_cost))],
-        fallback: format!("Reroll - {}", reroll_cost).into(),
+        args: smallvec![("amount", amount.to_smolstr())],
+        fallback: format!("Reroll - {} $", amount).into(),
     }
 }
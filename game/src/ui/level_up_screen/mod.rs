@@ -1,6 +1,7 @@
 pub mod commands;
 pub mod components;
 pub mod constants;
+pub mod events;
 pub mod localization;
 pub mod plugin;
 pub mod resources;
@@ -20,6 +20,9 @@ impl Plugin for LevelUpScreenPlugin {
         app.register_type::<LevelUpScreenFooterContainer>();
         app.register_type::<LevelUpScreenBalanceContainer>();
         app.register_type::<LevelUpScreenBalanceText>();
+        app.register_type::<LevelUpScreenLockButton>();
+        app.register_type::<LevelUpScreenBanishButton>();
+        app.register_type::<LevelUpScreenSkipButton>();
         app.register_type::<LevelUpScreenRerollButton>();
 
         // Register resources.
@@ -34,6 +37,9 @@ impl Plugin for LevelUpScreenPlugin {
         // Insert resources.
         app.init_resource::<LevelUpScreenConfiguration>();
 
+        // Add events.
+        app.add_event::<LevelUpSkippedEvent>();
+
         // Add console commands.
         app.add_console_command::<LevelUpScreenCommand, _>(apply_level_up_screen_command);
 
@@ -59,7 +65,8 @@ impl Plugin for LevelUpScreenPlugin {
                     },
                 ),
                 update_offered_perks.run_if(
-                    |level_up_screen_state: Option<Res<LevelUpScreenState>>,
+                    |level_up_screen_configuration: Res<LevelUpScreenConfiguration>,
+                     level_up_screen_state: Option<Res<LevelUpScreenState>>,
                      level_up_screen_widgets: Option<Res<LevelUpScreenWidgets>>,
                      perk_registry: Res<PerkRegistry>| {
                         if level_up_screen_state.is_none() || level_up_screen_widgets.is_none() {
@@ -72,10 +79,16 @@ impl Plugin for LevelUpScreenPlugin {
                         level_up_screen_state.is_added()
                             || level_up_screen_state.is_changed()
                             || level_up_screen_widgets.is_added()
+                            || level_up_screen_configuration.is_changed()
                             || perk_registry.is_changed()
                     },
                 ),
                 update_balance_text.run_if(|balance: Res<Balance>| balance.is_changed()),
+                update_skip_button.run_if(
+                    |level_up_screen_configuration: Res<LevelUpScreenConfiguration>| {
+                        level_up_screen_configuration.is_changed()
+                    },
+                ),
                 update_reroll_button.run_if(
                     |balance: Res<Balance>,
                      level_up_screen_configuration: Res<LevelUpScreenConfiguration>| {
@@ -113,18 +126,36 @@ impl Plugin for LevelUpScreenPlugin {
         app.add_systems(Update, navigation.in_set(LevelUpScreenSystems));
         app.add_systems(
             PostUpdate,
-            (select_button_interaction, reroll_button_interaction).in_set(LevelUpScreenSystems),
+            (
+                select_button_interaction,
+                lock_button_interaction,
+                banish_button_interaction,
+                skip_button_interaction,
+                reroll_button_interaction,
+            )
+                .in_set(LevelUpScreenSystems),
         );
         app.add_systems(OnExit(GameState::LevelUpScreen), despawn_level_up_screen);
-        app.add_systems(OnEnter(GameState::Over), reset_level_up_screen_configuration);
+        app.add_systems(
+            OnEnter(GameState::Over),
+            (reset_level_up_screen_configuration, reset_level_up_screen_state),
+        );
         app.add_systems(
             OnEnter(GameState::Restart),
-            (despawn_level_up_screen, reset_level_up_screen_configuration)
+            (
+                despawn_level_up_screen,
+                reset_level_up_screen_configuration,
+                reset_level_up_screen_state,
+            )
                 .in_set(RestartSystems::LevelUpScreen),
         );
         app.add_systems(
             OnExit(AppState::Game),
-            (despawn_level_up_screen, reset_level_up_screen_configuration),
+            (
+                despawn_level_up_screen,
+                reset_level_up_screen_configuration,
+                reset_level_up_screen_state,
+            ),
         );
     }
 }
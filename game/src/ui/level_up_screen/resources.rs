@@ -29,6 +29,22 @@ pub struct LevelUpScreenConfiguration {
 
     /// Cost of using the reroll button.
     pub reroll_cost: LevelUpScreenRerollCost,
+
+    /// Maximum number of perks that can be locked at the same time.
+    ///
+    /// Locked perks are offered again in the next level up screen.
+    pub max_locked_perks: u8,
+
+    /// Number of remaining banishes.
+    ///
+    /// Banished perks are never offered again in the rest of the game.
+    pub remaining_banishes: usize,
+
+    /// Number of remaining skips.
+    pub remaining_skips: usize,
+
+    /// Balance gained by skipping a level up.
+    pub skip_reward: Balance,
 }
 
 impl LevelUpScreenConfiguration {
@@ -38,16 +54,41 @@ impl LevelUpScreenConfiguration {
     }
 }
 
+impl LevelUpScreenConfiguration {
+    /// Applies the limits of the level up screen.
+    pub fn apply_limits(&mut self, limits: LevelUpScreenLimits) {
+        self.max_locked_perks = limits.max_locked_perks;
+        self.remaining_banishes = limits.banishes;
+        self.remaining_skips = limits.skips;
+    }
+}
+
 impl Default for LevelUpScreenConfiguration {
     fn default() -> LevelUpScreenConfiguration {
         LevelUpScreenConfiguration {
             number_of_perks: 4,
             reroll_cost: LevelUpScreenRerollCost::default(),
+            max_locked_perks: 0,
+            remaining_banishes: 0,
+            remaining_skips: 0,
+            skip_reward: Balance(0.00),
         }
     }
 }
 
 
+/// Limits of the level up screen for the whole game.
+#[derive(Clone, Copy, Debug)]
+pub struct LevelUpScreenLimits {
+    /// Maximum number of perks that can be locked at the same time.
+    pub max_locked_perks: u8,
+    /// Number of perks that can be banished during the game.
+    pub banishes: usize,
+    /// Number of level ups that can be skipped during the game.
+    pub skips: usize,
+}
+
+
 /// Custom reroll cost function for the level up screen.
 #[derive(Clone, Copy, Debug, Deref, DerefMut)]
 pub struct CustomRerollCostInStepFunction(pub fn(usize) -> Balance);
@@ -279,10 +320,165 @@ impl Display for LevelUpScreenRerollCost {
 
 
 /// Resource for the state of level up screen.
+///
+/// It's kept between level up screens until the end of the game,
+/// so locked perks and banished perks are remembered.
 #[derive(Debug, Default, Reflect, Resource)]
 #[reflect(Resource)]
 pub struct LevelUpScreenState {
     pub offered_perk_ids: Vec<SmolStr>,
+    pub locked_perk_ids: Vec<SmolStr>,
+    pub banished_perk_ids: Vec<SmolStr>,
+}
+
+impl LevelUpScreenState {
+    /// Gets whether the perk in a given position is locked.
+    pub fn is_locked(&self, position: NonZeroUsize) -> bool {
+        self.offered_perk_ids
+            .get(position.get() - 1)
+            .map(|perk_id| self.locked_perk_ids.contains(perk_id))
+            .unwrap_or(false)
+    }
+
+    /// Gets whether a perk is banished.
+    pub fn is_banished(&self, perk_id: &str) -> bool {
+        self.banished_perk_ids.iter().any(|banished_perk_id| banished_perk_id == perk_id)
+    }
+}
+
+impl LevelUpScreenState {
+    /// Locks the perk in the given position.
+    pub fn lock(
+        &mut self,
+        position: NonZeroUsize,
+        configuration: &LevelUpScreenConfiguration,
+    ) -> LevelUpScreenLockUnlockStatus {
+        let perk_id = match self.offered_perk_ids.get(position.get() - 1) {
+            Some(perk_id) => perk_id.clone(),
+            None => {
+                log::error!(
+                    "unable to lock perk {} in the level up screen as it doesn't exist",
+                    position,
+                );
+                return LevelUpScreenLockUnlockStatus::NotExist;
+            },
+        };
+
+        if self.locked_perk_ids.contains(&perk_id) {
+            log::error!(
+                "unable to lock perk {} in the level up screen as it's already locked",
+                position,
+            );
+            return LevelUpScreenLockUnlockStatus::AlreadyLocked;
+        }
+        if self.locked_perk_ids.len() >= (configuration.max_locked_perks as usize) {
+            log::error!(
+                "unable to lock perk {} in the level up screen as no more perks can be locked",
+                position,
+            );
+            return LevelUpScreenLockUnlockStatus::LimitReached;
+        }
+
+        log::info!("locking perk {} ({:?}) in the level up screen", position, perk_id);
+        self.locked_perk_ids.push(perk_id);
+        LevelUpScreenLockUnlockStatus::Locked
+    }
+
+    /// Unlocks the perk in the given position.
+    pub fn unlock(&mut self, position: NonZeroUsize) -> LevelUpScreenLockUnlockStatus {
+        let perk_id = match self.offered_perk_ids.get(position.get() - 1) {
+            Some(perk_id) => perk_id.clone(),
+            None => {
+                log::error!(
+                    "unable to unlock perk {} in the level up screen as it doesn't exist",
+                    position,
+                );
+                return LevelUpScreenLockUnlockStatus::NotExist;
+            },
+        };
+
+        if !self.locked_perk_ids.contains(&perk_id) {
+            log::error!(
+                "unable to unlock perk {} in the level up screen as it's not locked",
+                position,
+            );
+            return LevelUpScreenLockUnlockStatus::AlreadyUnlocked;
+        }
+
+        log::info!("unlocking perk {} ({:?}) in the level up screen", position, perk_id);
+        self.locked_perk_ids.retain(|locked_perk_id| *locked_perk_id != perk_id);
+        LevelUpScreenLockUnlockStatus::Unlocked
+    }
+
+    /// Banishes the perk in the given position.
+    ///
+    /// Banished perk is removed from the offered perks, and it'll be replaced by a new perk.
+    pub fn banish(
+        &mut self,
+        position: NonZeroUsize,
+        configuration: &mut LevelUpScreenConfiguration,
+    ) -> LevelUpScreenBanishStatus {
+        let index = position.get() - 1;
+        if index >= self.offered_perk_ids.len() {
+            log::error!(
+                "unable to banish perk {} in the level up screen as it doesn't exist",
+                position,
+            );
+            return LevelUpScreenBanishStatus::NotExist;
+        }
+        if configuration.remaining_banishes == 0 {
+            log::error!(
+                "unable to banish perk {} in the level up screen as no banishes are left",
+                position,
+            );
+            return LevelUpScreenBanishStatus::LimitReached;
+        }
+
+        let perk_id = self.offered_perk_ids.remove(index);
+        log::info!("banishing perk {} ({:?}) in the level up screen", position, perk_id);
+
+        self.locked_perk_ids.retain(|locked_perk_id| *locked_perk_id != perk_id);
+        self.offered_perk_ids.retain(|offered_perk_id| *offered_perk_id != perk_id);
+        self.banished_perk_ids.push(perk_id);
+
+        configuration.remaining_banishes -= 1;
+        LevelUpScreenBanishStatus::Banished
+    }
+
+    /// Prepares the state for the next level up screen after a perk is selected or skipped.
+    ///
+    /// Only the locked perks stay offered.
+    pub fn finish(&mut self, selected_perk_id: Option<&str>) {
+        if let Some(selected_perk_id) = selected_perk_id {
+            if let Some(index) =
+                self.locked_perk_ids.iter().position(|perk_id| perk_id == selected_perk_id)
+            {
+                self.locked_perk_ids.remove(index);
+            }
+        }
+        self.offered_perk_ids.retain(|perk_id| self.locked_perk_ids.contains(perk_id));
+    }
+}
+
+
+/// Status of locking or unlocking in the level up screen.
+#[derive(Debug)]
+pub enum LevelUpScreenLockUnlockStatus {
+    NotExist,
+    AlreadyLocked,
+    LimitReached,
+    Locked,
+    AlreadyUnlocked,
+    Unlocked,
+}
+
+
+/// Status of banishing in the level up screen.
+#[derive(Debug)]
+pub enum LevelUpScreenBanishStatus {
+    NotExist,
+    LimitReached,
+    Banished,
 }
 
 
@@ -293,8 +489,9 @@ pub struct LevelUpScreenWidgets(
     /// Rows of widgets in the level up screen.
     ///
     /// - Select buttons
-    /// - Balance & Reroll button
-    pub [Vec<Entity>; 2],
+    /// - Lock & Banish buttons
+    /// - Balance & Skip button & Reroll button
+    pub [Vec<Entity>; 3],
 );
 
 
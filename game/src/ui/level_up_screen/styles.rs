@@ -76,6 +76,31 @@ pub fn select_button() -> Style {
 }
 
 
+/// Gets the style of perk action containers in the level up screen.
+pub fn perk_actions_container() -> Style {
+    Style {
+        flex_direction: FlexDirection::Row,
+        column_gap: Val::Percent(4.00),
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        width: Val::Percent(100.00),
+        height: Val::Percent(12.00),
+        ..default()
+    }
+}
+
+/// Gets the style of lock and banish buttons in the level up screen.
+pub fn perk_action_button() -> Style {
+    Style {
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        width: Val::Percent(40.00),
+        height: Val::Percent(100.00),
+        ..default()
+    }
+}
+
+
 /// Gets the style of the footer container in the level up screen.
 pub fn footer_container() -> Style {
     Style {
@@ -115,3 +140,14 @@ pub fn reroll_button() -> Style {
         ..default()
     }
 }
+
+/// Gets the style of the skip button in the level up screen.
+pub fn skip_button() -> Style {
+    Style {
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        width: Val::Percent(20.00),
+        height: Val::Percent(90.00),
+        ..default()
+    }
+}
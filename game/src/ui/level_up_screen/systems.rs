@@ -1,4 +1,5 @@
 use crate::{
+    configuration::localization::currency,
     prelude::*,
     ui::level_up_screen::{
         commands::*,
@@ -61,7 +62,9 @@ pub fn apply_level_up_screen_command(
                 match game_state.get() {
                     GameState::LevelUpScreen => {
                         log::info!("closing the level up screen");
-                        commands.remove_resource::<LevelUpScreenState>();
+                        if let Some(mut level_up_screen_state) = level_up_screen_state {
+                            level_up_screen_state.finish(None);
+                        }
                         game_state_stack.pop();
                         next_game_state.set(GameState::Transition);
                         reply!(command, "Closed.");
@@ -95,11 +98,15 @@ pub fn apply_level_up_screen_command(
 
                 for (index, id) in level_up_screen_state.offered_perk_ids.iter().enumerate() {
                     let position = NonZeroUsize::new(index + 1).unwrap();
-                    reply!(command, "{}) {}", position, id);
+                    if level_up_screen_state.is_locked(position) {
+                        reply!(command, "{}) {} (locked)", position, id);
+                    } else {
+                        reply!(command, "{}) {}", position, id);
+                    }
                 }
             },
             LevelUpScreenCommands::Select { position } => {
-                let level_up_screen_state = match level_up_screen_state {
+                let mut level_up_screen_state = match level_up_screen_state {
                     Some(resource) if level_up_screen_reason.is_some() => resource,
                     _ => {
                         reply!(command, "Not available outside the level up screen.");
@@ -116,8 +123,8 @@ pub fn apply_level_up_screen_command(
                     return;
                 }
 
-                let selected_perk_id = &level_up_screen_state.offered_perk_ids[index];
-                let selected_perk = match perk_registry.find_perk_by_id(selected_perk_id) {
+                let selected_perk_id = level_up_screen_state.offered_perk_ids[index].clone();
+                let selected_perk = match perk_registry.find_perk_by_id(&selected_perk_id) {
                     Some(perk) => perk,
                     None => {
                         reply!(
@@ -139,7 +146,7 @@ pub fn apply_level_up_screen_command(
                     LevelUpScreenReason::Cheating => ObtainLosePerkReason::Cheating,
                 };
 
-                commands.remove_resource::<LevelUpScreenState>();
+                level_up_screen_state.finish(Some(&selected_perk_id));
                 commands.run_system_with_input(
                     registered_systems.perk.obtain_perk,
                     (selected_perk.deref().clone(), obtain_lose_perk_reason),
@@ -181,6 +188,99 @@ pub fn apply_level_up_screen_command(
                 level_up_screen_state.offered_perk_ids[index] = perk;
                 reply!(command, "Done.");
             },
+            LevelUpScreenCommands::Lock { position } => {
+                let mut level_up_screen_state = match level_up_screen_state {
+                    Some(resource) if level_up_screen_reason.is_some() => resource,
+                    _ => {
+                        reply!(command, "Not available outside the level up screen.");
+                        reply!(command, "");
+                        return;
+                    },
+                };
+
+                match level_up_screen_state.lock(position, &level_up_screen_configuration) {
+                    LevelUpScreenLockUnlockStatus::NotExist => {
+                        reply!(command, "Failed to lock perk {} as it doesn't exist.", position);
+                    },
+                    LevelUpScreenLockUnlockStatus::AlreadyLocked => {
+                        reply!(command, "Perk {} is already locked.", position);
+                    },
+                    LevelUpScreenLockUnlockStatus::LimitReached => {
+                        reply!(
+                            command,
+                            "Failed to lock perk {} as no more perks can be locked.",
+                            position
+                        );
+                    },
+                    _ => {
+                        reply!(command, "Locked.");
+                    },
+                }
+            },
+            LevelUpScreenCommands::Unlock { position } => {
+                let mut level_up_screen_state = match level_up_screen_state {
+                    Some(resource) if level_up_screen_reason.is_some() => resource,
+                    _ => {
+                        reply!(command, "Not available outside the level up screen.");
+                        reply!(command, "");
+                        return;
+                    },
+                };
+
+                match level_up_screen_state.unlock(position) {
+                    LevelUpScreenLockUnlockStatus::NotExist => {
+                        reply!(command, "Failed to unlock perk {} as it doesn't exist.", position);
+                    },
+                    LevelUpScreenLockUnlockStatus::AlreadyUnlocked => {
+                        reply!(command, "Perk {} is not locked.", position);
+                    },
+                    _ => {
+                        reply!(command, "Unlocked.");
+                    },
+                }
+            },
+            LevelUpScreenCommands::Banish { position } => {
+                let mut level_up_screen_state = match level_up_screen_state {
+                    Some(resource) if level_up_screen_reason.is_some() => resource,
+                    _ => {
+                        reply!(command, "Not available outside the level up screen.");
+                        reply!(command, "");
+                        return;
+                    },
+                };
+
+                match level_up_screen_state.banish(position, &mut level_up_screen_configuration) {
+                    LevelUpScreenBanishStatus::NotExist => {
+                        reply!(command, "Failed to banish perk {} as it doesn't exist.", position);
+                    },
+                    LevelUpScreenBanishStatus::LimitReached => {
+                        reply!(
+                            command,
+                            "Failed to banish perk {} as no banishes are left.",
+                            position
+                        );
+                    },
+                    LevelUpScreenBanishStatus::Banished => {
+                        reply!(command, "Banished.");
+                    },
+                }
+            },
+            LevelUpScreenCommands::Banished => {
+                match level_up_screen_state {
+                    Some(level_up_screen_state)
+                        if !level_up_screen_state.banished_perk_ids.is_empty() =>
+                    {
+                        for (index, id) in
+                            level_up_screen_state.banished_perk_ids.iter().enumerate()
+                        {
+                            reply!(command, "{}) {}", index + 1, id);
+                        }
+                    },
+                    _ => {
+                        reply!(command, "No perks are banished.");
+                    },
+                }
+            },
             LevelUpScreenCommands::NumberOfPerks { subcommand } => {
                 match subcommand {
                     NumberOfPerksCommands::Show => {
@@ -281,6 +381,7 @@ pub fn spawn_level_up_screen(
     level_up_screen_state: Option<ResMut<LevelUpScreenState>>,
     registered_systems: Res<RegisteredSystems>,
     previously_selected_level_up_screen_widget: Option<Res<PreviouslySelectedLevelUpScreenWidget>>,
+    number_format: Res<NumberFormat>,
     localization: Res<Localization>,
 ) {
     if !level_up_screen_query.is_empty() {
@@ -380,6 +481,7 @@ pub fn spawn_level_up_screen(
                 let text_font = asset_server.load("fonts/FiraSans-Bold.ttf");
                 let text_size = BALANCE_TEXT_FONT_SIZE;
 
+                let balance_label = currency(balance.0, &number_format);
                 let balance_text = commands
                     .spawn((
                         Name::new("Text"),
@@ -387,7 +489,7 @@ pub fn spawn_level_up_screen(
                         TextBundle {
                             text: Text {
                                 sections: vec![TextSection::new(
-                                    format!("{:.0}", *balance),
+                                    balance_label.get(&localization),
                                     TextStyle {
                                         font: text_font.clone(),
                                         font_size: text_size,
@@ -400,6 +502,7 @@ pub fn spawn_level_up_screen(
                             style: text_style,
                             ..default()
                         },
+                        balance_label,
                     ))
                     .id();
 
@@ -408,6 +511,33 @@ pub fn spawn_level_up_screen(
                 balance_container
             };
 
+            // Skip button.
+            let skip_button = if level_up_screen_configuration.remaining_skips > 0 {
+                let skip_reward = level_up_screen_configuration.skip_reward;
+
+                let skip_button_style = styles::skip_button();
+                let skip_button_colors = WidgetColors::button();
+                let skip_button_font = asset_server.load("fonts/FiraSans-Bold.ttf");
+                let skip_button_size = SKIP_BUTTON_FONT_SIZE;
+
+                Some(Widget::button(
+                    &mut commands,
+                    (
+                        Name::new("Skip Button"),
+                        LevelUpScreenSkipButton { reward: skip_reward },
+                        Widget::default(),
+                    ),
+                    &skip_button_style,
+                    skip_button_colors,
+                    &skip_button_font,
+                    skip_button_size,
+                    localization::skip_button(skip_reward, &number_format),
+                    &localization,
+                ))
+            } else {
+                None
+            };
+
             // Reroll button.
             let reroll_button = {
                 let reroll_cost = level_up_screen_configuration.reroll_cost();
@@ -429,7 +559,7 @@ pub fn spawn_level_up_screen(
                     reroll_button_colors,
                     &reroll_button_font,
                     reroll_button_size,
-                    localization::reroll_button(reroll_cost),
+                    localization::reroll_button(reroll_cost, &number_format),
                     &localization,
                 );
 
@@ -440,8 +570,14 @@ pub fn spawn_level_up_screen(
                 reroll_button
             };
 
-            commands.entity(footer_container).add_child(balance_container).add_child(reroll_button);
-            level_up_screen_widgets[1] = vec![balance_container, reroll_button];
+            commands.entity(footer_container).add_child(balance_container);
+            level_up_screen_widgets[2].push(balance_container);
+            if let Some(skip_button) = skip_button {
+                commands.entity(footer_container).add_child(skip_button);
+                level_up_screen_widgets[2].push(skip_button);
+            }
+            commands.entity(footer_container).add_child(reroll_button);
+            level_up_screen_widgets[2].push(reroll_button);
         }
     }
 
@@ -492,8 +628,12 @@ pub fn reroll_perks(
     mut commands: Commands,
     mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
     perk_registry: Res<PerkRegistry>,
+    obtained_perks: Res<ObtainedPerks>,
+    player_query: Query<(&Luck, Option<&Children>), With<Player>>,
+    item_query: Query<&Luck, (With<Item>, Without<Player>)>,
     level_up_screen_configuration: Res<LevelUpScreenConfiguration>,
     level_up_screen_state: Option<ResMut<LevelUpScreenState>>,
+    progression: Res<Persistent<Progression>>,
 ) {
     let mut new_level_up_screen_state = match level_up_screen_state {
         Some(mut level_up_screen_state) => std::mem::take(level_up_screen_state.deref_mut()),
@@ -512,18 +652,40 @@ pub fn reroll_perks(
         new_level_up_screen_state.offered_perk_ids.truncate(expected_number_of_perks);
     } else {
         if expected_number_of_perks == actual_number_of_perks {
-            // we're doing a regular reroll, so we need to reset everything.
-            new_level_up_screen_state.offered_perk_ids.clear();
-            actual_number_of_perks = 0;
+            // we're doing a regular reroll, so we need to reset everything but the locked perks.
+            let locked_perk_ids = new_level_up_screen_state.locked_perk_ids.clone();
+            new_level_up_screen_state
+                .offered_perk_ids
+                .retain(|perk_id| locked_perk_ids.contains(perk_id));
+            actual_number_of_perks = new_level_up_screen_state.offered_perk_ids.len();
         }
 
         let mut commonness_of_perks_that_can_be_offered = Vec::new();
         for entry in perk_registry.iter() {
+            let perk_id = entry.perk.id();
+            if !entry.perk.is_available(&progression)
+                || new_level_up_screen_state.is_banished(&perk_id)
+                || new_level_up_screen_state.locked_perk_ids.contains(&perk_id)
+            {
+                continue;
+            }
+
             let commonness = entry.perk.commonness;
-            if commonness != 0 {
-                commonness_of_perks_that_can_be_offered.push((entry.perk.id(), commonness));
+            if commonness != 0 && perk_registry.can_be_offered(&entry.perk, &obtained_perks) {
+                commonness_of_perks_that_can_be_offered.push((perk_id, commonness));
             }
         }
+
+        let luck = utils::property::find_luck_of_players(&player_query, &item_query);
+        if luck.0 != 0.00 {
+            log::info!("biasing the commonness of perks with {:.2} luck", luck.0);
+            luck.apply(
+                commonness_of_perks_that_can_be_offered
+                    .iter_mut()
+                    .map(|(_, commonness)| commonness),
+            );
+        }
+
         commonness_of_perks_that_can_be_offered.sort_by(|(id1, commonness1), (id2, commonness)| {
             if commonness1 == commonness {
                 id1.cmp(id2)
@@ -606,9 +768,11 @@ pub fn update_offered_perks(
     level_up_screen_perks_container_query: Query<Entity, With<LevelUpScreenPerksContainer>>,
     selected_widget_query: Query<Entity, With<WidgetSelected>>,
     asset_server: Res<AssetServer>,
+    level_up_screen_configuration: Res<LevelUpScreenConfiguration>,
     level_up_screen_state: Res<LevelUpScreenState>,
     mut level_up_screen_widgets: ResMut<LevelUpScreenWidgets>,
     perk_registry: Res<PerkRegistry>,
+    number_format: Res<NumberFormat>,
     localization: Res<Localization>,
 ) {
     let level_up_screen_perks_container_entity =
@@ -634,8 +798,19 @@ pub fn update_offered_perks(
     let select_button_font = asset_server.load("fonts/FiraSans-Bold.ttf");
     let select_button_size = SELECT_BUTTON_FONT_SIZE;
 
+    let perk_action_button_style = styles::perk_action_button();
+    let perk_action_button_colors = WidgetColors::button();
+    let perk_action_button_font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let perk_action_button_size = PERK_ACTION_BUTTON_FONT_SIZE;
+
+    let can_lock = level_up_screen_configuration.max_locked_perks > 0;
+    let can_lock_more = level_up_screen_state.locked_perk_ids.len()
+        < (level_up_screen_configuration.max_locked_perks as usize);
+    let can_banish = level_up_screen_configuration.remaining_banishes > 0;
+
     let mut children = Vec::new();
     let mut select_widgets = Vec::new();
+    let mut action_widgets = Vec::new();
 
     for (perk_index, perk_id) in level_up_screen_state.offered_perk_ids.iter().enumerate() {
         let perk_position = NonZeroUsize::new(perk_index + 1).unwrap();
@@ -742,6 +917,68 @@ pub fn update_offered_perks(
         select_widgets.push(select_button);
 
         commands.entity(perk_container).add_child(perk_details).add_child(select_button);
+
+        if can_lock || can_banish {
+            let perk_actions = commands
+                .spawn((
+                    Name::new("Actions"),
+                    NodeBundle { style: styles::perk_actions_container(), ..default() },
+                ))
+                .id();
+
+            if can_lock {
+                let is_locked = level_up_screen_state.is_locked(perk_position);
+                let lock_button = Widget::button(
+                    &mut commands,
+                    (
+                        Name::new("Lock Button"),
+                        LevelUpScreenLockButton { perk_index },
+                        Widget::default(),
+                    ),
+                    &perk_action_button_style,
+                    perk_action_button_colors,
+                    &perk_action_button_font,
+                    perk_action_button_size,
+                    if is_locked {
+                        localization::unlock_button()
+                    } else {
+                        localization::lock_button()
+                    },
+                    &localization,
+                );
+                if !is_locked && !can_lock_more {
+                    commands.entity(lock_button).insert(WidgetDisabled);
+                }
+
+                action_widgets.push(lock_button);
+                commands.entity(perk_actions).add_child(lock_button);
+            }
+
+            if can_banish {
+                let banish_button = Widget::button(
+                    &mut commands,
+                    (
+                        Name::new("Banish Button"),
+                        LevelUpScreenBanishButton { perk_index },
+                        Widget::default(),
+                    ),
+                    &perk_action_button_style,
+                    perk_action_button_colors,
+                    &perk_action_button_font,
+                    perk_action_button_size,
+                    localization::banish_button(
+                        level_up_screen_configuration.remaining_banishes,
+                        &number_format,
+                    ),
+                    &localization,
+                );
+
+                action_widgets.push(banish_button);
+                commands.entity(perk_actions).add_child(banish_button);
+            }
+
+            commands.entity(perk_container).add_child(perk_actions);
+        }
     }
 
     if let Ok(selected_widget) = selected_widget_query.get_single() {
@@ -755,14 +992,20 @@ pub fn update_offered_perks(
             }
         }
         if let Some((i, j)) = selected_widget_index {
-            if i == 0 {
+            let new_widgets = match i {
+                0 => Some(&select_widgets),
+                1 => Some(&action_widgets),
+                _ => None,
+            };
+            if let Some(new_widgets) = new_widgets {
                 commands
                     .entity(
-                        select_widgets
+                        new_widgets
                             .get(j)
                             .cloned()
+                            .or(new_widgets.last().cloned())
                             .or(select_widgets.last().cloned())
-                            .unwrap_or(level_up_screen_widgets[1].last().cloned().unwrap()),
+                            .unwrap_or(level_up_screen_widgets[2].last().cloned().unwrap()),
                     )
                     .insert(WidgetSelected::now());
             }
@@ -778,6 +1021,7 @@ pub fn update_offered_perks(
     }
 
     level_up_screen_widgets[0] = select_widgets;
+    level_up_screen_widgets[1] = action_widgets;
 }
 
 /// Updates level up screen widgets with appropriate widget up/down/left/right components.
@@ -911,15 +1155,16 @@ pub fn update_level_up_screen_widget_hierarchy(
 
 /// Updates balance text.
 pub fn update_balance_text(
-    mut balance_text_query: Query<&mut Text, With<LevelUpScreenBalanceText>>,
+    mut balance_text_query: Query<&mut LocalizedText, With<LevelUpScreenBalanceText>>,
     balance: Res<Balance>,
+    number_format: Res<NumberFormat>,
 ) {
     let mut balance_text = match balance_text_query.get_single_mut() {
         Ok(query_result) => query_result,
         Err(_) => return,
     };
 
-    balance_text.sections[0].value = format!("{}", *balance);
+    *balance_text = currency(balance.0, &number_format);
 }
 
 /// Updates reroll button.
@@ -929,6 +1174,7 @@ pub fn update_reroll_button(
     mut text_query: Query<(&Parent, &mut LocalizedText)>,
     balance: Res<Balance>,
     level_up_screen_configuration: Res<LevelUpScreenConfiguration>,
+    number_format: Res<NumberFormat>,
 ) {
     let (reroll_button_entity, mut reroll_button) = match reroll_button_query.get_single_mut() {
         Ok(query_result) => query_result,
@@ -940,7 +1186,7 @@ pub fn update_reroll_button(
 
     for (parent_entity, mut refresh_button_text) in text_query.iter_mut() {
         if parent_entity.get() == reroll_button_entity {
-            *refresh_button_text = localization::reroll_button(reroll_cost);
+            *refresh_button_text = localization::reroll_button(reroll_cost, &number_format);
             break;
         }
     }
@@ -953,6 +1199,37 @@ pub fn update_reroll_button(
 }
 
 
+/// Updates skip button.
+pub fn update_skip_button(
+    mut commands: Commands,
+    mut skip_button_query: Query<(Entity, &mut LevelUpScreenSkipButton)>,
+    mut text_query: Query<(&Parent, &mut LocalizedText)>,
+    level_up_screen_configuration: Res<LevelUpScreenConfiguration>,
+    number_format: Res<NumberFormat>,
+) {
+    let (skip_button_entity, mut skip_button) = match skip_button_query.get_single_mut() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+
+    let skip_reward = level_up_screen_configuration.skip_reward;
+    skip_button.reward = skip_reward;
+
+    for (parent_entity, mut skip_button_text) in text_query.iter_mut() {
+        if parent_entity.get() == skip_button_entity {
+            *skip_button_text = localization::skip_button(skip_reward, &number_format);
+            break;
+        }
+    }
+
+    if level_up_screen_configuration.remaining_skips == 0 {
+        commands.entity(skip_button_entity).insert(WidgetDisabled);
+    } else {
+        commands.entity(skip_button_entity).remove::<WidgetDisabled>();
+    }
+}
+
+
 /// Navigates the level up screen using level up screen actions.
 pub fn navigation(
     mut commands: Commands,
@@ -1017,6 +1294,7 @@ pub fn select_button_interaction(
     mut game_state_stack: ResMut<GameStateStack>,
     mut next_game_state: ResMut<NextState<GameState>>,
     mut level_up_screen_reason: ResMut<LevelUpScreenReason>,
+    mut level_up_screen_state: ResMut<LevelUpScreenState>,
     registered_systems: Res<RegisteredSystems>,
 ) {
     if let Ok((mut button, metadata)) = select_button_query.get_single_mut() {
@@ -1030,7 +1308,7 @@ pub fn select_button_interaction(
                 LevelUpScreenReason::Cheating => ObtainLosePerkReason::Cheating,
             };
 
-            commands.remove_resource::<LevelUpScreenState>();
+            level_up_screen_state.finish(Some(&metadata.perk.id()));
             commands.run_system_with_input(
                 registered_systems.perk.obtain_perk,
                 (metadata.perk.clone(), obtain_lose_perk_reason),
@@ -1042,6 +1320,81 @@ pub fn select_button_interaction(
     }
 }
 
+/// Locks or unlocks offered perks in the level up screen.
+pub fn lock_button_interaction(
+    mut lock_button_query: Query<(&mut Widget, &LevelUpScreenLockButton), Changed<Widget>>,
+    level_up_screen_configuration: Res<LevelUpScreenConfiguration>,
+    mut level_up_screen_state: ResMut<LevelUpScreenState>,
+) {
+    for (mut button, metadata) in lock_button_query.iter_mut() {
+        button.on_click(|| {
+            let perk_position = NonZeroUsize::new(metadata.perk_index + 1).unwrap();
+            if level_up_screen_state.is_locked(perk_position) {
+                level_up_screen_state.unlock(perk_position);
+            } else {
+                level_up_screen_state.lock(perk_position, &level_up_screen_configuration);
+            }
+        });
+    }
+}
+
+/// Banishes offered perks in the level up screen.
+pub fn banish_button_interaction(
+    mut banish_button_query: Query<(&mut Widget, &LevelUpScreenBanishButton), Changed<Widget>>,
+    mut level_up_screen_configuration: ResMut<LevelUpScreenConfiguration>,
+    mut level_up_screen_state: ResMut<LevelUpScreenState>,
+) {
+    for (mut button, metadata) in banish_button_query.iter_mut() {
+        button.on_click(|| {
+            let perk_position = NonZeroUsize::new(metadata.perk_index + 1).unwrap();
+            level_up_screen_state.banish(perk_position, &mut level_up_screen_configuration);
+        });
+    }
+}
+
+/// Skips the level up for balance and transitions to the next state.
+pub fn skip_button_interaction(
+    mut skip_button_query: Query<(&mut Widget, &LevelUpScreenSkipButton), Changed<Widget>>,
+    mut game_state_stack: ResMut<GameStateStack>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut balance: ResMut<Balance>,
+    mut level_up_screen_configuration: ResMut<LevelUpScreenConfiguration>,
+    mut level_up_screen_reason: ResMut<LevelUpScreenReason>,
+    mut level_up_screen_state: ResMut<LevelUpScreenState>,
+    mut level_up_skipped_event_writer: EventWriter<LevelUpSkippedEvent>,
+) {
+    if let Ok((mut button, metadata)) = skip_button_query.get_single_mut() {
+        button.on_click(|| {
+            log::info!("skip button is clicked");
+
+            if level_up_screen_configuration.remaining_skips == 0 {
+                log::error!("unable to skip the level up as no skips are left");
+                return;
+            }
+            level_up_screen_configuration.remaining_skips -= 1;
+
+            let level = match level_up_screen_reason.deref_mut() {
+                LevelUpScreenReason::LevelingUp { to } => {
+                    let result = *to;
+                    to.0 = to.0.checked_add(1).unwrap_or(NonZeroU16::MAX);
+                    Some(result)
+                },
+                LevelUpScreenReason::Cheating => None,
+            };
+
+            if *metadata.reward != 0.00 {
+                balance.gain(metadata.reward, "skip the level up");
+            }
+            level_up_skipped_event_writer.send(LevelUpSkippedEvent { level });
+
+            level_up_screen_state.finish(None);
+
+            game_state_stack.pop();
+            next_game_state.set(GameState::Transition);
+        });
+    }
+}
+
 /// Rerolls offered perks in the level up screen.
 pub fn reroll_button_interaction(
     mut commands: Commands,
@@ -1081,3 +1434,8 @@ pub fn reroll_button_interaction(
 pub fn reset_level_up_screen_configuration(mut commands: Commands) {
     commands.insert_resource(LevelUpScreenConfiguration::default());
 }
+
+/// Resets the state of the level up screen, including locked and banished perks.
+pub fn reset_level_up_screen_state(mut commands: Commands) {
+    commands.remove_resource::<LevelUpScreenState>();
+}
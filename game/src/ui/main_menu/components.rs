@@ -11,6 +11,21 @@ pub struct MainMenu;
 pub struct MainMenuPlayButton;
 
 
+/// Tag component for the daily challenge button in the main menu.
+#[derive(Component, Debug, Reflect)]
+pub struct MainMenuDailyChallengeButton;
+
+
+/// Tag component for the unlocks button in the main menu.
+#[derive(Component, Debug, Reflect)]
+pub struct MainMenuUnlocksButton;
+
+
+/// Tag component for the achievements button in the main menu.
+#[derive(Component, Debug, Reflect)]
+pub struct MainMenuAchievementsButton;
+
+
 /// Tag component for the settings button in the main menu.
 #[derive(Component, Debug, Reflect)]
 pub struct MainMenuSettingsButton;
@@ -10,6 +10,41 @@ pub fn play_button() -> LocalizedText {
     }
 }
 
+/// Gets the localized text of the daily challenge button.
+pub fn daily_challenge_button(attempted: bool) -> LocalizedText {
+    if attempted {
+        LocalizedText::Localized {
+            key: "main-menu-attempted-daily-challenge-button",
+            args: smallvec![],
+            fallback: "Daily Challenge (Practice)".into(),
+        }
+    } else {
+        LocalizedText::Localized {
+            key: "main-menu-daily-challenge-button",
+            args: smallvec![],
+            fallback: "Daily Challenge".into(),
+        }
+    }
+}
+
+/// Gets the localized text of the unlocks button.
+pub fn unlocks_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "main-menu-unlocks-button",
+        args: smallvec![],
+        fallback: "Unlocks".into(),
+    }
+}
+
+/// Gets the localized text of the achievements button.
+pub fn achievements_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "main-menu-achievements-button",
+        args: smallvec![],
+        fallback: "Achievements".into(),
+    }
+}
+
 /// Gets the localized text of the settings button.
 pub fn settings_button() -> LocalizedText {
     LocalizedText::Localized {
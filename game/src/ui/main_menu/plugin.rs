@@ -11,6 +11,9 @@ impl Plugin for MainMenuPlugin {
         // Register components.
         app.register_type::<MainMenu>();
         app.register_type::<MainMenuPlayButton>();
+        app.register_type::<MainMenuDailyChallengeButton>();
+        app.register_type::<MainMenuUnlocksButton>();
+        app.register_type::<MainMenuAchievementsButton>();
         app.register_type::<MainMenuSettingsButton>();
         app.register_type::<MainMenuQuitButton>();
 
@@ -22,7 +25,14 @@ impl Plugin for MainMenuPlugin {
         app.add_systems(Update, navigation.in_set(MainMenuSystems));
         app.add_systems(
             PostUpdate,
-            (play_button_interaction, settings_button_interaction, quit_button_interaction)
+            (
+                play_button_interaction,
+                daily_challenge_button_interaction,
+                unlocks_button_interaction,
+                achievements_button_interaction,
+                settings_button_interaction,
+                quit_button_interaction,
+            )
                 .in_set(MainMenuSystems),
         );
         app.add_systems(OnExit(AppState::MainMenu), despawn_main_menu);
@@ -13,6 +13,7 @@ pub fn spawn_main_menu(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     main_menu_action_input_map: Res<InputMap<MainMenuAction>>,
+    daily_challenges: Res<Persistent<DailyChallenges>>,
     localization: Res<Localization>,
 ) {
     let button_style = styles::button();
@@ -36,6 +37,41 @@ pub fn spawn_main_menu(
         &localization,
     );
 
+    let daily_challenge_button = Widget::button(
+        &mut commands,
+        (Name::new("Daily Challenge Button"), MainMenuDailyChallengeButton, Widget::default()),
+        &button_style,
+        button_colors,
+        &button_font,
+        button_font_size,
+        localization::daily_challenge_button(
+            daily_challenges.is_attempted(DailyChallengeDate::today()),
+        ),
+        &localization,
+    );
+
+    let unlocks_button = Widget::button(
+        &mut commands,
+        (Name::new("Unlocks Button"), MainMenuUnlocksButton, Widget::default()),
+        &button_style,
+        button_colors,
+        &button_font,
+        button_font_size,
+        localization::unlocks_button(),
+        &localization,
+    );
+
+    let achievements_button = Widget::button(
+        &mut commands,
+        (Name::new("Achievements Button"), MainMenuAchievementsButton, Widget::default()),
+        &button_style,
+        button_colors,
+        &button_font,
+        button_font_size,
+        localization::achievements_button(),
+        &localization,
+    );
+
     let settings_button = Widget::button(
         &mut commands,
         (Name::new("Settings Button"), MainMenuSettingsButton, Widget::default()),
@@ -58,7 +94,14 @@ pub fn spawn_main_menu(
         &localization,
     );
 
-    let entities = [play_button, settings_button, quit_button];
+    let entities = [
+        play_button,
+        daily_challenge_button,
+        unlocks_button,
+        achievements_button,
+        settings_button,
+        quit_button,
+    ];
     for i in 0..entities.len() {
         let up = if i != 0 { entities[i - 1] } else { entities[entities.len() - 1] };
         let current = entities[i];
@@ -144,6 +187,49 @@ pub fn play_button_interaction(
     }
 }
 
+/// Starts the daily challenge.
+pub fn daily_challenge_button_interaction(
+    mut commands: Commands,
+    mut daily_challenge_button_query: Query<
+        &mut Widget,
+        (Changed<Widget>, With<MainMenuDailyChallengeButton>),
+    >,
+    registered_systems: Res<RegisteredSystems>,
+) {
+    if let Ok(mut button) = daily_challenge_button_query.get_single_mut() {
+        button.on_click(|| {
+            commands.run_system(registered_systems.daily_challenge.start_daily_challenge);
+        });
+    }
+}
+
+/// Transitions to the unlock screen.
+pub fn unlocks_button_interaction(
+    mut unlocks_button_query: Query<&mut Widget, (Changed<Widget>, With<MainMenuUnlocksButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok(mut button) = unlocks_button_query.get_single_mut() {
+        button.on_click(|| {
+            next_app_state.set(AppState::UnlockScreen);
+        });
+    }
+}
+
+/// Transitions to the achievements screen.
+pub fn achievements_button_interaction(
+    mut achievements_button_query: Query<
+        &mut Widget,
+        (Changed<Widget>, With<MainMenuAchievementsButton>),
+    >,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok(mut button) = achievements_button_query.get_single_mut() {
+        button.on_click(|| {
+            next_app_state.set(AppState::AchievementsScreen);
+        });
+    }
+}
+
 /// Transitions to the settings menu.
 pub fn settings_button_interaction(
     mut settings_button_query: Query<&mut Widget, (Changed<Widget>, With<MainMenuSettingsButton>)>,
@@ -2,11 +2,12 @@ use crate::prelude::*;
 
 
 /// Gets the localized text of the refresh button.
-pub fn refresh_button(refresh_cost: Balance) -> LocalizedText {
+pub fn refresh_button(refresh_cost: Balance, number_format: &NumberFormat) -> LocalizedText {
+    let amount = number_format.decimal(refresh_cost.0, 2);
     LocalizedText::Localized {
         key: "market-refresh-button",
-        args: smallvec![("cost", format_smolstr!("{}", refresh_cost))],
-        fallback: format!("Refresh - {}", refresh_cost).into(),
+        args: smallvec![("amount", amount.to_smolstr())],
+        fallback: format!("Refresh - {} $", amount).into(),
     }
 }
 
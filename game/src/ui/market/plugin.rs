@@ -29,7 +29,12 @@ impl Plugin for MarketPlugin {
         app.world_mut().resource_mut::<LocaleAssets>().push("ui/market.ftl");
 
         // Add systems.
-        app.add_systems(OnEnter(GameState::Market), spawn_market);
+        app.add_systems(
+            OnEnter(GameState::Market),
+            spawn_market.run_if(|market_configuration: Res<MarketConfiguration>| {
+                market_configuration.is_enabled
+            }),
+        );
         app.add_systems(
             PreUpdate,
             (
@@ -1,4 +1,5 @@
 use crate::{
+    configuration::localization::currency,
     prelude::*,
     ui::market::{
         constants::*,
@@ -18,6 +19,7 @@ pub fn spawn_market(
     market_configuration: Res<MarketConfiguration>,
     market_state: Res<MarketState>,
     previously_selected_widget: Option<Res<PreviouslySelectedMarketWidget>>,
+    number_format: Res<NumberFormat>,
     localization: Res<Localization>,
 ) {
     if !market_query.is_empty() {
@@ -112,6 +114,7 @@ pub fn spawn_market(
                 let text_font = asset_server.load("fonts/FiraSans-Bold.ttf");
                 let text_size = BALANCE_TEXT_FONT_SIZE;
 
+                let balance_label = currency(balance.0, &number_format);
                 let balance_text = commands
                     .spawn((
                         Name::new("Text"),
@@ -119,7 +122,7 @@ pub fn spawn_market(
                         TextBundle {
                             text: Text {
                                 sections: vec![TextSection::new(
-                                    format!("{:.0}", *balance),
+                                    balance_label.get(&localization),
                                     TextStyle {
                                         font: text_font.clone(),
                                         font_size: text_size,
@@ -132,6 +135,7 @@ pub fn spawn_market(
                             style: text_style,
                             ..default()
                         },
+                        balance_label,
                     ))
                     .id();
 
@@ -160,7 +164,10 @@ pub fn spawn_market(
                     refresh_button_colors,
                     &refresh_button_font,
                     refresh_button_size,
-                    localization::refresh_button(market_configuration.refresh_cost(&market_state)),
+                    localization::refresh_button(
+                        market_configuration.refresh_cost(&market_state),
+                        &number_format,
+                    ),
                     &localization,
                 );
 
@@ -268,15 +275,16 @@ pub fn despawn_market(
 
 /// Updates balance text.
 pub fn update_balance_text(
-    mut balance_text_query: Query<&mut Text, With<MarketBalanceText>>,
+    mut balance_text_query: Query<&mut LocalizedText, With<MarketBalanceText>>,
     balance: Res<Balance>,
+    number_format: Res<NumberFormat>,
 ) {
     let mut balance_text = match balance_text_query.get_single_mut() {
         Ok(query_result) => query_result,
         Err(_) => return,
     };
 
-    balance_text.sections[0].value = format!("{}", *balance);
+    *balance_text = currency(balance.0, &number_format);
 }
 
 /// Updates refresh button.
@@ -287,6 +295,7 @@ pub fn update_refresh_button(
     balance: Res<Balance>,
     market_configuration: Res<MarketConfiguration>,
     market_state: Res<MarketState>,
+    number_format: Res<NumberFormat>,
 ) {
     let (refresh_button_entity, mut refresh_button) = match refresh_button_query.get_single_mut() {
         Ok(query_result) => query_result,
@@ -298,7 +307,7 @@ pub fn update_refresh_button(
 
     for (parent_entity, mut refresh_button_text) in text_query.iter_mut() {
         if parent_entity.get() == refresh_button_entity {
-            *refresh_button_text = localization::refresh_button(refresh_cost);
+            *refresh_button_text = localization::refresh_button(refresh_cost, &number_format);
             break;
         }
     }
@@ -321,6 +330,7 @@ pub fn update_offered_items(
     market_state: Res<MarketState>,
     mut market_widgets: ResMut<MarketWidgets>,
     item_registry: Res<ItemRegistry>,
+    number_format: Res<NumberFormat>,
     localization: Res<Localization>,
 ) {
     let market_items_container_entity = match market_items_container_query.get_single() {
@@ -416,7 +426,7 @@ pub fn update_offered_items(
                 .id();
 
             let price = item.base_price;
-            let buy_button_label = format!("{}", price);
+            let buy_button_label = currency(price.0, &number_format);
 
             let buy_button = Widget::button(
                 &mut commands,
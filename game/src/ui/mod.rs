@@ -1,3 +1,5 @@
+pub mod achievement_toast;
+pub mod achievements_screen;
 pub mod diagnostics_overlay;
 pub mod enemy_selection_screen;
 pub mod game_mode_selection_screen;
@@ -10,4 +12,5 @@ pub mod pause_menu;
 pub mod player_selection_screen;
 pub mod plugin;
 pub mod settings_menu;
+pub mod unlock_screen;
 pub mod widget;
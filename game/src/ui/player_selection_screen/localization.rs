@@ -9,3 +9,12 @@ pub fn back_button() -> LocalizedText {
         fallback: "Back".into(),
     }
 }
+
+/// Gets the localized text of the buttons of locked players.
+pub fn locked_player_button(name: impl Display) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "player-selection-screen-locked-player-button",
+        args: smallvec![("name", format_smolstr!("{}", name))],
+        fallback: format!("{} (Locked)", name).into(),
+    }
+}
@@ -14,6 +14,7 @@ pub fn spawn_player_selection_screen(
     asset_server: Res<AssetServer>,
     player_selection_screen_action_input_map: Res<InputMap<PlayerSelectionScreenAction>>,
     player_registry: Res<PlayerRegistry>,
+    progression: Res<Persistent<Progression>>,
     localization: Res<Localization>,
 ) {
     let button_style = styles::button();
@@ -30,6 +31,26 @@ pub fn spawn_player_selection_screen(
             let mythology_index = SelectedMythologyIndex(mythology_index);
             let player_index = SelectedPlayerIndex(player_index);
 
+            if !player.is_available(&progression) {
+                let player_button = Widget::button(
+                    &mut commands,
+                    (
+                        Name::new(format!("Player Button [{}]", player.id())),
+                        PlayerSelectionScreenPlayerButton { mythology_index, player_index },
+                        Widget::default(),
+                        WidgetDisabled,
+                    ),
+                    &button_style,
+                    button_colors,
+                    &button_font,
+                    button_font_size,
+                    localization::locked_player_button(player.name().get(&localization)),
+                    &localization,
+                );
+                entities.push(player_button);
+                continue;
+            }
+
             let player_button = if first {
                 first = false;
                 Widget::button(
@@ -77,6 +98,9 @@ pub fn spawn_player_selection_screen(
         localization::back_button(),
         &localization,
     );
+    if first {
+        commands.entity(back_button).insert((Widget::default().selected(), WidgetSelected::now()));
+    }
     entities.push(back_button);
 
     for i in 0..entities.len() {
@@ -186,7 +210,7 @@ pub fn player_button_interaction(
     mut commands: Commands,
     mut player_button_query: Query<
         (&mut Widget, &PlayerSelectionScreenPlayerButton),
-        Changed<Widget>,
+        (Changed<Widget>, Without<WidgetDisabled>),
     >,
 ) {
     for (mut button, metadata) in &mut player_button_query {
@@ -210,6 +234,7 @@ pub fn select_player_when_starting_in_game(
     args: ResMut<Args>,
     mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>,
     player_registry: Res<PlayerRegistry>,
+    progression: Res<Persistent<Progression>>,
 ) {
     match &args.start_in_game_player {
         Some(specified_player_id) => {
@@ -219,6 +244,16 @@ pub fn select_player_when_starting_in_game(
                 let selected_mythology = &player_registry[mythology_index];
                 let selected_player = &selected_mythology[player_index];
 
+                if !selected_player.is_available(&progression) {
+                    log::error!(
+                        "couldn't select \
+                        manually specified {} as the player \
+                        as it isn't unlocked",
+                        specified_player_id,
+                    );
+                    return;
+                }
+
                 log::info!(
                     "selected manually specified {:?} first found in {:?} mythology \
                     as the player",
@@ -243,14 +278,30 @@ pub fn select_player_when_starting_in_game(
                 return;
             }
 
-            let number_of_mythologies = player_registry.len();
-            let mythology_index =
-                SelectedMythologyIndex((0..number_of_mythologies).choose(rng.deref_mut()).unwrap());
+            let available_mythology_indices = (0..player_registry.len())
+                .map(SelectedMythologyIndex)
+                .filter(|&mythology_index| {
+                    player_registry[mythology_index]
+                        .players
+                        .iter()
+                        .any(|player| player.is_available(&progression))
+                })
+                .collect::<Vec<_>>();
+            if available_mythology_indices.is_empty() {
+                log::error!("couldn't select the player randomly as no players are available");
+                return;
+            }
 
-            let number_of_players_in_mythology = player_registry[mythology_index].players.len();
-            let player_index = SelectedPlayerIndex(
-                (0..number_of_players_in_mythology).choose(rng.deref_mut()).unwrap(),
-            );
+            let mythology_index = *available_mythology_indices.choose(rng.deref_mut()).unwrap();
+
+            let available_player_indices = player_registry[mythology_index]
+                .players
+                .iter()
+                .enumerate()
+                .filter(|(_, player)| player.is_available(&progression))
+                .map(|(player_index, _)| SelectedPlayerIndex(player_index))
+                .collect::<Vec<_>>();
+            let player_index = *available_player_indices.choose(rng.deref_mut()).unwrap();
 
             let selected_mythology = player_registry[mythology_index].clone();
             let selected_player = player_registry[mythology_index][player_index].clone();
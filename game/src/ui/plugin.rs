@@ -1,6 +1,8 @@
 use crate::{
     prelude::*,
     ui::{
+        achievement_toast::plugin::AchievementToastPlugin,
+        achievements_screen::plugin::AchievementsScreenPlugin,
         diagnostics_overlay::plugin::DiagnosticsOverlayPlugin,
         enemy_selection_screen::plugin::EnemySelectionScreenPlugin,
         game_mode_selection_screen::plugin::GameModeSelectionScreenPlugin,
@@ -12,6 +14,7 @@ use crate::{
         pause_menu::plugin::PauseMenuPlugin,
         player_selection_screen::plugin::PlayerSelectionScreenPlugin,
         settings_menu::plugin::SettingsMenuPlugin,
+        unlock_screen::plugin::UnlockScreenPlugin,
         widget::plugin::WidgetPlugin,
     },
 };
@@ -25,6 +28,8 @@ impl Plugin for UiPlugin {
         app.add_plugins(WidgetPlugin);
         app.add_plugins(MainMenuPlugin);
         app.add_plugins(SettingsMenuPlugin);
+        app.add_plugins(UnlockScreenPlugin);
+        app.add_plugins(AchievementsScreenPlugin);
         app.add_plugins(GameModeSelectionScreenPlugin);
         app.add_plugins(PlayerSelectionScreenPlugin);
         app.add_plugins(EnemySelectionScreenPlugin);
@@ -34,5 +39,6 @@ impl Plugin for UiPlugin {
         app.add_plugins(GameOverMenuPlugin);
         app.add_plugins(HudPlugin);
         app.add_plugins(DiagnosticsOverlayPlugin);
+        app.add_plugins(AchievementToastPlugin);
     }
 }
@@ -7,6 +7,18 @@ use crate::prelude::*;
 pub struct SettingsMenu;
 
 
+/// Tag component for the previous button of the section setting in the settings menu.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuSectionSettingPreviousButton;
+
+
+/// Tag component for the next button of the section setting in the settings menu.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuSectionSettingNextButton;
+
+
 /// Tag component for the language setting in the settings menu.
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
@@ -37,6 +49,99 @@ pub struct SettingsMenuLanguageSettingValue;
 pub struct SettingsMenuLanguageSettingNextButton;
 
 
+/// Settings that can be changed in the settings menu.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Reflect)]
+pub enum SettingsMenuSetting {
+    AimWithMouse,
+    FireManually,
+    DamageNumbers,
+    HitFlashes,
+    DeathEffects,
+    EnemyHealthBars,
+    #[cfg(feature = "native")]
+    WindowMode,
+    #[cfg(feature = "native")]
+    Resolution,
+    #[cfg(feature = "native")]
+    Vsync,
+    #[cfg(feature = "native")]
+    FrameRateLimit,
+    MasterVolume,
+    MusicVolume,
+    SoundEffectsVolume,
+    UiScale,
+    ColorPalette,
+    ReduceScreenEffects,
+    HighContrast,
+}
+
+impl SettingsMenuSetting {
+    /// Gets whether the setting is stored in the persisted state of the primary window.
+    #[cfg(feature = "native")]
+    pub fn is_window_setting(&self) -> bool {
+        matches!(self, SettingsMenuSetting::WindowMode | SettingsMenuSetting::Resolution)
+    }
+}
+
+
+/// Component for the previous buttons of the settings in the settings menu.
+#[derive(Clone, Copy, Component, Debug, Deref, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuSettingPreviousButton(pub SettingsMenuSetting);
+
+
+/// Component for the values of the settings in the settings menu.
+#[derive(Clone, Copy, Component, Debug, Deref, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuSettingValue(pub SettingsMenuSetting);
+
+
+/// Component for the next buttons of the settings in the settings menu.
+#[derive(Clone, Copy, Component, Debug, Deref, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuSettingNextButton(pub SettingsMenuSetting);
+
+
+/// Component for the key binding buttons in the settings menu.
+#[derive(Clone, Copy, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuKeyBindingButton {
+    /// Binding to change with the button.
+    pub binding: KeyBinding,
+    /// Input of the binding to change with the button.
+    pub input: SettingsMenuKeyBindingInput,
+}
+
+
+/// Inputs of key bindings that can be changed in the settings menu.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Reflect)]
+pub enum SettingsMenuKeyBindingInput {
+    /// Key at the slot.
+    Key { slot: usize },
+    /// Gamepad button.
+    GamepadButton,
+}
+
+
+/// Tag component for the key binding button that is waiting for an input in the settings menu.
+#[derive(Component, Debug, Default, Reflect)]
+#[component(storage = "SparseSet")]
+#[reflect(Component)]
+pub struct SettingsMenuKeyBindingCapture;
+
+
+/// Tag component for the status text of the key bindings in the settings menu.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuKeyBindingStatus;
+
+
+/// Tag component for the reset key bindings button in the settings menu.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SettingsMenuResetKeyBindingsButton;
+
+
 /// Tag component for the back button in the settings menu.
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
@@ -5,22 +5,66 @@ use crate::prelude::*;
 pub const BACKGROUND_COLOR: Color = Color::srgba(0.00, 0.00, 0.00, 1.00);
 
 
-/// Font size of the previous and next buttons in the settings menu in pt.
-pub const LANGUAGE_SETTING_CHANGER_TEXT_FONT_SIZE: f32 = 32.0;
+/// Font size of the previous and next buttons of the section setting in the settings menu in pt.
+pub const SECTION_SETTING_CHANGER_TEXT_FONT_SIZE: f32 = 32.0;
 
 
-/// Font size of the language setting name text in the setting menu in pt.
-pub const LANGUAGE_SETTING_NAME_TEXT_FONT_SIZE: f32 = 40.0;
+/// Font size of the section setting name text in the setting menu in pt.
+pub const SECTION_SETTING_NAME_TEXT_FONT_SIZE: f32 = 40.0;
 
-/// Color of the language setting name text in the setting menu.
-pub const LANGUAGE_SETTING_NAME_TEXT_COLOR: Color = Color::WHITE;
+/// Color of the section setting name text in the setting menu.
+pub const SECTION_SETTING_NAME_TEXT_COLOR: Color = Color::WHITE;
 
 
-/// Font size of the language setting value text in the setting menu in pt.
-pub const LANGUAGE_SETTING_VALUE_TEXT_FONT_SIZE: f32 = 40.0;
+/// Font size of the previous and next buttons of the settings in the settings menu in pt.
+pub const SETTING_CHANGER_TEXT_FONT_SIZE: f32 = 32.0;
 
-/// Color of the language setting value text in the setting menu.
-pub const LANGUAGE_SETTING_VALUE_TEXT_COLOR: Color = Color::srgb(0.50, 0.50, 0.50);
+
+/// Font size of the setting name texts in the setting menu in pt.
+pub const SETTING_NAME_TEXT_FONT_SIZE: f32 = 32.0;
+
+/// Color of the setting name texts in the setting menu.
+pub const SETTING_NAME_TEXT_COLOR: Color = Color::WHITE;
+
+
+/// Font size of the setting value texts in the setting menu in pt.
+pub const SETTING_VALUE_TEXT_FONT_SIZE: f32 = 32.0;
+
+/// Color of the setting value texts in the setting menu.
+pub const SETTING_VALUE_TEXT_COLOR: Color = Color::srgb(0.50, 0.50, 0.50);
+
+
+/// Font size of the key binding name texts in the setting menu in pt.
+pub const KEY_BINDING_NAME_TEXT_FONT_SIZE: f32 = 28.0;
+
+/// Color of the key binding name texts in the setting menu.
+pub const KEY_BINDING_NAME_TEXT_COLOR: Color = Color::WHITE;
+
+
+/// Font size of the key binding buttons in the settings menu in pt.
+pub const KEY_BINDING_BUTTON_FONT_SIZE: f32 = 24.0;
+
+
+/// Amount to change the volume settings with a single click in the settings menu.
+pub const VOLUME_SETTING_STEP: f32 = 0.10;
+
+/// Amount to change the user interface scale setting with a single click in the settings menu.
+pub const UI_SCALE_SETTING_STEP: f32 = 0.10;
+
+
+/// Number of keys that can be bound to a key binding in the settings menu.
+pub const NUMBER_OF_KEY_SLOTS: usize = 2;
+
+
+/// Font size of the key binding status text in the setting menu in pt.
+pub const KEY_BINDING_STATUS_TEXT_FONT_SIZE: f32 = 24.0;
+
+/// Color of the key binding status text in the setting menu.
+pub const KEY_BINDING_STATUS_TEXT_COLOR: Color = Color::srgb(0.50, 0.50, 0.50);
+
+
+/// Font size of the reset key bindings button in the settings menu in pt.
+pub const RESET_KEY_BINDINGS_BUTTON_FONT_SIZE: f32 = 32.0;
 
 
 /// Font size of the back button in the settings menu in pt.
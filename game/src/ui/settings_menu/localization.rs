@@ -1,6 +1,59 @@
 use crate::prelude::*;
 
 
+/// Gets the localized text of the previous section setting.
+pub fn section_setting_previous_button() -> LocalizedText {
+    LocalizedText::Constant { text: "<".into() }
+}
+
+/// Gets the localized text of the section setting name.
+pub fn section_setting_name(section: SettingsMenuSection) -> LocalizedText {
+    match section {
+        SettingsMenuSection::General => {
+            LocalizedText::Localized {
+                key: "settings-menu-general-section",
+                args: smallvec![],
+                fallback: "General".into(),
+            }
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSection::Video => {
+            LocalizedText::Localized {
+                key: "settings-menu-video-section",
+                args: smallvec![],
+                fallback: "Video".into(),
+            }
+        },
+        SettingsMenuSection::Audio => {
+            LocalizedText::Localized {
+                key: "settings-menu-audio-section",
+                args: smallvec![],
+                fallback: "Audio".into(),
+            }
+        },
+        SettingsMenuSection::Accessibility => {
+            LocalizedText::Localized {
+                key: "settings-menu-accessibility-section",
+                args: smallvec![],
+                fallback: "Accessibility".into(),
+            }
+        },
+        SettingsMenuSection::Controls => {
+            LocalizedText::Localized {
+                key: "settings-menu-controls-section",
+                args: smallvec![],
+                fallback: "Controls".into(),
+            }
+        },
+    }
+}
+
+/// Gets the localized text of the next section setting.
+pub fn section_setting_next_button() -> LocalizedText {
+    LocalizedText::Constant { text: ">".into() }
+}
+
+
 /// Gets the localized text of the previous language setting.
 pub fn language_setting_previous_button() -> LocalizedText {
     LocalizedText::Constant { text: "<".into() }
@@ -30,6 +83,229 @@ pub fn language_setting_next_button() -> LocalizedText {
 }
 
 
+/// Gets the localized text of the previous setting.
+pub fn setting_previous_button() -> LocalizedText {
+    LocalizedText::Constant { text: "<".into() }
+}
+
+/// Gets the localized text of a setting name.
+pub fn setting_name(setting: SettingsMenuSetting) -> LocalizedText {
+    let (key, fallback) = match setting {
+        SettingsMenuSetting::AimWithMouse => {
+            ("settings-menu-aim-with-mouse-setting-name", "Aim with Mouse:")
+        },
+        SettingsMenuSetting::FireManually => {
+            ("settings-menu-fire-manually-setting-name", "Fire Manually:")
+        },
+        SettingsMenuSetting::DamageNumbers => {
+            ("settings-menu-damage-numbers-setting-name", "Damage Numbers:")
+        },
+        SettingsMenuSetting::HitFlashes => {
+            ("settings-menu-hit-flashes-setting-name", "Hit Flashes:")
+        },
+        SettingsMenuSetting::DeathEffects => {
+            ("settings-menu-death-effects-setting-name", "Death Effects:")
+        },
+        SettingsMenuSetting::EnemyHealthBars => {
+            ("settings-menu-enemy-health-bars-setting-name", "Enemy Health Bars:")
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::WindowMode => {
+            ("settings-menu-window-mode-setting-name", "Window Mode:")
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::Resolution => ("settings-menu-resolution-setting-name", "Resolution:"),
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::Vsync => ("settings-menu-vsync-setting-name", "VSync:"),
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::FrameRateLimit => {
+            ("settings-menu-frame-rate-limit-setting-name", "Frame Rate Limit:")
+        },
+        SettingsMenuSetting::MasterVolume => {
+            ("settings-menu-master-volume-setting-name", "Master Volume:")
+        },
+        SettingsMenuSetting::MusicVolume => {
+            ("settings-menu-music-volume-setting-name", "Music Volume:")
+        },
+        SettingsMenuSetting::SoundEffectsVolume => {
+            ("settings-menu-sound-effects-volume-setting-name", "Sound Effects Volume:")
+        },
+        SettingsMenuSetting::UiScale => ("settings-menu-ui-scale-setting-name", "UI Scale:"),
+        SettingsMenuSetting::ColorPalette => {
+            ("settings-menu-color-palette-setting-name", "Color Palette:")
+        },
+        SettingsMenuSetting::ReduceScreenEffects => {
+            ("settings-menu-reduce-screen-effects-setting-name", "Reduce Screen Effects:")
+        },
+        SettingsMenuSetting::HighContrast => {
+            ("settings-menu-high-contrast-setting-name", "High Contrast:")
+        },
+    };
+    LocalizedText::Localized { key, args: smallvec![], fallback: fallback.into() }
+}
+
+/// Gets the localized text of a setting value.
+pub fn setting_value(
+    setting: SettingsMenuSetting,
+    general_settings: &GeneralSettings,
+    #[cfg(feature = "native")] window_state: Option<&WindowState>,
+) -> LocalizedText {
+    match setting {
+        SettingsMenuSetting::AimWithMouse => toggle(general_settings.aim_with_mouse),
+        SettingsMenuSetting::FireManually => toggle(general_settings.fire_manually),
+        SettingsMenuSetting::DamageNumbers => toggle(general_settings.damage_numbers),
+        SettingsMenuSetting::HitFlashes => toggle(general_settings.hit_flashes),
+        SettingsMenuSetting::DeathEffects => toggle(general_settings.death_effects),
+        SettingsMenuSetting::EnemyHealthBars => {
+            enemy_health_bars(general_settings.enemy_health_bars)
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::WindowMode => {
+            match window_state {
+                Some(window_state) => window_mode(window_state.mode),
+                None => LocalizedText::Constant { text: "-".into() },
+            }
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::Resolution => {
+            resolution(window_state.and_then(|window_state| window_state.resolution))
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::Vsync => toggle(general_settings.vsync),
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::FrameRateLimit => frame_rate_limit(general_settings.frame_rate_limit),
+        SettingsMenuSetting::MasterVolume => volume(general_settings.master_volume),
+        SettingsMenuSetting::MusicVolume => volume(general_settings.music_volume),
+        SettingsMenuSetting::SoundEffectsVolume => volume(general_settings.sound_effects_volume),
+        SettingsMenuSetting::UiScale => scale(general_settings.ui_scale),
+        SettingsMenuSetting::ColorPalette => color_palette(general_settings.color_palette),
+        SettingsMenuSetting::ReduceScreenEffects => toggle(general_settings.reduce_screen_effects),
+        SettingsMenuSetting::HighContrast => toggle(general_settings.high_contrast),
+    }
+}
+
+/// Gets the localized text of the next setting.
+pub fn setting_next_button() -> LocalizedText {
+    LocalizedText::Constant { text: ">".into() }
+}
+
+/// Gets the localized text of a toggle.
+pub fn toggle(enabled: bool) -> LocalizedText {
+    if enabled {
+        LocalizedText::Localized {
+            key: "settings-menu-toggle-on",
+            args: smallvec![],
+            fallback: "On".into(),
+        }
+    } else {
+        LocalizedText::Localized {
+            key: "settings-menu-toggle-off",
+            args: smallvec![],
+            fallback: "Off".into(),
+        }
+    }
+}
+
+
+/// Gets the localized text of a key binding name.
+pub fn key_binding_name(binding: KeyBinding) -> LocalizedText {
+    let (key, fallback) = match binding {
+        KeyBinding::Up => ("settings-menu-key-binding-up", "Move Up"),
+        KeyBinding::Left => ("settings-menu-key-binding-left", "Move Left"),
+        KeyBinding::Down => ("settings-menu-key-binding-down", "Move Down"),
+        KeyBinding::Right => ("settings-menu-key-binding-right", "Move Right"),
+        KeyBinding::Dash => ("settings-menu-key-binding-dash", "Dash"),
+        KeyBinding::Pause => ("settings-menu-key-binding-pause", "Pause"),
+        KeyBinding::Market => ("settings-menu-key-binding-market", "Open Market"),
+        KeyBinding::Fire => ("settings-menu-key-binding-fire", "Fire"),
+        KeyBinding::Select => ("settings-menu-key-binding-select", "Select"),
+        KeyBinding::Back => ("settings-menu-key-binding-back", "Back"),
+    };
+    LocalizedText::Localized { key, args: smallvec![], fallback: fallback.into() }
+}
+
+/// Gets the localized text of a key.
+pub fn key(key_code: Option<KeyCode>) -> LocalizedText {
+    match key_code {
+        Some(key_code) => {
+            let name = format!("{:?}", key_code);
+            let name = name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .map(|name| name.to_owned())
+                .unwrap_or(name);
+            LocalizedText::Constant { text: name.into() }
+        },
+        None => LocalizedText::Constant { text: "-".into() },
+    }
+}
+
+/// Gets the localized text of a gamepad button.
+pub fn gamepad_button(gamepad_button: Option<GamepadButtonType>) -> LocalizedText {
+    match gamepad_button {
+        Some(gamepad_button) => {
+            LocalizedText::Constant { text: format!("{:?}", gamepad_button).into() }
+        },
+        None => LocalizedText::Constant { text: "-".into() },
+    }
+}
+
+/// Gets the localized text of a key binding button that is waiting for a key.
+pub fn key_binding_capturing_key() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "settings-menu-key-binding-capturing-key",
+        args: smallvec![],
+        fallback: "Press a key...".into(),
+    }
+}
+
+/// Gets the localized text of a key binding button that is waiting for a gamepad button.
+pub fn key_binding_capturing_gamepad_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "settings-menu-key-binding-capturing-gamepad-button",
+        args: smallvec![],
+        fallback: "Press a button...".into(),
+    }
+}
+
+/// Gets the localized text of the key binding status.
+pub fn key_binding_status() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "settings-menu-key-binding-status",
+        args: smallvec![],
+        fallback: "Escape to cancel, Backspace to unbind.".into(),
+    }
+}
+
+/// Gets the localized text of the key binding status when there is a conflict.
+pub fn key_binding_conflict(input: &str, binding: &str) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "settings-menu-key-binding-conflict",
+        args: smallvec![("input", input.into()), ("binding", binding.into())],
+        fallback: format!("{} is already bound to {}.", input, binding).into(),
+    }
+}
+
+/// Gets the localized text of the key binding status when the last input of a binding that is
+/// used in menus is attempted to be unbound.
+pub fn key_binding_required(binding: &str) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "settings-menu-key-binding-required",
+        args: smallvec![("binding", binding.into())],
+        fallback: format!("{} is needed in menus, so it can't be unbound.", binding).into(),
+    }
+}
+
+/// Gets the localized text of the reset key bindings button.
+pub fn reset_key_bindings_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "settings-menu-reset-key-bindings-button",
+        args: smallvec![],
+        fallback: "Reset to Defaults".into(),
+    }
+}
+
+
 /// Gets the localized text of the back button.
 pub fn back_button() -> LocalizedText {
     LocalizedText::Localized {
@@ -38,3 +314,76 @@ pub fn back_button() -> LocalizedText {
         fallback: "Back".into(),
     }
 }
+
+/// Gets the localized text of a volume.
+pub fn volume(volume: f32) -> LocalizedText {
+    LocalizedText::Constant { text: format!("{:.0}%", volume * 100.00).into() }
+}
+
+/// Gets the localized text of a scale.
+pub fn scale(scale: f32) -> LocalizedText {
+    LocalizedText::Constant { text: format!("{:.0}%", scale * 100.00).into() }
+}
+
+/// Gets the localized text of a color palette.
+pub fn color_palette(color_palette: ColorPalette) -> LocalizedText {
+    let (key, fallback) = match color_palette {
+        ColorPalette::Default => ("settings-menu-color-palette-default", "Default"),
+        ColorPalette::Deuteranopia => ("settings-menu-color-palette-deuteranopia", "Deuteranopia"),
+        ColorPalette::Protanopia => ("settings-menu-color-palette-protanopia", "Protanopia"),
+        ColorPalette::Tritanopia => ("settings-menu-color-palette-tritanopia", "Tritanopia"),
+    };
+    LocalizedText::Localized { key, args: smallvec![], fallback: fallback.into() }
+}
+
+/// Gets the localized text of the visibility of enemy health bars.
+pub fn enemy_health_bars(enemy_health_bars: EnemyHealthBars) -> LocalizedText {
+    let (key, fallback) = match enemy_health_bars {
+        EnemyHealthBars::Always => ("settings-menu-enemy-health-bars-always", "Always"),
+        EnemyHealthBars::DamagedOnly => {
+            ("settings-menu-enemy-health-bars-damaged-only", "Damaged Only")
+        },
+        EnemyHealthBars::Never => ("settings-menu-enemy-health-bars-never", "Never"),
+    };
+    LocalizedText::Localized { key, args: smallvec![], fallback: fallback.into() }
+}
+
+/// Gets the localized text of a window mode.
+#[cfg(feature = "native")]
+pub fn window_mode(window_mode: WindowMode) -> LocalizedText {
+    let (key, fallback) = match window_mode {
+        WindowMode::Windowed => ("settings-menu-window-mode-windowed", "Windowed"),
+        WindowMode::BorderlessFullscreen => {
+            ("settings-menu-window-mode-borderless-fullscreen", "Borderless Fullscreen")
+        },
+        WindowMode::SizedFullscreen | WindowMode::Fullscreen => {
+            ("settings-menu-window-mode-fullscreen", "Fullscreen")
+        },
+    };
+    LocalizedText::Localized { key, args: smallvec![], fallback: fallback.into() }
+}
+
+/// Gets the localized text of a resolution.
+#[cfg(feature = "native")]
+pub fn resolution(resolution: Option<(u32, u32)>) -> LocalizedText {
+    match resolution {
+        Some((width, height)) => {
+            LocalizedText::Constant { text: format!("{}x{}", width, height).into() }
+        },
+        None => LocalizedText::Constant { text: "-".into() },
+    }
+}
+
+/// Gets the localized text of a frame rate limit.
+#[cfg(feature = "native")]
+pub fn frame_rate_limit(frame_rate_limit: u32) -> LocalizedText {
+    if frame_rate_limit == 0 {
+        LocalizedText::Localized {
+            key: "settings-menu-frame-rate-limit-unlimited",
+            args: smallvec![],
+            fallback: "Unlimited".into(),
+        }
+    } else {
+        LocalizedText::Constant { text: format!("{} FPS", frame_rate_limit).into() }
+    }
+}
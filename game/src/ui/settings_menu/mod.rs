@@ -2,5 +2,6 @@ pub mod components;
 pub mod constants;
 pub mod localization;
 pub mod plugin;
+pub mod resources;
 pub mod styles;
 pub mod systems;
@@ -3,6 +3,9 @@ use crate::{
     ui::settings_menu::systems::*,
 };
 
+#[cfg(feature = "native")]
+use crate::video::conditions::*;
+
 /// Plugin for managing the settings menu.
 #[derive(Default)]
 pub struct SettingsMenuPlugin;
@@ -11,17 +14,39 @@ impl Plugin for SettingsMenuPlugin {
     fn build(&self, app: &mut App) {
         // Register components.
         app.register_type::<SettingsMenu>();
+        app.register_type::<SettingsMenuSectionSettingPreviousButton>();
+        app.register_type::<SettingsMenuSectionSettingNextButton>();
         app.register_type::<SettingsMenuLanguageSettingContainer>();
         app.register_type::<SettingsMenuLanguageSettingPreviousButton>();
         app.register_type::<SettingsMenuLanguageSettingName>();
         app.register_type::<SettingsMenuLanguageSettingValue>();
         app.register_type::<SettingsMenuLanguageSettingNextButton>();
+        app.register_type::<SettingsMenuSettingPreviousButton>();
+        app.register_type::<SettingsMenuSettingValue>();
+        app.register_type::<SettingsMenuSettingNextButton>();
+        app.register_type::<SettingsMenuKeyBindingButton>();
+        app.register_type::<SettingsMenuKeyBindingCapture>();
+        app.register_type::<SettingsMenuKeyBindingStatus>();
+        app.register_type::<SettingsMenuResetKeyBindingsButton>();
         app.register_type::<SettingsMenuBackButton>();
 
+        // Register resources.
+        app.register_type::<SettingsMenuSection>();
+
+        // Insert resources.
+        app.init_resource::<SettingsMenuSection>();
+
         // Setup localization.
         app.world_mut().resource_mut::<LocaleAssets>().push("ui/settings_menu.ftl");
 
         // Add systems.
+        #[cfg(feature = "native")]
+        let update_setting_values = update_setting_values.run_if(
+            resource_changed::<Persistent<GeneralSettings>>.or_else(primary_window_state_changed),
+        );
+        #[cfg(not(feature = "native"))]
+        let update_setting_values =
+            update_setting_values.run_if(resource_changed::<Persistent<GeneralSettings>>);
         app.add_systems(OnEnter(AppState::SettingsMenu), spawn_settings_menu);
         app.add_systems(OnEnter(GameState::Settings), spawn_settings_menu);
         app.add_systems(Update, navigation.in_set(SettingsMenuSystems));
@@ -29,8 +54,21 @@ impl Plugin for SettingsMenuPlugin {
             PostUpdate,
             (
                 back_button_interaction,
+                section_setting_button_interaction,
                 language_setting_previous_button_interaction,
                 language_setting_next_button_interaction,
+                (
+                    (setting_previous_button_interaction, setting_next_button_interaction),
+                    update_setting_values,
+                )
+                    .chain(),
+                key_binding_button_interaction,
+                (
+                    reset_key_bindings_button_interaction,
+                    capture_key_binding,
+                    update_key_binding_buttons.run_if(resource_changed::<Persistent<KeyBindings>>),
+                )
+                    .chain(),
             )
                 .in_set(SettingsMenuSystems),
         );
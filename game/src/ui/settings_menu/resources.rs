@@ -0,0 +1,72 @@
+use crate::prelude::*;
+
+
+/// Resource for the section shown in the settings menu.
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, PartialEq, Reflect, Resource)]
+#[reflect(Resource)]
+pub enum SettingsMenuSection {
+    #[default]
+    General,
+    #[cfg(feature = "native")]
+    Video,
+    Audio,
+    Accessibility,
+    Controls,
+}
+
+impl SettingsMenuSection {
+    /// Gets the settings in the section.
+    pub fn settings(&self) -> &'static [SettingsMenuSetting] {
+        match self {
+            SettingsMenuSection::General => {
+                &[
+                    SettingsMenuSetting::AimWithMouse,
+                    SettingsMenuSetting::FireManually,
+                    SettingsMenuSetting::DamageNumbers,
+                    SettingsMenuSetting::HitFlashes,
+                    SettingsMenuSetting::DeathEffects,
+                    SettingsMenuSetting::EnemyHealthBars,
+                ]
+            },
+            #[cfg(feature = "native")]
+            SettingsMenuSection::Video => {
+                &[
+                    SettingsMenuSetting::WindowMode,
+                    SettingsMenuSetting::Resolution,
+                    SettingsMenuSetting::Vsync,
+                    SettingsMenuSetting::FrameRateLimit,
+                ]
+            },
+            SettingsMenuSection::Audio => {
+                &[
+                    SettingsMenuSetting::MasterVolume,
+                    SettingsMenuSetting::MusicVolume,
+                    SettingsMenuSetting::SoundEffectsVolume,
+                ]
+            },
+            SettingsMenuSection::Accessibility => {
+                &[
+                    SettingsMenuSetting::UiScale,
+                    SettingsMenuSetting::ColorPalette,
+                    SettingsMenuSetting::ReduceScreenEffects,
+                    SettingsMenuSetting::HighContrast,
+                ]
+            },
+            SettingsMenuSection::Controls => &[],
+        }
+    }
+
+    /// Gets the previous section.
+    pub fn previous(&self) -> SettingsMenuSection {
+        let sections = SettingsMenuSection::iter().collect::<Vec<_>>();
+        let position = sections.iter().position(|section| section == self).unwrap_or(0);
+        if position == 0 { sections[sections.len() - 1] } else { sections[position - 1] }
+    }
+
+    /// Gets the next section.
+    pub fn next(&self) -> SettingsMenuSection {
+        let sections = SettingsMenuSection::iter().collect::<Vec<_>>();
+        let position = sections.iter().position(|section| section == self).unwrap_or(0);
+        if position == sections.len() - 1 { sections[0] } else { sections[position + 1] }
+    }
+}
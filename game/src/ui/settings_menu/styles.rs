@@ -15,11 +15,11 @@ pub fn root() -> Style {
 }
 
 
-/// Gets the style of the language setting container of the settings menu.
-pub fn language_setting_container() -> Style {
+/// Gets the style of the section setting container of the settings menu.
+pub fn section_setting_container() -> Style {
     Style {
         width: Val::Percent(80.0),
-        height: Val::Percent(25.0),
+        height: Val::Percent(12.0),
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
         flex_direction: FlexDirection::Row,
@@ -28,11 +28,11 @@ pub fn language_setting_container() -> Style {
     }
 }
 
-/// Gets the style of language setting changers of the settings menu.
-pub fn language_setting_changer() -> Style {
+/// Gets the style of section setting changers of the settings menu.
+pub fn section_setting_changer() -> Style {
     Style {
         width: Val::Percent(4.50),
-        height: Val::Percent(25.00),
+        height: Val::Percent(50.00),
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
         margin: UiRect::horizontal(Val::Percent(2.50)),
@@ -40,22 +40,128 @@ pub fn language_setting_changer() -> Style {
     }
 }
 
-/// Gets the style of the language setting name of the settings menu.
-pub fn language_setting_name() -> Style {
+/// Gets the style of the section setting name of the settings menu.
+pub fn section_setting_name() -> Style {
+    Style { width: Val::Percent(25.00), justify_content: JustifyContent::Center, ..default() }
+}
+
+
+/// Gets the style of the section containers of the settings menu.
+pub fn section_container() -> Style {
+    Style {
+        width: Val::Percent(80.0),
+        height: Val::Percent(60.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Percent(1.00),
+        ..default()
+    }
+}
+
+
+/// Gets the style of the setting containers of the settings menu.
+pub fn setting_container() -> Style {
+    Style {
+        width: Val::Percent(100.0),
+        height: Val::Percent(14.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Row,
+        column_gap: Val::Percent(1.00),
+        ..default()
+    }
+}
+
+/// Gets the style of setting changers of the settings menu.
+pub fn setting_changer() -> Style {
+    Style {
+        width: Val::Percent(4.50),
+        height: Val::Percent(70.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        margin: UiRect::horizontal(Val::Percent(2.50)),
+        ..default()
+    }
+}
+
+/// Gets the style of the setting names of the settings menu.
+pub fn setting_name() -> Style {
     Style { ..default() }
 }
 
-/// Gets the style of the language setting value of the settings menu.
-pub fn language_setting_value() -> Style {
+/// Gets the style of the setting values of the settings menu.
+pub fn setting_value() -> Style {
     Style { ..default() }
 }
 
 
+/// Gets the style of the key binding rows of the settings menu.
+pub fn key_binding_row() -> Style {
+    Style {
+        width: Val::Percent(100.0),
+        height: Val::Percent(9.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Row,
+        column_gap: Val::Percent(1.00),
+        ..default()
+    }
+}
+
+/// Gets the style of the key binding names of the settings menu.
+pub fn key_binding_name() -> Style {
+    Style { width: Val::Percent(30.00), ..default() }
+}
+
+/// Gets the style of the key binding buttons of the settings menu.
+pub fn key_binding_button() -> Style {
+    Style {
+        width: Val::Percent(18.00),
+        height: Val::Percent(100.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+/// Gets the style of the key binding status of the settings menu.
+pub fn key_binding_status() -> Style {
+    Style { margin: UiRect::top(Val::Percent(1.00)), ..default() }
+}
+
+
+/// Gets the style of the footer container of the settings menu.
+pub fn footer_container() -> Style {
+    Style {
+        width: Val::Percent(80.0),
+        height: Val::Percent(9.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Row,
+        column_gap: Val::Percent(2.00),
+        ..default()
+    }
+}
+
+
+/// Gets the style of the reset key bindings button in the settings menu.
+pub fn reset_key_bindings_button() -> Style {
+    Style {
+        width: Val::Percent(30.00),
+        height: Val::Percent(100.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+
 /// Gets the style of the back button in the settings menu.
 pub fn back_button() -> Style {
     Style {
-        width: Val::Percent(16.00),
-        height: Val::Percent(9.00),
+        width: Val::Percent(20.00),
+        height: Val::Percent(100.00),
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
         ..default()
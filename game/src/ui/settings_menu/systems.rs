@@ -1,4 +1,5 @@
 use crate::{
+    accessibility::constants::*,
     prelude::*,
     ui::settings_menu::{
         constants::*,
@@ -7,6 +8,9 @@ use crate::{
     },
 };
 
+#[cfg(feature = "native")]
+use crate::video::constants::*;
+
 
 /// Spawns the settings menu.
 pub fn spawn_settings_menu(
@@ -14,121 +18,230 @@ pub fn spawn_settings_menu(
     asset_server: Res<AssetServer>,
     settings_menu_action_input_map: Res<InputMap<SettingsMenuAction>>,
     localization: Res<Localization>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    #[cfg(feature = "native")] window_state_query: Query<
+        &Persistent<WindowState>,
+        With<PrimaryWindow>,
+    >,
+    key_bindings: Res<Persistent<KeyBindings>>,
+    section: Res<SettingsMenuSection>,
+) {
+    spawn(
+        &mut commands,
+        &asset_server,
+        &settings_menu_action_input_map,
+        &localization,
+        &general_settings,
+        #[cfg(feature = "native")]
+        window_state_query.get_single().ok().map(|window_state| window_state.get()),
+        &key_bindings,
+        *section,
+        false,
+    );
+}
+
+/// Spawns the settings menu with the specified section.
+fn spawn(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    settings_menu_action_input_map: &InputMap<SettingsMenuAction>,
+    localization: &Localization,
+    general_settings: &GeneralSettings,
+    #[cfg(feature = "native")] window_state: Option<&WindowState>,
+    key_bindings: &KeyBindings,
+    section: SettingsMenuSection,
+    select_section_setting: bool,
 ) {
     let font = asset_server.load("fonts/FiraSans-Bold.ttf");
 
-    let language_setting_previous_button = {
-        let previous = localization::language_setting_previous_button();
-        Widget::button(
-            &mut commands,
-            (
-                Name::new("Previous Button"),
-                SettingsMenuLanguageSettingPreviousButton,
-                Widget::default(),
-            ),
-            &styles::language_setting_changer(),
-            WidgetColors::button(),
-            &font,
-            LANGUAGE_SETTING_CHANGER_TEXT_FONT_SIZE,
-            previous,
-            &localization,
-        )
-    };
-    let language_setting_name = {
-        let name = localization::language_setting_name();
+    let section_setting_previous_button = Widget::button(
+        commands,
+        (Name::new("Previous Button"), SettingsMenuSectionSettingPreviousButton, Widget::default()),
+        &styles::section_setting_changer(),
+        WidgetColors::button(),
+        &font,
+        SECTION_SETTING_CHANGER_TEXT_FONT_SIZE,
+        localization::section_setting_previous_button(),
+        localization,
+    );
+    let section_setting_name = {
+        let name = localization::section_setting_name(section);
         commands
             .spawn((
                 Name::new("Name"),
                 TextBundle {
                     text: Text {
                         sections: vec![TextSection::new(
-                            name.get(&localization),
+                            name.get(localization),
                             TextStyle {
-                                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                                font_size: LANGUAGE_SETTING_NAME_TEXT_FONT_SIZE,
-                                color: LANGUAGE_SETTING_NAME_TEXT_COLOR,
+                                font: font.clone(),
+                                font_size: SECTION_SETTING_NAME_TEXT_FONT_SIZE,
+                                color: SECTION_SETTING_NAME_TEXT_COLOR,
                             },
                         )],
                         justify: JustifyText::Center,
                         ..default()
                     },
-                    style: styles::language_setting_name(),
+                    style: styles::section_setting_name(),
                     ..default()
                 },
                 name,
             ))
             .id()
     };
-    let language_setting_value = {
-        let value = localization::language_setting_value();
-        commands
-            .spawn((
-                Name::new("Value"),
-                TextBundle {
-                    text: Text {
-                        sections: vec![TextSection::new(
-                            value.get(&localization),
-                            TextStyle {
-                                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                                font_size: LANGUAGE_SETTING_VALUE_TEXT_FONT_SIZE,
-                                color: LANGUAGE_SETTING_VALUE_TEXT_COLOR,
-                            },
-                        )],
-                        justify: JustifyText::Center,
-                        ..default()
-                    },
-                    style: styles::language_setting_name(),
-                    ..default()
-                },
-                value,
-            ))
-            .id()
-    };
-    let language_setting_next_button = {
-        let next = localization::language_setting_next_button();
-        Widget::button(
-            &mut commands,
-            (Name::new("Next Button"), SettingsMenuLanguageSettingNextButton, Widget::default()),
-            &styles::language_setting_changer(),
+    let section_setting_next_button = {
+        let mut button = Widget::default();
+        if select_section_setting {
+            button = button.selected();
+        }
+        let section_setting_next_button = Widget::button(
+            commands,
+            (Name::new("Next Button"), SettingsMenuSectionSettingNextButton, button),
+            &styles::section_setting_changer(),
             WidgetColors::button(),
             &font,
-            LANGUAGE_SETTING_CHANGER_TEXT_FONT_SIZE,
-            next,
-            &localization,
-        )
+            SECTION_SETTING_CHANGER_TEXT_FONT_SIZE,
+            localization::section_setting_next_button(),
+            localization,
+        );
+        if select_section_setting {
+            commands.entity(section_setting_next_button).insert(WidgetSelected::now());
+        }
+        section_setting_next_button
     };
 
-    let language_setting = commands
+    let section_setting = commands
         .spawn((
-            Name::new("Language Setting"),
-            NodeBundle { style: styles::language_setting_container(), ..default() },
+            Name::new("Section Setting"),
+            NodeBundle { style: styles::section_setting_container(), ..default() },
         ))
-        .add_child(language_setting_previous_button)
-        .add_child(language_setting_name)
-        .add_child(language_setting_value)
-        .add_child(language_setting_next_button)
+        .add_child(section_setting_previous_button)
+        .add_child(section_setting_name)
+        .add_child(section_setting_next_button)
         .id();
 
-    let back_button = Widget::button(
-        &mut commands,
-        (
-            Name::new("Back Button"),
-            SettingsMenuBackButton,
-            Widget::default().selected(),
-            WidgetSelected::now(),
-        ),
-        &styles::back_button(),
-        WidgetColors::button(),
-        &font,
-        BACK_BUTTON_FONT_SIZE,
-        localization::back_button(),
-        &localization,
-    );
+    let mut section_widgets = Vec::new();
+    let section_container = match section {
+        SettingsMenuSection::General => {
+            let (general_section_container, general_section_widgets) = spawn_general_section(
+                commands,
+                asset_server,
+                localization,
+                general_settings,
+                #[cfg(feature = "native")]
+                window_state,
+            );
+            section_widgets.extend(general_section_widgets);
+            general_section_container
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSection::Video => {
+            let (video_section_container, video_section_widgets) = spawn_settings_section(
+                commands,
+                asset_server,
+                localization,
+                general_settings,
+                window_state,
+                section,
+            );
+            section_widgets.extend(video_section_widgets);
+            video_section_container
+        },
+        SettingsMenuSection::Audio => {
+            let (audio_section_container, audio_section_widgets) = spawn_settings_section(
+                commands,
+                asset_server,
+                localization,
+                general_settings,
+                #[cfg(feature = "native")]
+                window_state,
+                section,
+            );
+            section_widgets.extend(audio_section_widgets);
+            audio_section_container
+        },
+        SettingsMenuSection::Accessibility => {
+            let (accessibility_section_container, accessibility_section_widgets) =
+                spawn_settings_section(
+                    commands,
+                    asset_server,
+                    localization,
+                    general_settings,
+                    #[cfg(feature = "native")]
+                    window_state,
+                    section,
+                );
+            section_widgets.extend(accessibility_section_widgets);
+            accessibility_section_container
+        },
+        SettingsMenuSection::Controls => {
+            let (key_bindings_container, key_binding_widgets) =
+                spawn_controls_section(commands, asset_server, localization, key_bindings);
+            section_widgets.extend(key_binding_widgets);
+            key_bindings_container
+        },
+    };
+
+    let reset_key_bindings_button = if section == SettingsMenuSection::Controls {
+        Some(Widget::button(
+            commands,
+            (
+                Name::new("Reset Key Bindings Button"),
+                SettingsMenuResetKeyBindingsButton,
+                Widget::default(),
+            ),
+            &styles::reset_key_bindings_button(),
+            WidgetColors::button(),
+            &font,
+            RESET_KEY_BINDINGS_BUTTON_FONT_SIZE,
+            localization::reset_key_bindings_button(),
+            localization,
+        ))
+    } else {
+        None
+    };
+
+    let back_button = {
+        let mut button = Widget::default();
+        if !select_section_setting {
+            button = button.selected();
+        }
+        let back_button = Widget::button(
+            commands,
+            (Name::new("Back Button"), SettingsMenuBackButton, button),
+            &styles::back_button(),
+            WidgetColors::button(),
+            &font,
+            BACK_BUTTON_FONT_SIZE,
+            localization::back_button(),
+            localization,
+        );
+        if !select_section_setting {
+            commands.entity(back_button).insert(WidgetSelected::now());
+        }
+        back_button
+    };
+
+    let footer = {
+        let mut footer = commands.spawn((
+            Name::new("Footer"),
+            NodeBundle { style: styles::footer_container(), ..default() },
+        ));
+        if let Some(reset_key_bindings_button) = reset_key_bindings_button {
+            footer.add_child(reset_key_bindings_button);
+        }
+        footer.add_child(back_button);
+        footer.id()
+    };
+
+    let section_setting_widgets =
+        vec![section_setting_previous_button, section_setting_next_button];
+    let footer_widgets = reset_key_bindings_button.into_iter().chain([back_button]).collect();
 
-    let language_setting_widgets = [language_setting_previous_button, language_setting_next_button];
-    let footer_widgets = [back_button];
+    let mut widgets = vec![section_setting_widgets];
+    widgets.extend(section_widgets);
+    widgets.push(footer_widgets);
 
-    let widgets = [language_setting_widgets.as_slice(), footer_widgets.as_slice()];
     for i in 0..widgets.len() {
         let mut upper_row_index = if i != 0 { i - 1 } else { i };
         if upper_row_index != 0 {
@@ -156,7 +269,7 @@ pub fn spawn_settings_menu(
             lower_row_index = i;
         }
 
-        let row = widgets[i];
+        let row = &widgets[i];
         for j in 0..row.len() {
             let left_widget = if j != 0 { row[j - 1] } else { row[j] };
             let current_widget = row[j];
@@ -210,8 +323,408 @@ pub fn spawn_settings_menu(
         },
     ));
 
-    settings_menu.add_child(language_setting);
-    settings_menu.add_child(back_button);
+    settings_menu.add_child(section_setting);
+    settings_menu.add_child(section_container);
+    settings_menu.add_child(footer);
+}
+
+/// Spawns the general section of the settings menu.
+fn spawn_general_section(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    localization: &Localization,
+    general_settings: &GeneralSettings,
+    #[cfg(feature = "native")] window_state: Option<&WindowState>,
+) -> (Entity, Vec<Vec<Entity>>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    let language_setting_previous_button = {
+        let previous = localization::language_setting_previous_button();
+        Widget::button(
+            commands,
+            (
+                Name::new("Previous Button"),
+                SettingsMenuLanguageSettingPreviousButton,
+                Widget::default(),
+            ),
+            &styles::setting_changer(),
+            WidgetColors::button(),
+            &font,
+            SETTING_CHANGER_TEXT_FONT_SIZE,
+            previous,
+            localization,
+        )
+    };
+    let language_setting_name = {
+        let name = localization::language_setting_name();
+        commands
+            .spawn((
+                Name::new("Name"),
+                TextBundle {
+                    text: Text {
+                        sections: vec![TextSection::new(
+                            name.get(localization),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: SETTING_NAME_TEXT_FONT_SIZE,
+                                color: SETTING_NAME_TEXT_COLOR,
+                            },
+                        )],
+                        justify: JustifyText::Center,
+                        ..default()
+                    },
+                    style: styles::setting_name(),
+                    ..default()
+                },
+                name,
+            ))
+            .id()
+    };
+    let language_setting_value = {
+        let value = localization::language_setting_value();
+        commands
+            .spawn((
+                Name::new("Value"),
+                TextBundle {
+                    text: Text {
+                        sections: vec![TextSection::new(
+                            value.get(localization),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: SETTING_VALUE_TEXT_FONT_SIZE,
+                                color: SETTING_VALUE_TEXT_COLOR,
+                            },
+                        )],
+                        justify: JustifyText::Center,
+                        ..default()
+                    },
+                    style: styles::setting_value(),
+                    ..default()
+                },
+                value,
+            ))
+            .id()
+    };
+    let language_setting_next_button = {
+        let next = localization::language_setting_next_button();
+        Widget::button(
+            commands,
+            (Name::new("Next Button"), SettingsMenuLanguageSettingNextButton, Widget::default()),
+            &styles::setting_changer(),
+            WidgetColors::button(),
+            &font,
+            SETTING_CHANGER_TEXT_FONT_SIZE,
+            next,
+            localization,
+        )
+    };
+
+    let language_setting = commands
+        .spawn((
+            Name::new("Language Setting"),
+            SettingsMenuLanguageSettingContainer,
+            NodeBundle { style: styles::setting_container(), ..default() },
+        ))
+        .add_child(language_setting_previous_button)
+        .add_child(language_setting_name)
+        .add_child(language_setting_value)
+        .add_child(language_setting_next_button)
+        .id();
+
+    let container = commands
+        .spawn((
+            Name::new("General"),
+            NodeBundle { style: styles::section_container(), ..default() },
+        ))
+        .add_child(language_setting)
+        .id();
+
+    let mut widgets = vec![vec![language_setting_previous_button, language_setting_next_button]];
+    for &setting in SettingsMenuSection::General.settings() {
+        let (setting_container, setting_widgets) = spawn_setting(
+            commands,
+            &font,
+            localization,
+            general_settings,
+            #[cfg(feature = "native")]
+            window_state,
+            setting,
+        );
+        commands.entity(container).add_child(setting_container);
+        widgets.push(setting_widgets.to_vec());
+    }
+
+    (container, widgets)
+}
+
+/// Spawns a section of the settings menu which consists only of settings.
+fn spawn_settings_section(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    localization: &Localization,
+    general_settings: &GeneralSettings,
+    #[cfg(feature = "native")] window_state: Option<&WindowState>,
+    section: SettingsMenuSection,
+) -> (Entity, Vec<Vec<Entity>>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    let container = commands
+        .spawn((
+            Name::new(format!("{:?}", section)),
+            NodeBundle { style: styles::section_container(), ..default() },
+        ))
+        .id();
+
+    let mut widgets = Vec::new();
+    for &setting in section.settings() {
+        let (setting_container, setting_widgets) = spawn_setting(
+            commands,
+            &font,
+            localization,
+            general_settings,
+            #[cfg(feature = "native")]
+            window_state,
+            setting,
+        );
+        commands.entity(container).add_child(setting_container);
+        widgets.push(setting_widgets.to_vec());
+    }
+
+    (container, widgets)
+}
+
+/// Spawns a setting of the settings menu.
+fn spawn_setting(
+    commands: &mut Commands,
+    font: &Handle<Font>,
+    localization: &Localization,
+    general_settings: &GeneralSettings,
+    #[cfg(feature = "native")] window_state: Option<&WindowState>,
+    setting: SettingsMenuSetting,
+) -> (Entity, [Entity; 2]) {
+    let previous_button = Widget::button(
+        commands,
+        (
+            Name::new("Previous Button"),
+            SettingsMenuSettingPreviousButton(setting),
+            Widget::default(),
+        ),
+        &styles::setting_changer(),
+        WidgetColors::button(),
+        font,
+        SETTING_CHANGER_TEXT_FONT_SIZE,
+        localization::setting_previous_button(),
+        localization,
+    );
+    let name = {
+        let name = localization::setting_name(setting);
+        commands
+            .spawn((
+                Name::new("Name"),
+                TextBundle {
+                    text: Text {
+                        sections: vec![TextSection::new(
+                            name.get(localization),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: SETTING_NAME_TEXT_FONT_SIZE,
+                                color: SETTING_NAME_TEXT_COLOR,
+                            },
+                        )],
+                        justify: JustifyText::Center,
+                        ..default()
+                    },
+                    style: styles::setting_name(),
+                    ..default()
+                },
+                name,
+            ))
+            .id()
+    };
+    let value = {
+        let value = localization::setting_value(
+            setting,
+            general_settings,
+            #[cfg(feature = "native")]
+            window_state,
+        );
+        commands
+            .spawn((
+                Name::new("Value"),
+                SettingsMenuSettingValue(setting),
+                TextBundle {
+                    text: Text {
+                        sections: vec![TextSection::new(
+                            value.get(localization),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: SETTING_VALUE_TEXT_FONT_SIZE,
+                                color: SETTING_VALUE_TEXT_COLOR,
+                            },
+                        )],
+                        justify: JustifyText::Center,
+                        ..default()
+                    },
+                    style: styles::setting_value(),
+                    ..default()
+                },
+                value,
+            ))
+            .id()
+    };
+    let next_button = Widget::button(
+        commands,
+        (Name::new("Next Button"), SettingsMenuSettingNextButton(setting), Widget::default()),
+        &styles::setting_changer(),
+        WidgetColors::button(),
+        font,
+        SETTING_CHANGER_TEXT_FONT_SIZE,
+        localization::setting_next_button(),
+        localization,
+    );
+
+    let container = commands
+        .spawn((
+            Name::new(format!("{:?} Setting", setting)),
+            NodeBundle { style: styles::setting_container(), ..default() },
+        ))
+        .add_child(previous_button)
+        .add_child(name)
+        .add_child(value)
+        .add_child(next_button)
+        .id();
+
+    (container, [previous_button, next_button])
+}
+
+/// Spawns the controls section of the settings menu.
+fn spawn_controls_section(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    localization: &Localization,
+    key_bindings: &KeyBindings,
+) -> (Entity, Vec<Vec<Entity>>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    let mut rows = Vec::new();
+    let mut widgets = Vec::new();
+
+    for binding in KeyBinding::all() {
+        let name = {
+            let name = localization::key_binding_name(binding);
+            commands
+                .spawn((
+                    Name::new("Name"),
+                    TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new(
+                                name.get(localization),
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: KEY_BINDING_NAME_TEXT_FONT_SIZE,
+                                    color: KEY_BINDING_NAME_TEXT_COLOR,
+                                },
+                            )],
+                            ..default()
+                        },
+                        style: styles::key_binding_name(),
+                        ..default()
+                    },
+                    name,
+                ))
+                .id()
+        };
+
+        let mut buttons = Vec::new();
+        for slot in 0..NUMBER_OF_KEY_SLOTS {
+            let input = SettingsMenuKeyBindingInput::Key { slot };
+            let button = Widget::button(
+                commands,
+                (
+                    Name::new(format!("Key {} Button", slot + 1)),
+                    SettingsMenuKeyBindingButton { binding, input },
+                    Widget::default(),
+                ),
+                &styles::key_binding_button(),
+                WidgetColors::button(),
+                &font,
+                KEY_BINDING_BUTTON_FONT_SIZE,
+                localization::key(key_bindings.keys(binding).get(slot).cloned()),
+                localization,
+            );
+            buttons.push(button);
+        }
+        {
+            let input = SettingsMenuKeyBindingInput::GamepadButton;
+            let button = Widget::button(
+                commands,
+                (
+                    Name::new("Gamepad Button"),
+                    SettingsMenuKeyBindingButton { binding, input },
+                    Widget::default(),
+                ),
+                &styles::key_binding_button(),
+                WidgetColors::button(),
+                &font,
+                KEY_BINDING_BUTTON_FONT_SIZE,
+                localization::gamepad_button(
+                    key_bindings.gamepad_buttons(binding).first().cloned(),
+                ),
+                localization,
+            );
+            buttons.push(button);
+        }
+
+        let mut row = commands.spawn((
+            Name::new(format!("{:?} Key Binding", binding)),
+            NodeBundle { style: styles::key_binding_row(), ..default() },
+        ));
+        row.add_child(name);
+        for button in buttons.iter() {
+            row.add_child(*button);
+        }
+
+        rows.push(row.id());
+        widgets.push(buttons);
+    }
+
+    let status = {
+        let status = localization::key_binding_status();
+        commands
+            .spawn((
+                Name::new("Status"),
+                SettingsMenuKeyBindingStatus,
+                TextBundle {
+                    text: Text {
+                        sections: vec![TextSection::new(
+                            status.get(localization),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: KEY_BINDING_STATUS_TEXT_FONT_SIZE,
+                                color: KEY_BINDING_STATUS_TEXT_COLOR,
+                            },
+                        )],
+                        justify: JustifyText::Center,
+                        ..default()
+                    },
+                    style: styles::key_binding_status(),
+                    ..default()
+                },
+                status,
+            ))
+            .id()
+    };
+
+    let mut container = commands.spawn((
+        Name::new("Key Bindings"),
+        NodeBundle { style: styles::section_container(), ..default() },
+    ));
+    for row in rows {
+        container.add_child(row);
+    }
+    container.add_child(status);
+
+    (container.id(), widgets)
 }
 
 /// Despawns the settings menu.
@@ -233,11 +746,16 @@ pub fn navigation(
         (&mut Widget, &WidgetUp, &WidgetDown, &WidgetLeft, &WidgetRight),
         With<WidgetSelected>,
     >,
+    key_binding_capture_query: Query<(), With<SettingsMenuKeyBindingCapture>>,
     app_state: Res<State<AppState>>,
     mut next_app_state: ResMut<NextState<AppState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
     mut game_state_stack: ResMut<GameStateStack>,
 ) {
+    if !key_binding_capture_query.is_empty() {
+        return;
+    }
+
     let settings_menu_action_state = match settings_menu_query.get_single_mut() {
         Ok(query_result) => query_result,
         Err(_) => return,
@@ -288,6 +806,65 @@ pub fn navigation(
 }
 
 
+/// Changes the section of the settings menu.
+pub fn section_setting_button_interaction(
+    mut commands: Commands,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+    mut section_setting_previous_button_query: Query<
+        &mut Widget,
+        (Changed<Widget>, With<SettingsMenuSectionSettingPreviousButton>),
+    >,
+    mut section_setting_next_button_query: Query<
+        &mut Widget,
+        (
+            Changed<Widget>,
+            With<SettingsMenuSectionSettingNextButton>,
+            Without<SettingsMenuSectionSettingPreviousButton>,
+        ),
+    >,
+    asset_server: Res<AssetServer>,
+    settings_menu_action_input_map: Res<InputMap<SettingsMenuAction>>,
+    localization: Res<Localization>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    #[cfg(feature = "native")] window_state_query: Query<
+        &Persistent<WindowState>,
+        With<PrimaryWindow>,
+    >,
+    key_bindings: Res<Persistent<KeyBindings>>,
+    mut section: ResMut<SettingsMenuSection>,
+) {
+    let mut new_section = None;
+    if let Ok(mut button) = section_setting_previous_button_query.get_single_mut() {
+        button.on_click(|| new_section = Some(section.previous()));
+    }
+    if let Ok(mut button) = section_setting_next_button_query.get_single_mut() {
+        button.on_click(|| new_section = Some(section.next()));
+    }
+
+    let new_section = match new_section {
+        Some(new_section) => new_section,
+        None => return,
+    };
+    *section = new_section;
+
+    if let Ok(entity) = settings_menu_query.get_single() {
+        commands.entity(entity).despawn_recursive();
+    }
+    spawn(
+        &mut commands,
+        &asset_server,
+        &settings_menu_action_input_map,
+        &localization,
+        &general_settings,
+        #[cfg(feature = "native")]
+        window_state_query.get_single().ok().map(|window_state| window_state.get()),
+        &key_bindings,
+        new_section,
+        true,
+    );
+}
+
+
 /// Sets the locale to the previous supported locale.
 pub fn language_setting_previous_button_interaction(
     mut commands: Commands,
@@ -352,6 +929,322 @@ pub fn language_setting_next_button_interaction(
     }
 }
 
+/// Changes the settings to their previous values.
+pub fn setting_previous_button_interaction(
+    mut setting_previous_button_query: Query<
+        (&mut Widget, &SettingsMenuSettingPreviousButton),
+        Changed<Widget>,
+    >,
+    mut general_settings: ResMut<Persistent<GeneralSettings>>,
+    #[cfg(feature = "native")] mut window_query: Query<
+        (&mut Window, &mut Persistent<WindowState>),
+        With<PrimaryWindow>,
+    >,
+) {
+    for (mut button, &SettingsMenuSettingPreviousButton(setting)) in
+        setting_previous_button_query.iter_mut()
+    {
+        button.on_click(|| {
+            #[cfg(feature = "native")]
+            if setting.is_window_setting() {
+                if let Ok((mut window, mut window_state)) = window_query.get_single_mut() {
+                    change_window_setting(&mut window, &mut window_state, setting, false);
+                }
+                return;
+            }
+            general_settings
+                .update(|general_settings| change_setting(general_settings, setting, false))
+                .ok();
+        });
+    }
+}
+
+/// Changes the settings to their next values.
+pub fn setting_next_button_interaction(
+    mut setting_next_button_query: Query<
+        (&mut Widget, &SettingsMenuSettingNextButton),
+        Changed<Widget>,
+    >,
+    mut general_settings: ResMut<Persistent<GeneralSettings>>,
+    #[cfg(feature = "native")] mut window_query: Query<
+        (&mut Window, &mut Persistent<WindowState>),
+        With<PrimaryWindow>,
+    >,
+) {
+    for (mut button, &SettingsMenuSettingNextButton(setting)) in
+        setting_next_button_query.iter_mut()
+    {
+        button.on_click(|| {
+            #[cfg(feature = "native")]
+            if setting.is_window_setting() {
+                if let Ok((mut window, mut window_state)) = window_query.get_single_mut() {
+                    change_window_setting(&mut window, &mut window_state, setting, true);
+                }
+                return;
+            }
+            general_settings
+                .update(|general_settings| change_setting(general_settings, setting, true))
+                .ok();
+        });
+    }
+}
+
+/// Updates the values of the settings when general settings are changed.
+pub fn update_setting_values(
+    mut setting_value_query: Query<(&mut LocalizedText, &SettingsMenuSettingValue)>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+    #[cfg(feature = "native")] window_state_query: Query<
+        &Persistent<WindowState>,
+        With<PrimaryWindow>,
+    >,
+) {
+    #[cfg(feature = "native")]
+    let window_state = window_state_query.get_single().ok().map(|window_state| window_state.get());
+    for (mut text, &SettingsMenuSettingValue(setting)) in setting_value_query.iter_mut() {
+        *text = localization::setting_value(
+            setting,
+            &general_settings,
+            #[cfg(feature = "native")]
+            window_state,
+        );
+    }
+}
+
+
+/// Starts waiting for an input to change the key binding of the clicked key binding button.
+pub fn key_binding_button_interaction(
+    mut commands: Commands,
+    mut key_binding_button_query: Query<
+        (Entity, &mut Widget, &SettingsMenuKeyBindingButton, &Children),
+        Changed<Widget>,
+    >,
+    key_binding_capture_query: Query<
+        (Entity, &SettingsMenuKeyBindingButton, &Children),
+        With<SettingsMenuKeyBindingCapture>,
+    >,
+    mut localized_text_query: Query<&mut LocalizedText, Without<SettingsMenuKeyBindingStatus>>,
+    mut key_binding_status_query: Query<&mut LocalizedText, With<SettingsMenuKeyBindingStatus>>,
+    key_bindings: Res<Persistent<KeyBindings>>,
+) {
+    for (entity, mut button, key_binding_button, children) in key_binding_button_query.iter_mut() {
+        button.on_click(|| {
+            for (capturing_entity, capturing_key_binding_button, capturing_children) in
+                key_binding_capture_query.iter()
+            {
+                commands.entity(capturing_entity).remove::<SettingsMenuKeyBindingCapture>();
+                for &child in capturing_children.iter() {
+                    if let Ok(mut text) = localized_text_query.get_mut(child) {
+                        *text =
+                            key_binding_button_text(capturing_key_binding_button, &key_bindings);
+                    }
+                }
+            }
+
+            commands.entity(entity).insert(SettingsMenuKeyBindingCapture);
+            for &child in children.iter() {
+                if let Ok(mut text) = localized_text_query.get_mut(child) {
+                    *text = match key_binding_button.input {
+                        SettingsMenuKeyBindingInput::Key { .. } => {
+                            localization::key_binding_capturing_key()
+                        },
+                        SettingsMenuKeyBindingInput::GamepadButton => {
+                            localization::key_binding_capturing_gamepad_button()
+                        },
+                    };
+                }
+            }
+
+            if let Ok(mut status) = key_binding_status_query.get_single_mut() {
+                *status = localization::key_binding_status();
+            }
+        });
+    }
+}
+
+/// Changes the key binding of the key binding button that is waiting for an input.
+pub fn capture_key_binding(
+    mut commands: Commands,
+    key_binding_capture_query: Query<
+        (Entity, &SettingsMenuKeyBindingButton, &Children),
+        With<SettingsMenuKeyBindingCapture>,
+    >,
+    mut localized_text_query: Query<&mut LocalizedText, Without<SettingsMenuKeyBindingStatus>>,
+    mut key_binding_status_query: Query<&mut LocalizedText, With<SettingsMenuKeyBindingStatus>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepad_button_input: Res<ButtonInput<GamepadButton>>,
+    localization: Res<Localization>,
+    mut key_bindings: ResMut<Persistent<KeyBindings>>,
+) {
+    let (entity, key_binding_button, children) = match key_binding_capture_query.get_single() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+    let binding = key_binding_button.binding;
+
+    let mut stop_capturing = |key_bindings: &KeyBindings| {
+        commands.entity(entity).remove::<SettingsMenuKeyBindingCapture>();
+        for &child in children.iter() {
+            if let Ok(mut text) = localized_text_query.get_mut(child) {
+                *text = key_binding_button_text(key_binding_button, key_bindings);
+            }
+        }
+    };
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        stop_capturing(&key_bindings);
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Backspace) {
+        let removes_last_input = match key_binding_button.input {
+            SettingsMenuKeyBindingInput::Key { slot } => {
+                let keys = key_bindings.keys(binding);
+                slot < keys.len() && keys.len() == 1
+            },
+            SettingsMenuKeyBindingInput::GamepadButton => {
+                !key_bindings.gamepad_buttons(binding).is_empty()
+            },
+        };
+        if removes_last_input && binding.is_used_in_menus() {
+            if let Ok(mut status) = key_binding_status_query.get_single_mut() {
+                *status = localization::key_binding_required(
+                    &localization::key_binding_name(binding).get(&localization),
+                );
+            }
+            stop_capturing(&key_bindings);
+            return;
+        }
+
+        key_bindings
+            .update(|key_bindings| {
+                match key_binding_button.input {
+                    SettingsMenuKeyBindingInput::Key { slot } => {
+                        let keys = key_bindings.keys_mut(binding);
+                        if slot < keys.len() {
+                            keys.remove(slot);
+                        }
+                    },
+                    SettingsMenuKeyBindingInput::GamepadButton => {
+                        key_bindings.gamepad_buttons_mut(binding).clear();
+                    },
+                }
+            })
+            .ok();
+        stop_capturing(&key_bindings);
+        return;
+    }
+
+    let conflict = match key_binding_button.input {
+        SettingsMenuKeyBindingInput::Key { slot } => {
+            let key_code = match keyboard_input.get_just_pressed().next() {
+                Some(key_code) => *key_code,
+                None => return,
+            };
+            match key_bindings.find_key(binding, key_code) {
+                Some(existing_binding) => {
+                    if existing_binding == binding
+                        && key_bindings.keys(binding).get(slot) == Some(&key_code)
+                    {
+                        None
+                    } else {
+                        Some((localization::key(Some(key_code)), existing_binding))
+                    }
+                },
+                None => {
+                    key_bindings
+                        .update(|key_bindings| {
+                            let keys = key_bindings.keys_mut(binding);
+                            if slot < keys.len() {
+                                keys[slot] = key_code;
+                            } else {
+                                keys.push(key_code);
+                            }
+                        })
+                        .ok();
+                    None
+                },
+            }
+        },
+        SettingsMenuKeyBindingInput::GamepadButton => {
+            let gamepad_button = match gamepad_button_input.get_just_pressed().next() {
+                Some(gamepad_button) => gamepad_button.button_type,
+                None => return,
+            };
+            match key_bindings.find_gamepad_button(binding, gamepad_button) {
+                Some(existing_binding) => {
+                    if existing_binding == binding {
+                        None
+                    } else {
+                        Some((localization::gamepad_button(Some(gamepad_button)), existing_binding))
+                    }
+                },
+                None => {
+                    key_bindings
+                        .update(|key_bindings| {
+                            let gamepad_buttons = key_bindings.gamepad_buttons_mut(binding);
+                            gamepad_buttons.clear();
+                            gamepad_buttons.push(gamepad_button);
+                        })
+                        .ok();
+                    None
+                },
+            }
+        },
+    };
+
+    if let Ok(mut status) = key_binding_status_query.get_single_mut() {
+        *status = match conflict {
+            Some((input, existing_binding)) => {
+                localization::key_binding_conflict(
+                    &input.get(&localization),
+                    &localization::key_binding_name(existing_binding).get(&localization),
+                )
+            },
+            None => localization::key_binding_status(),
+        };
+    }
+
+    stop_capturing(&key_bindings);
+}
+
+/// Updates the texts of the key binding buttons when key bindings are changed.
+pub fn update_key_binding_buttons(
+    key_binding_button_query: Query<
+        (&SettingsMenuKeyBindingButton, &Children),
+        Without<SettingsMenuKeyBindingCapture>,
+    >,
+    mut localized_text_query: Query<&mut LocalizedText>,
+    key_bindings: Res<Persistent<KeyBindings>>,
+) {
+    for (key_binding_button, children) in key_binding_button_query.iter() {
+        for &child in children.iter() {
+            if let Ok(mut text) = localized_text_query.get_mut(child) {
+                *text = key_binding_button_text(key_binding_button, &key_bindings);
+            }
+        }
+    }
+}
+
+/// Resets the key bindings to their defaults.
+pub fn reset_key_bindings_button_interaction(
+    mut commands: Commands,
+    mut reset_key_bindings_button_query: Query<
+        &mut Widget,
+        (Changed<Widget>, With<SettingsMenuResetKeyBindingsButton>),
+    >,
+    key_binding_capture_query: Query<Entity, With<SettingsMenuKeyBindingCapture>>,
+    mut key_bindings: ResMut<Persistent<KeyBindings>>,
+) {
+    if let Ok(mut button) = reset_key_bindings_button_query.get_single_mut() {
+        button.on_click(|| {
+            for entity in key_binding_capture_query.iter() {
+                commands.entity(entity).remove::<SettingsMenuKeyBindingCapture>();
+            }
+            key_bindings.revert_to_default().ok();
+        });
+    }
+}
+
 /// Returns to the main menu.
 pub fn back_button_interaction(
     mut back_button_query: Query<&mut Widget, (Changed<Widget>, With<SettingsMenuBackButton>)>,
@@ -372,3 +1265,159 @@ pub fn back_button_interaction(
         });
     }
 }
+
+
+/// Gets the text of a key binding button.
+fn key_binding_button_text(
+    key_binding_button: &SettingsMenuKeyBindingButton,
+    key_bindings: &KeyBindings,
+) -> LocalizedText {
+    match key_binding_button.input {
+        SettingsMenuKeyBindingInput::Key { slot } => {
+            localization::key(key_bindings.keys(key_binding_button.binding).get(slot).cloned())
+        },
+        SettingsMenuKeyBindingInput::GamepadButton => {
+            localization::gamepad_button(
+                key_bindings.gamepad_buttons(key_binding_button.binding).first().cloned(),
+            )
+        },
+    }
+}
+
+
+/// Changes a setting to its next or previous value.
+fn change_setting(
+    general_settings: &mut GeneralSettings,
+    setting: SettingsMenuSetting,
+    next: bool,
+) {
+    let change_volume = |volume: &mut f32| {
+        let step = if next { VOLUME_SETTING_STEP } else { -VOLUME_SETTING_STEP };
+        *volume = ((*volume + step) / VOLUME_SETTING_STEP).round() * VOLUME_SETTING_STEP;
+        *volume = volume.clamp(0.00, 1.00);
+    };
+    match setting {
+        SettingsMenuSetting::AimWithMouse => {
+            general_settings.aim_with_mouse = !general_settings.aim_with_mouse;
+        },
+        SettingsMenuSetting::FireManually => {
+            general_settings.fire_manually = !general_settings.fire_manually;
+        },
+        SettingsMenuSetting::DamageNumbers => {
+            general_settings.damage_numbers = !general_settings.damage_numbers;
+        },
+        SettingsMenuSetting::HitFlashes => {
+            general_settings.hit_flashes = !general_settings.hit_flashes;
+        },
+        SettingsMenuSetting::DeathEffects => {
+            general_settings.death_effects = !general_settings.death_effects;
+        },
+        SettingsMenuSetting::EnemyHealthBars => {
+            let enemy_health_bars = general_settings.enemy_health_bars;
+            general_settings.enemy_health_bars =
+                if next { enemy_health_bars.next() } else { enemy_health_bars.previous() };
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::Vsync => {
+            general_settings.vsync = !general_settings.vsync;
+        },
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::FrameRateLimit => {
+            let position = FRAME_RATE_LIMITS
+                .iter()
+                .position(|&limit| limit == general_settings.frame_rate_limit)
+                .unwrap_or(0);
+            let new_position = if next {
+                (position + 1) % FRAME_RATE_LIMITS.len()
+            } else {
+                (position + FRAME_RATE_LIMITS.len() - 1) % FRAME_RATE_LIMITS.len()
+            };
+            general_settings.frame_rate_limit = FRAME_RATE_LIMITS[new_position];
+        },
+        SettingsMenuSetting::MasterVolume => {
+            change_volume(&mut general_settings.master_volume);
+        },
+        SettingsMenuSetting::MusicVolume => {
+            change_volume(&mut general_settings.music_volume);
+        },
+        SettingsMenuSetting::SoundEffectsVolume => {
+            change_volume(&mut general_settings.sound_effects_volume);
+        },
+        SettingsMenuSetting::UiScale => {
+            let step = if next { UI_SCALE_SETTING_STEP } else { -UI_SCALE_SETTING_STEP };
+            let scale = &mut general_settings.ui_scale;
+            *scale = ((*scale + step) / UI_SCALE_SETTING_STEP).round() * UI_SCALE_SETTING_STEP;
+            *scale = scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+        },
+        SettingsMenuSetting::ColorPalette => {
+            let palette = general_settings.color_palette;
+            general_settings.color_palette = if next { palette.next() } else { palette.previous() };
+        },
+        SettingsMenuSetting::ReduceScreenEffects => {
+            general_settings.reduce_screen_effects = !general_settings.reduce_screen_effects;
+        },
+        SettingsMenuSetting::HighContrast => {
+            general_settings.high_contrast = !general_settings.high_contrast;
+        },
+        // Window mode and resolution are stored in the window state instead of the general
+        // settings, so they are changed by `change_window_setting`.
+        #[cfg(feature = "native")]
+        SettingsMenuSetting::WindowMode | SettingsMenuSetting::Resolution => {},
+    }
+}
+
+/// Changes a setting that is stored in the persisted window state to its next or previous value.
+#[cfg(feature = "native")]
+fn change_window_setting(
+    window: &mut Window,
+    window_state: &mut Persistent<WindowState>,
+    setting: SettingsMenuSetting,
+    next: bool,
+) {
+    let mut window_mode = window_state.mode;
+    let mut resolution = window_state.resolution;
+    match setting {
+        SettingsMenuSetting::WindowMode => {
+            if window_mode == WindowMode::Fullscreen {
+                window_mode = WindowMode::SizedFullscreen;
+            }
+            let position = WINDOW_MODES.iter().position(|&mode| mode == window_mode).unwrap_or(0);
+            let new_position = if next {
+                (position + 1) % WINDOW_MODES.len()
+            } else {
+                (position + WINDOW_MODES.len() - 1) % WINDOW_MODES.len()
+            };
+            window_mode = WINDOW_MODES[new_position];
+        },
+        SettingsMenuSetting::Resolution => {
+            let (width, height) =
+                resolution.unwrap_or((window.physical_width(), window.physical_height()));
+            let new_resolution = if next {
+                RESOLUTIONS
+                    .iter()
+                    .find(|&&(w, h)| (w, h) > (width, height))
+                    .unwrap_or(&RESOLUTIONS[0])
+            } else {
+                RESOLUTIONS
+                    .iter()
+                    .rev()
+                    .find(|&&(w, h)| (w, h) < (width, height))
+                    .unwrap_or(&RESOLUTIONS[RESOLUTIONS.len() - 1])
+            };
+            resolution = Some(*new_resolution);
+        },
+        _ => return,
+    }
+
+    window.mode = window_mode;
+    if let Some((width, height)) = resolution {
+        window.resolution.set_physical_resolution(width, height);
+    }
+
+    window_state
+        .update(|window_state| {
+            window_state.mode = window_mode;
+            window_state.resolution = resolution;
+        })
+        .ok();
+}
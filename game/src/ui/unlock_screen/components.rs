@@ -0,0 +1,30 @@
+use crate::prelude::*;
+
+
+/// Tag component for the unlock screen.
+#[derive(Component, Debug, Reflect)]
+pub struct UnlockScreen;
+
+
+/// Tag component for the ambrosia text in the unlock screen.
+#[derive(Component, Debug, Reflect)]
+pub struct UnlockScreenAmbrosiaText;
+
+
+/// Component for unlock buttons in the unlock screen.
+#[derive(Component, Debug, Reflect)]
+pub struct UnlockScreenUnlockButton {
+    /// Kind of the content the button unlocks.
+    pub kind: UnlockableKind,
+    /// Identifier of the content the button unlocks.
+    pub id: SmolStr,
+    /// Localized name of the content the button unlocks.
+    pub name: SmolStr,
+    /// Cost of unlocking the content.
+    pub cost: Ambrosia,
+}
+
+
+/// Tag component for the back button in the unlock screen.
+#[derive(Component, Debug, Reflect)]
+pub struct UnlockScreenBackButton;
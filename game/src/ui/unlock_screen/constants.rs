@@ -0,0 +1,12 @@
+/// Font size of the title in the unlock screen in pt.
+pub const TITLE_FONT_SIZE: f32 = 64.0;
+
+/// Font size of the ambrosia text in the unlock screen in pt.
+pub const AMBROSIA_TEXT_FONT_SIZE: f32 = 32.0;
+
+
+/// Font size of the unlock buttons in the unlock screen in pt.
+pub const UNLOCK_BUTTON_FONT_SIZE: f32 = 24.0;
+
+/// Font size of the buttons in the unlock screen in pt.
+pub const BUTTON_FONT_SIZE: f32 = 32.0;
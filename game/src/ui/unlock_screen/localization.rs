@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+
+/// Gets the localized text of the title.
+pub fn title() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "unlock-screen-title",
+        args: smallvec![],
+        fallback: "Unlocks".into(),
+    }
+}
+
+/// Gets the localized text of the ambrosia text.
+pub fn ambrosia_text(ambrosia: Ambrosia) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "unlock-screen-ambrosia-text",
+        args: smallvec![("ambrosia", format_smolstr!("{}", ambrosia.0))],
+        fallback: format!("Ambrosia: {}", ambrosia.0).into(),
+    }
+}
+
+
+/// Gets the localized text of unlock buttons.
+pub fn unlock_button(name: &str, cost: Ambrosia) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "unlock-screen-unlock-button",
+        args: smallvec![("name", name.into()), ("cost", format_smolstr!("{}", cost.0))],
+        fallback: format!("{} - {} Ambrosia", name, cost.0).into(),
+    }
+}
+
+/// Gets the localized text of unlock buttons of unlocked content.
+pub fn unlocked_button(name: &str) -> LocalizedText {
+    LocalizedText::Localized {
+        key: "unlock-screen-unlocked-button",
+        args: smallvec![("name", name.into())],
+        fallback: format!("{} (Unlocked)", name).into(),
+    }
+}
+
+
+/// Gets the localized text of the back button.
+pub fn back_button() -> LocalizedText {
+    LocalizedText::Localized {
+        key: "unlock-screen-back-button",
+        args: smallvec![],
+        fallback: "Back".into(),
+    }
+}
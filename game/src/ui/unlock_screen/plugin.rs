@@ -0,0 +1,35 @@
+use crate::{
+    prelude::*,
+    ui::unlock_screen::systems::*,
+};
+
+/// Plugin for managing the unlock screen.
+pub struct UnlockScreenPlugin;
+
+impl Plugin for UnlockScreenPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<UnlockScreen>();
+        app.register_type::<UnlockScreenAmbrosiaText>();
+        app.register_type::<UnlockScreenUnlockButton>();
+        app.register_type::<UnlockScreenBackButton>();
+
+        // Setup localization.
+        app.world_mut().resource_mut::<LocaleAssets>().push("ui/unlock_screen.ftl");
+
+        // Add systems.
+        app.add_systems(OnEnter(AppState::UnlockScreen), spawn_unlock_screen);
+        app.add_systems(Update, navigation.in_set(UnlockScreenSystems));
+        app.add_systems(
+            Update,
+            (unlock_button_interaction, back_button_interaction).in_set(UnlockScreenSystems),
+        );
+        app.add_systems(
+            PostUpdate,
+            update_unlock_screen
+                .in_set(UnlockScreenSystems)
+                .run_if(resource_changed::<Persistent<Progression>>),
+        );
+        app.add_systems(OnExit(AppState::UnlockScreen), despawn_unlock_screen);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+
+
+/// Gets the style of the root container of the unlock screen.
+pub fn root() -> Style {
+    Style {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Percent(1.50),
+        ..default()
+    }
+}
+
+
+/// Gets the style of the texts in the unlock screen.
+pub fn text() -> Style {
+    Style { justify_content: JustifyContent::Center, align_items: AlignItems::Center, ..default() }
+}
+
+
+/// Gets the style of the unlock buttons container in the unlock screen.
+pub fn unlock_buttons_container() -> Style {
+    Style {
+        width: Val::Percent(60.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(5.00),
+        ..default()
+    }
+}
+
+/// Gets the style of the unlock buttons in the unlock screen.
+pub fn unlock_button() -> Style {
+    Style {
+        width: Val::Percent(60.00),
+        height: Val::Px(40.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+
+/// Gets the style of the buttons in the unlock screen.
+pub fn button() -> Style {
+    Style {
+        width: Val::Percent(16.00),
+        height: Val::Percent(9.00),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
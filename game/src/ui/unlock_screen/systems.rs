@@ -0,0 +1,323 @@
+use crate::{
+    prelude::*,
+    ui::unlock_screen::{
+        constants::*,
+        localization,
+        styles,
+    },
+};
+
+
+/// Spawns the unlock screen.
+pub fn spawn_unlock_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    unlock_screen_action_input_map: Res<InputMap<UnlockScreenAction>>,
+    player_registry: Res<PlayerRegistry>,
+    item_registry: Res<ItemRegistry>,
+    perk_registry: Res<PerkRegistry>,
+    progression: Res<Persistent<Progression>>,
+    localization: Res<Localization>,
+) {
+    let mut unlockables = Vec::new();
+    for entry in player_registry.iter() {
+        for player in entry.players.iter() {
+            if let Some(cost) = player.unlock_cost {
+                let name = player.name().get(&localization).to_smolstr();
+                unlockables.push((UnlockableKind::Player, player.id(), name, cost));
+            }
+        }
+    }
+    for entry in item_registry.iter() {
+        if let Some(cost) = entry.item.unlock_cost {
+            let name = entry.item.name().get(&localization).to_smolstr();
+            unlockables.push((UnlockableKind::Item, entry.item.id(), name, cost));
+        }
+    }
+    for entry in perk_registry.iter() {
+        if let Some(cost) = entry.perk.unlock_cost {
+            let name = entry.perk.name.get(&localization).to_smolstr();
+            unlockables.push((UnlockableKind::Perk, entry.perk.id(), name, cost));
+        }
+    }
+
+    let unlock_button_style = styles::unlock_button();
+    let unlock_button_colors = WidgetColors::button();
+    let unlock_button_font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let unlock_button_font_size = UNLOCK_BUTTON_FONT_SIZE;
+
+    let mut entities = Vec::new();
+    for (kind, id, name, cost) in unlockables {
+        let is_unlocked = progression.is_unlocked(kind, &id);
+        let text = if is_unlocked {
+            localization::unlocked_button(&name)
+        } else {
+            localization::unlock_button(&name, cost)
+        };
+
+        let unlock_button = Widget::button(
+            &mut commands,
+            (
+                Name::new(format!("Unlock Button [{} {}]", kind, id)),
+                UnlockScreenUnlockButton { kind, id, name, cost },
+                Widget::default(),
+            ),
+            &unlock_button_style,
+            unlock_button_colors,
+            &unlock_button_font,
+            unlock_button_font_size,
+            text,
+            &localization,
+        );
+        if is_unlocked || progression.ambrosia < cost {
+            commands.entity(unlock_button).insert(WidgetDisabled);
+        }
+
+        entities.push(unlock_button);
+    }
+
+    let unlock_buttons_container = commands
+        .spawn((
+            Name::new("Unlock Buttons"),
+            NodeBundle { style: styles::unlock_buttons_container(), ..default() },
+        ))
+        .push_children(&entities)
+        .id();
+
+    let button_style = styles::button();
+    let button_colors = WidgetColors::button();
+    let button_font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let button_font_size = BUTTON_FONT_SIZE;
+
+    let back_button = Widget::button(
+        &mut commands,
+        (Name::new("Back Button"), UnlockScreenBackButton, Widget::default()),
+        &button_style,
+        button_colors,
+        &button_font,
+        button_font_size,
+        localization::back_button(),
+        &localization,
+    );
+    entities.push(back_button);
+
+    commands.entity(entities[0]).insert((Widget::default().selected(), WidgetSelected::now()));
+    for i in 0..entities.len() {
+        let up = if i != 0 { entities[i - 1] } else { entities[entities.len() - 1] };
+        let current = entities[i];
+        let down = if i != entities.len() - 1 { entities[i + 1] } else { entities[0] };
+
+        commands.entity(current).insert((WidgetUp(up), WidgetDown(down)));
+    }
+
+    let title_text = localization::title();
+    let title = commands
+        .spawn((
+            Name::new("Title"),
+            TextBundle {
+                style: styles::text(),
+                text: Text::from_section(
+                    title_text.get(&localization),
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: TITLE_FONT_SIZE,
+                        ..default()
+                    },
+                ),
+                ..default()
+            },
+            title_text,
+        ))
+        .id();
+
+    let ambrosia_text = localization::ambrosia_text(progression.ambrosia);
+    let ambrosia = commands
+        .spawn((
+            Name::new("Ambrosia"),
+            UnlockScreenAmbrosiaText,
+            TextBundle {
+                style: styles::text(),
+                text: Text::from_section(
+                    ambrosia_text.get(&localization),
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: AMBROSIA_TEXT_FONT_SIZE,
+                        ..default()
+                    },
+                ),
+                ..default()
+            },
+            ambrosia_text,
+        ))
+        .id();
+
+    let pressed = ActionData { state: ButtonState::Pressed, ..default() };
+    let mut action_state = ActionState::default();
+
+    action_state.set_action_data(UnlockScreenAction::Back, pressed.clone());
+    action_state.set_action_data(UnlockScreenAction::Up, pressed.clone());
+    action_state.set_action_data(UnlockScreenAction::Down, pressed.clone());
+    action_state.set_action_data(UnlockScreenAction::Select, pressed);
+
+    commands
+        .spawn((
+            Name::new("Unlock Screen"),
+            UnlockScreen,
+            InputManagerBundle::<UnlockScreenAction> {
+                action_state,
+                input_map: unlock_screen_action_input_map.clone(),
+            },
+            NodeBundle { style: styles::root(), ..default() },
+        ))
+        .add_child(title)
+        .add_child(ambrosia)
+        .add_child(unlock_buttons_container)
+        .add_child(back_button);
+}
+
+/// Despawns the unlock screen.
+pub fn despawn_unlock_screen(
+    mut commands: Commands,
+    unlock_screen_query: Query<Entity, With<UnlockScreen>>,
+) {
+    if let Ok(entity) = unlock_screen_query.get_single() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+
+/// Updates the unlock screen when the progression changes.
+pub fn update_unlock_screen(
+    mut commands: Commands,
+    mut ambrosia_text_query: Query<&mut LocalizedText, With<UnlockScreenAmbrosiaText>>,
+    unlock_button_query: Query<(Entity, &UnlockScreenUnlockButton)>,
+    mut text_query: Query<(&Parent, &mut LocalizedText), Without<UnlockScreenAmbrosiaText>>,
+    progression: Res<Persistent<Progression>>,
+) {
+    if let Ok(mut ambrosia_text) = ambrosia_text_query.get_single_mut() {
+        *ambrosia_text = localization::ambrosia_text(progression.ambrosia);
+    }
+
+    for (unlock_button_entity, unlock_button) in unlock_button_query.iter() {
+        let is_unlocked = progression.is_unlocked(unlock_button.kind, &unlock_button.id);
+        for (parent_entity, mut unlock_button_text) in text_query.iter_mut() {
+            if parent_entity.get() == unlock_button_entity {
+                *unlock_button_text = if is_unlocked {
+                    localization::unlocked_button(&unlock_button.name)
+                } else {
+                    localization::unlock_button(&unlock_button.name, unlock_button.cost)
+                };
+                break;
+            }
+        }
+
+        if is_unlocked || progression.ambrosia < unlock_button.cost {
+            commands.entity(unlock_button_entity).insert(WidgetDisabled);
+        } else {
+            commands.entity(unlock_button_entity).remove::<WidgetDisabled>();
+        }
+    }
+}
+
+
+/// Navigates the unlock screen using unlock screen actions.
+pub fn navigation(
+    mut commands: Commands,
+    mut unlock_screen_query: Query<&ActionState<UnlockScreenAction>, With<UnlockScreen>>,
+    mut selected_widget_query: Query<(&mut Widget, &WidgetUp, &WidgetDown), With<WidgetSelected>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let unlock_screen_action_state = match unlock_screen_query.get_single_mut() {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+    let (mut selected_widget, up_widget, down_widget) = match selected_widget_query.get_single_mut()
+    {
+        Ok(query_result) => query_result,
+        Err(_) => return,
+    };
+
+    if unlock_screen_action_state.just_pressed(&UnlockScreenAction::Back) {
+        next_app_state.set(AppState::MainMenu);
+        return;
+    }
+
+    if unlock_screen_action_state.just_pressed(&UnlockScreenAction::Select) {
+        selected_widget.clicked = true;
+        return;
+    }
+
+    let go_up = unlock_screen_action_state.just_pressed(&UnlockScreenAction::Up);
+    let go_down = unlock_screen_action_state.just_pressed(&UnlockScreenAction::Down);
+
+    if (go_up || go_down) && !(go_up && go_down) {
+        if go_down {
+            commands.entity(down_widget.0).insert(WidgetSelected::now());
+        } else {
+            commands.entity(up_widget.0).insert(WidgetSelected::now());
+        }
+    }
+}
+
+
+/// Unlocks the content by spending ambrosia.
+pub fn unlock_button_interaction(
+    mut unlock_button_query: Query<
+        (&mut Widget, &UnlockScreenUnlockButton, Has<WidgetDisabled>),
+        Changed<Widget>,
+    >,
+    mut progression: ResMut<Persistent<Progression>>,
+    mut unlocked_event_writer: EventWriter<UnlockedEvent>,
+) {
+    for (mut button, metadata, is_disabled) in unlock_button_query.iter_mut() {
+        button.on_click(|| {
+            if is_disabled {
+                return;
+            }
+
+            let mut status = UnlockStatus::AlreadyUnlocked;
+            progression
+                .update(|progression| {
+                    status = progression.unlock(metadata.kind, &metadata.id, metadata.cost);
+                })
+                .ok();
+
+            match status {
+                UnlockStatus::AlreadyUnlocked => {
+                    log::warn!("{:?} {} is already unlocked", metadata.id, metadata.kind);
+                },
+                UnlockStatus::NotEnoughAmbrosia => {
+                    log::error!(
+                        "unable to unlock {:?} {}, which required {}, but only {} was available",
+                        metadata.id,
+                        metadata.kind,
+                        metadata.cost,
+                        progression.ambrosia,
+                    );
+                },
+                UnlockStatus::Unlocked => {
+                    log::info!(
+                        "unlocked {:?} {} for {}",
+                        metadata.id,
+                        metadata.kind,
+                        metadata.cost
+                    );
+                    unlocked_event_writer
+                        .send(UnlockedEvent { kind: metadata.kind, id: metadata.id.clone() });
+                },
+            }
+        });
+    }
+}
+
+/// Returns to the main menu.
+pub fn back_button_interaction(
+    mut back_button_query: Query<&mut Widget, (Changed<Widget>, With<UnlockScreenBackButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok(mut button) = back_button_query.get_single_mut() {
+        button.on_click(|| {
+            next_app_state.set(AppState::MainMenu);
+        });
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    accessibility::constants::*,
     prelude::*,
     ui::widget::constants::*,
 };
@@ -184,6 +185,22 @@ impl WidgetColors {
     }
 }
 
+impl WidgetColors {
+    /// Gets the high contrast variant of the widget colors.
+    ///
+    /// Transparency of the colors is preserved so invisible containers stay invisible.
+    pub fn high_contrast(&self) -> WidgetColors {
+        let with_alpha_of = |color: Color, original: Color| color.with_alpha(original.alpha());
+        WidgetColors {
+            text: HIGH_CONTRAST_TEXT_COLOR,
+            disabled_text: HIGH_CONTRAST_DISABLED_TEXT_COLOR,
+            normal: with_alpha_of(HIGH_CONTRAST_NORMAL_COLOR, self.normal),
+            selected: with_alpha_of(HIGH_CONTRAST_SELECTED_COLOR, self.selected),
+            pressed: with_alpha_of(HIGH_CONTRAST_PRESSED_COLOR, self.pressed),
+        }
+    }
+}
+
 impl WidgetColors {
     /// Sets the text color.
     pub fn text(mut self, text: Color) -> Self {
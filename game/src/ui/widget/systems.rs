@@ -116,8 +116,14 @@ pub fn update_widget_state_on_user_interactions(
 /// Updates colors of the widgets with changed states.
 pub fn update_widget_colors_on_state_change(
     mut widget_query: Query<(&Widget, &WidgetColors, &mut BackgroundColor), Changed<Widget>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
 ) {
     for (widget, widget_colors, mut background_color) in &mut widget_query {
+        let widget_colors = if general_settings.high_contrast {
+            widget_colors.high_contrast()
+        } else {
+            *widget_colors
+        };
         let new_background_color = if widget.is_pressed {
             widget_colors.pressed.into()
         } else if widget.is_selected {
@@ -134,10 +140,15 @@ pub fn update_widget_colors_on_state_change(
 pub fn change_text_color_when_disabled(
     mut text_query: Query<(&Parent, &mut Text)>,
     widget_query: Query<&WidgetColors, (With<Widget>, Added<WidgetDisabled>)>,
+    general_settings: Res<Persistent<GeneralSettings>>,
 ) {
     for (parent, mut text) in text_query.iter_mut() {
         if let Ok(widget_colors) = widget_query.get(parent.get()) {
-            text.sections[0].style.color = widget_colors.disabled_text;
+            text.sections[0].style.color = if general_settings.high_contrast {
+                widget_colors.high_contrast().disabled_text
+            } else {
+                widget_colors.disabled_text
+            };
         }
     }
 }
@@ -147,6 +158,7 @@ pub fn change_text_color_when_enabled(
     mut removed_components: RemovedComponents<WidgetDisabled>,
     mut text_query: Query<(&Parent, &mut Text)>,
     widget_query: Query<&WidgetColors, With<Widget>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
 ) {
     for widget_entity in removed_components.read() {
         let widget_colors = match widget_query.get(widget_entity) {
@@ -155,7 +167,11 @@ pub fn change_text_color_when_enabled(
         };
         for (parent, mut text) in text_query.iter_mut() {
             if parent.get() == widget_entity {
-                text.sections[0].style.color = widget_colors.text;
+                text.sections[0].style.color = if general_settings.high_contrast {
+                    widget_colors.high_contrast().text
+                } else {
+                    widget_colors.text
+                };
                 break;
             }
         }
@@ -0,0 +1,9 @@
+use crate::prelude::*;
+
+
+/// Condition to run when the persisted state of the primary window is changed.
+pub fn primary_window_state_changed(
+    window_state_query: Query<(), (With<PrimaryWindow>, Changed<Persistent<WindowState>>)>,
+) -> bool {
+    !window_state_query.is_empty()
+}
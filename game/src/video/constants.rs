@@ -0,0 +1,17 @@
+use crate::prelude::*;
+
+
+/// Resolutions that can be selected for the window.
+pub const RESOLUTIONS: [(u32, u32); 7] =
+    [(1280, 720), (1366, 768), (1600, 900), (1920, 1080), (2560, 1080), (2560, 1440), (3840, 2160)];
+
+/// Frame rate limits that can be selected, where zero means unlimited.
+pub const FRAME_RATE_LIMITS: [u32; 6] = [0, 30, 60, 120, 144, 240];
+
+/// Window modes that can be selected.
+pub const WINDOW_MODES: [WindowMode; 3] =
+    [WindowMode::Windowed, WindowMode::BorderlessFullscreen, WindowMode::SizedFullscreen];
+
+/// Amount of time to spin instead of sleeping at the end of the frames when the frame rate is
+/// limited, as sleeping can take longer than requested.
+pub const FRAME_RATE_LIMITER_SPIN_DURATION: Duration = Duration::from_millis(2);
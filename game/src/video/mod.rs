@@ -0,0 +1,4 @@
+pub mod conditions;
+pub mod constants;
+pub mod plugin;
+pub mod systems;
@@ -0,0 +1,18 @@
+use crate::{
+    prelude::*,
+    video::systems::*,
+};
+
+/// Plugin for managing the video settings.
+pub struct VideoPlugin;
+
+impl Plugin for VideoPlugin {
+    fn build(&self, app: &mut App) {
+        // Add systems.
+        app.add_systems(
+            PostUpdate,
+            update_present_mode.run_if(resource_changed::<Persistent<GeneralSettings>>),
+        );
+        app.add_systems(Last, limit_frame_rate);
+    }
+}
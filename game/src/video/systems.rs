@@ -0,0 +1,57 @@
+use crate::{
+    prelude::*,
+    video::constants::*,
+};
+
+
+/// Updates the present mode of the primary window according to the vsync setting.
+pub fn update_present_mode(
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    general_settings: Res<Persistent<GeneralSettings>>,
+) {
+    let present_mode =
+        if general_settings.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync };
+    for mut window in window_query.iter_mut() {
+        if window.present_mode != present_mode {
+            log::info!("setting the present mode to {:?}", present_mode);
+            window.present_mode = present_mode;
+        }
+    }
+}
+
+/// Limits the frame rate by waiting on the main thread until the frame time of the limit is
+/// reached.
+///
+/// Sleeping is only as precise as the scheduler of the operating system, which can oversleep
+/// by a few milliseconds, so the thread sleeps until shortly before the end of the frame and
+/// spins for the rest. Frames are scheduled from the end of the previous frame time instead of
+/// the end of the previous wait, so small errors don't accumulate into a lower frame rate.
+pub fn limit_frame_rate(
+    general_settings: Res<Persistent<GeneralSettings>>,
+    mut previous_frame: Local<Option<Instant>>,
+) {
+    let now = Instant::now();
+    if general_settings.frame_rate_limit == 0 {
+        *previous_frame = Some(now);
+        return;
+    }
+
+    let frame_time = Duration::from_secs_f64(1.00 / general_settings.frame_rate_limit as f64);
+    let frame_end = match *previous_frame {
+        Some(previous_frame) if previous_frame + frame_time > now => previous_frame + frame_time,
+        _ => {
+            *previous_frame = Some(now);
+            return;
+        },
+    };
+
+    let remaining = frame_end - now;
+    if remaining > FRAME_RATE_LIMITER_SPIN_DURATION {
+        std::thread::sleep(remaining - FRAME_RATE_LIMITER_SPIN_DURATION);
+    }
+    while Instant::now() < frame_end {
+        std::hint::spin_loop();
+    }
+
+    *previous_frame = Some(frame_end);
+}
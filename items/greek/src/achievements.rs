@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// Achievement for having 500 $ in a run.
+#[derive(Debug)]
+pub struct FavouredByTyche;
+
+impl IAchievement for FavouredByTyche {
+    fn id(&self) -> SmolStr {
+        "favoured-by-tyche".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "favoured-by-tyche-name",
+            args: smallvec![],
+            fallback: "Favoured by Tyche".into(),
+        }
+    }
+
+    fn description(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "favoured-by-tyche-description",
+            args: smallvec![],
+            fallback: "Have 500 $ in a run.".into(),
+        }
+    }
+
+    fn condition(&self) -> AchievementCondition {
+        AchievementCondition::ReachBalance(Balance(500.00))
+    }
+}
@@ -92,7 +92,6 @@ impl Plugin for BidentOfHadesPlugin {
 pub fn acquire(
     In(item): In<BidentOfHades>,
     mut commands: Commands,
-    inventory: Res<Inventory>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) -> Entity {
@@ -100,7 +99,6 @@ pub fn acquire(
     commands
         .spawn((
             // Tags
-            Name::new(format!("Item {} [{}]", inventory.items.len() + 1, item.id())),
             item,
             // Properties
             base_range,
@@ -132,13 +130,21 @@ pub fn release(In(entity): In<Entity>, mut commands: Commands) {
 pub fn attack(
     mut commands: Commands,
     item_query: Query<
-        (Entity, &GlobalTransform, &Range),
+        (Entity, &GlobalTransform, &Range, Option<&ManualAim>, Option<&ManualFire>),
         (With<BidentOfHades>, Without<Attack>, Without<Cooldown<Attack>>),
     >,
     enemy_hit_box_query: Query<&Position, With<EnemyHitBox>>,
     spatial_query: SpatialQuery,
 ) {
-    for (item_entity, item_global_transform, item_range) in item_query.iter() {
+    for (item_entity, item_global_transform, item_range, item_manual_aim, item_manual_fire) in
+        item_query.iter()
+    {
+        if let Some(item_manual_fire) = item_manual_fire {
+            if !item_manual_fire.firing {
+                continue;
+            }
+        }
+
         let item_position = Position(item_global_transform.translation().xy());
         let attack_area = Collider::circle(item_range.0);
 
@@ -148,13 +154,20 @@ pub fn attack(
             &attack_area,
             &enemy_hit_box_query,
         );
-        if enemies_in_range.is_empty() {
+        if enemies_in_range.is_empty() && item_manual_fire.is_none() {
             continue;
         }
 
-        let (_, closest_enemy_position, _) = enemies_in_range[0];
-
-        let direction = (closest_enemy_position.xy() - item_position.xy()).normalize();
+        let direction = match item_manual_aim {
+            Some(item_manual_aim) => item_manual_aim.as_vec2(),
+            None => {
+                let (_, closest_enemy_position, _) = match enemies_in_range.first() {
+                    Some(closest_enemy) => *closest_enemy,
+                    None => continue,
+                };
+                (closest_enemy_position.xy() - item_position.xy()).normalize()
+            },
+        };
         let range = BASE_RANGE;
         let duration = BASE_ATTACK_DURATION;
 
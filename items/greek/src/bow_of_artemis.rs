@@ -97,7 +97,6 @@ impl Plugin for BowOfArtemisPlugin {
 pub fn acquire(
     In(item): In<BowOfArtemis>,
     mut commands: Commands,
-    inventory: Res<Inventory>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) -> Entity {
@@ -105,7 +104,6 @@ pub fn acquire(
     commands
         .spawn((
             // Tags
-            Name::new(format!("Item {} [{}]", inventory.items.len() + 1, item.id())),
             item,
             // Properties
             base_range,
@@ -133,13 +131,21 @@ pub fn attack(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     item_query: Query<
-        (Entity, &GlobalTransform, &Range),
+        (Entity, &GlobalTransform, &Range, Option<&ManualAim>, Option<&ManualFire>),
         (With<BowOfArtemis>, Without<Cooldown<Attack>>),
     >,
     enemy_hit_box_query: Query<&Position, With<EnemyHitBox>>,
     spatial_query: SpatialQuery,
 ) {
-    for (item_entity, &item_transform, &item_range) in item_query.iter() {
+    for (item_entity, &item_transform, &item_range, item_manual_aim, item_manual_fire) in
+        item_query.iter()
+    {
+        if let Some(item_manual_fire) = item_manual_fire {
+            if !item_manual_fire.firing {
+                continue;
+            }
+        }
+
         let item_position = Position(item_transform.translation().xy());
         let attack_area = Collider::circle(item_range.0);
 
@@ -149,17 +155,37 @@ pub fn attack(
             &attack_area,
             &enemy_hit_box_query,
         );
-        for (_, enemy_position, enemy_distance) in enemies_in_range {
-            let enemy_direction = (enemy_position.xy() - item_position.xy()).normalize();
-
-            let obstacle_between_item_and_enemy = utils::map::find_obstacle(
-                &spatial_query,
-                &item_position,
-                &enemy_direction,
-                enemy_distance,
-            );
-            if obstacle_between_item_and_enemy.is_some() {
-                continue;
+
+        let targets = match item_manual_aim {
+            Some(item_manual_aim) => {
+                if item_manual_fire.is_none() && enemies_in_range.is_empty() {
+                    continue;
+                }
+                vec![(item_manual_aim.as_vec2(), None)]
+            },
+            None => {
+                enemies_in_range
+                    .into_iter()
+                    .map(|(_, enemy_position, enemy_distance)| {
+                        let enemy_direction =
+                            (enemy_position.xy() - item_position.xy()).normalize();
+                        (enemy_direction, Some(enemy_distance))
+                    })
+                    .collect()
+            },
+        };
+
+        for (enemy_direction, enemy_distance) in targets {
+            if let Some(enemy_distance) = enemy_distance {
+                let obstacle_between_item_and_enemy = utils::map::find_obstacle(
+                    &spatial_query,
+                    &item_position,
+                    &enemy_direction,
+                    enemy_distance,
+                );
+                if obstacle_between_item_and_enemy.is_some() {
+                    continue;
+                }
             }
 
             ProjectileBundle::builder()
@@ -0,0 +1,91 @@
+use {
+    crate::{
+        constants::*,
+        prelude::*,
+    },
+    mythmallow::item::constants::DEFAULT_ITEM_COMMONNESS,
+};
+
+/// Base luck provided by the item.
+pub const BASE_LUCK: Luck = Luck(15.00);
+
+/// Base price of the item.
+pub const BASE_PRICE: Balance = Balance(25.00);
+
+/// Cost of unlocking the item.
+pub const UNLOCK_COST: Ambrosia = Ambrosia(30);
+
+/// Tag component for the item "Coin of Tyche".
+#[derive(Clone, Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct CoinOfTyche;
+
+impl IItem for CoinOfTyche {
+    fn id(&self) -> SmolStr {
+        "coin-of-tyche".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "coin-of-tyche-name",
+            args: smallvec![],
+            fallback: "Coin of Tyche".into(),
+        }
+    }
+
+    fn is_weapon(&self) -> bool {
+        false
+    }
+
+    fn commonness(&self) -> u64 {
+        DEFAULT_ITEM_COMMONNESS / 3
+    }
+
+    fn base_price(&self) -> Balance {
+        BASE_PRICE
+    }
+
+    fn instantiate(&self) -> ItemInstance {
+        ItemInstance::new(self.clone())
+    }
+
+    fn acquire(&self, world: &mut World) -> Entity {
+        world.run_system_once_with(self.clone(), acquire)
+    }
+
+    fn release(&self, world: &mut World, entity: Entity) {
+        world.run_system_once_with(entity, release);
+    }
+}
+
+/// Plugin for managing the item "Coin of Tyche".
+pub struct CoinOfTychePlugin;
+
+impl Plugin for CoinOfTychePlugin {
+    fn build(&self, app: &mut App) {
+        // Register the item.
+        let mut item_registry = app.world_mut().resource_mut::<ItemRegistry>();
+        item_registry.register(CoinOfTyche).add_tag(GREEK_ITEM_TAG).unlockable(UNLOCK_COST);
+
+        // Register components.
+        app.register_type::<CoinOfTyche>();
+    }
+}
+
+/// Acquires the item.
+pub fn acquire(In(item): In<CoinOfTyche>, mut commands: Commands) -> Entity {
+    commands
+        .spawn((
+            // Tags
+            item, // Properties
+            BASE_LUCK,
+        ))
+        .id()
+}
+
+/// Releases the item.
+pub fn release(In(entity): In<Entity>, mut commands: Commands) {
+    if let Some(entity) = commands.get_entity(entity) {
+        entity.despawn_recursive();
+    }
+}
@@ -1,6 +1,8 @@
+pub mod achievements;
 pub mod constants;
 pub mod plugin;
 pub mod prelude;
 
 pub mod bident_of_hades;
 pub mod bow_of_artemis;
+pub mod coin_of_tyche;
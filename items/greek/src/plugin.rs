@@ -1,6 +1,7 @@
 use crate::{
     bident_of_hades::BidentOfHadesPlugin,
     bow_of_artemis::BowOfArtemisPlugin,
+    coin_of_tyche::CoinOfTychePlugin,
     prelude::*,
 };
 
@@ -15,5 +16,10 @@ impl Plugin for GreekItemsPlugin {
         // Add sub-plugins.
         app.add_plugins(BidentOfHadesPlugin);
         app.add_plugins(BowOfArtemisPlugin);
+        app.add_plugins(CoinOfTychePlugin);
+
+        // Register achievements.
+        let mut achievement_registry = app.world_mut().resource_mut::<AchievementRegistry>();
+        achievement_registry.register(FavouredByTyche);
     }
 }
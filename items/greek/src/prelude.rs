@@ -1,6 +1,8 @@
 pub use crate::{
+    achievements::FavouredByTyche,
     bident_of_hades::BidentOfHades,
     bow_of_artemis::BowOfArtemis,
+    coin_of_tyche::CoinOfTyche,
     plugin::GreekItemsPlugin,
 };
 
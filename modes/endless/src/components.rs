@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+
+/// Tag component for the elapsed time container in the HUD.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ElapsedTimeContainer;
+
+
+/// Tag component for the elapsed time text in the HUD.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ElapsedTimeText;
+
+
+/// Tag component for the score container in the HUD.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ScoreContainer;
+
+
+/// Tag component for the score text in the HUD.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ScoreText;
@@ -0,0 +1,66 @@
+use crate::prelude::*;
+
+
+/// Grid of the map. Size of 12 means the map will be a 12x12 grid of squares.
+pub const MAP_GRID: MapGrid = MapGrid::new(12, Color::srgb(0.22, 0.22, 0.27));
+
+
+/// Amount of time between market breaks.
+pub const MARKET_BREAK_INTERVAL: Duration = Duration::from_secs(60);
+
+
+/// Number of enemies in each spawn group at the start of the run.
+pub const BASE_ENEMY_SPAWN_COUNT: u32 = 3;
+
+/// Increase in the number of enemies in each spawn group for each minute survived.
+pub const ENEMY_SPAWN_COUNT_INCREASE_PER_MINUTE: f32 = 1.00;
+
+/// Amount of time between spawn groups at the start of the run.
+pub const BASE_ENEMY_SPAWN_REPEAT: Duration = Duration::from_millis(3000);
+
+/// Minimum amount of time between spawn groups.
+pub const MIN_ENEMY_SPAWN_REPEAT: Duration = Duration::from_millis(500);
+
+/// Increase in the spawn pressure for each second survived.
+pub const SPAWN_PRESSURE_INCREASE_PER_SECOND: f32 = 0.01;
+
+
+/// Score for each second survived.
+pub const SCORE_PER_SECOND: u64 = 10;
+
+/// Score for each enemy killed.
+pub const SCORE_PER_KILL: u64 = 25;
+
+/// Maximum number of high scores to keep.
+pub const MAX_HIGH_SCORES: usize = 10;
+
+/// Storage format for high score files.
+pub const HIGH_SCORES_STORAGE_FORMAT: StorageFormat = {
+    #[cfg(feature = "native")]
+    {
+        StorageFormat::Toml
+    }
+    #[cfg(feature = "wasm")]
+    {
+        StorageFormat::Json
+    }
+};
+
+
+/// Limits of the level up screen.
+pub const LEVEL_UP_SCREEN_LIMITS: LevelUpScreenLimits =
+    LevelUpScreenLimits { max_locked_perks: 1, banishes: 5, skips: 5 };
+
+
+/// Font size of the elapsed time text in the HUD in pt.
+pub const ELAPSED_TIME_TEXT_FONT_SIZE: f32 = 40.0;
+
+/// Color of the elapsed time text in the HUD.
+pub const ELAPSED_TIME_TEXT_COLOR: Color = Color::WHITE;
+
+
+/// Font size of the score text in the HUD in pt.
+pub const SCORE_TEXT_FONT_SIZE: f32 = 30.0;
+
+/// Color of the score text in the HUD.
+pub const SCORE_TEXT_COLOR: Color = Color::WHITE;
@@ -0,0 +1,9 @@
+pub mod components;
+pub mod constants;
+pub mod localization;
+pub mod mode;
+pub mod plugin;
+pub mod prelude;
+pub mod resources;
+pub mod styles;
+pub mod systems;
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+
+/// Gets the localized text of the score.
+pub fn score(score: &RunScore, number_format: &NumberFormat) -> LocalizedText {
+    let score = number_format.integer(score.value());
+    LocalizedText::Localized {
+        key: "endless-mode-hud-score",
+        args: smallvec![("score", score.to_smolstr())],
+        fallback: format!("Score {}", score).into(),
+    }
+}
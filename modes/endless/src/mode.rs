@@ -0,0 +1,96 @@
+use {
+    crate::{
+        constants::*,
+        prelude::*,
+    },
+    mythmallow::enemy::constants::{
+        MELEE_ENEMY_TAG,
+        RANGED_ENEMY_TAG,
+    },
+};
+
+/// Resource for "Endless" game mode.
+#[derive(Debug, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct Endless;
+
+impl IGameMode for Endless {
+    fn id(&self) -> SmolStr {
+        "endless".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "endless-mode-name",
+            args: smallvec![],
+            fallback: "Endless Mode".into(),
+        }
+    }
+
+    fn market_can_be_opened_by_player(&self) -> bool {
+        false
+    }
+
+    fn default_enemy_spawn_pattern(&self, world: &World) -> EnemySpawnPattern {
+        let enemy_registry = world.resource::<EnemyRegistry>();
+
+        let selected_enemy_pack_index = world.resource::<SelectedEnemyPackIndex>();
+        let enemies_in_selected_pack = &enemy_registry[*selected_enemy_pack_index].enemies;
+
+        let first_melee_enemy = enemies_in_selected_pack
+            .iter()
+            .find(|enemy| enemy.has_tag(MELEE_ENEMY_TAG))
+            .map(|enemy| enemy.deref());
+        let first_ranged_enemy = enemies_in_selected_pack
+            .iter()
+            .find(|enemy| enemy.has_tag(RANGED_ENEMY_TAG))
+            .map(|enemy| enemy.deref());
+
+        let run_score = world.resource::<RunScore>();
+        let completed_breaks = world.resource::<CompletedBreaks>();
+
+        let minutes_survived = run_score.survived.as_secs_f32() / 60.00;
+        let count = BASE_ENEMY_SPAWN_COUNT
+            + (minutes_survived * ENEMY_SPAWN_COUNT_INCREASE_PER_MINUTE).floor() as u32;
+
+        let mut spawns = Vec::new();
+        if let Some(enemy) = first_melee_enemy {
+            spawns.push(
+                EnemySpawn::new_dyn(Duration::from_millis(500), enemy)
+                    .count(count)
+                    .interval(Duration::from_millis(150))
+                    .spread(EnemySpawnSpread::square(100.00))
+                    .repeat(BASE_ENEMY_SPAWN_REPEAT),
+            );
+        }
+        if completed_breaks.0 > 0 {
+            if let Some(enemy) = first_ranged_enemy {
+                spawns.push(
+                    EnemySpawn::new_dyn(Duration::from_millis(1000), enemy)
+                        .count(count)
+                        .interval(Duration::from_millis(150))
+                        .spread(EnemySpawnSpread::square(100.00))
+                        .repeat(BASE_ENEMY_SPAWN_REPEAT),
+                );
+            }
+        }
+        EnemySpawnPattern::new(spawns)
+    }
+
+    fn player_level_structure(&self) -> PlayerLevelStructure {
+        PlayerLevelStructure {
+            max_level: None,
+            required_experience_calculator: |_world, level| {
+                ExperienceRequiredToLevelUp(Experience(20.00 * level.get() as f64))
+            },
+        }
+    }
+
+    fn initialize(&self, world: &mut World) {
+        world.init_resource::<GameMode<Endless>>();
+    }
+
+    fn deinitialize(&self, world: &mut World) {
+        world.remove_resource::<GameMode<Endless>>();
+    }
+}
@@ -0,0 +1,93 @@
+use {
+    crate::{
+        prelude::*,
+        systems::*,
+    },
+    mythmallow::leveling::systems::handle_leftover_experience_points,
+};
+
+/// Plugin for managing "Endless" game mode.
+pub struct EndlessModePlugin;
+
+impl Plugin for EndlessModePlugin {
+    fn build(&self, app: &mut App) {
+        // Register the game mode.
+        let mut game_mode_registry = app.world_mut().resource_mut::<GameModeRegistry>();
+        game_mode_registry.register(Endless);
+
+        // Register components.
+        app.register_type::<ElapsedTimeContainer>();
+        app.register_type::<ElapsedTimeText>();
+        app.register_type::<ScoreContainer>();
+        app.register_type::<ScoreText>();
+
+        // Register resources.
+        app.register_type::<CompletedBreaks>();
+        app.register_type::<Endless>();
+        app.register_type::<GameMode<Endless>>();
+        app.register_type::<HighScores>();
+        app.register_type::<MarketBreakTimer>();
+        app.register_type::<RunScore>();
+
+        // Insert resources.
+        HighScores::initialize(app);
+
+        // Setup localization.
+        app.world_mut().resource_mut::<LocaleAssets>().push("content/modes/endless.ftl");
+
+        // Add initialization systems.
+        app.add_systems(
+            OnEnter(GameState::Initialization),
+            initialize.in_set(InitializationSystems::GameMode).run_if(in_game_mode::<Endless>),
+        );
+
+        // Add loading systems.
+        app.add_systems(
+            OnEnter(GameState::Loading),
+            load.in_set(LoadingSystems::GameMode).run_if(in_game_mode::<Endless>),
+        );
+        app.add_systems(
+            OnEnter(GameState::Loading),
+            spawn_map.in_set(LoadingSystems::Map).run_if(in_game_mode::<Endless>),
+        );
+
+        // Add gameplay systems.
+        app.add_systems(
+            PreUpdate,
+            (tick, ramp_spawn_pressure)
+                .chain()
+                .in_set(GameplaySystems::GameMode)
+                .run_if(in_game_mode::<Endless>),
+        );
+        app.add_systems(
+            PostUpdate,
+            (count_kills, update_score_text).chain().run_if(in_game_mode::<Endless>),
+        );
+
+        // Add market break systems.
+        app.add_systems(
+            OnEnter(GameState::Won),
+            (unload, take_market_break)
+                .after(handle_leftover_experience_points)
+                .run_if(in_game_mode::<Endless>),
+        );
+
+        // Add game over systems.
+        app.add_systems(
+            OnEnter(GameState::Over),
+            (record_high_score, (unload, deinitialize)).chain().run_if(in_game_mode::<Endless>),
+        );
+
+        // Add restart systems.
+        app.add_systems(
+            OnEnter(GameState::Restart),
+            (unload, deinitialize).in_set(RestartSystems::GameMode).run_if(in_game_mode::<Endless>),
+        );
+
+        // Add exit systems.
+        app.add_systems(
+            OnExit(AppState::Game),
+            (unload, deinitialize).run_if(in_game_mode::<Endless>),
+        );
+    }
+}
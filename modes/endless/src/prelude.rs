@@ -0,0 +1,8 @@
+pub use crate::{
+    components::*,
+    mode::Endless,
+    plugin::EndlessModePlugin,
+    resources::*,
+};
+
+pub(crate) use mythmallow::prelude::*;
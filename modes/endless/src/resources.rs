@@ -0,0 +1,124 @@
+use crate::{
+    constants::*,
+    prelude::*,
+};
+
+
+/// Resource for the score of the current run.
+#[derive(Clone, Copy, Debug, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct RunScore {
+    /// Amount of time survived.
+    pub survived: Duration,
+    /// Number of enemies killed.
+    pub kills: u32,
+}
+
+impl RunScore {
+    /// Gets the value of the score.
+    pub fn value(&self) -> u64 {
+        (self.survived.as_secs() * SCORE_PER_SECOND) + (self.kills as u64 * SCORE_PER_KILL)
+    }
+
+    /// Gets the spawn pressure, which increases continuously with the time survived.
+    pub fn spawn_pressure(&self) -> f32 {
+        1.00 + (self.survived.as_secs_f32() * SPAWN_PRESSURE_INCREASE_PER_SECOND)
+    }
+}
+
+
+/// Resource for the remaining time until the next market break.
+#[derive(Debug, Deref, DerefMut, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct MarketBreakTimer(pub Timer);
+
+impl MarketBreakTimer {
+    /// Creates a new market break timer.
+    pub fn new(duration: Duration) -> MarketBreakTimer {
+        MarketBreakTimer(Timer::new(duration, TimerMode::Once))
+    }
+}
+
+impl Default for MarketBreakTimer {
+    fn default() -> MarketBreakTimer {
+        MarketBreakTimer::new(MARKET_BREAK_INTERVAL)
+    }
+}
+
+
+/// Resource for the number of market breaks taken since the start of the game.
+#[derive(Clone, Copy, Debug, Default, Deref, DerefMut, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct CompletedBreaks(pub u32);
+
+
+/// High score of a run.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct HighScore {
+    /// Value of the score.
+    pub score: u64,
+    /// Amount of seconds survived.
+    pub survived_seconds: u64,
+    /// Number of enemies killed.
+    pub kills: u32,
+    /// Id of the player.
+    pub player: String,
+}
+
+
+/// Resource for the local high scores of the game mode, which persists across runs.
+#[derive(Debug, Default, Deserialize, Reflect, Resource, Serialize)]
+#[serde(default)]
+pub struct HighScores {
+    pub entries: Vec<HighScore>,
+}
+
+impl HighScores {
+    /// Initializes the resource in the app.
+    pub fn initialize(app: &mut App) {
+        let args = app.world().resource::<Args>();
+        app.insert_resource(
+            Persistent::<HighScores>::builder()
+                .name("endless mode high scores")
+                .format(HIGH_SCORES_STORAGE_FORMAT)
+                .path({
+                    #[cfg(feature = "native")]
+                    {
+                        args.data_directory.join("endless").join("high-scores.toml")
+                    }
+                    #[cfg(feature = "wasm")]
+                    {
+                        args.data_directory.join("endless-high-scores")
+                    }
+                })
+                .default(HighScores::default())
+                .revertible(true)
+                .build()
+                .unwrap_or_else(|_| panic!("fatal: unable to initialize the high scores")),
+        );
+    }
+}
+
+impl HighScores {
+    /// Gets the best score.
+    pub fn best(&self) -> Option<&HighScore> {
+        self.entries.first()
+    }
+}
+
+impl HighScores {
+    /// Records a high score, returning its rank if it made it to the list.
+    pub fn record(&mut self, high_score: HighScore) -> Option<usize> {
+        let rank = self.entries.iter().position(|entry| entry.score < high_score.score);
+        let rank = match rank {
+            Some(rank) => rank,
+            None if self.entries.len() < MAX_HIGH_SCORES => self.entries.len(),
+            None => return None,
+        };
+
+        self.entries.insert(rank, high_score);
+        self.entries.truncate(MAX_HIGH_SCORES);
+
+        Some(rank + 1)
+    }
+}
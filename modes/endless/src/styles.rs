@@ -0,0 +1,32 @@
+use crate::prelude::*;
+
+
+/// Gets the style of the elapsed time container in the HUD.
+pub fn elapsed_time_container() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        align_self: AlignSelf::End,
+        justify_self: JustifySelf::Start,
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        width: Val::Percent(10.00),
+        height: Val::Percent(5.00),
+        top: Val::Percent(4.00),
+        ..default()
+    }
+}
+
+/// Gets the style of the score container in the HUD.
+pub fn score_container() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        align_self: AlignSelf::End,
+        justify_self: JustifySelf::Start,
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        width: Val::Percent(10.00),
+        height: Val::Percent(5.00),
+        top: Val::Percent(8.00),
+        ..default()
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    constants::*,
+    localization,
+    prelude::*,
+    styles,
+};
+
+
+/// Initializes the game mode.
+pub fn initialize(
+    mut commands: Commands,
+    hud_query: Query<Entity, With<Hud>>,
+    asset_server: Res<AssetServer>,
+    number_format: Res<NumberFormat>,
+    localization: Res<Localization>,
+    mut level_up_screen_configuration: ResMut<LevelUpScreenConfiguration>,
+) {
+    let run_score = RunScore::default();
+    let completed_breaks = CompletedBreaks::default();
+    let level_up_rewards = LevelUpRewards::default();
+
+    if let Ok(hud) = hud_query.get_single() {
+        commands.entity(hud).with_children(|parent| {
+            parent
+                .spawn((
+                    Name::new("Elapsed Time"),
+                    ElapsedTimeContainer,
+                    NodeBundle { style: styles::elapsed_time_container(), ..default() },
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Name::new("Text"),
+                        ElapsedTimeText,
+                        TextBundle {
+                            text: Text {
+                                sections: vec![TextSection::new(
+                                    number_format.duration(run_score.survived),
+                                    TextStyle {
+                                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                        font_size: ELAPSED_TIME_TEXT_FONT_SIZE,
+                                        color: ELAPSED_TIME_TEXT_COLOR,
+                                    },
+                                )],
+                                justify: JustifyText::Center,
+                                ..default()
+                            },
+                            ..default()
+                        },
+                    ));
+                });
+
+            parent
+                .spawn((
+                    Name::new("Score"),
+                    ScoreContainer,
+                    NodeBundle { style: styles::score_container(), ..default() },
+                ))
+                .with_children(|parent| {
+                    let text = localization::score(&run_score, &number_format);
+                    parent.spawn((
+                        Name::new("Text"),
+                        ScoreText,
+                        TextBundle {
+                            text: Text {
+                                sections: vec![TextSection::new(
+                                    text.get(&localization),
+                                    TextStyle {
+                                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                        font_size: SCORE_TEXT_FONT_SIZE,
+                                        color: SCORE_TEXT_COLOR,
+                                    },
+                                )],
+                                justify: JustifyText::Center,
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        text,
+                    ));
+                });
+        });
+    }
+
+    level_up_screen_configuration.apply_limits(LEVEL_UP_SCREEN_LIMITS);
+    level_up_screen_configuration.skip_reward = Balance(1.00);
+
+    commands.insert_resource(run_score);
+    commands.insert_resource(completed_breaks);
+    commands.insert_resource(level_up_rewards);
+}
+
+
+/// Loads the next stretch until the market break.
+pub fn load(mut commands: Commands, run_score: Res<RunScore>, number_format: Res<NumberFormat>) {
+    log::info!(
+        "starting the stretch after surviving for {} with the spawn pressure of {:.2}",
+        number_format.duration(run_score.survived),
+        run_score.spawn_pressure(),
+    );
+    commands.insert_resource(MarketBreakTimer::new(MARKET_BREAK_INTERVAL));
+}
+
+/// Spawns the map.
+pub fn spawn_map(mut commands: Commands) {
+    utils::map::spawn_grid_map(&mut commands, MAP_GRID);
+}
+
+
+/// Ticks the elapsed time and the market break timer, and takes a break when it's time.
+pub fn tick(
+    mut elapsed_time_text_query: Query<&mut Text, With<ElapsedTimeText>>,
+    time: Res<Time>,
+    mut run_score: ResMut<RunScore>,
+    mut market_break_timer: ResMut<MarketBreakTimer>,
+    mut game_state_stack: ResMut<GameStateStack>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    number_format: Res<NumberFormat>,
+) {
+    run_score.survived += time.delta();
+    if let Ok(mut elapsed_time_text) = elapsed_time_text_query.get_single_mut() {
+        elapsed_time_text.sections[0].value = number_format.duration(run_score.survived);
+    }
+
+    market_break_timer.tick(time.delta());
+    if market_break_timer.just_finished() {
+        game_state_stack.transition(GameState::Won);
+        next_game_state.set(GameState::Transition);
+    }
+}
+
+/// Ramps the spawn pressure by shortening the time between spawn groups as time passes.
+pub fn ramp_spawn_pressure(
+    run_score: Res<RunScore>,
+    enemy_spawn_pattern: Option<Res<EnemySpawnPattern>>,
+) {
+    let enemy_spawn_pattern = match enemy_spawn_pattern {
+        Some(enemy_spawn_pattern) => enemy_spawn_pattern,
+        None => return,
+    };
+
+    let repeat =
+        BASE_ENEMY_SPAWN_REPEAT.div_f32(run_score.spawn_pressure()).max(MIN_ENEMY_SPAWN_REPEAT);
+
+    for spawn in enemy_spawn_pattern.spawns.lock().unwrap().iter_mut() {
+        if let Some(timer) = spawn.repeat.as_mut() {
+            if timer.duration() != repeat {
+                timer.set_duration(repeat);
+            }
+        }
+    }
+}
+
+/// Counts the killed enemies for the score.
+pub fn count_kills(
+    mut run_score: ResMut<RunScore>,
+    mut enemy_died_event_reader: EventReader<EnemyDiedEvent>,
+) {
+    let kills = enemy_died_event_reader.read().count() as u32;
+    if kills > 0 {
+        run_score.kills += kills;
+    }
+}
+
+/// Updates the score text in the HUD when the score changes.
+pub fn update_score_text(
+    mut score_text_query: Query<&mut LocalizedText, With<ScoreText>>,
+    run_score: Res<RunScore>,
+    number_format: Res<NumberFormat>,
+    mut displayed_score: Local<u64>,
+) {
+    let score = run_score.value();
+    if score == *displayed_score {
+        return;
+    }
+    if let Ok(mut score_text) = score_text_query.get_single_mut() {
+        *score_text = localization::score(&run_score, &number_format);
+        *displayed_score = score;
+    }
+}
+
+
+/// Takes a market break.
+pub fn take_market_break(
+    mut commands: Commands,
+    run_score: Res<RunScore>,
+    mut completed_breaks: ResMut<CompletedBreaks>,
+    number_format: Res<NumberFormat>,
+    registered_systems: Res<RegisteredSystems>,
+) {
+    completed_breaks.0 += 1;
+    log::info!(
+        "taking market break {} after surviving for {}",
+        completed_breaks.0,
+        number_format.duration(run_score.survived),
+    );
+
+    commands.run_system_with_input(
+        registered_systems.market.take_market_break,
+        MarketBreak {
+            base_cost: Balance(completed_breaks.0 as f64),
+            skip_reward: Balance((completed_breaks.0 + 1) as f64),
+        },
+    );
+}
+
+
+/// Records the score of the run to the high scores.
+pub fn record_high_score(
+    run_score: Res<RunScore>,
+    player_registry: Res<PlayerRegistry>,
+    selected_mythology_index: Option<Res<SelectedMythologyIndex>>,
+    selected_player_index: Option<Res<SelectedPlayerIndex>>,
+    number_format: Res<NumberFormat>,
+    mut high_scores: ResMut<Persistent<HighScores>>,
+) {
+    let selected_player = match (selected_mythology_index, selected_player_index) {
+        (Some(selected_mythology_index), Some(selected_player_index)) => {
+            player_registry.find_selected_player(*selected_mythology_index, *selected_player_index)
+        },
+        _ => None,
+    };
+    let player = match selected_player {
+        Some(selected_player) => selected_player,
+        None => {
+            log::error!("couldn't record the high score as the player isn't selected");
+            return;
+        },
+    };
+    let high_score = HighScore {
+        score: run_score.value(),
+        survived_seconds: run_score.survived.as_secs(),
+        kills: run_score.kills,
+        player: player.id().to_string(),
+    };
+
+    log::info!(
+        "run ended with the score of {} after surviving for {} and killing {} enemies",
+        high_score.score,
+        number_format.duration(run_score.survived),
+        high_score.kills,
+    );
+
+    let mut rank = None;
+    high_scores.update(|high_scores| rank = high_scores.record(high_score)).ok();
+
+    if let Some(rank) = rank {
+        log::info!("run is ranked #{} in the high scores", rank);
+    }
+}
+
+
+/// Unloads the current stretch.
+pub fn unload(mut commands: Commands) {
+    commands.remove_resource::<MarketBreakTimer>();
+}
+
+
+/// Deinitializes the game mode.
+pub fn deinitialize(mut commands: Commands) {
+    commands.remove_resource::<RunScore>();
+    commands.remove_resource::<CompletedBreaks>();
+}
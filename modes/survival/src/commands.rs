@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+
+/// Controls the waves.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "wave")]
+#[command(disable_help_flag = true)]
+pub struct WaveCommand {
+    #[clap(subcommand)]
+    pub subcommand: WaveCommands,
+}
+
+/// Wave commands.
+#[derive(Debug, Subcommand)]
+pub enum WaveCommands {
+    /// Shows the current wave.
+    Show,
+    /// Skips the current wave.
+    Skip,
+    /// Sets the current wave, skipping the current wave if it's in progress.
+    Set { wave: NonZeroU8 },
+}
+
+
+/// Controls the wave timer.
+#[derive(ConsoleCommand, Parser)]
+#[command(name = "timer")]
+#[command(disable_help_flag = true)]
+pub struct TimerCommand {
+    #[clap(subcommand)]
+    pub subcommand: TimerCommands,
+}
+
+/// Timer commands.
+#[derive(Debug, Subcommand)]
+pub enum TimerCommands {
+    /// Shows the remaining seconds of the current wave.
+    Show,
+    /// Sets the remaining seconds of the current wave.
+    Set { seconds: f32 },
+}
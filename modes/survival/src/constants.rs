@@ -1,33 +1,43 @@
 use crate::prelude::*;
 
 
-/// Size of the grid. Value of 10 means the map will be a 10x10 grid of squares.
-pub const GRID_SIZE: i32 = 10;
+/// Grid of the map. Size of 10 means the map will be a 10x10 grid of squares.
+pub const MAP_GRID: MapGrid = MapGrid::new(10, Color::srgb(0.27, 0.27, 0.27));
 
-/// Amount of space between grid elements.
-pub const GRID_SPACING: f32 = 50.0;
 
-/// Thickness of grid elements.
-pub const GRID_WIDTH: f32 = 2.0;
+/// Number of waves.
+pub const WAVES: u8 = 3;
 
-/// Color of the grid.
-pub const GRID_COLOR: Color = Color::srgb(0.27, 0.27, 0.27);
+/// Default handling of the experience points left on the map at the end of the waves.
+pub const DEFAULT_LEFTOVER_EXPERIENCE_POINTS: LeftoverExperiencePoints =
+    LeftoverExperiencePoints::Discard;
 
 
-/// Size of the map.
-pub const MAP_SIZE: f32 = (GRID_SIZE as f32) * GRID_SPACING;
+/// Number of difficulties.
+pub const DIFFICULTIES: u8 = 5;
 
-/// Bounds of the map.
-pub const MAP_BOUNDS: MapBounds = MapBounds {
-    x_min: -(MAP_SIZE / 2.00),
-    x_max: (MAP_SIZE / 2.00),
-    y_min: -(MAP_SIZE / 2.00),
-    y_max: (MAP_SIZE / 2.00),
-};
+/// Increase in the health of enemies for each difficulty above the first one.
+pub const ENEMY_HEALTH_INCREASE_PER_DIFFICULTY: f32 = 0.25;
 
+/// Increase in the damage of enemies for each difficulty above the first one.
+pub const ENEMY_DAMAGE_INCREASE_PER_DIFFICULTY: f32 = 0.20;
 
-/// Number of waves.
-pub const WAVES: u8 = 3;
+/// Increase in the speed of enemies for each difficulty above the first one.
+pub const ENEMY_SPEED_INCREASE_PER_DIFFICULTY: f32 = 0.05;
+
+/// Increase in the spawn counts of enemies for each difficulty above the first one.
+pub const ENEMY_SPAWN_COUNT_INCREASE_PER_DIFFICULTY: f32 = 0.25;
+
+/// Increase in the experience rewards for each difficulty above the first one.
+pub const EXPERIENCE_REWARD_INCREASE_PER_DIFFICULTY: f64 = 0.10;
+
+/// Increase in the balance rewards for each difficulty above the first one.
+pub const BALANCE_REWARD_INCREASE_PER_DIFFICULTY: f64 = 0.15;
+
+
+/// Limits of the level up screen.
+pub const LEVEL_UP_SCREEN_LIMITS: LevelUpScreenLimits =
+    LevelUpScreenLimits { max_locked_perks: 1, banishes: 3, skips: 3 };
 
 
 /// Font size of the current wave text in the HUD in pt.
@@ -1,3 +1,4 @@
+pub mod commands;
 pub mod components;
 pub mod constants;
 pub mod localization;
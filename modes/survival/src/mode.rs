@@ -1,5 +1,13 @@
 use {
-    crate::prelude::*,
+    crate::{
+        commands::*,
+        constants::DIFFICULTIES,
+        prelude::*,
+        systems::{
+            apply_timer_command,
+            apply_wave_command,
+        },
+    },
     mythmallow::enemy::constants::{
         MELEE_ENEMY_TAG,
         RANGED_ENEMY_TAG,
@@ -42,6 +50,10 @@ impl IGameMode for Survival {
             .iter()
             .find(|enemy| enemy.has_tag(RANGED_ENEMY_TAG))
             .map(|enemy| enemy.deref());
+        let first_boss = enemies_in_selected_pack
+            .iter()
+            .find(|enemy| enemy.is_boss())
+            .map(|enemy| enemy.deref());
 
         let current_wave = world.resource::<CurrentWave>();
 
@@ -90,6 +102,11 @@ impl IGameMode for Survival {
                 }
             },
         }
+        if current_wave.is_last() {
+            if let Some(enemy) = first_boss {
+                spawns.push(EnemySpawn::new_dyn(Duration::from_secs(5), enemy));
+            }
+        }
         EnemySpawnPattern::new(spawns)
     }
 
@@ -102,6 +119,15 @@ impl IGameMode for Survival {
         }
     }
 
+    fn number_of_difficulties(&self) -> u8 {
+        DIFFICULTIES
+    }
+
+    fn add_console_commands(&self, app: &mut App) {
+        app.add_console_command::<WaveCommand, _>(apply_wave_command);
+        app.add_console_command::<TimerCommand, _>(apply_timer_command);
+    }
+
     fn initialize(&self, world: &mut World) {
         world.init_resource::<GameMode<Survival>>();
     }
@@ -1,6 +1,12 @@
-use crate::{
-    prelude::*,
-    systems::*,
+use {
+    crate::{
+        prelude::*,
+        systems::*,
+    },
+    mythmallow::{
+        enemy::systems::initialize_enemy_spawn_pattern,
+        leveling::systems::handle_leftover_experience_points,
+    },
 };
 
 /// Plugin for managing "Survival" game mode.
@@ -20,7 +26,9 @@ impl Plugin for SurvivalModePlugin {
 
         // Register resources.
         app.register_type::<CurrentWave>();
+        app.register_type::<DifficultyModifiers>();
         app.register_type::<GameMode<Survival>>();
+        app.register_type::<NextWave>();
         app.register_type::<Survival>();
         app.register_type::<SurvivalModeArgs>();
         app.register_type::<WaveDurations>();
@@ -56,6 +64,7 @@ impl Plugin for SurvivalModePlugin {
         app.add_systems(
             OnEnter(GameState::Initialization),
             (
+                select_difficulty_when_starting_in_game.run_if(run_once()),
                 initialize,
                 (apply_deferred, select_wave_when_starting_in_game).chain().run_if(run_once()),
             )
@@ -73,16 +82,29 @@ impl Plugin for SurvivalModePlugin {
             OnEnter(GameState::Loading),
             spawn_map.in_set(LoadingSystems::Map).run_if(in_game_mode::<Survival>),
         );
+        app.add_systems(
+            OnEnter(GameState::Loading),
+            scale_enemy_spawn_pattern
+                .in_set(LoadingSystems::Enemy)
+                .after(initialize_enemy_spawn_pattern)
+                .run_if(in_game_mode::<Survival>),
+        );
 
         // Add gameplay systems.
         app.add_systems(
             PreUpdate,
             tick.in_set(GameplaySystems::GameMode).run_if(in_game_mode::<Survival>),
         );
-        app.add_systems(PostUpdate, (obtain_perk, level_change).run_if(in_game_mode::<Survival>));
+        app.add_systems(
+            PostUpdate,
+            (scale_enemies, scale_enemy_projectiles).run_if(in_game_mode::<Survival>),
+        );
 
         // Add game won systems.
-        app.add_systems(OnEnter(GameState::Won), (unload, win).run_if(in_game_mode::<Survival>));
+        app.add_systems(
+            OnEnter(GameState::Won),
+            (unload, win).after(handle_leftover_experience_points).run_if(in_game_mode::<Survival>),
+        );
 
         // Add game over systems.
         app.add_systems(
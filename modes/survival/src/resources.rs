@@ -10,6 +10,10 @@ use crate::{
 pub struct SurvivalModeArgs {
     /// Wave to start when starting in game.
     pub start_in_game_waves: Option<NonZeroU8>,
+    /// Difficulty to play when starting in game.
+    pub start_in_game_difficulty: Option<NonZeroU8>,
+    /// Handling of the experience points left on the map at the end of the waves.
+    pub leftover_experience_points: Option<LeftoverExperiencePoints>,
 }
 
 impl SurvivalModeArgs {
@@ -20,7 +24,7 @@ impl SurvivalModeArgs {
     /// Arguments are parsed from the "mode" command line argument.
     ///
     /// ```shell
-    /// mythmallow --game --mode "survival --wave 3"
+    /// mythmallow --game --mode "survival --wave 3 --difficulty 2"
     /// ```
     ///
     /// # WebAssembly
@@ -28,7 +32,7 @@ impl SurvivalModeArgs {
     /// Arguments are parsed from the "mode" query parameter.
     ///
     /// ```txt
-    /// https://mythmallow.io/?game&mode=|survival?wave=2|
+    /// https://mythmallow.io/?game&mode=|survival?wave=2&difficulty=3|
     /// ```
     pub fn parse<'i>(args: impl Iterator<Item = &'i str>) -> Result<SurvivalModeArgs, clap::Error> {
         #[derive(Parser)]
@@ -38,11 +42,15 @@ impl SurvivalModeArgs {
         struct ArgsParser {
             #[arg(long)]
             pub wave: Option<NonZeroU8>,
+            #[arg(long)]
+            pub difficulty: Option<NonZeroU8>,
+            #[arg(long)]
+            pub leftover_experience: Option<LeftoverExperiencePoints>,
         }
 
         impl Default for ArgsParser {
             fn default() -> ArgsParser {
-                ArgsParser { wave: None }
+                ArgsParser { wave: None, difficulty: None, leftover_experience: None }
             }
         }
 
@@ -51,6 +59,12 @@ impl SurvivalModeArgs {
                 if let Some(wave) = &self.wave {
                     write!(f, " --wave {}", wave)?;
                 }
+                if let Some(difficulty) = &self.difficulty {
+                    write!(f, " --difficulty {}", difficulty)?;
+                }
+                if let Some(leftover_experience) = &self.leftover_experience {
+                    write!(f, " --leftover-experience {}", leftover_experience)?;
+                }
                 Ok(())
             }
         }
@@ -63,8 +77,14 @@ impl SurvivalModeArgs {
                 }
 
                 let start_in_game_waves = self.wave;
+                let start_in_game_difficulty = self.difficulty;
+                let leftover_experience_points = self.leftover_experience;
 
-                SurvivalModeArgs { start_in_game_waves }
+                SurvivalModeArgs {
+                    start_in_game_waves,
+                    start_in_game_difficulty,
+                    leftover_experience_points,
+                }
             }
         }
 
@@ -115,6 +135,14 @@ impl Default for CurrentWave {
 }
 
 
+/// Resource for the wave to play after the current wave.
+///
+/// Used to jump to a wave instead of the next one.
+#[derive(Clone, Copy, Debug, Deref, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct NextWave(pub NonZeroU8);
+
+
 /// Resource for the remaining time to complete the current wave.
 #[derive(Debug, Deref, DerefMut, Reflect, Resource)]
 #[reflect(Resource)]
@@ -172,15 +200,41 @@ impl Index<CurrentWave> for WaveDurations {
 }
 
 
-/// Resource for the level up rewards obtained in since the start of the game.
-#[derive(Clone, Debug, Resource)]
-pub struct LevelUpRewards {
-    pub health: Health,
-    pub perks: Vec<Arc<dyn IPerk>>,
+/// Resource for the modifiers of the selected difficulty.
+#[derive(Clone, Copy, Debug, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct DifficultyModifiers {
+    /// Multiplier for the health of enemies.
+    pub enemy_health: f32,
+    /// Multiplier for the damage of enemies.
+    pub enemy_damage: f32,
+    /// Multiplier for the speed of enemies.
+    pub enemy_speed: f32,
+    /// Multiplier for the spawn counts of enemies.
+    pub enemy_spawn_count: f32,
+    /// Multiplier for the experience rewards.
+    pub experience: f64,
+    /// Multiplier for the balance rewards.
+    pub balance: f64,
+}
+
+impl DifficultyModifiers {
+    /// Gets the modifiers of a difficulty.
+    pub fn of(difficulty: SelectedDifficulty) -> DifficultyModifiers {
+        let tier = (difficulty.get() - 1) as f32;
+        DifficultyModifiers {
+            enemy_health: 1.00 + (tier * ENEMY_HEALTH_INCREASE_PER_DIFFICULTY),
+            enemy_damage: 1.00 + (tier * ENEMY_DAMAGE_INCREASE_PER_DIFFICULTY),
+            enemy_speed: 1.00 + (tier * ENEMY_SPEED_INCREASE_PER_DIFFICULTY),
+            enemy_spawn_count: 1.00 + (tier * ENEMY_SPAWN_COUNT_INCREASE_PER_DIFFICULTY),
+            experience: 1.00 + (tier as f64 * EXPERIENCE_REWARD_INCREASE_PER_DIFFICULTY),
+            balance: 1.00 + (tier as f64 * BALANCE_REWARD_INCREASE_PER_DIFFICULTY),
+        }
+    }
 }
 
-impl Default for LevelUpRewards {
-    fn default() -> LevelUpRewards {
-        LevelUpRewards { health: Health(0.00), perks: Vec::new() }
+impl Default for DifficultyModifiers {
+    fn default() -> DifficultyModifiers {
+        DifficultyModifiers::of(SelectedDifficulty::default())
     }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    commands::*,
     constants::*,
     localization,
     prelude::*,
@@ -6,13 +7,125 @@ use crate::{
 };
 
 
+/// Applies the wave command.
+pub fn apply_wave_command(
+    mut commands: Commands,
+    mut current_wave_text_query: Query<&mut LocalizedText, With<CurrentWaveText>>,
+    game_mode: Option<Res<GameMode<Survival>>>,
+    current_wave: Option<ResMut<CurrentWave>>,
+    wave_timer: Option<Res<WaveTimer>>,
+    game_state: Res<State<GameState>>,
+    mut game_state_stack: ResMut<GameStateStack>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut command: ConsoleCommand<WaveCommand>,
+) {
+    if let Some(Ok(WaveCommand { subcommand })) = command.take() {
+        let mut current_wave = match (game_mode, current_wave) {
+            (Some(_), Some(current_wave)) => current_wave,
+            _ => {
+                reply!(command, "Not available outside the survival mode.");
+                reply!(command, "");
+                return;
+            },
+        };
+        let wave_is_in_progress = wave_timer.is_some() && *game_state.get() == GameState::Playing;
+
+        match subcommand {
+            WaveCommands::Show => {
+                reply!(command, "{}/{}", current_wave.get(), WAVES);
+            },
+            WaveCommands::Skip => {
+                if wave_is_in_progress {
+                    game_state_stack.transition(GameState::Won);
+                    next_game_state.set(GameState::Transition);
+                    reply!(command, "Skipped.");
+                } else {
+                    reply!(command, "Only available during the waves.");
+                }
+            },
+            WaveCommands::Set { wave } => {
+                if wave.get() > WAVES {
+                    reply!(command, "Wave must be between 1 and {}.", WAVES);
+                } else if wave_is_in_progress {
+                    commands.insert_resource(NextWave(wave));
+                    game_state_stack.transition(GameState::Won);
+                    next_game_state.set(GameState::Transition);
+                    reply!(command, "Set, will take effect after the market.");
+                } else if wave_timer.is_none() {
+                    *current_wave = CurrentWave(wave);
+                    if let Ok(mut current_wave_text) = current_wave_text_query.get_single_mut() {
+                        *current_wave_text = localization::current_wave(&current_wave);
+                    }
+                    reply!(command, "Set.");
+                } else {
+                    reply!(command, "Only available during or between the waves.");
+                }
+            },
+        }
+
+        reply!(command, "");
+    }
+}
+
+/// Applies the timer command.
+pub fn apply_timer_command(
+    game_mode: Option<Res<GameMode<Survival>>>,
+    wave_timer: Option<ResMut<WaveTimer>>,
+    mut command: ConsoleCommand<TimerCommand>,
+) {
+    if let Some(Ok(TimerCommand { subcommand })) = command.take() {
+        if game_mode.is_none() {
+            reply!(command, "Not available outside the survival mode.");
+            reply!(command, "");
+            return;
+        }
+        let mut wave_timer = match wave_timer {
+            Some(wave_timer) => wave_timer,
+            None => {
+                reply!(command, "Only available during the waves.");
+                reply!(command, "");
+                return;
+            },
+        };
+
+        match subcommand {
+            TimerCommands::Show => {
+                reply!(command, "{:.2}", wave_timer.remaining_secs());
+            },
+            TimerCommands::Set { seconds } => {
+                match Duration::try_from_secs_f32(seconds) {
+                    Ok(remaining) => {
+                        let duration = wave_timer.elapsed() + remaining;
+                        wave_timer.set_duration(duration);
+                        log::info!("setting the remaining time of the wave to {:?}", remaining);
+                        reply!(command, "Set.");
+                    },
+                    Err(_) => {
+                        reply!(command, "Seconds must be a non-negative number.");
+                    },
+                }
+            },
+        }
+
+        reply!(command, "");
+    }
+}
+
+
 /// Initializes the game mode.
 pub fn initialize(
     mut commands: Commands,
     hud_query: Query<Entity, With<Hud>>,
     asset_server: Res<AssetServer>,
+    number_format: Res<NumberFormat>,
     localization: Res<Localization>,
+    selected_difficulty: Res<SelectedDifficulty>,
+    survival_mode_args: Res<SurvivalModeArgs>,
+    mut level_up_screen_configuration: ResMut<LevelUpScreenConfiguration>,
 ) {
+    let difficulty_modifiers = DifficultyModifiers::of(*selected_difficulty);
+    log::info!("difficulty modifiers:\n{:#?}", difficulty_modifiers);
+
     let wave_durations = WaveDurations::new(WAVES);
     let current_wave = CurrentWave::default();
     let level_up_rewards = LevelUpRewards::default();
@@ -65,7 +178,7 @@ pub fn initialize(
                         TextBundle {
                             text: Text {
                                 sections: vec![TextSection::new(
-                                    format!("{:.0}", wave_duration.as_secs_f32().ceil()),
+                                    number_format.seconds(wave_duration),
                                     TextStyle {
                                         font: asset_server.load("fonts/FiraSans-Bold.ttf"),
                                         font_size: REMAINING_SECONDS_TEXT_FONT_SIZE,
@@ -82,11 +195,63 @@ pub fn initialize(
         });
     }
 
+    level_up_screen_configuration.apply_limits(LEVEL_UP_SCREEN_LIMITS);
+    level_up_screen_configuration.skip_reward =
+        Balance(current_wave.get() as f64 * difficulty_modifiers.balance);
+
+    commands.insert_resource(ExperienceToBalanceRatio(
+        difficulty_modifiers.balance / difficulty_modifiers.experience,
+    ));
+    let leftover_experience_points =
+        survival_mode_args.leftover_experience_points.unwrap_or(DEFAULT_LEFTOVER_EXPERIENCE_POINTS);
+    log::info!(
+        "experience points left on the map at the end of the waves will be handled with {:?}",
+        leftover_experience_points.to_string(),
+    );
+    commands.insert_resource(leftover_experience_points);
+
+    commands.insert_resource(difficulty_modifiers);
     commands.insert_resource(wave_durations);
     commands.insert_resource(current_wave);
     commands.insert_resource(level_up_rewards);
 }
 
+/// Selects the difficulty from the arguments of the survival game mode.
+pub fn select_difficulty_when_starting_in_game(
+    args: Res<Args>,
+    survival_mode_args: Res<SurvivalModeArgs>,
+    progression: Res<Persistent<Progression>>,
+    mut selected_difficulty: ResMut<SelectedDifficulty>,
+) {
+    if args.start_in_game {
+        if let Some(difficulty) = &survival_mode_args.start_in_game_difficulty {
+            let mut difficulty = difficulty.get();
+            if difficulty > DIFFICULTIES {
+                log::warn!(
+                    "difficulty {} doesn't exist, selecting the highest difficulty {} instead",
+                    difficulty,
+                    DIFFICULTIES,
+                );
+                difficulty = DIFFICULTIES;
+            }
+
+            let survival = Survival.id();
+            if !progression.is_difficulty_unlocked(&survival, difficulty) {
+                let highest_unlocked_difficulty =
+                    (progression.highest_won_difficulty(&survival) + 1).min(DIFFICULTIES);
+                log::warn!(
+                    "difficulty {} isn't unlocked yet, selecting difficulty {} instead",
+                    difficulty,
+                    highest_unlocked_difficulty,
+                );
+                difficulty = highest_unlocked_difficulty;
+            }
+
+            *selected_difficulty = SelectedDifficulty(NonZeroU8::new(difficulty).unwrap());
+        }
+    }
+}
+
 /// Selects the wave from the arguments of the survival game mode.
 pub fn select_wave_when_starting_in_game(
     args: Res<Args>,
@@ -107,6 +272,7 @@ pub fn load(
     mut remaining_seconds_text_query: Query<&mut Text, With<RemainingSecondsText>>,
     current_wave: Res<CurrentWave>,
     wave_durations: Res<WaveDurations>,
+    number_format: Res<NumberFormat>,
 ) {
     log::info!("starting wave {}", current_wave.0);
 
@@ -115,54 +281,24 @@ pub fn load(
     commands.insert_resource(WaveTimer::new(wave_duration));
 
     if let Ok(mut remaining_seconds_text) = remaining_seconds_text_query.get_single_mut() {
-        remaining_seconds_text.sections[0].value =
-            format!("{:.0}", wave_duration.as_secs_f32().ceil());
+        remaining_seconds_text.sections[0].value = number_format.seconds(wave_duration);
+    }
+}
+
+/// Scales the spawn counts in the enemy spawn pattern according to the selected difficulty.
+pub fn scale_enemy_spawn_pattern(
+    enemy_spawn_pattern: Res<EnemySpawnPattern>,
+    difficulty_modifiers: Res<DifficultyModifiers>,
+) {
+    for spawn in enemy_spawn_pattern.spawns.lock().unwrap().iter_mut() {
+        let scaled_count = (spawn.count as f32 * difficulty_modifiers.enemy_spawn_count).round();
+        spawn.count = (scaled_count as u32).max(1);
     }
 }
 
 /// Spawns the map.
 pub fn spawn_map(mut commands: Commands) {
-    commands.insert_resource(MAP_BOUNDS);
-    commands.spawn((Name::new("Map"), Map, SpatialBundle::default())).with_children(|parent| {
-        // Spawn horizontal lines.
-        for i in 0..=GRID_SIZE {
-            parent.spawn((
-                Name::new(format!("Horizontal Line {}", i + 1)),
-                SpriteBundle {
-                    transform: Transform::from_translation(Vec3::new(
-                        0.00,
-                        (((GRID_SIZE as f32) / 2.00) - (i as f32)) * GRID_SPACING,
-                        Depth::Map.z(),
-                    )),
-                    sprite: Sprite {
-                        color: GRID_COLOR,
-                        custom_size: Some(Vec2::new(GRID_SIZE as f32 * GRID_SPACING, GRID_WIDTH)),
-                        ..default()
-                    },
-                    ..default()
-                },
-            ));
-        }
-        // Spawn vertical lines.
-        for i in 0..=GRID_SIZE {
-            parent.spawn((
-                Name::new(format!("Vertical Line {}", i + 1)),
-                SpriteBundle {
-                    transform: Transform::from_translation(Vec3::new(
-                        ((i as f32) - ((GRID_SIZE as f32) / 2.00)) * GRID_SPACING,
-                        0.00,
-                        Depth::Map.z(),
-                    )),
-                    sprite: Sprite {
-                        color: GRID_COLOR,
-                        custom_size: Some(Vec2::new(GRID_WIDTH, GRID_SIZE as f32 * GRID_SPACING)),
-                        ..default()
-                    },
-                    ..default()
-                },
-            ));
-        }
-    });
+    utils::map::spawn_grid_map(&mut commands, MAP_GRID);
 }
 
 
@@ -173,11 +309,11 @@ pub fn tick(
     mut wave_timer: ResMut<WaveTimer>,
     mut game_state_stack: ResMut<GameStateStack>,
     mut next_game_state: ResMut<NextState<GameState>>,
+    number_format: Res<NumberFormat>,
 ) {
     wave_timer.tick(time.delta());
     if let Ok(mut remaining_seconds_text) = remaining_seconds_text_query.get_single_mut() {
-        remaining_seconds_text.sections[0].value =
-            format!("{:.0}", wave_timer.remaining_secs().ceil());
+        remaining_seconds_text.sections[0].value = number_format.seconds(wave_timer.remaining());
     }
 
     if wave_timer.just_finished() {
@@ -186,94 +322,19 @@ pub fn tick(
     }
 }
 
-/// Processes player level change.
-pub fn level_change(
-    mut commands: Commands,
-    mut player_query: Query<
-        (&Level, &mut Health, &mut RemainingHealth),
-        (With<Player>, Changed<Level>),
-    >,
-    mut level_up_rewards: ResMut<LevelUpRewards>,
-    registered_systems: Res<RegisteredSystems>,
-) {
-    if let Ok((player_level, mut player_health, mut player_remaining_health)) =
-        player_query.get_single_mut()
-    {
-        {
-            let expected_bonus_health = (player_level.get() as f32) - 1.00;
-            if level_up_rewards.health.0 != expected_bonus_health {
-                let difference = expected_bonus_health - level_up_rewards.health.0;
-
-                if difference > 0.00 {
-                    log::info!(
-                        "increasing the player health by {} for leveling up to level {}",
-                        difference,
-                        player_level.get(),
-                    );
-                } else {
-                    log::info!(
-                        "decreasing the player health by {} for leveling down to level {}",
-                        difference.abs(),
-                        player_level.get(),
-                    );
-                }
-
-                player_health.0 += difference;
-                player_remaining_health.0 += difference;
-
-                level_up_rewards.health.0 += difference;
-            }
-        }
-
-        {
-            let expected_number_of_perks = (player_level.get() as usize) - 1;
-            if level_up_rewards.perks.len() > expected_number_of_perks {
-                let mut level_to_lose = level_up_rewards.perks.len();
-                for perk in level_up_rewards.perks[expected_number_of_perks..].iter_mut().rev() {
-                    commands.run_system_with_input(
-                        registered_systems.perk.lose_perk,
-                        (
-                            perk.clone(),
-                            ObtainLosePerkReason::LevelingDown {
-                                to: Level::new(level_to_lose as u16),
-                            },
-                        ),
-                    );
-                    level_to_lose -= 1;
-                }
-            }
-            level_up_rewards.perks.truncate(expected_number_of_perks);
-        }
-    }
-}
-
-/// Processes obtaining a new perk.
-pub fn obtain_perk(
-    mut level_up_rewards: ResMut<LevelUpRewards>,
-    mut perk_obtained_event_reader: EventReader<PerkObtainedEvent>,
-) {
-    for event in perk_obtained_event_reader.read() {
-        if matches!(event.reason, ObtainLosePerkReason::LevelingUp { .. }) {
-            level_up_rewards.perks.push(event.perk.clone());
-        }
-    }
-}
-
 
 /// Wins the current wave.
 pub fn win(
     mut commands: Commands,
     mut current_wave_text_query: Query<&mut LocalizedText, With<CurrentWaveText>>,
-    mut player_query: Query<(&Level, &mut RemainingHealth, &Health), With<Player>>,
     mut current_wave: ResMut<CurrentWave>,
-    level_up_rewards: Res<LevelUpRewards>,
-    mut market_configuration: ResMut<MarketConfiguration>,
-    mut level_up_screen_configuration: ResMut<LevelUpScreenConfiguration>,
+    next_wave: Option<Res<NextWave>>,
+    difficulty_modifiers: Res<DifficultyModifiers>,
     mut game_state_stack: ResMut<GameStateStack>,
     mut next_game_state: ResMut<NextState<GameState>>,
     registered_systems: Res<RegisteredSystems>,
 ) {
-    if current_wave.is_last() {
+    if current_wave.is_last() && next_wave.is_none() {
         log::info!("game won!");
         commands.insert_resource(GameResult::Won);
 
@@ -282,47 +343,71 @@ pub fn win(
     } else {
         log::info!("wave {} won", current_wave.0);
 
-        let refresh_cost =
-            MarketRefreshCost::exponential(Balance(current_wave.get() as f64), 1.50, None);
-        log::info!("setting the refresh cost model of the market to {}", refresh_cost);
-        market_configuration.refresh_cost = refresh_cost;
-
-        commands.run_system(registered_systems.market.refresh_market);
-
-        let reroll_cost =
-            LevelUpScreenRerollCost::exponential(Balance(current_wave.get() as f64), 1.50, None);
-        log::info!("setting the reroll cost model of the level up screen to {}", reroll_cost);
-        level_up_screen_configuration.reroll_cost = reroll_cost;
+        commands.run_system_with_input(
+            registered_systems.market.take_market_break,
+            MarketBreak {
+                base_cost: Balance(current_wave.get() as f64),
+                skip_reward: Balance(
+                    (current_wave.get() + 1) as f64 * difficulty_modifiers.balance,
+                ),
+            },
+        );
+
+        match next_wave {
+            Some(next_wave) => {
+                log::info!("jumping to wave {}", next_wave.0);
+                commands.remove_resource::<NextWave>();
+                *current_wave = CurrentWave(next_wave.0);
+            },
+            None => {
+                current_wave.increment();
+            },
+        }
 
-        game_state_stack.pop();
-        game_state_stack.push(GameState::Loading);
-        game_state_stack.push(GameState::Market);
+        if let Ok(mut current_wave_text) = current_wave_text_query.get_single_mut() {
+            *current_wave_text = localization::current_wave(&current_wave);
+        }
+    }
+}
 
-        if let Ok((player_level, mut player_remaining_health, player_health)) =
-            player_query.get_single_mut()
-        {
-            log::info!("resetting player health to {}", player_health.0);
-            player_remaining_health.0 = player_health.0;
 
-            let reward_count = ((player_level.get() as usize) - 1) - level_up_rewards.perks.len();
-            for _ in 0..reward_count {
-                game_state_stack.push(GameState::LevelUpScreen);
-            }
+/// Scales the properties of the spawned enemies according to the selected difficulty.
+pub fn scale_enemies(
+    mut enemy_query: Query<
+        (
+            &mut Health,
+            &mut RemainingHealth,
+            Option<&mut Damage>,
+            &mut AttractionSpeed,
+            &mut Experience,
+        ),
+        Added<Enemy>,
+    >,
+    difficulty_modifiers: Res<DifficultyModifiers>,
+) {
+    for (mut health, mut remaining_health, damage, mut attraction_speed, mut experience) in
+        enemy_query.iter_mut()
+    {
+        health.0 *= difficulty_modifiers.enemy_health;
+        remaining_health.0 = health.0;
 
-            if reward_count > 0 {
-                commands.insert_resource(LevelUpScreenReason::LevelingUp {
-                    to: Level::new(level_up_rewards.perks.len() as u16 + 2),
-                });
-            }
+        if let Some(mut damage) = damage {
+            damage.0 *= difficulty_modifiers.enemy_damage;
         }
 
-        next_game_state.set(GameState::Transition);
+        attraction_speed.scale(difficulty_modifiers.enemy_speed);
 
-        current_wave.increment();
+        experience.0 *= difficulty_modifiers.experience;
+    }
+}
 
-        if let Ok(mut current_wave_text) = current_wave_text_query.get_single_mut() {
-            *current_wave_text = localization::current_wave(&current_wave);
-        }
+/// Scales the damage of the projectiles shot toward players according to the selected difficulty.
+pub fn scale_enemy_projectiles(
+    mut projectile_query: Query<&mut Damage, (Added<Projectile>, With<DamagePlayerOnContact>)>,
+    difficulty_modifiers: Res<DifficultyModifiers>,
+) {
+    for mut damage in projectile_query.iter_mut() {
+        damage.0 *= difficulty_modifiers.enemy_damage;
     }
 }
 
@@ -336,4 +421,6 @@ pub fn unload(mut commands: Commands) {
 /// Deinitializes the game mode.
 pub fn deinitialize(mut commands: Commands) {
     commands.remove_resource::<CurrentWave>();
+    commands.remove_resource::<NextWave>();
+    commands.remove_resource::<DifficultyModifiers>();
 }
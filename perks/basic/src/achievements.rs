@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// Achievement for reaching level 10 in a run.
+#[derive(Debug)]
+pub struct Seasoned;
+
+impl IAchievement for Seasoned {
+    fn id(&self) -> SmolStr {
+        "seasoned".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "seasoned-name",
+            args: smallvec![],
+            fallback: "Seasoned".into(),
+        }
+    }
+
+    fn description(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "seasoned-description",
+            args: smallvec![],
+            fallback: "Reach level 10 in a run.".into(),
+        }
+    }
+
+    fn condition(&self) -> AchievementCondition {
+        AchievementCondition::ReachLevel(10)
+    }
+}
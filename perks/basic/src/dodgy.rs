@@ -39,6 +39,21 @@ impl IPerk for Dodgy {
         self.rarity
     }
 
+    fn exclusions(&self) -> Vec<SmolStr> {
+        match self.rarity {
+            Rarity::Legendary => vec![Healthy { rarity: Rarity::Legendary }.id()],
+            _ => Vec::new(),
+        }
+    }
+
+    fn upgrades(&self) -> Option<SmolStr> {
+        self.rarity.previous().map(|rarity| Dodgy { rarity }.id())
+    }
+
+    fn replaces_upgraded_perk(&self) -> bool {
+        true
+    }
+
     fn obtain(&self, world: &mut World) {
         world.run_system_once_with(*self, obtain);
     }
@@ -50,14 +65,14 @@ impl IPerk for Dodgy {
 
 /// Obtains the perk.
 pub fn obtain(In(perk): In<Dodgy>, mut player_query: Query<&mut DodgeChance, With<Player>>) {
-    if let Ok(mut player_dodge_chance) = player_query.get_single_mut() {
+    for mut player_dodge_chance in player_query.iter_mut() {
         player_dodge_chance.0 += perk.delta_dodge_chance();
     }
 }
 
 /// Loses the perk.
 pub fn lose(In(perk): In<Dodgy>, mut player_query: Query<&mut DodgeChance, With<Player>>) {
-    if let Ok(mut player_dodge_chance) = player_query.get_single_mut() {
+    for mut player_dodge_chance in player_query.iter_mut() {
         player_dodge_chance.0 -= perk.delta_dodge_chance();
     }
 }
@@ -39,6 +39,14 @@ impl IPerk for Healthy {
         self.rarity
     }
 
+    fn upgrades(&self) -> Option<SmolStr> {
+        self.rarity.previous().map(|rarity| Healthy { rarity }.id())
+    }
+
+    fn replaces_upgraded_perk(&self) -> bool {
+        true
+    }
+
     fn obtain(&self, world: &mut World) {
         world.run_system_once_with(*self, obtain);
     }
@@ -53,7 +61,7 @@ pub fn obtain(
     In(perk): In<Healthy>,
     mut player_query: Query<(&mut Health, &mut RemainingHealth), With<Player>>,
 ) {
-    if let Ok((mut player_health, mut player_remaining_health)) = player_query.get_single_mut() {
+    for (mut player_health, mut player_remaining_health) in player_query.iter_mut() {
         player_health.0 += perk.health_bonus();
         player_remaining_health.0 += perk.health_bonus();
     }
@@ -64,7 +72,7 @@ pub fn lose(
     In(perk): In<Healthy>,
     mut player_query: Query<(&mut Health, &mut RemainingHealth), With<Player>>,
 ) {
-    if let Ok((mut player_health, mut player_remaining_health)) = player_query.get_single_mut() {
+    for (mut player_health, mut player_remaining_health) in player_query.iter_mut() {
         player_health.0 -= perk.health_bonus();
         player_remaining_health.0 -= perk.health_bonus();
     }
@@ -1,7 +1,9 @@
+pub mod achievements;
 pub mod plugin;
 pub mod prelude;
 
 pub mod dodgy;
 pub mod healthy;
+pub mod lucky;
 pub mod regenerative;
 pub mod speedy;
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+/// Lucky perk which increases the luck of the player.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+pub struct Lucky {
+    pub rarity: Rarity,
+}
+
+impl Lucky {
+    pub fn delta_luck(&self) -> f32 {
+        5.00 * (self.rarity.level() as f32)
+    }
+
+    pub fn unlock_cost(&self) -> Ambrosia {
+        Ambrosia(10 * (self.rarity.level() as u64))
+    }
+}
+
+impl IPerk for Lucky {
+    fn id(&self) -> SmolStr {
+        format_smolstr!("lucky-{}", self.rarity.id())
+    }
+
+    fn name(&self) -> LocalizedText {
+        let rarity = self.rarity.name();
+        LocalizedText::Localized {
+            key: "lucky-name",
+            args: smallvec![("rarity", rarity.into())],
+            fallback: format!("Lucky {}", rarity).into(),
+        }
+    }
+
+    fn description(&self) -> LocalizedText {
+        let bonus = self.delta_luck();
+        LocalizedText::Localized {
+            key: "lucky-description",
+            args: smallvec![("bonus", format_smolstr!("{:.0}", bonus))],
+            fallback: format!("+{:.0} Luck", bonus).into(),
+        }
+    }
+
+    fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+
+    fn upgrades(&self) -> Option<SmolStr> {
+        self.rarity.previous().map(|rarity| Lucky { rarity }.id())
+    }
+
+    fn obtain(&self, world: &mut World) {
+        world.run_system_once_with(*self, obtain);
+    }
+
+    fn lose(&self, world: &mut World) {
+        world.run_system_once_with(*self, lose);
+    }
+}
+
+/// Obtains the perk.
+pub fn obtain(In(perk): In<Lucky>, mut player_query: Query<&mut Luck, With<Player>>) {
+    for mut player_luck in player_query.iter_mut() {
+        player_luck.0 += perk.delta_luck();
+    }
+}
+
+/// Loses the perk.
+pub fn lose(In(perk): In<Lucky>, mut player_query: Query<&mut Luck, With<Player>>) {
+    for mut player_luck in player_query.iter_mut() {
+        player_luck.0 -= perk.delta_luck();
+    }
+}
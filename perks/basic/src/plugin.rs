@@ -10,6 +10,7 @@ impl Plugin for BasicPerksPlugin {
         app.register_type::<Speedy>();
         app.register_type::<Dodgy>();
         app.register_type::<Regenerative>();
+        app.register_type::<Lucky>();
 
         // Setup localization.
         app.world_mut().resource_mut::<LocaleAssets>().push("content/perks/basic.ftl");
@@ -23,6 +24,11 @@ impl Plugin for BasicPerksPlugin {
             perk_registry.register(Speedy { rarity });
             perk_registry.register(Dodgy { rarity });
             perk_registry.register(Regenerative { rarity });
+            perk_registry.register(Lucky { rarity }).unlockable(Lucky { rarity }.unlock_cost());
         }
+
+        // Register achievements.
+        let mut achievement_registry = app.world_mut().resource_mut::<AchievementRegistry>();
+        achievement_registry.register(Seasoned);
     }
 }
@@ -1,6 +1,8 @@
 pub use crate::{
+    achievements::Seasoned,
     dodgy::Dodgy,
     healthy::Healthy,
+    lucky::Lucky,
     plugin::BasicPerksPlugin,
     regenerative::Regenerative,
     speedy::Speedy,
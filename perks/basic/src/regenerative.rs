@@ -44,6 +44,18 @@ impl IPerk for Regenerative {
         self.rarity
     }
 
+    fn prerequisites(&self) -> Vec<SmolStr> {
+        vec![Healthy { rarity: Rarity::Common }.id()]
+    }
+
+    fn upgrades(&self) -> Option<SmolStr> {
+        self.rarity.previous().map(|rarity| Regenerative { rarity }.id())
+    }
+
+    fn replaces_upgraded_perk(&self) -> bool {
+        true
+    }
+
     fn obtain(&self, world: &mut World) {
         world.run_system_once_with(*self, obtain);
     }
@@ -58,7 +70,7 @@ pub fn obtain(
     In(perk): In<Regenerative>,
     mut player_query: Query<&mut HpRegeneration, With<Player>>,
 ) {
-    if let Ok(mut player_hp_regeneration) = player_query.get_single_mut() {
+    for mut player_hp_regeneration in player_query.iter_mut() {
         player_hp_regeneration.0 += perk.delta_hp_regeneration();
     }
 }
@@ -68,7 +80,7 @@ pub fn lose(
     In(perk): In<Regenerative>,
     mut player_query: Query<&mut HpRegeneration, With<Player>>,
 ) {
-    if let Ok(mut player_hp_regeneration) = player_query.get_single_mut() {
+    for mut player_hp_regeneration in player_query.iter_mut() {
         player_hp_regeneration.0 -= perk.delta_hp_regeneration();
     }
 }
@@ -39,6 +39,14 @@ impl IPerk for Speedy {
         self.rarity
     }
 
+    fn upgrades(&self) -> Option<SmolStr> {
+        self.rarity.previous().map(|rarity| Speedy { rarity }.id())
+    }
+
+    fn replaces_upgraded_perk(&self) -> bool {
+        true
+    }
+
     fn obtain(&self, world: &mut World) {
         world.run_system_once_with(*self, obtain);
     }
@@ -50,14 +58,14 @@ impl IPerk for Speedy {
 
 /// Obtains the perk.
 pub fn obtain(In(perk): In<Speedy>, mut player_query: Query<&mut SpeedMultiplier, With<Player>>) {
-    if let Ok(mut player_speed_multiplier) = player_query.get_single_mut() {
+    for mut player_speed_multiplier in player_query.iter_mut() {
         player_speed_multiplier.0 += perk.delta_speed_multiplier();
     }
 }
 
 /// Loses the perk.
 pub fn lose(In(perk): In<Speedy>, mut player_query: Query<&mut SpeedMultiplier, With<Player>>) {
-    if let Ok(mut player_speed_multiplier) = player_query.get_single_mut() {
+    for mut player_speed_multiplier in player_query.iter_mut() {
         player_speed_multiplier.0 -= perk.delta_speed_multiplier();
     }
 }
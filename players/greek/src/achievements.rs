@@ -0,0 +1,61 @@
+use crate::prelude::*;
+
+/// Achievement for winning a run with "Artemis".
+#[derive(Debug)]
+pub struct MistressOfTheHunt;
+
+impl IAchievement for MistressOfTheHunt {
+    fn id(&self) -> SmolStr {
+        "mistress-of-the-hunt".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "mistress-of-the-hunt-name",
+            args: smallvec![],
+            fallback: "Mistress of the Hunt".into(),
+        }
+    }
+
+    fn description(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "mistress-of-the-hunt-description",
+            args: smallvec![],
+            fallback: "Win a run with Artemis.".into(),
+        }
+    }
+
+    fn condition(&self) -> AchievementCondition {
+        AchievementCondition::WinWithPlayer(Artemis.id())
+    }
+}
+
+/// Achievement for winning a run with "Hades".
+#[derive(Debug)]
+pub struct LordOfTheUnderworld;
+
+impl IAchievement for LordOfTheUnderworld {
+    fn id(&self) -> SmolStr {
+        "lord-of-the-underworld".into()
+    }
+
+    fn name(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "lord-of-the-underworld-name",
+            args: smallvec![],
+            fallback: "Lord of the Underworld".into(),
+        }
+    }
+
+    fn description(&self) -> LocalizedText {
+        LocalizedText::Localized {
+            key: "lord-of-the-underworld-description",
+            args: smallvec![],
+            fallback: "Win a run with Hades.".into(),
+        }
+    }
+
+    fn condition(&self) -> AchievementCondition {
+        AchievementCondition::WinWithPlayer(Hades.id())
+    }
+}
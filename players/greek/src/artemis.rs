@@ -31,8 +31,8 @@ impl IPlayer for Artemis {
         Collider::circle(SIZE)
     }
 
-    fn spawn(&self, world: &mut World) {
-        world.run_system_once_with(self.clone(), spawn);
+    fn spawn(&self, world: &mut World, index: PlayerIndex) {
+        world.run_system_once_with((self.clone(), index), spawn);
     }
 }
 
@@ -52,12 +52,13 @@ impl Plugin for ArtemisPlugin {
 
 /// Spawns the player.
 pub fn spawn(
-    In(player): In<Artemis>,
+    In((player, index)): In<(Artemis, PlayerIndex)>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    game_action_input_map: Res<InputMap<GameAction>>,
-    mut inventory: ResMut<Inventory>,
+    key_bindings: Res<Persistent<KeyBindings>>,
+    number_of_players: Res<NumberOfPlayers>,
+    player_gamepads: Res<PlayerGamepads>,
 ) {
     let mesh = MaterialMesh2dBundle {
         mesh: meshes.add(Circle::new(SIZE)).into(),
@@ -66,12 +67,15 @@ pub fn spawn(
         ..default()
     };
 
+    let mut inventory = Inventory::default();
+    inventory.add(BowOfArtemis.instantiate());
+
     PlayerBundle::builder()
         .player(player)
+        .index(index)
         .mesh(mesh)
-        .input(game_action_input_map.clone())
+        .input(GameAction::input_map_of(index, *number_of_players, &key_bindings, &player_gamepads))
+        .inventory(inventory)
         .build()
         .spawn(&mut commands);
-
-    inventory.add(BowOfArtemis.instantiate());
 }
@@ -9,6 +9,9 @@ pub const SIZE: f32 = 20.00;
 /// Color of the player.
 pub const COLOR: Color = Color::srgb(0.00, 0.00, 0.00);
 
+/// Cost of unlocking the player.
+pub const UNLOCK_COST: Ambrosia = Ambrosia(50);
+
 /// Tag component for the player "Hades".
 #[derive(Clone, Component, Debug, Default, Reflect)]
 #[reflect(Component)]
@@ -27,8 +30,8 @@ impl IPlayer for Hades {
         Collider::circle(SIZE)
     }
 
-    fn spawn(&self, world: &mut World) {
-        world.run_system_once_with(self.clone(), spawn);
+    fn spawn(&self, world: &mut World, index: PlayerIndex) {
+        world.run_system_once_with((self.clone(), index), spawn);
     }
 }
 
@@ -39,7 +42,7 @@ impl Plugin for HadesPlugin {
     fn build(&self, app: &mut App) {
         // Register the player.
         let mut player_registry = app.world_mut().resource_mut::<PlayerRegistry>();
-        player_registry.register(GreekMythology, Hades);
+        player_registry.register(GreekMythology, Hades).unlockable(UNLOCK_COST);
 
         // Register components.
         app.register_type::<Hades>();
@@ -48,12 +51,13 @@ impl Plugin for HadesPlugin {
 
 /// Spawns the player.
 pub fn spawn(
-    In(player): In<Hades>,
+    In((player, index)): In<(Hades, PlayerIndex)>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    game_action_input_map: Res<InputMap<GameAction>>,
-    mut inventory: ResMut<Inventory>,
+    key_bindings: Res<Persistent<KeyBindings>>,
+    number_of_players: Res<NumberOfPlayers>,
+    player_gamepads: Res<PlayerGamepads>,
 ) {
     let mesh = MaterialMesh2dBundle {
         mesh: meshes.add(Circle::new(SIZE)).into(),
@@ -62,12 +66,15 @@ pub fn spawn(
         ..default()
     };
 
+    let mut inventory = Inventory::default();
+    inventory.add(BidentOfHades.instantiate());
+
     PlayerBundle::builder()
         .player(player)
+        .index(index)
         .mesh(mesh)
-        .input(game_action_input_map.clone())
+        .input(GameAction::input_map_of(index, *number_of_players, &key_bindings, &player_gamepads))
+        .inventory(inventory)
         .build()
         .spawn(&mut commands);
-
-    inventory.add(BidentOfHades.instantiate());
 }
@@ -1,3 +1,4 @@
+pub mod achievements;
 pub mod mythology;
 pub mod plugin;
 pub mod prelude;
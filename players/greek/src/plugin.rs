@@ -15,5 +15,10 @@ impl Plugin for GreekPlayersPlugin {
         // Add sub-plugins.
         app.add_plugins(ArtemisPlugin);
         app.add_plugins(HadesPlugin);
+
+        // Register achievements.
+        let mut achievement_registry = app.world_mut().resource_mut::<AchievementRegistry>();
+        achievement_registry.register(MistressOfTheHunt);
+        achievement_registry.register(LordOfTheUnderworld);
     }
 }
@@ -1,4 +1,8 @@
 pub use crate::{
+    achievements::{
+        LordOfTheUnderworld,
+        MistressOfTheHunt,
+    },
     artemis::Artemis,
     hades::Hades,
     mythology::GreekMythology,
@@ -12,6 +12,9 @@ pub mod content {
         }
     }
     pub mod modes {
+        pub mod endless {
+            pub use mythmallow_mode_endless::*;
+        }
         pub mod survival {
             pub use mythmallow_mode_survival::*;
         }
@@ -5,6 +5,7 @@ use {
     mythmallow_enemies_sweet::prelude::*,
     mythmallow_game::prelude::*,
     mythmallow_items_greek::prelude::*,
+    mythmallow_mode_endless::prelude::*,
     mythmallow_mode_survival::prelude::*,
     mythmallow_perks_basic::prelude::*,
     mythmallow_players_greek::prelude::*,
@@ -59,6 +60,7 @@ fn main() {
 
     // Add game mode plugins.
     app.add_plugins(SurvivalModePlugin);
+    app.add_plugins(EndlessModePlugin);
     {
         let game_mode_registry = app.world().resource::<GameModeRegistry>();
         let number_of_game_modes = game_mode_registry.number_of_game_modes();
@@ -127,6 +129,16 @@ fn main() {
             if number_of_perks == 1 { "is" } else { "are" },
         );
     }
+    {
+        let achievement_registry = app.world().resource::<AchievementRegistry>();
+        let number_of_achievements = achievement_registry.number_of_achievements();
+        log::info!(
+            "{} achievement{} {} registered",
+            number_of_achievements,
+            if number_of_achievements == 1 { "" } else { "s" },
+            if number_of_achievements == 1 { "is" } else { "are" },
+        );
+    }
 
     // Start the application.
     log::info!("starting the application");